@@ -0,0 +1,6 @@
+// Las migraciones se incrustan en el binario con `sqlx::migrate!`; se recompila si cambian.
+fn main() {
+    println!("cargo:rerun-if-changed=database/migrations");
+    println!("cargo:rerun-if-changed=database/migrations-postgres");
+    println!("cargo:rerun-if-changed=database/migrations-sqlite");
+}
@@ -0,0 +1,3 @@
+-- Las devoluciones se reservan como `pending` antes de pedirlas a la pasarela y pasan a
+-- `completed` o `failed` según su respuesta. Las existentes ya se habían completado.
+alter table refund add column status varchar(10) not null default 'completed';
@@ -0,0 +1,14 @@
+-- Una línea del pedido solo puede estar en una devolución que no haya fallado. Las líneas de
+-- una devolución fallida dejan `active_order_item` en null y se pueden volver a devolver. Si ya
+-- había una línea repetida, queda activa en la primera devolución.
+alter table refund_item add column active_order_item int null;
+
+update refund_item
+set active_order_item = order_item
+where refund = (select min(ri.refund)
+                from refund_item ri
+                         join refund r on r.id = ri.refund
+                where r.status <> 'failed'
+                  and ri.order_item = refund_item.order_item);
+
+create unique index refund_item_active_order_item on refund_item (active_order_item);
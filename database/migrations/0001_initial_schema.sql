@@ -0,0 +1,337 @@
+-- Esquema inicial. Usa `if not exists` para que una base creada con el antiguo
+-- `database/schemas.sql` pueda adoptar las migraciones sin perder datos.
+
+create table if not exists `user_role`
+(
+    `code`        char        not null,
+    `description` varchar(20) not null
+);
+
+insert into `user_role` (`code`, `description`)
+select 'A', 'Administrador' from dual
+where not exists (select 1 from `user_role` where `code` = 'A');
+insert into `user_role` (`code`, `description`)
+select 'E', 'Empleado' from dual
+where not exists (select 1 from `user_role` where `code` = 'E');
+insert into `user_role` (`code`, `description`)
+select 'C', 'Cliente' from dual
+where not exists (select 1 from `user_role` where `code` = 'C');
+
+create table if not exists `user`
+(
+    `id`           int primary key auto_increment,
+    `name`         varchar(50)  not null,
+    `surname`      varchar(50)  not null,
+    `phone_number` varchar(20),
+    `email`        varchar(50)  not null,
+    `password`     varchar(100) not null,
+    `role`         char         not null default 'C',
+    `status`       char default 'V'
+);
+
+create table if not exists `address`
+(
+    `id`       int auto_increment,
+    `user`     int          not null,
+    `province` varchar(30)  not null,
+    `city`     varchar(30)  not null,
+    `address`  varchar(100) not null,
+    primary key (`id`, `user`)
+);
+
+create table if not exists `delivery_zone`
+(
+    `id`            int primary key auto_increment,
+    `province`      varchar(30) not null,
+    `city`          varchar(30),
+    `fee`           bigint      not null default 0,
+    `minimum_order` bigint      not null default 0,
+    `active`        bool        not null default true
+);
+
+create table if not exists `payment_method`
+(
+    `code`        varchar(5)  not null,
+    `description` varchar(20) not null,
+    `enabled`     bool default true
+);
+create table if not exists `category`
+(
+    `id`          int primary key auto_increment,
+    `name`        varchar(50) not null,
+    `description` varchar(200),
+    `tax_rate`    int
+);
+
+create table if not exists `product`
+(
+    `id`          int primary key auto_increment,
+    `category`    int         not null,
+    `name`        varchar(50) not null,
+    `description` varchar(200),
+    `price`       bigint      not null,
+    `image_url`   varchar(200),
+    `available`   bool        not null default true
+);
+
+create table if not exists `modifier_group`
+(
+    `id`             int primary key auto_increment,
+    `name`           varchar(50) not null,
+    `min_selections` int         not null default 0,
+    `max_selections` int         not null default 1
+);
+
+create table if not exists `modifier_option`
+(
+    `id`             int primary key auto_increment,
+    `modifier_group` int         not null,
+    `name`           varchar(50) not null,
+    `price_delta`    bigint      not null default 0,
+    `available`      bool        not null default true
+);
+
+create table if not exists `product_modifier_group`
+(
+    `product`        int not null,
+    `modifier_group` int not null,
+    primary key (`product`, `modifier_group`)
+);
+
+create table if not exists `orders`
+(
+    `id`                  int primary key auto_increment,
+    `user`                int         not null,
+    `status`              varchar(20) not null,
+    `currency`            char(3)     not null,
+    `subtotal`            bigint      not null,
+    `discount`            bigint      not null default 0,
+    `tax`                 bigint      not null default 0,
+    `service_charge`      bigint      not null default 0,
+    `tip`                 bigint      not null default 0,
+    `delivery_fee`        bigint      not null default 0,
+    `total`               bigint      not null,
+    `points_earned`       bigint      not null default 0,
+    `points_redeemed`     bigint      not null default 0,
+    `payment_method`      varchar(20) not null default 'card',
+    `claimed_by`          int,
+    `store_id`            int,
+    `scheduled_for`       datetime,
+    `release_at`          datetime,
+    `fulfilment_type`     varchar(20) not null default 'pickup',
+    `delivery_address_id` int,
+    `delivery_address`    varchar(255),
+    `courier_id`          int,
+    `created_at`          datetime    not null,
+    `updated_at`          datetime    not null
+);
+
+create table if not exists `order_item`
+(
+    `id`           int primary key auto_increment,
+    `order_id`     int         not null,
+    `product`      int         not null,
+    `product_name` varchar(50) not null,
+    `quantity`     int         not null,
+    `unit_price`   bigint      not null,
+    `tax_rate`     int         not null default 0
+);
+
+create table if not exists `order_item_option`
+(
+    `order_item`      int         not null,
+    `modifier_option` int         not null,
+    `name`            varchar(50) not null,
+    `price_delta`     bigint      not null
+);
+
+create table if not exists `cart_item`
+(
+    `id`       int primary key auto_increment,
+    `user`     int not null,
+    `product`  int not null,
+    `quantity` int not null
+);
+
+create table if not exists `cart_item_option`
+(
+    `cart_item`       int not null,
+    `modifier_option` int not null
+);
+
+create table if not exists `order_status_history`
+(
+    `id`         int primary key auto_increment,
+    `order_id`   int         not null,
+    `status`     varchar(20) not null,
+    `changed_by` int         not null,
+    `changed_at` datetime    not null
+);
+
+create table if not exists `payment`
+(
+    `id`             int primary key auto_increment,
+    `order_id`       int         not null,
+    `method`         varchar(20) not null,
+    `status`         varchar(20) not null,
+    `amount`         bigint      not null,
+    `reference`      varchar(64),
+    `card_last_four` char(4),
+    `created_at`     datetime    not null,
+    `updated_at`     datetime    not null
+);
+
+create table if not exists `payment_attempt`
+(
+    `id`             int primary key auto_increment,
+    `payment`        int          not null,
+    `operation`      varchar(20)  not null,
+    `amount`         bigint       not null,
+    `succeeded`      bool         not null,
+    `failure_reason` varchar(255),
+    `created_at`     datetime     not null
+);
+
+create table if not exists `refund`
+(
+    `id`              int primary key auto_increment,
+    `order_id`        int          not null,
+    `payment`         int          not null,
+    `amount`          bigint       not null,
+    `reason`          varchar(20)  not null,
+    `note`            varchar(255),
+    `idempotency_key` varchar(64)  not null,
+    `refunded_by`     int          not null,
+    `created_at`      datetime     not null,
+    unique (`order_id`, `idempotency_key`)
+);
+
+create table if not exists `refund_item`
+(
+    `refund`     int not null,
+    `order_item` int not null
+);
+
+create table if not exists `promotion`
+(
+    `id`                int primary key auto_increment,
+    `name`              varchar(100) not null,
+    `code`              varchar(50) unique,
+    `kind`              varchar(20)  not null,
+    `rate`              int,
+    `amount`            bigint,
+    `category`          int,
+    `starts_at`         datetime     not null,
+    `ends_at`           datetime,
+    `daily_starts`      time,
+    `daily_ends`        time,
+    `min_order_amount`  bigint       not null default 0,
+    `max_uses`          int,
+    `max_uses_per_user` int,
+    `stackable`         bool         not null default false,
+    `active`            bool         not null default true
+);
+
+create table if not exists `promotion_redemption`
+(
+    `id`         int primary key auto_increment,
+    `promotion`  int          not null,
+    `user`       int          not null,
+    `order_id`   int          not null,
+    `name`       varchar(100) not null,
+    `code`       varchar(50),
+    `amount`     bigint       not null,
+    `created_at` datetime     not null
+);
+
+create table if not exists `cart_coupon`
+(
+    `user`      int not null,
+    `promotion` int not null,
+    primary key (`user`, `promotion`)
+);
+
+create table if not exists `loyalty_entry`
+(
+    `id`         int primary key auto_increment,
+    `user`       int         not null,
+    `kind`       varchar(20) not null,
+    `points`     bigint      not null,
+    `order_id`   int,
+    `expires_at` datetime,
+    `created_at` datetime    not null
+);
+
+create table if not exists `ingredient`
+(
+    `id`                  int primary key auto_increment,
+    `name`                varchar(100) not null,
+    `unit`                varchar(20)  not null,
+    `stock`               bigint       not null default 0,
+    `low_stock_threshold` bigint       not null default 0
+);
+
+create table if not exists `product_ingredient`
+(
+    `product`    int    not null,
+    `ingredient` int    not null,
+    `quantity`   bigint not null,
+    primary key (`product`, `ingredient`)
+);
+
+create table if not exists `modifier_option_ingredient`
+(
+    `modifier_option` int    not null,
+    `ingredient`      int    not null,
+    `quantity`        bigint not null,
+    primary key (`modifier_option`, `ingredient`)
+);
+
+create table if not exists `stock_adjustment`
+(
+    `id`          int primary key auto_increment,
+    `ingredient`  int          not null,
+    `delta`       bigint       not null,
+    `reason`      varchar(20)  not null,
+    `note`        varchar(255),
+    `order_id`    int,
+    `adjusted_by` int          not null,
+    `created_at`  datetime     not null
+);
+
+create table if not exists `store`
+(
+    `id`                     int primary key auto_increment,
+    `name`                   varchar(100) not null,
+    `address`                varchar(255) not null,
+    `timezone`               varchar(64)  not null,
+    `active`                 bool         not null default true,
+    `pickup_slot_minutes`    int          not null default 15,
+    `pickup_slot_capacity`   int          not null default 10,
+    `pickup_release_minutes` int          not null default 10
+);
+
+create table if not exists `store_opening_hours`
+(
+    `store`   int  not null,
+    `weekday` int  not null,
+    `opens`   time not null,
+    `closes`  time not null
+);
+
+create table if not exists `store_exception`
+(
+    `store`  int  not null,
+    `date`   date not null,
+    `opens`  time,
+    `closes` time,
+    `reason` varchar(255),
+    primary key (`store`, `date`)
+);
+
+create table if not exists `store_unavailable_product`
+(
+    `store`   int not null,
+    `product` int not null,
+    primary key (`store`, `product`)
+);
@@ -0,0 +1,4 @@
+-- Estado de las cuentas: V (vigente), S (suspendida) o B (dada de baja, pendiente de purga).
+update `user` set `status` = 'V' where `status` is null;
+alter table `user` modify `status` char not null default 'V';
+alter table `user` add column `deleted_at` datetime null;
@@ -0,0 +1,2 @@
+-- Los códigos de medio de pago (`card`, `cash_on_pickup`) no entraban en varchar(5).
+alter table `payment_method` modify `code` varchar(20) not null;
@@ -0,0 +1,15 @@
+-- Una línea del pedido solo puede estar en una devolución que no haya fallado. Las líneas de
+-- una devolución fallida dejan `active_order_item` en null y se pueden volver a devolver. Si ya
+-- había una línea repetida, queda activa en la primera devolución.
+alter table `refund_item` add column `active_order_item` int null;
+
+update `refund_item` ri
+    join (select ri2.`order_item`, min(ri2.`refund`) as `refund`
+          from `refund_item` ri2
+                   join `refund` r on r.`id` = ri2.`refund`
+          where r.`status` <> 'failed'
+          group by ri2.`order_item`) first_refund
+    on first_refund.`refund` = ri.`refund` and first_refund.`order_item` = ri.`order_item`
+set ri.`active_order_item` = ri.`order_item`;
+
+create unique index `refund_item_active_order_item` on `refund_item` (`active_order_item`);
@@ -0,0 +1,369 @@
+//! Tareas de operación sobre la base de datos, pensadas para ejecutarse desde scripts:
+//! no piden nada por consola y terminan con código distinto de cero si fallan.
+//!
+//! ```text
+//! cafelatte-admin migrate [--check]
+//! cafelatte-admin seed
+//! cafelatte-admin create-admin --name Ana --surname Paz --email ana@cafe.com [--if-missing]
+//! cafelatte-admin reset-password --id 7
+//! cafelatte-admin list-users [--status suspended]
+//! cafelatte-admin suspend-user --id 7
+//! cafelatte-admin purge-users --older-than-days 30
+//! ```
+//!
+//! Las contraseñas se pueden pasar con `--password` o con la variable `CAFELATTE_PASSWORD`
+//! para que no queden en el historial de la consola.
+#[cfg(feature = "postgres")]
+use cafelatte::infrastructure::data::postgres;
+#[cfg(feature = "sqlite")]
+use cafelatte::infrastructure::data::sqlite;
+use cafelatte::{
+    core::{
+        entities::{user::User, user_status::UserStatus},
+        errors::user_errors::UserError,
+        ports::user_port::{IUserRepository, IUserService},
+        services::user_service::UserService,
+    },
+    infrastructure::{
+        config::{Config, DatabaseBackend},
+        data::{
+            backend::Backend,
+            migrations::{self, Migrations},
+            mysql,
+            pool::DbPool,
+            seed,
+        },
+        metrics::noop_metrics_recorder::NoopMetricsRecorder,
+    },
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use sqlx::{database::HasArguments, migrate::Migrate, Encode, Executor, IntoArguments, Pool, Type};
+use std::{future::Future, process, sync::Arc};
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "cafelatte-admin",
+    about = "Tareas de administración de cafelatte"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand, Debug, PartialEq)]
+enum Command {
+    /// Aplica las migraciones pendientes y muestra su estado.
+    Migrate {
+        /// Solo verifica que la base esté al día, sin aplicar nada.
+        #[arg(long)]
+        check: bool,
+    },
+    /// Inserta los roles y medios de pago que falten.
+    Seed,
+    /// Crea el primer administrador.
+    CreateAdmin {
+        #[arg(long)]
+        name: String,
+        #[arg(long)]
+        surname: String,
+        #[arg(long)]
+        email: String,
+        #[arg(long)]
+        phone_number: Option<String>,
+        #[arg(long, env = PASSWORD_ENV, hide_env_values = true)]
+        password: String,
+        /// Termina sin error si ya existe un administrador.
+        #[arg(long)]
+        if_missing: bool,
+    },
+    /// Asigna una nueva contraseña a un usuario.
+    ResetPassword {
+        #[arg(long)]
+        id: i32,
+        #[arg(long, env = PASSWORD_ENV, hide_env_values = true)]
+        password: String,
+    },
+    /// Lista los usuarios; por defecto, los que no están dados de baja.
+    ListUsers {
+        #[arg(long, value_enum)]
+        status: Option<StatusArg>,
+    },
+    /// Impide que un usuario inicie sesión.
+    SuspendUser {
+        #[arg(long)]
+        id: i32,
+    },
+    /// Vuelve a habilitar a un usuario suspendido.
+    ReactivateUser {
+        #[arg(long)]
+        id: i32,
+    },
+    /// Elimina definitivamente las cuentas dadas de baja.
+    PurgeUsers {
+        /// Solo purga las cuentas dadas de baja hace al menos esta cantidad de días.
+        #[arg(long, default_value_t = 30)]
+        older_than_days: u32,
+    },
+}
+
+const PASSWORD_ENV: &str = "CAFELATTE_PASSWORD";
+
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq)]
+enum StatusArg {
+    Active,
+    Suspended,
+    Deleted,
+}
+
+impl StatusArg {
+    fn to_user_status(self) -> UserStatus {
+        match self {
+            StatusArg::Active => UserStatus::Active,
+            StatusArg::Suspended => UserStatus::Suspended,
+            StatusArg::Deleted => UserStatus::Deleted,
+        }
+    }
+}
+
+#[actix_web::main]
+async fn main() {
+    env_logger::init_from_env(env_logger::Env::new().default_filter_or("warn"));
+    let cli = Cli::parse();
+
+    let config =
+        Config::load().unwrap_or_else(|err| fail(format!("Invalid configuration: {}", err)));
+
+    let result = match config.database.backend {
+        DatabaseBackend::MySql => {
+            let conn = connect(mysql::connect_to_database(&config.database)).await;
+            let repos = mysql::repositories(DbPool::new(conn.clone()));
+            run(cli.command, &conn, &user_service(repos.user, &config)).await
+        }
+        #[cfg(feature = "postgres")]
+        DatabaseBackend::Postgres => {
+            let conn = connect(postgres::connect_to_database(&config.database)).await;
+            let repos = postgres::repositories(DbPool::new(conn.clone()));
+            run(cli.command, &conn, &user_service(repos.user, &config)).await
+        }
+        #[cfg(feature = "sqlite")]
+        DatabaseBackend::Sqlite => {
+            let conn = connect(sqlite::connect_to_database(&config.database)).await;
+            let repos = sqlite::repositories(DbPool::new(conn.clone()));
+            run(cli.command, &conn, &user_service(repos.user, &config)).await
+        }
+        #[cfg(not(feature = "postgres"))]
+        DatabaseBackend::Postgres => unreachable!("rejected when loading the configuration"),
+        #[cfg(not(feature = "sqlite"))]
+        DatabaseBackend::Sqlite => unreachable!("rejected when loading the configuration"),
+        DatabaseBackend::Memory => {
+            fail("The admin CLI needs a MySQL, PostgreSQL or SQLite database".to_string())
+        }
+    };
+
+    if let Err(message) = result {
+        fail(message);
+    }
+}
+
+async fn connect<DB: sqlx::Database>(
+    connecting: impl Future<Output = Result<Pool<DB>, sqlx::Error>>,
+) -> Pool<DB> {
+    connecting
+        .await
+        .unwrap_or_else(|err| fail(format!("Cannot connect to database: {}", err)))
+}
+
+fn user_service<R: IUserRepository>(user_repository: R, config: &Config) -> UserService<R> {
+    UserService::new(
+        user_repository,
+        config.security.jwt_secret.clone(),
+        Arc::new(NoopMetricsRecorder),
+    )
+}
+
+async fn run<DB>(
+    command: Command,
+    conn: &Pool<DB>,
+    user_service: &dyn IUserService,
+) -> Result<(), String>
+where
+    DB: Migrations + Backend,
+    DB::Connection: Migrate,
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+{
+    match command {
+        Command::Migrate { check } => {
+            let result = if check {
+                migrations::check(conn).await
+            } else {
+                migrations::run(conn).await
+            };
+            let statuses = migrations::status(conn)
+                .await
+                .map_err(|err| err.to_string())?;
+            for migration in statuses {
+                println!(
+                    "{}\t{:?}\t{}",
+                    migration.version, migration.state, migration.description
+                );
+            }
+
+            result.map_err(|err| err.to_string())
+        }
+        Command::Seed => {
+            let report = seed::seed_reference_data(conn)
+                .await
+                .map_err(|err| err.to_string())?;
+            println!(
+                "Inserted {} roles and {} payment methods.",
+                report.roles, report.payment_methods
+            );
+
+            Ok(())
+        }
+        Command::CreateAdmin {
+            name,
+            surname,
+            email,
+            phone_number,
+            password,
+            if_missing,
+        } => {
+            let user = User {
+                name,
+                surname,
+                phone_number,
+                email,
+                password,
+                ..User::new()
+            };
+
+            match user_service.create_administrator(user).await {
+                Ok(user) => {
+                    println!(
+                        "Administrator created with ID {}.",
+                        user.id.unwrap_or_default()
+                    );
+                    Ok(())
+                }
+                Err(UserError::AdministratorExists) if if_missing => {
+                    println!("{}, nothing to do.", UserError::AdministratorExists);
+                    Ok(())
+                }
+                Err(err) => Err(err.to_string()),
+            }
+        }
+        Command::ResetPassword { id, password } => {
+            user_service
+                .reset_password(id, password)
+                .await
+                .map_err(|err| err.to_string())?;
+            println!("Password updated.");
+
+            Ok(())
+        }
+        Command::ListUsers { status } => {
+            let users = match status {
+                Some(status) => {
+                    user_service
+                        .get_users_by_status(status.to_user_status())
+                        .await
+                }
+                None => user_service.get_users().await,
+            }
+            .map_err(|err| err.to_string())?;
+
+            for user in users {
+                println!(
+                    "{}\t{}\t{} {}\t{:?}\t{:?}",
+                    user.id.unwrap_or_default(),
+                    user.email,
+                    user.name,
+                    user.surname,
+                    user.role,
+                    user.status
+                );
+            }
+
+            Ok(())
+        }
+        Command::SuspendUser { id } => {
+            user_service
+                .suspend_user(id)
+                .await
+                .map_err(|err| err.to_string())?;
+            println!("User suspended.");
+
+            Ok(())
+        }
+        Command::ReactivateUser { id } => {
+            user_service
+                .reactivate_user(id)
+                .await
+                .map_err(|err| err.to_string())?;
+            println!("User reactivated.");
+
+            Ok(())
+        }
+        Command::PurgeUsers { older_than_days } => {
+            let purged = user_service
+                .purge_deleted_users(older_than_days)
+                .await
+                .map_err(|err| err.to_string())?;
+            println!("Purged {} users.", purged);
+
+            Ok(())
+        }
+    }
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("{}", message);
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod admin_tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    fn parse(args: &[&str]) -> Result<Command, clap::Error> {
+        Cli::try_parse_from([&["cafelatte-admin"], args].concat()).map(|cli| cli.command)
+    }
+
+    #[test]
+    fn test_cli_definition_is_valid() {
+        Cli::command().debug_assert();
+    }
+
+    #[test]
+    fn test_commands_are_parsed_from_flags() {
+        assert_eq!(
+            parse(&["purge-users"]).unwrap(),
+            Command::PurgeUsers {
+                older_than_days: 30
+            }
+        );
+        assert_eq!(
+            parse(&["list-users", "--status", "suspended"]).unwrap(),
+            Command::ListUsers {
+                status: Some(StatusArg::Suspended)
+            }
+        );
+        assert_eq!(
+            parse(&["reset-password", "--id", "7", "--password", "n3w-p4ssw0rd"]).unwrap(),
+            Command::ResetPassword {
+                id: 7,
+                password: "n3w-p4ssw0rd".to_string()
+            }
+        );
+    }
+
+    #[test]
+    fn test_missing_required_flags_are_rejected() {
+        assert!(parse(&["suspend-user"]).is_err());
+        assert!(parse(&["list-users", "--status", "archived"]).is_err());
+    }
+}
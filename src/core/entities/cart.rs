@@ -0,0 +1,152 @@
+use super::{
+    money::{Currency, Money},
+    order::{OrderItem, OrderItemOption},
+    pricing::{PriceBreakdown, PricedLine, PricingRules},
+    promotion::{AppliedDiscount, PromotionLine},
+};
+use crate::infrastructure::data::models::cart::CartItem as CartItemModel;
+
+/// Carrito de un usuario, valorizado con los precios actuales del catálogo.
+#[derive(Clone, Debug)]
+pub struct Cart {
+    pub user_id: i32,
+    pub items: Vec<CartItem>,
+    /// Códigos de cupón canjeados; sólo descuentan mientras la promoción siga siendo válida.
+    pub coupon_codes: Vec<String>,
+    pub discounts: Vec<AppliedDiscount>,
+    pub pricing: PriceBreakdown,
+}
+
+#[derive(Clone, Debug)]
+pub struct CartItem {
+    pub id: Option<i32>,
+    pub product_id: i32,
+    pub product_name: String,
+    /// Categoría del producto, usada para evaluar las promociones.
+    pub category_id: i32,
+    pub quantity: i32,
+    pub options: Vec<OrderItemOption>,
+    /// Precio unitario en centavos sin IVA, calculado al leer el carrito.
+    pub unit_price: i64,
+    /// IVA de la categoría del producto en puntos básicos.
+    pub tax_rate: i32,
+    /// Motivo por el cual el ítem ya no se puede pedir; `None` si está disponible.
+    pub unavailable_reason: Option<String>,
+}
+
+impl Cart {
+    pub fn new(user_id: i32) -> Cart {
+        Cart {
+            user_id,
+            items: vec![],
+            coupon_codes: vec![],
+            discounts: vec![],
+            pricing: PriceBreakdown::zero(Currency::default()),
+        }
+    }
+
+    /// Líneas disponibles con las que se evalúan las promociones.
+    pub fn promotion_lines(&self, currency: Currency) -> Vec<PromotionLine> {
+        self.items
+            .iter()
+            .filter(|item| item.is_available())
+            .map(|item| PromotionLine {
+                category_id: item.category_id,
+                unit_price: Money::new(item.unit_price, currency),
+                quantity: item.quantity,
+            })
+            .collect()
+    }
+
+    /// Suma de los descuentos aplicados, en centavos.
+    pub fn discount_total(&self) -> i64 {
+        self.discounts.iter().map(|discount| discount.amount).sum()
+    }
+
+    /// Desglose de los ítems disponibles con los descuentos aplicados, sin propina.
+    pub fn price_breakdown(&self, rules: &PricingRules) -> PriceBreakdown {
+        let lines: Vec<PricedLine> = self
+            .items
+            .iter()
+            .filter(|item| item.is_available())
+            .map(|item| PricedLine {
+                subtotal: Money::new(item.subtotal(), rules.currency),
+                tax_rate: item.tax_rate,
+            })
+            .collect();
+
+        PriceBreakdown::compute(
+            &lines,
+            Money::new(self.discount_total(), rules.currency),
+            Money::zero(rules.currency),
+            rules,
+        )
+    }
+
+    pub fn has_unavailable_items(&self) -> bool {
+        self.items.iter().any(|item| !item.is_available())
+    }
+}
+
+impl CartItem {
+    pub fn new() -> CartItem {
+        CartItem {
+            id: None,
+            product_id: 0,
+            product_name: "".to_string(),
+            category_id: 0,
+            quantity: 1,
+            options: vec![],
+            unit_price: 0,
+            tax_rate: 0,
+            unavailable_reason: None,
+        }
+    }
+
+    pub fn from_cart_item_model(row: CartItemModel, option_ids: Vec<i32>) -> Self {
+        CartItem {
+            id: row.id,
+            product_id: row.product,
+            quantity: row.quantity,
+            options: option_ids
+                .into_iter()
+                .map(|option_id| OrderItemOption {
+                    option_id,
+                    name: "".to_string(),
+                    price_delta: 0,
+                })
+                .collect(),
+            ..CartItem::new()
+        }
+    }
+
+    pub fn option_ids(&self) -> Vec<i32> {
+        self.options.iter().map(|option| option.option_id).collect()
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.unavailable_reason.is_none()
+    }
+
+    pub fn subtotal(&self) -> i64 {
+        self.unit_price * self.quantity as i64
+    }
+
+    pub fn to_order_item(&self) -> OrderItem {
+        OrderItem {
+            id: None,
+            product_id: self.product_id,
+            product_name: self.product_name.clone(),
+            quantity: self.quantity,
+            unit_price: self.unit_price,
+            tax_rate: self.tax_rate,
+            options: self.options.clone(),
+        }
+    }
+}
+
+impl Default for CartItem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
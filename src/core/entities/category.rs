@@ -0,0 +1,42 @@
+use crate::infrastructure::data::models::category::Category as CategoryModel;
+
+#[derive(Clone, Debug)]
+pub struct Category {
+    pub id: Option<i32>,
+    pub name: String,
+    pub description: Option<String>,
+    /// IVA de los productos de la categoría en puntos básicos; `None` usa la tasa por defecto.
+    pub tax_rate: Option<i32>,
+}
+
+impl Category {
+    pub fn new() -> Category {
+        Category {
+            id: None,
+            name: "".to_string(),
+            description: None,
+            tax_rate: None,
+        }
+    }
+
+    pub fn from_category_model(row: CategoryModel) -> Self {
+        Category {
+            id: row.id,
+            name: row.name,
+            description: row.description,
+            tax_rate: row.tax_rate,
+        }
+    }
+
+    pub fn from_category_model_vec(rows: Vec<CategoryModel>) -> Vec<Self> {
+        rows.iter()
+            .map(|row| Self::from_category_model(row.clone()))
+            .collect()
+    }
+}
+
+impl Default for Category {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{
+    core::errors::delivery_errors::DeliveryError,
+    infrastructure::data::models::delivery::{
+        Address as AddressModel, DeliveryZone as DeliveryZoneModel,
+    },
+};
+
+/// Dirección de la libreta del cliente.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Address {
+    pub id: Option<i32>,
+    pub user_id: i32,
+    pub province: String,
+    pub city: String,
+    pub address: String,
+}
+
+impl Address {
+    pub fn new() -> Address {
+        Address {
+            id: None,
+            user_id: 0,
+            province: "".to_string(),
+            city: "".to_string(),
+            address: "".to_string(),
+        }
+    }
+
+    pub fn from_address_model(row: AddressModel) -> Self {
+        Address {
+            id: row.id,
+            user_id: row.user,
+            province: row.province,
+            city: row.city,
+            address: row.address,
+        }
+    }
+
+    pub fn from_address_model_vec(rows: Vec<AddressModel>) -> Vec<Self> {
+        rows.into_iter().map(Self::from_address_model).collect()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let fields = [
+            ("province", &self.province, 30),
+            ("city", &self.city, 30),
+            ("address", &self.address, 100),
+        ];
+        for (name, value, max) in fields {
+            if value.trim().is_empty() {
+                return Err(format!("{} is required", name));
+            }
+            if value.chars().count() > max {
+                return Err(format!("{} cannot exceed {} characters", name, max));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Dirección completa en una línea, tal como se guarda en el pedido.
+    pub fn full_address(&self) -> String {
+        format!("{}, {}, {}", self.address, self.city, self.province)
+    }
+}
+
+impl Default for Address {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Zona de reparto. Una zona sin ciudad cubre toda la provincia; si una ciudad tiene su propia
+/// zona, esa tiene prioridad.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DeliveryZone {
+    pub id: Option<i32>,
+    pub province: String,
+    pub city: Option<String>,
+    /// Costo de envío en centavos; no lleva IVA.
+    pub fee: i64,
+    /// Subtotal mínimo del pedido, en centavos, para repartir en la zona.
+    pub minimum_order: i64,
+    pub active: bool,
+}
+
+impl DeliveryZone {
+    pub fn new() -> DeliveryZone {
+        DeliveryZone {
+            id: None,
+            province: "".to_string(),
+            city: None,
+            fee: 0,
+            minimum_order: 0,
+            active: true,
+        }
+    }
+
+    pub fn from_delivery_zone_model(row: DeliveryZoneModel) -> Self {
+        DeliveryZone {
+            id: row.id,
+            province: row.province,
+            city: row.city,
+            fee: row.fee,
+            minimum_order: row.minimum_order,
+            active: row.active,
+        }
+    }
+
+    pub fn from_delivery_zone_model_vec(rows: Vec<DeliveryZoneModel>) -> Vec<Self> {
+        rows.into_iter()
+            .map(Self::from_delivery_zone_model)
+            .collect()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.province.trim().is_empty() {
+            return Err("province is required".to_string());
+        }
+        if self.fee < 0 || self.minimum_order < 0 {
+            return Err("fee and minimum order cannot be negative".to_string());
+        }
+
+        Ok(())
+    }
+
+    fn covers(&self, address: &Address) -> bool {
+        let same = |a: &str, b: &str| a.trim().eq_ignore_ascii_case(b.trim());
+
+        self.active
+            && same(&self.province, &address.province)
+            && self
+                .city
+                .as_deref()
+                .is_none_or(|city| same(city, &address.city))
+    }
+
+    /// Zona activa que cubre la dirección, prefiriendo la de su ciudad sobre la de su
+    /// provincia.
+    pub fn for_address<'a>(
+        zones: &'a [DeliveryZone],
+        address: &Address,
+    ) -> Result<&'a DeliveryZone, DeliveryError> {
+        zones
+            .iter()
+            .filter(|zone| zone.covers(address))
+            .max_by_key(|zone| zone.city.is_some())
+            .ok_or(DeliveryError::OutsideDeliveryArea)
+    }
+
+    /// Comprueba que el subtotal alcance el mínimo de la zona.
+    pub fn check_minimum(&self, subtotal: i64) -> Result<(), DeliveryError> {
+        if subtotal < self.minimum_order {
+            return Err(DeliveryError::BelowMinimumOrder(self.minimum_order));
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for DeliveryZone {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod delivery_tests {
+    use super::*;
+
+    fn address(province: &str, city: &str) -> Address {
+        Address {
+            province: province.to_string(),
+            city: city.to_string(),
+            address: "Del parque central 2c al norte".to_string(),
+            ..Address::new()
+        }
+    }
+
+    fn zone(id: i32, province: &str, city: Option<&str>, fee: i64) -> DeliveryZone {
+        DeliveryZone {
+            id: Some(id),
+            province: province.to_string(),
+            city: city.map(str::to_string),
+            fee,
+            minimum_order: 1000,
+            ..DeliveryZone::new()
+        }
+    }
+
+    #[test]
+    fn test_city_zone_takes_priority_over_province_zone() {
+        let zones = vec![
+            zone(1, "Managua", None, 300),
+            zone(2, "Managua", Some("Ticuantepe"), 500),
+        ];
+
+        let found = DeliveryZone::for_address(&zones, &address("managua", "Ticuantepe")).unwrap();
+        assert_eq!(found.id, Some(2));
+
+        let found = DeliveryZone::for_address(&zones, &address("Managua", "Managua")).unwrap();
+        assert_eq!(found.id, Some(1));
+    }
+
+    #[test]
+    fn test_address_outside_zones_is_rejected() {
+        let mut inactive = zone(1, "León", None, 300);
+        inactive.active = false;
+
+        assert!(matches!(
+            DeliveryZone::for_address(&[inactive], &address("León", "León")),
+            Err(DeliveryError::OutsideDeliveryArea)
+        ));
+    }
+
+    #[test]
+    fn test_check_minimum() {
+        let zone = zone(1, "Managua", None, 300);
+
+        assert!(zone.check_minimum(1000).is_ok());
+        assert!(matches!(
+            zone.check_minimum(999),
+            Err(DeliveryError::BelowMinimumOrder(1000))
+        ));
+    }
+}
@@ -0,0 +1,66 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Forma en que el cliente recibe su pedido.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum FulfilmentType {
+    /// El cliente retira el pedido en el local.
+    #[default]
+    Pickup,
+    /// Un empleado lleva el pedido a una dirección de la libreta del cliente.
+    Delivery,
+}
+
+impl FulfilmentType {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "delivery" => FulfilmentType::Delivery,
+            _ => FulfilmentType::Pickup,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            FulfilmentType::Pickup => "pickup",
+            FulfilmentType::Delivery => "delivery",
+        }
+    }
+}
+
+/// Local, forma de entrega y momento en que el cliente quiere recibir su pedido.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fulfilment {
+    pub store_id: i32,
+    pub fulfilment_type: FulfilmentType,
+    /// Dirección de entrega, obligatoria para los pedidos a domicilio.
+    pub address_id: Option<i32>,
+    /// Inicio de la franja de retiro elegida; sin franja, el pedido se atiende de inmediato.
+    pub pickup_at: Option<DateTime<Utc>>,
+    /// Si el local está cerrado, programa el pedido para la primera franja con cupo en lugar
+    /// de rechazarlo.
+    pub schedule_if_closed: bool,
+}
+
+impl Fulfilment {
+    pub fn new(store_id: i32) -> Fulfilment {
+        Fulfilment {
+            store_id,
+            fulfilment_type: FulfilmentType::Pickup,
+            address_id: None,
+            pickup_at: None,
+            schedule_if_closed: false,
+        }
+    }
+
+    /// Dirección a la que se entrega el pedido; `None` si se retira en el local.
+    pub fn delivery_address_id(&self) -> Result<Option<i32>, String> {
+        match (self.fulfilment_type, self.address_id) {
+            (FulfilmentType::Pickup, _) => Ok(None),
+            (FulfilmentType::Delivery, Some(address_id)) => Ok(Some(address_id)),
+            (FulfilmentType::Delivery, None) => {
+                Err("delivery orders require an address".to_string())
+            }
+        }
+    }
+}
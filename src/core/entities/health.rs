@@ -0,0 +1,79 @@
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthStatus {
+    Up,
+    Down,
+}
+
+/// Resultado de comprobar una dependencia del servidor, como la base de datos.
+#[derive(Clone, Debug)]
+pub struct ComponentHealth {
+    pub name: String,
+    pub status: HealthStatus,
+    pub latency: Duration,
+    /// Motivo del fallo, si lo hubo.
+    pub error: Option<String>,
+}
+
+/// Estado del servidor para recibir tráfico: está listo solo si todas sus dependencias lo están.
+#[derive(Clone, Debug)]
+pub struct HealthReport {
+    pub status: HealthStatus,
+    pub components: Vec<ComponentHealth>,
+}
+
+impl HealthReport {
+    pub fn from_components(components: Vec<ComponentHealth>) -> Self {
+        let status = if components
+            .iter()
+            .all(|component| component.status == HealthStatus::Up)
+        {
+            HealthStatus::Up
+        } else {
+            HealthStatus::Down
+        };
+
+        HealthReport { status, components }
+    }
+
+    pub fn is_up(&self) -> bool {
+        self.status == HealthStatus::Up
+    }
+}
+
+#[cfg(test)]
+mod health_tests {
+    use super::*;
+
+    fn component(name: &str, status: HealthStatus) -> ComponentHealth {
+        ComponentHealth {
+            name: name.to_string(),
+            status,
+            latency: Duration::from_millis(1),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_report_is_up_only_if_every_component_is_up() {
+        let report = HealthReport::from_components(vec![
+            component("database", HealthStatus::Up),
+            component("migrations", HealthStatus::Up),
+        ]);
+        assert!(report.is_up());
+
+        let report = HealthReport::from_components(vec![
+            component("database", HealthStatus::Up),
+            component("migrations", HealthStatus::Down),
+        ]);
+        assert_eq!(report.status, HealthStatus::Down);
+    }
+
+    #[test]
+    fn test_report_without_components_is_up() {
+        assert!(HealthReport::from_components(vec![]).is_up());
+    }
+}
@@ -0,0 +1,320 @@
+use super::order::Order;
+use crate::infrastructure::data::models::inventory::{
+    Ingredient as IngredientModel, RecipeLine as RecipeLineModel,
+    StockAdjustment as StockAdjustmentModel,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum IngredientUnit {
+    Gram,
+    Milliliter,
+    #[default]
+    Unit,
+}
+
+impl IngredientUnit {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "gram" => IngredientUnit::Gram,
+            "milliliter" => IngredientUnit::Milliliter,
+            _ => IngredientUnit::Unit,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            IngredientUnit::Gram => "gram",
+            IngredientUnit::Milliliter => "milliliter",
+            IngredientUnit::Unit => "unit",
+        }
+    }
+}
+
+/// Insumo del inventario. El stock y el umbral se expresan en la unidad del insumo.
+#[derive(Clone, Debug)]
+pub struct Ingredient {
+    pub id: Option<i32>,
+    pub name: String,
+    pub unit: IngredientUnit,
+    pub stock: i64,
+    /// Al llegar a este nivel de stock se emite una alerta para reponer el insumo.
+    pub low_stock_threshold: i64,
+}
+
+impl Ingredient {
+    pub fn new() -> Ingredient {
+        Ingredient {
+            id: None,
+            name: "".to_string(),
+            unit: IngredientUnit::default(),
+            stock: 0,
+            low_stock_threshold: 0,
+        }
+    }
+
+    pub fn from_ingredient_model(row: IngredientModel) -> Self {
+        Ingredient {
+            id: row.id,
+            name: row.name,
+            unit: IngredientUnit::from_code(&row.unit),
+            stock: row.stock,
+            low_stock_threshold: row.low_stock_threshold,
+        }
+    }
+
+    pub fn from_ingredient_model_vec(rows: Vec<IngredientModel>) -> Vec<Self> {
+        rows.into_iter().map(Self::from_ingredient_model).collect()
+    }
+
+    pub fn is_low_stock(&self) -> bool {
+        self.stock <= self.low_stock_threshold
+    }
+}
+
+impl Default for Ingredient {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Cantidad de un insumo que consume una unidad de un producto o de una opción.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RecipeLine {
+    pub ingredient_id: i32,
+    pub quantity: i64,
+}
+
+impl RecipeLine {
+    pub fn from_recipe_line_model(row: RecipeLineModel) -> Self {
+        RecipeLine {
+            ingredient_id: row.ingredient,
+            quantity: row.quantity,
+        }
+    }
+
+    pub fn from_recipe_line_model_vec(rows: Vec<RecipeLineModel>) -> Vec<Self> {
+        rows.into_iter().map(Self::from_recipe_line_model).collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum StockAdjustmentReason {
+    Restock,
+    Waste,
+    Correction,
+    /// Descuento automático de los insumos de un pedido aceptado.
+    OrderConsumption,
+}
+
+impl StockAdjustmentReason {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "restock" => StockAdjustmentReason::Restock,
+            "waste" => StockAdjustmentReason::Waste,
+            "order_consumption" => StockAdjustmentReason::OrderConsumption,
+            _ => StockAdjustmentReason::Correction,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            StockAdjustmentReason::Restock => "restock",
+            StockAdjustmentReason::Waste => "waste",
+            StockAdjustmentReason::Correction => "correction",
+            StockAdjustmentReason::OrderConsumption => "order_consumption",
+        }
+    }
+}
+
+/// Movimiento de stock de un insumo. Todo cambio de stock queda registrado como un movimiento,
+/// que sirve de auditoría del inventario.
+#[derive(Clone, Debug)]
+pub struct StockAdjustment {
+    pub id: Option<i32>,
+    pub ingredient_id: i32,
+    /// Cantidad sumada al stock; es negativa cuando el insumo se consume o se descarta.
+    pub delta: i64,
+    pub reason: StockAdjustmentReason,
+    pub note: Option<String>,
+    pub order_id: Option<i32>,
+    pub adjusted_by: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl StockAdjustment {
+    pub fn new() -> StockAdjustment {
+        StockAdjustment {
+            id: None,
+            ingredient_id: 0,
+            delta: 0,
+            reason: StockAdjustmentReason::Correction,
+            note: None,
+            order_id: None,
+            adjusted_by: 0,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn from_stock_adjustment_model(row: StockAdjustmentModel) -> Self {
+        StockAdjustment {
+            id: row.id,
+            ingredient_id: row.ingredient,
+            delta: row.delta,
+            reason: StockAdjustmentReason::from_code(&row.reason),
+            note: row.note,
+            order_id: row.order_id,
+            adjusted_by: row.adjusted_by,
+            created_at: row.created_at,
+        }
+    }
+
+    pub fn from_stock_adjustment_model_vec(rows: Vec<StockAdjustmentModel>) -> Vec<Self> {
+        rows.into_iter()
+            .map(Self::from_stock_adjustment_model)
+            .collect()
+    }
+
+    /// Movimientos que descuentan los insumos del pedido. `item_recipes` trae, en el mismo
+    /// orden que las líneas del pedido, la receta de una unidad de cada línea (producto más
+    /// opciones elegidas). Se genera un único movimiento por insumo, ordenados por ID para que
+    /// las transacciones concurrentes bloqueen los insumos siempre en el mismo orden.
+    pub fn for_order(
+        order: &Order,
+        item_recipes: &[Vec<RecipeLine>],
+        adjusted_by: i32,
+        now: DateTime<Utc>,
+    ) -> Vec<StockAdjustment> {
+        let mut consumption: BTreeMap<i32, i64> = BTreeMap::new();
+
+        for (item, recipe) in order.items.iter().zip(item_recipes) {
+            for line in recipe {
+                *consumption.entry(line.ingredient_id).or_default() +=
+                    line.quantity * item.quantity as i64;
+            }
+        }
+
+        consumption
+            .into_iter()
+            .filter(|(_, quantity)| *quantity > 0)
+            .map(|(ingredient_id, quantity)| StockAdjustment {
+                ingredient_id,
+                delta: -quantity,
+                reason: StockAdjustmentReason::OrderConsumption,
+                order_id: order.id,
+                adjusted_by,
+                created_at: now,
+                ..StockAdjustment::new()
+            })
+            .collect()
+    }
+}
+
+impl Default for StockAdjustment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Aviso de que un insumo llegó a su umbral de stock bajo.
+#[derive(Clone, Debug)]
+pub struct StockAlert {
+    pub ingredient: Ingredient,
+    pub raised_at: DateTime<Utc>,
+}
+
+impl StockAlert {
+    /// Retorna la alerta solo si el movimiento `delta` dejó al insumo, ya actualizado, en su
+    /// umbral o por debajo cuando antes estaba por encima, para no repetir el aviso en cada
+    /// pedido.
+    pub fn crossed(ingredient: &Ingredient, delta: i64, now: DateTime<Utc>) -> Option<Self> {
+        let previous_stock = ingredient.stock - delta;
+
+        (ingredient.is_low_stock() && previous_stock > ingredient.low_stock_threshold).then(|| {
+            StockAlert {
+                ingredient: ingredient.clone(),
+                raised_at: now,
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod inventory_tests {
+    use super::*;
+    use crate::core::entities::order::OrderItem;
+
+    fn line(ingredient_id: i32, quantity: i64) -> RecipeLine {
+        RecipeLine {
+            ingredient_id,
+            quantity,
+        }
+    }
+
+    fn milk(stock: i64) -> Ingredient {
+        Ingredient {
+            id: Some(1),
+            name: "Leche".to_string(),
+            unit: IngredientUnit::Milliliter,
+            stock,
+            low_stock_threshold: 1000,
+        }
+    }
+
+    #[test]
+    fn test_for_order_multiplies_by_quantity_and_groups_by_ingredient() {
+        let order = Order {
+            id: Some(7),
+            items: vec![
+                OrderItem {
+                    quantity: 2,
+                    ..OrderItem::new()
+                },
+                OrderItem {
+                    quantity: 1,
+                    ..OrderItem::new()
+                },
+            ],
+            ..Order::new()
+        };
+        let recipes = vec![vec![line(2, 18), line(1, 200)], vec![line(1, 150)]];
+
+        let adjustments = StockAdjustment::for_order(&order, &recipes, 3, Utc::now());
+
+        let deltas: Vec<(i32, i64)> = adjustments
+            .iter()
+            .map(|adjustment| (adjustment.ingredient_id, adjustment.delta))
+            .collect();
+        assert_eq!(deltas, vec![(1, -550), (2, -36)]);
+        assert!(adjustments.iter().all(|adjustment| {
+            adjustment.reason == StockAdjustmentReason::OrderConsumption
+                && adjustment.order_id == Some(7)
+                && adjustment.adjusted_by == 3
+        }));
+    }
+
+    #[test]
+    fn test_for_order_without_recipes_has_no_adjustments() {
+        let order = Order {
+            items: vec![OrderItem::new()],
+            ..Order::new()
+        };
+
+        assert!(StockAdjustment::for_order(&order, &[vec![]], 1, Utc::now()).is_empty());
+    }
+
+    #[test]
+    fn test_alert_is_raised_when_crossing_threshold() {
+        assert!(StockAlert::crossed(&milk(900), -200, Utc::now()).is_some());
+    }
+
+    #[test]
+    fn test_alert_is_not_repeated_below_threshold() {
+        assert!(StockAlert::crossed(&milk(700), -200, Utc::now()).is_none());
+        assert!(StockAlert::crossed(&milk(1500), -200, Utc::now()).is_none());
+    }
+}
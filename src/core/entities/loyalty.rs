@@ -0,0 +1,378 @@
+use super::{
+    money::{Currency, Money},
+    order::{Order, OrderStatus},
+};
+use crate::infrastructure::data::models::loyalty::LoyaltyEntry as LoyaltyEntryModel;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Reglas del programa de puntos para clientes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LoyaltyRules {
+    /// Puntos por cada unidad de moneda (100 centavos) pagada, sin IVA, cargos ni propina.
+    pub points_per_unit: i64,
+    /// Centavos que descuenta cada punto canjeado.
+    pub point_value: i64,
+    /// Días que dura cada punto antes de vencer.
+    pub expiry_days: i64,
+}
+
+impl LoyaltyRules {
+    /// Puntos que otorga un pedido al completarse.
+    pub fn earned_points(&self, order: &Order) -> i64 {
+        let paid = order.pricing.subtotal - order.pricing.discount;
+
+        paid.amount.max(0) * self.points_per_unit / 100
+    }
+
+    /// Descuento que equivale a canjear los puntos indicados.
+    pub fn redemption_value(&self, points: i64, currency: Currency) -> Money {
+        Money::new(points * self.point_value, currency)
+    }
+
+    /// Movimientos que genera el cambio de estado del pedido: los puntos se acreditan al
+    /// completarse, los canjeados vuelven al cliente si se cancela y, si se reembolsa un pedido
+    /// completado, se revierten los acreditados.
+    pub fn entries_for_transition(
+        &self,
+        order: &Order,
+        to: OrderStatus,
+        now: DateTime<Utc>,
+    ) -> Vec<LoyaltyEntry> {
+        let entry = |kind: LoyaltyEntryKind, points: i64| LoyaltyEntry {
+            id: None,
+            user_id: order.user_id,
+            kind,
+            points,
+            order_id: order.id,
+            expires_at: (points > 0).then(|| now + Duration::days(self.expiry_days)),
+            created_at: now,
+        };
+        let completed = matches!(order.status, OrderStatus::PickedUp | OrderStatus::Delivered);
+        let mut entries = vec![];
+
+        match to {
+            OrderStatus::PickedUp | OrderStatus::Delivered if order.points_earned > 0 => {
+                entries.push(entry(LoyaltyEntryKind::Earned, order.points_earned));
+            }
+            OrderStatus::Cancelled if order.points_redeemed > 0 => {
+                entries.push(entry(LoyaltyEntryKind::Restored, order.points_redeemed));
+            }
+            OrderStatus::Refunded if completed => {
+                if order.points_earned > 0 {
+                    entries.push(entry(LoyaltyEntryKind::Reversed, -order.points_earned));
+                }
+                if order.points_redeemed > 0 {
+                    entries.push(entry(LoyaltyEntryKind::Restored, order.points_redeemed));
+                }
+            }
+            _ => {}
+        }
+
+        entries
+    }
+}
+
+impl Default for LoyaltyRules {
+    fn default() -> Self {
+        LoyaltyRules {
+            points_per_unit: 1,
+            point_value: 1,
+            expiry_days: 365,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LoyaltyEntryKind {
+    Earned,
+    Redeemed,
+    /// Devolución de puntos canjeados en un pedido cancelado o reembolsado.
+    Restored,
+    /// Anulación de los puntos acreditados por un pedido reembolsado.
+    Reversed,
+    Expired,
+}
+
+impl LoyaltyEntryKind {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "redeemed" => LoyaltyEntryKind::Redeemed,
+            "restored" => LoyaltyEntryKind::Restored,
+            "reversed" => LoyaltyEntryKind::Reversed,
+            "expired" => LoyaltyEntryKind::Expired,
+            _ => LoyaltyEntryKind::Earned,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            LoyaltyEntryKind::Earned => "earned",
+            LoyaltyEntryKind::Redeemed => "redeemed",
+            LoyaltyEntryKind::Restored => "restored",
+            LoyaltyEntryKind::Reversed => "reversed",
+            LoyaltyEntryKind::Expired => "expired",
+        }
+    }
+}
+
+/// Movimiento del libro de puntos. Los movimientos no se modifican ni se borran: cualquier
+/// corrección se registra como un movimiento nuevo.
+#[derive(Clone, Debug)]
+pub struct LoyaltyEntry {
+    pub id: Option<i32>,
+    pub user_id: i32,
+    pub kind: LoyaltyEntryKind,
+    /// Positivo para acreditaciones y negativo para débitos.
+    pub points: i64,
+    pub order_id: Option<i32>,
+    /// Vencimiento de los puntos acreditados; `None` en los débitos.
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LoyaltyEntry {
+    pub fn from_loyalty_entry_model(row: LoyaltyEntryModel) -> Self {
+        LoyaltyEntry {
+            id: row.id,
+            user_id: row.user,
+            kind: LoyaltyEntryKind::from_code(&row.kind),
+            points: row.points,
+            order_id: row.order_id,
+            expires_at: row.expires_at,
+            created_at: row.created_at,
+        }
+    }
+
+    pub fn redeemed(order: &Order) -> Self {
+        LoyaltyEntry {
+            id: None,
+            user_id: order.user_id,
+            kind: LoyaltyEntryKind::Redeemed,
+            points: -order.points_redeemed,
+            order_id: order.id,
+            expires_at: None,
+            created_at: order.created_at,
+        }
+    }
+}
+
+/// Saldo de puntos de un usuario calculado a partir de su libro.
+#[derive(Clone, Debug)]
+pub struct LoyaltyAccount {
+    pub user_id: i32,
+    /// Puntos disponibles para canjear.
+    pub balance: i64,
+    /// Puntos ya vencidos que todavía no tienen su movimiento `expired` en el libro.
+    pub pending_expiration: i64,
+    /// Movimientos del más reciente al más antiguo.
+    pub entries: Vec<LoyaltyEntry>,
+}
+
+/// Puntos acreditados en un mismo movimiento que todavía no se usaron.
+struct Lot {
+    points: i64,
+    expires_at: Option<DateTime<Utc>>,
+}
+
+impl LoyaltyAccount {
+    /// Recorre el libro en orden: los débitos consumen primero los puntos que vencen antes y
+    /// los lotes vencidos dejan de estar disponibles desde su vencimiento, aunque el movimiento
+    /// `expired` se registre más tarde. Si un débito supera el saldo, la diferencia se cubre con
+    /// las siguientes acreditaciones.
+    pub fn from_entries(user_id: i32, mut entries: Vec<LoyaltyEntry>, now: DateTime<Utc>) -> Self {
+        entries.sort_by_key(|entry| (entry.created_at, entry.id));
+
+        let mut lots: Vec<Lot> = vec![];
+        let mut expired = 0;
+        let mut deficit = 0;
+
+        for entry in &entries {
+            expired += Self::expire_lots(&mut lots, entry.created_at);
+
+            match entry.kind {
+                LoyaltyEntryKind::Expired => expired += entry.points,
+                _ if entry.points > 0 => {
+                    let points = entry.points - deficit.min(entry.points);
+                    deficit -= entry.points - points;
+                    if points > 0 {
+                        lots.push(Lot {
+                            points,
+                            expires_at: entry.expires_at,
+                        });
+                        lots.sort_by_key(|lot| (lot.expires_at.is_none(), lot.expires_at));
+                    }
+                }
+                _ => {
+                    let mut debit = -entry.points;
+                    for lot in lots.iter_mut() {
+                        let used = lot.points.min(debit);
+                        lot.points -= used;
+                        debit -= used;
+                    }
+                    lots.retain(|lot| lot.points > 0);
+                    deficit += debit;
+                }
+            }
+        }
+        expired += Self::expire_lots(&mut lots, now);
+        entries.reverse();
+
+        LoyaltyAccount {
+            user_id,
+            balance: lots.iter().map(|lot| lot.points).sum(),
+            pending_expiration: expired.max(0),
+            entries,
+        }
+    }
+
+    fn expire_lots(lots: &mut Vec<Lot>, at: DateTime<Utc>) -> i64 {
+        let expired = lots
+            .iter()
+            .filter(|lot| lot.expires_at.is_some_and(|expires_at| expires_at <= at))
+            .map(|lot| lot.points)
+            .sum();
+        lots.retain(|lot| lot.expires_at.is_none_or(|expires_at| expires_at > at));
+
+        expired
+    }
+
+    /// Movimiento que registra los puntos vencidos pendientes, si los hay.
+    pub fn expiration_entry(&self, now: DateTime<Utc>) -> Option<LoyaltyEntry> {
+        (self.pending_expiration > 0).then(|| LoyaltyEntry {
+            id: None,
+            user_id: self.user_id,
+            kind: LoyaltyEntryKind::Expired,
+            points: -self.pending_expiration,
+            order_id: None,
+            expires_at: None,
+            created_at: now,
+        })
+    }
+}
+
+#[cfg(test)]
+mod loyalty_tests {
+    use super::*;
+    use crate::core::entities::pricing::PriceBreakdown;
+    use chrono::TimeZone;
+
+    fn day(day: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 1, day, 12, 0, 0).unwrap()
+    }
+
+    fn entry(kind: LoyaltyEntryKind, points: i64, at: u32, expires: Option<u32>) -> LoyaltyEntry {
+        LoyaltyEntry {
+            id: None,
+            user_id: 1,
+            kind,
+            points,
+            order_id: None,
+            expires_at: expires.map(day),
+            created_at: day(at),
+        }
+    }
+
+    #[test]
+    fn test_earned_points_use_the_discounted_subtotal() {
+        let order = Order {
+            pricing: PriceBreakdown {
+                subtotal: Money::new(1250, Currency::Eur),
+                discount: Money::new(300, Currency::Eur),
+                ..PriceBreakdown::zero(Currency::Eur)
+            },
+            ..Order::new()
+        };
+        let rules = LoyaltyRules {
+            points_per_unit: 10,
+            ..LoyaltyRules::default()
+        };
+
+        assert_eq!(rules.earned_points(&order), 95);
+    }
+
+    #[test]
+    fn test_entries_for_transition() {
+        let rules = LoyaltyRules::default();
+        let order = Order {
+            id: Some(7),
+            user_id: 1,
+            points_earned: 40,
+            points_redeemed: 100,
+            ..Order::new()
+        };
+
+        let earned = rules.entries_for_transition(&order, OrderStatus::PickedUp, day(1));
+        assert_eq!(earned.len(), 1);
+        assert_eq!(earned[0].kind, LoyaltyEntryKind::Earned);
+        assert_eq!(earned[0].expires_at, Some(day(1) + Duration::days(365)));
+
+        let cancelled = rules.entries_for_transition(&order, OrderStatus::Cancelled, day(1));
+        assert_eq!(cancelled[0].kind, LoyaltyEntryKind::Restored);
+        assert_eq!(cancelled[0].points, 100);
+
+        let completed = Order {
+            status: OrderStatus::Delivered,
+            ..order
+        };
+        let refunded = rules.entries_for_transition(&completed, OrderStatus::Refunded, day(1));
+        assert_eq!(
+            refunded
+                .iter()
+                .map(|e| (e.kind, e.points))
+                .collect::<Vec<_>>(),
+            vec![
+                (LoyaltyEntryKind::Reversed, -40),
+                (LoyaltyEntryKind::Restored, 100)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_account_redeems_the_points_that_expire_first() {
+        let entries = vec![
+            entry(LoyaltyEntryKind::Earned, 100, 1, Some(10)),
+            entry(LoyaltyEntryKind::Earned, 50, 2, Some(20)),
+            entry(LoyaltyEntryKind::Redeemed, -120, 3, None),
+        ];
+
+        let account = LoyaltyAccount::from_entries(1, entries.clone(), day(5));
+        assert_eq!(account.balance, 30);
+        assert_eq!(account.pending_expiration, 0);
+
+        let account = LoyaltyAccount::from_entries(1, entries, day(25));
+        assert_eq!(account.balance, 0);
+        assert_eq!(account.pending_expiration, 30);
+        assert_eq!(account.expiration_entry(day(25)).unwrap().points, -30);
+    }
+
+    #[test]
+    fn test_account_does_not_redeem_expired_points_before_they_are_recorded() {
+        let entries = vec![
+            entry(LoyaltyEntryKind::Earned, 100, 1, Some(5)),
+            entry(LoyaltyEntryKind::Earned, 50, 6, Some(20)),
+            entry(LoyaltyEntryKind::Redeemed, -50, 7, None),
+            entry(LoyaltyEntryKind::Expired, -100, 8, None),
+        ];
+
+        let account = LoyaltyAccount::from_entries(1, entries, day(9));
+
+        assert_eq!(account.balance, 0);
+        assert_eq!(account.pending_expiration, 0);
+        assert_eq!(account.entries[0].kind, LoyaltyEntryKind::Expired);
+    }
+
+    #[test]
+    fn test_account_reversal_beyond_balance_is_covered_by_later_points() {
+        let entries = vec![
+            entry(LoyaltyEntryKind::Earned, 30, 1, Some(20)),
+            entry(LoyaltyEntryKind::Reversed, -50, 2, None),
+            entry(LoyaltyEntryKind::Earned, 40, 3, Some(20)),
+        ];
+
+        let account = LoyaltyAccount::from_entries(1, entries, day(4));
+
+        assert_eq!(account.balance, 20);
+    }
+}
@@ -0,0 +1,12 @@
+/// Hechos de negocio que se cuentan en las métricas del servidor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MetricEvent {
+    UserRegistered,
+    LoginSucceeded,
+    /// Credenciales inválidas o usuario inactivo.
+    LoginFailed,
+    /// Pedido creado, ya sea directamente o desde el carrito.
+    OrderPlaced,
+    /// Petición rechazada por el middleware de autenticación, con el motivo.
+    TokenRejected(&'static str),
+}
@@ -1,3 +1,23 @@
+pub mod cart;
+pub mod category;
+pub mod delivery;
+pub mod fulfilment;
+pub mod health;
+pub mod inventory;
 pub mod login;
+pub mod loyalty;
+pub mod metric_event;
+pub mod modifier;
+pub mod money;
+pub mod order;
+pub mod order_event;
+pub mod payment;
+pub mod pricing;
+pub mod product;
+pub mod promotion;
+pub mod refund;
+pub mod store;
 pub mod user;
 pub mod user_payload;
+pub mod user_role;
+pub mod user_status;
@@ -0,0 +1,110 @@
+use crate::infrastructure::data::models::modifier::{
+    ModifierGroup as ModifierGroupModel, ModifierOption as ModifierOptionModel,
+};
+
+/// Grupo de opciones configurables de un producto (tamaño, leche, jarabes, shots extra).
+///
+/// `min_selections` y `max_selections` indican cuántas opciones del grupo se deben elegir.
+#[derive(Clone, Debug)]
+pub struct ModifierGroup {
+    pub id: Option<i32>,
+    pub name: String,
+    pub min_selections: i32,
+    pub max_selections: i32,
+    pub options: Vec<ModifierOption>,
+}
+
+#[derive(Clone, Debug)]
+pub struct ModifierOption {
+    pub id: Option<i32>,
+    pub group_id: i32,
+    pub name: String,
+    /// Diferencia de precio en centavos respecto al precio base del producto.
+    pub price_delta: i64,
+    pub available: bool,
+    /// Falso si a algún insumo de la receta de la opción no le alcanza el stock.
+    pub in_stock: bool,
+}
+
+impl ModifierGroup {
+    pub fn new() -> ModifierGroup {
+        ModifierGroup {
+            id: None,
+            name: "".to_string(),
+            min_selections: 0,
+            max_selections: 1,
+            options: vec![],
+        }
+    }
+
+    pub fn from_modifier_group_model(
+        row: ModifierGroupModel,
+        options: Vec<ModifierOption>,
+    ) -> Self {
+        ModifierGroup {
+            id: row.id,
+            name: row.name,
+            min_selections: row.min_selections,
+            max_selections: row.max_selections,
+            options: options
+                .into_iter()
+                .filter(|option| Some(option.group_id) == row.id)
+                .collect(),
+        }
+    }
+
+    pub fn from_modifier_group_model_vec(
+        rows: Vec<ModifierGroupModel>,
+        options: Vec<ModifierOption>,
+    ) -> Vec<Self> {
+        rows.iter()
+            .map(|row| Self::from_modifier_group_model(row.clone(), options.clone()))
+            .collect()
+    }
+}
+
+impl Default for ModifierGroup {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ModifierOption {
+    pub fn new() -> ModifierOption {
+        ModifierOption {
+            id: None,
+            group_id: 0,
+            name: "".to_string(),
+            price_delta: 0,
+            available: true,
+            in_stock: true,
+        }
+    }
+
+    pub fn from_modifier_option_model(row: ModifierOptionModel) -> Self {
+        ModifierOption {
+            id: row.id,
+            group_id: row.modifier_group,
+            name: row.name,
+            price_delta: row.price_delta,
+            available: row.available,
+            in_stock: row.in_stock,
+        }
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.available && self.in_stock
+    }
+
+    pub fn from_modifier_option_model_vec(rows: Vec<ModifierOptionModel>) -> Vec<Self> {
+        rows.iter()
+            .map(|row| Self::from_modifier_option_model(row.clone()))
+            .collect()
+    }
+}
+
+impl Default for ModifierOption {
+    fn default() -> Self {
+        Self::new()
+    }
+}
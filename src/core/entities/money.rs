@@ -0,0 +1,178 @@
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Sub};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Currency {
+    #[default]
+    Eur,
+    Usd,
+    Ars,
+}
+
+impl Currency {
+    pub fn from_code(code: &str) -> Self {
+        match code.to_uppercase().as_str() {
+            "USD" => Currency::Usd,
+            "ARS" => Currency::Ars,
+            _ => Currency::Eur,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::Eur => "EUR",
+            Currency::Usd => "USD",
+            Currency::Ars => "ARS",
+        }
+    }
+}
+
+/// Monto exacto en unidades menores (centavos) de una moneda. Nunca se usan flotantes: los
+/// porcentajes se expresan en puntos básicos (`2100` = 21 %) y se redondean al centavo más
+/// cercano, alejándose de cero en los empates.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Money {
+    pub amount: i64,
+    pub currency: Currency,
+}
+
+impl Money {
+    pub fn new(amount: i64, currency: Currency) -> Money {
+        Money { amount, currency }
+    }
+
+    pub fn zero(currency: Currency) -> Money {
+        Money::new(0, currency)
+    }
+
+    pub fn times(&self, quantity: i32) -> Money {
+        Money::new(self.amount * quantity as i64, self.currency)
+    }
+
+    /// Porcentaje del monto expresado en puntos básicos.
+    pub fn percentage(&self, basis_points: i32) -> Money {
+        let amount = round_div(self.amount as i128 * basis_points as i128, 10_000);
+
+        Money::new(amount as i64, self.currency)
+    }
+
+    pub fn min(self, other: Money) -> Money {
+        if self.amount <= other.amount {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Reparte el monto en partes proporcionales a `weights` sin perder centavos: cada parte
+    /// recibe su cociente entero y los centavos sobrantes van a las partes con mayor resto, y
+    /// ante restos iguales a la primera.
+    pub fn allocate(&self, weights: &[i64]) -> Vec<Money> {
+        let total_weight: i128 = weights.iter().map(|weight| *weight as i128).sum();
+
+        if total_weight == 0 {
+            return weights.iter().map(|_| Money::zero(self.currency)).collect();
+        }
+
+        let mut shares: Vec<i64> = vec![];
+        let mut remainders: Vec<(usize, i128)> = vec![];
+
+        for (index, weight) in weights.iter().enumerate() {
+            let product = self.amount as i128 * *weight as i128;
+            shares.push((product / total_weight) as i64);
+            remainders.push((index, product % total_weight));
+        }
+
+        let mut leftover = self.amount - shares.iter().sum::<i64>();
+        remainders.sort_by(|(a_index, a), (b_index, b)| b.cmp(a).then(a_index.cmp(b_index)));
+
+        for (index, _) in remainders {
+            if leftover == 0 {
+                break;
+            }
+            shares[index] += leftover.signum();
+            leftover -= leftover.signum();
+        }
+
+        shares
+            .into_iter()
+            .map(|share| Money::new(share, self.currency))
+            .collect()
+    }
+}
+
+impl Add for Money {
+    type Output = Money;
+
+    fn add(self, other: Money) -> Money {
+        assert_eq!(
+            self.currency, other.currency,
+            "cannot add different currencies"
+        );
+        Money::new(self.amount + other.amount, self.currency)
+    }
+}
+
+impl Sub for Money {
+    type Output = Money;
+
+    fn sub(self, other: Money) -> Money {
+        assert_eq!(
+            self.currency, other.currency,
+            "cannot subtract different currencies"
+        );
+        Money::new(self.amount - other.amount, self.currency)
+    }
+}
+
+/// División entera redondeando al entero más cercano y alejándose de cero en los empates.
+fn round_div(numerator: i128, denominator: i128) -> i128 {
+    let half = denominator / 2;
+
+    if numerator >= 0 {
+        (numerator + half) / denominator
+    } else {
+        (numerator - half) / denominator
+    }
+}
+
+#[cfg(test)]
+mod money_tests {
+    use super::*;
+
+    fn eur(amount: i64) -> Money {
+        Money::new(amount, Currency::Eur)
+    }
+
+    #[test]
+    fn test_percentage_rounds_half_away_from_zero() {
+        assert_eq!(eur(250).percentage(1000), eur(25));
+        // 21 % de 0,50 € = 10,5 centavos.
+        assert_eq!(eur(50).percentage(2100), eur(11));
+        // 21 % de 0,49 € = 10,29 centavos.
+        assert_eq!(eur(49).percentage(2100), eur(10));
+        assert_eq!(eur(-50).percentage(2100), eur(-11));
+    }
+
+    #[test]
+    fn test_allocate_keeps_every_cent() {
+        let shares = eur(100).allocate(&[1, 1, 1]);
+
+        assert_eq!(shares, vec![eur(34), eur(33), eur(33)]);
+        assert_eq!(eur(0).allocate(&[0, 0]), vec![eur(0), eur(0)]);
+        assert_eq!(eur(10).allocate(&[300, 700]), vec![eur(3), eur(7)]);
+    }
+
+    #[test]
+    fn test_add_and_subtract() {
+        assert_eq!(eur(150) + eur(75) - eur(25), eur(200));
+        assert_eq!(eur(325).times(3), eur(975));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add different currencies")]
+    fn test_add_rejects_mixed_currencies() {
+        let _ = eur(1) + Money::new(1, Currency::Usd);
+    }
+}
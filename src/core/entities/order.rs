@@ -0,0 +1,732 @@
+use super::{
+    delivery::{Address, DeliveryZone},
+    fulfilment::FulfilmentType,
+    money::{Currency, Money},
+    payment::PaymentMethod,
+    pricing::{PriceBreakdown, PricedLine, PricingRules},
+    promotion::AppliedDiscount,
+    refund::{Refund, RefundStatus},
+};
+use crate::core::errors::delivery_errors::DeliveryError;
+use crate::infrastructure::data::models::order::{
+    Order as OrderModel, OrderItem as OrderItemModel, OrderItemOption as OrderItemOptionModel,
+    OrderStatusChange as OrderStatusChangeModel,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Estados de un pedido.
+///
+/// ```text
+/// placed -> accepted -> preparing -> ready -> picked_up | delivered -> refunded
+///    |          |           |            |
+///    |          |           |            +-> out_for_delivery -> delivered
+///    +----------+-----------+-> cancelled -> refunded
+/// ```
+///
+/// Qué salida de `ready` corresponde depende de la forma de entrega; ver
+/// [`Order::can_transition_to`].
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStatus {
+    Placed,
+    Accepted,
+    Preparing,
+    Ready,
+    PickedUp,
+    OutForDelivery,
+    Delivered,
+    Cancelled,
+    Refunded,
+}
+
+impl OrderStatus {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "accepted" => OrderStatus::Accepted,
+            "preparing" => OrderStatus::Preparing,
+            "ready" => OrderStatus::Ready,
+            "picked_up" => OrderStatus::PickedUp,
+            "out_for_delivery" => OrderStatus::OutForDelivery,
+            "delivered" => OrderStatus::Delivered,
+            "cancelled" => OrderStatus::Cancelled,
+            "refunded" => OrderStatus::Refunded,
+            _ => OrderStatus::Placed,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            OrderStatus::Placed => "placed",
+            OrderStatus::Accepted => "accepted",
+            OrderStatus::Preparing => "preparing",
+            OrderStatus::Ready => "ready",
+            OrderStatus::PickedUp => "picked_up",
+            OrderStatus::OutForDelivery => "out_for_delivery",
+            OrderStatus::Delivered => "delivered",
+            OrderStatus::Cancelled => "cancelled",
+            OrderStatus::Refunded => "refunded",
+        }
+    }
+
+    /// Indica si el pedido puede pasar del estado actual al estado `next`.
+    pub fn can_transition_to(&self, next: OrderStatus) -> bool {
+        matches!(
+            (self, next),
+            (OrderStatus::Placed, OrderStatus::Accepted)
+                | (OrderStatus::Placed, OrderStatus::Cancelled)
+                | (OrderStatus::Accepted, OrderStatus::Preparing)
+                | (OrderStatus::Accepted, OrderStatus::Cancelled)
+                | (OrderStatus::Preparing, OrderStatus::Ready)
+                | (OrderStatus::Preparing, OrderStatus::Cancelled)
+                | (OrderStatus::Ready, OrderStatus::PickedUp)
+                | (OrderStatus::Ready, OrderStatus::Delivered)
+                | (OrderStatus::Ready, OrderStatus::OutForDelivery)
+                | (OrderStatus::OutForDelivery, OrderStatus::Delivered)
+                | (OrderStatus::PickedUp, OrderStatus::Refunded)
+                | (OrderStatus::Delivered, OrderStatus::Refunded)
+                | (OrderStatus::Cancelled, OrderStatus::Refunded)
+        )
+    }
+
+    /// Siguiente estado de preparación en la cola del barista, si lo hay.
+    pub fn next_preparation_status(&self) -> Option<OrderStatus> {
+        match self {
+            OrderStatus::Placed => Some(OrderStatus::Accepted),
+            OrderStatus::Accepted => Some(OrderStatus::Preparing),
+            OrderStatus::Preparing => Some(OrderStatus::Ready),
+            _ => None,
+        }
+    }
+
+    /// Estados que aparecen en la cola de pedidos abiertos.
+    pub fn open_statuses() -> Vec<OrderStatus> {
+        vec![
+            OrderStatus::Placed,
+            OrderStatus::Accepted,
+            OrderStatus::Preparing,
+            OrderStatus::Ready,
+            OrderStatus::OutForDelivery,
+        ]
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Order {
+    pub id: Option<i32>,
+    pub user_id: i32,
+    pub status: OrderStatus,
+    pub items: Vec<OrderItem>,
+    /// Desglose fijado al crear el pedido; no cambia aunque luego cambien los precios.
+    pub pricing: PriceBreakdown,
+    /// Promociones canjeadas; su suma es el descuento del desglose.
+    pub discounts: Vec<AppliedDiscount>,
+    /// Puntos que se acreditan al completar el pedido; se fijan al crearlo.
+    pub points_earned: i64,
+    /// Puntos canjeados como descuento al crear el pedido.
+    pub points_redeemed: i64,
+    pub payment_method: PaymentMethod,
+    /// Empleado que tomó el pedido desde la cola.
+    pub claimed_by: Option<i32>,
+    /// Local que atiende el pedido.
+    pub store_id: Option<i32>,
+    /// Hora de atención de un pedido hecho con el local cerrado; `None` si se atiende de
+    /// inmediato.
+    pub scheduled_for: Option<DateTime<Utc>>,
+    /// Momento en que un pedido programado entra a la cola del barista; `None` cuando ya está
+    /// en ella.
+    pub release_at: Option<DateTime<Utc>>,
+    pub fulfilment_type: FulfilmentType,
+    pub delivery_address_id: Option<i32>,
+    /// Dirección de entrega tal como estaba al crear el pedido.
+    pub delivery_address: Option<String>,
+    /// Empleado asignado para llevar el pedido a domicilio.
+    pub courier_id: Option<i32>,
+    pub history: Vec<OrderStatusChange>,
+    pub refunds: Vec<Refund>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Registro de un cambio de estado y del usuario que lo realizó.
+#[derive(Clone, Debug)]
+pub struct OrderStatusChange {
+    pub status: OrderStatus,
+    pub changed_by: i32,
+    pub changed_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Debug)]
+pub struct OrderItem {
+    pub id: Option<i32>,
+    pub product_id: i32,
+    pub product_name: String,
+    pub quantity: i32,
+    /// Precio unitario en centavos sin IVA, incluyendo los modificadores elegidos.
+    pub unit_price: i64,
+    /// IVA de la línea en puntos básicos.
+    pub tax_rate: i32,
+    pub options: Vec<OrderItemOption>,
+}
+
+#[derive(Clone, Debug)]
+pub struct OrderItemOption {
+    pub option_id: i32,
+    pub name: String,
+    pub price_delta: i64,
+}
+
+impl Order {
+    pub fn new() -> Order {
+        let now = Utc::now();
+
+        Order {
+            id: None,
+            user_id: 0,
+            status: OrderStatus::Placed,
+            items: vec![],
+            pricing: PriceBreakdown::zero(Currency::default()),
+            discounts: vec![],
+            points_earned: 0,
+            points_redeemed: 0,
+            payment_method: PaymentMethod::Card,
+            claimed_by: None,
+            store_id: None,
+            scheduled_for: None,
+            release_at: None,
+            fulfilment_type: FulfilmentType::Pickup,
+            delivery_address_id: None,
+            delivery_address: None,
+            courier_id: None,
+            history: vec![],
+            refunds: vec![],
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn from_order_model(
+        row: OrderModel,
+        items: Vec<OrderItem>,
+        history: Vec<OrderStatusChange>,
+        refunds: Vec<Refund>,
+        discounts: Vec<AppliedDiscount>,
+    ) -> Self {
+        let currency = Currency::from_code(&row.currency);
+
+        Order {
+            id: row.id,
+            user_id: row.user,
+            status: OrderStatus::from_code(&row.status),
+            items,
+            pricing: PriceBreakdown {
+                subtotal: Money::new(row.subtotal, currency),
+                discount: Money::new(row.discount, currency),
+                tax: Money::new(row.tax, currency),
+                service_charge: Money::new(row.service_charge, currency),
+                tip: Money::new(row.tip, currency),
+                delivery_fee: Money::new(row.delivery_fee, currency),
+                total: Money::new(row.total, currency),
+            },
+            discounts,
+            points_earned: row.points_earned,
+            points_redeemed: row.points_redeemed,
+            payment_method: PaymentMethod::from_code(&row.payment_method),
+            claimed_by: row.claimed_by,
+            store_id: row.store_id,
+            scheduled_for: row.scheduled_for,
+            release_at: row.release_at,
+            fulfilment_type: FulfilmentType::from_code(&row.fulfilment_type),
+            delivery_address_id: row.delivery_address_id,
+            delivery_address: row.delivery_address,
+            courier_id: row.courier_id,
+            history,
+            refunds,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+
+    /// Crea un pedido nuevo del usuario con el desglose de sus líneas y descuentos, y el primer
+    /// registro del historial.
+    pub fn place(
+        user_id: i32,
+        items: Vec<OrderItem>,
+        discounts: Vec<AppliedDiscount>,
+        tip: i64,
+        rules: &PricingRules,
+    ) -> Order {
+        let order = Order::new();
+        let discount = discounts.iter().map(|discount| discount.amount).sum();
+        let lines: Vec<PricedLine> = items
+            .iter()
+            .map(|item| item.priced_line(rules.currency))
+            .collect();
+
+        Order {
+            user_id,
+            pricing: PriceBreakdown::compute(
+                &lines,
+                Money::new(discount, rules.currency),
+                Money::new(tip, rules.currency),
+                rules,
+            ),
+            items,
+            discounts,
+            history: vec![OrderStatusChange {
+                status: OrderStatus::Placed,
+                changed_by: user_id,
+                changed_at: order.created_at,
+            }],
+            ..order
+        }
+    }
+
+    /// Canjea puntos por un descuento de `amount` centavos, que se suma al de las promociones, y
+    /// recalcula el desglose.
+    pub fn redeem_points(self, points: i64, amount: i64, rules: &PricingRules) -> Order {
+        let lines: Vec<PricedLine> = self
+            .items
+            .iter()
+            .map(|item| item.priced_line(rules.currency))
+            .collect();
+
+        Order {
+            pricing: PriceBreakdown::compute(
+                &lines,
+                self.pricing.discount + Money::new(amount, rules.currency),
+                self.pricing.tip,
+                rules,
+            )
+            .with_delivery_fee(self.pricing.delivery_fee),
+            points_redeemed: points,
+            ..self
+        }
+    }
+
+    /// Convierte el pedido en uno a domicilio con el costo de envío de la zona. El pedido mínimo
+    /// se compara con el subtotal de los productos, antes de descuentos.
+    pub fn deliver_to(
+        self,
+        address: &Address,
+        zone: &DeliveryZone,
+    ) -> Result<Order, DeliveryError> {
+        zone.check_minimum(self.pricing.subtotal.amount)?;
+
+        Ok(Order {
+            fulfilment_type: FulfilmentType::Delivery,
+            delivery_address_id: address.id,
+            delivery_address: Some(address.full_address()),
+            pricing: self
+                .pricing
+                .with_delivery_fee(Money::new(zone.fee, self.pricing.total.currency)),
+            ..self
+        })
+    }
+
+    /// Aplica la máquina de estados teniendo en cuenta la forma de entrega: los pedidos a
+    /// domicilio no se retiran en el local y solo se entregan después de salir a reparto, y los
+    /// de retiro nunca salen a reparto.
+    pub fn can_transition_to(&self, next: OrderStatus) -> bool {
+        let not_for_fulfilment = matches!(
+            (self.fulfilment_type, self.status, next),
+            (FulfilmentType::Pickup, _, OrderStatus::OutForDelivery)
+                | (FulfilmentType::Delivery, _, OrderStatus::PickedUp)
+                | (
+                    FulfilmentType::Delivery,
+                    OrderStatus::Ready,
+                    OrderStatus::Delivered
+                )
+        );
+
+        !not_for_fulfilment && self.status.can_transition_to(next)
+    }
+
+    /// Indica si el pedido sigue en curso y todavía puede cobrarse.
+    pub fn is_open(&self) -> bool {
+        OrderStatus::open_statuses().contains(&self.status)
+    }
+
+    /// Total a cobrar en centavos.
+    pub fn total(&self) -> i64 {
+        self.pricing.total.amount
+    }
+
+    /// Suma devuelta al cliente o en curso, en centavos. Las devoluciones fallidas no cuentan.
+    pub fn refunded_total(&self) -> i64 {
+        self.active_refunds().map(|refund| refund.amount).sum()
+    }
+
+    /// Indica si la línea ya fue incluida en una devolución anterior que no falló.
+    pub fn is_item_refunded(&self, item_id: i32) -> bool {
+        self.active_refunds()
+            .any(|refund| refund.item_ids.contains(&item_id))
+    }
+
+    fn active_refunds(&self) -> impl Iterator<Item = &Refund> {
+        self.refunds
+            .iter()
+            .filter(|refund| refund.status != RefundStatus::Failed)
+    }
+
+    /// Monto a devolver por las líneas indicadas o, si no se indica ninguna, por el saldo que
+    /// queda del monto `captured`. Nunca permite devolver más de lo cobrado.
+    pub fn refundable_amount(&self, item_ids: &[i32], captured: i64) -> Result<i64, String> {
+        let remaining = captured - self.refunded_total();
+
+        if item_ids.is_empty() {
+            if remaining <= 0 {
+                return Err("nothing left to refund".to_string());
+            }
+            return Ok(remaining);
+        }
+
+        // Cada línea devuelve su subtotal menos la parte del descuento que le corresponde.
+        let weights: Vec<i64> = self.items.iter().map(OrderItem::subtotal).collect();
+        let discounts = self.pricing.discount.allocate(&weights);
+        let mut amount = 0;
+
+        for (index, item_id) in item_ids.iter().enumerate() {
+            let position = self
+                .items
+                .iter()
+                .position(|item| item.id == Some(*item_id))
+                .ok_or(format!("item {} does not belong to the order", item_id))?;
+
+            if item_ids[..index].contains(item_id) || self.is_item_refunded(*item_id) {
+                return Err(format!("item {} already refunded", item_id));
+            }
+
+            amount += self.items[position].net_with_tax(discounts[position]);
+        }
+
+        // El IVA redondeado por línea puede desviarse un centavo por línea del cobrado. La
+        // devolución que cierra las líneas pendientes devuelve el saldo exacto.
+        let covers_rest = self.items.iter().all(|item| {
+            item.id
+                .is_some_and(|id| item_ids.contains(&id) || self.is_item_refunded(id))
+        });
+        if covers_rest && remaining > 0 && (amount - remaining).abs() <= self.items.len() as i64 {
+            return Ok(remaining);
+        }
+
+        if amount > remaining {
+            return Err("refund exceeds captured amount".to_string());
+        }
+
+        Ok(amount)
+    }
+
+    pub fn is_owned_by(&self, user_id: i32) -> bool {
+        self.user_id == user_id
+    }
+
+    /// Indica si el pedido ya debe verse en la cola del barista.
+    pub fn is_released(&self, now: DateTime<Utc>) -> bool {
+        self.release_at.is_none_or(|release_at| release_at <= now)
+    }
+}
+
+impl Default for Order {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OrderItem {
+    pub fn new() -> OrderItem {
+        OrderItem {
+            id: None,
+            product_id: 0,
+            product_name: "".to_string(),
+            quantity: 1,
+            unit_price: 0,
+            tax_rate: 0,
+            options: vec![],
+        }
+    }
+
+    pub fn from_order_item_model(row: OrderItemModel, options: Vec<OrderItemOption>) -> Self {
+        OrderItem {
+            id: row.id,
+            product_id: row.product,
+            product_name: row.product_name,
+            quantity: row.quantity,
+            unit_price: row.unit_price,
+            tax_rate: row.tax_rate,
+            options,
+        }
+    }
+
+    pub fn option_ids(&self) -> Vec<i32> {
+        self.options.iter().map(|option| option.option_id).collect()
+    }
+
+    pub fn subtotal(&self) -> i64 {
+        self.unit_price * self.quantity as i64
+    }
+
+    /// Subtotal menos el descuento de la línea, más su IVA, redondeado por separado; se usa al
+    /// devolver líneas.
+    pub fn net_with_tax(&self, discount: Money) -> i64 {
+        let net = Money::new(self.subtotal(), discount.currency) - discount;
+
+        (net + net.percentage(self.tax_rate)).amount
+    }
+
+    pub fn priced_line(&self, currency: Currency) -> PricedLine {
+        PricedLine {
+            subtotal: Money::new(self.subtotal(), currency),
+            tax_rate: self.tax_rate,
+        }
+    }
+}
+
+impl Default for OrderItem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl OrderStatusChange {
+    pub fn from_order_status_change_model(row: OrderStatusChangeModel) -> Self {
+        OrderStatusChange {
+            status: OrderStatus::from_code(&row.status),
+            changed_by: row.changed_by,
+            changed_at: row.changed_at,
+        }
+    }
+}
+
+impl OrderItemOption {
+    pub fn from_order_item_option_model(row: OrderItemOptionModel) -> Self {
+        OrderItemOption {
+            option_id: row.modifier_option,
+            name: row.name,
+            price_delta: row.price_delta,
+        }
+    }
+}
+
+#[cfg(test)]
+mod order_tests {
+    use super::*;
+
+    #[test]
+    fn test_order_status_allows_happy_path() {
+        assert!(OrderStatus::Placed.can_transition_to(OrderStatus::Accepted));
+        assert!(OrderStatus::Accepted.can_transition_to(OrderStatus::Preparing));
+        assert!(OrderStatus::Preparing.can_transition_to(OrderStatus::Ready));
+        assert!(OrderStatus::Ready.can_transition_to(OrderStatus::PickedUp));
+        assert!(OrderStatus::PickedUp.can_transition_to(OrderStatus::Refunded));
+    }
+
+    #[test]
+    fn test_order_status_rejects_illegal_transitions() {
+        assert!(!OrderStatus::Placed.can_transition_to(OrderStatus::Ready));
+        assert!(!OrderStatus::Ready.can_transition_to(OrderStatus::Cancelled));
+        assert!(!OrderStatus::PickedUp.can_transition_to(OrderStatus::Placed));
+        assert!(!OrderStatus::Refunded.can_transition_to(OrderStatus::Cancelled));
+    }
+
+    #[test]
+    fn test_next_preparation_status_stops_at_ready() {
+        assert_eq!(
+            OrderStatus::Placed.next_preparation_status(),
+            Some(OrderStatus::Accepted)
+        );
+        assert_eq!(
+            OrderStatus::Preparing.next_preparation_status(),
+            Some(OrderStatus::Ready)
+        );
+        assert_eq!(OrderStatus::Ready.next_preparation_status(), None);
+    }
+
+    fn refundable_order() -> Order {
+        Order {
+            items: vec![
+                OrderItem {
+                    id: Some(1),
+                    quantity: 2,
+                    unit_price: 300,
+                    ..OrderItem::new()
+                },
+                OrderItem {
+                    id: Some(2),
+                    quantity: 1,
+                    unit_price: 450,
+                    ..OrderItem::new()
+                },
+            ],
+            ..Order::new()
+        }
+    }
+
+    #[test]
+    fn test_refundable_amount_for_items_and_remaining_balance() {
+        let mut order = refundable_order();
+
+        assert_eq!(order.refundable_amount(&[1], 1050), Ok(600));
+
+        order.refunds.push(Refund {
+            amount: 600,
+            item_ids: vec![1],
+            ..Refund::new()
+        });
+
+        assert_eq!(order.refundable_amount(&[], 1050), Ok(450));
+        assert!(order.refundable_amount(&[1], 1050).is_err());
+    }
+
+    #[test]
+    fn test_failed_refunds_do_not_count() {
+        let mut order = refundable_order();
+
+        order.refunds.push(Refund {
+            amount: 600,
+            item_ids: vec![1],
+            status: RefundStatus::Failed,
+            ..Refund::new()
+        });
+
+        assert_eq!(order.refunded_total(), 0);
+        assert_eq!(order.refundable_amount(&[1], 1050), Ok(600));
+    }
+
+    #[test]
+    fn test_refundable_amount_subtracts_each_line_share_of_the_discount() {
+        let mut order = refundable_order();
+        order.items[0].tax_rate = 1000;
+        order.items[1].tax_rate = 2100;
+        order.pricing.discount = Money::new(100, Currency::Eur);
+
+        // El descuento se reparte 57 y 43: (600 - 57) + 10 % y (450 - 43) + 21 %.
+        assert_eq!(order.refundable_amount(&[1], 1089), Ok(597));
+        assert_eq!(order.refundable_amount(&[2], 1089), Ok(492));
+        assert_eq!(order.refundable_amount(&[1, 2], 1089), Ok(1089));
+    }
+
+    #[test]
+    fn test_last_item_refund_returns_exactly_the_remaining_balance() {
+        let line = |id| OrderItem {
+            id: Some(id),
+            quantity: 1,
+            unit_price: 5,
+            tax_rate: 1000,
+            ..OrderItem::new()
+        };
+        let mut order = Order {
+            items: vec![line(1), line(2), line(3)],
+            ..Order::new()
+        };
+
+        // El pedido cobra 15 + 2 de IVA, pero cada línea suelta redondea 0,5 a 1: 6 por línea.
+        assert_eq!(order.refundable_amount(&[1, 2, 3], 17), Ok(17));
+        assert_eq!(order.refundable_amount(&[1, 2], 17), Ok(12));
+        order.refunds.push(Refund {
+            amount: 12,
+            item_ids: vec![1, 2],
+            ..Refund::new()
+        });
+
+        assert_eq!(order.refundable_amount(&[3], 17), Ok(5));
+    }
+
+    #[test]
+    fn test_refundable_amount_never_exceeds_captured() {
+        let order = refundable_order();
+
+        assert!(order.refundable_amount(&[1, 2], 900).is_err());
+        assert!(order.refundable_amount(&[2, 2], 1050).is_err());
+        assert!(order.refundable_amount(&[3], 1050).is_err());
+        assert!(order.refundable_amount(&[], 0).is_err());
+    }
+
+    #[test]
+    fn test_order_status_codes_round_trip() {
+        for status in [
+            OrderStatus::Placed,
+            OrderStatus::Accepted,
+            OrderStatus::Preparing,
+            OrderStatus::Ready,
+            OrderStatus::PickedUp,
+            OrderStatus::OutForDelivery,
+            OrderStatus::Delivered,
+            OrderStatus::Cancelled,
+            OrderStatus::Refunded,
+        ] {
+            assert_eq!(OrderStatus::from_code(status.code()), status);
+        }
+    }
+
+    #[test]
+    fn test_delivery_orders_go_out_for_delivery_before_being_delivered() {
+        let mut order = Order {
+            status: OrderStatus::Ready,
+            fulfilment_type: FulfilmentType::Delivery,
+            ..Order::new()
+        };
+
+        assert!(order.can_transition_to(OrderStatus::OutForDelivery));
+        assert!(!order.can_transition_to(OrderStatus::Delivered));
+        assert!(!order.can_transition_to(OrderStatus::PickedUp));
+
+        order.status = OrderStatus::OutForDelivery;
+        assert!(order.can_transition_to(OrderStatus::Delivered));
+        assert!(!order.can_transition_to(OrderStatus::Cancelled));
+    }
+
+    #[test]
+    fn test_pickup_orders_never_go_out_for_delivery() {
+        let order = Order {
+            status: OrderStatus::Ready,
+            ..Order::new()
+        };
+
+        assert!(order.can_transition_to(OrderStatus::PickedUp));
+        assert!(!order.can_transition_to(OrderStatus::OutForDelivery));
+    }
+
+    #[test]
+    fn test_deliver_to_adds_fee_and_checks_minimum() {
+        let order = Order::place(
+            1,
+            vec![OrderItem {
+                unit_price: 1000,
+                tax_rate: 2100,
+                ..OrderItem::new()
+            }],
+            vec![],
+            0,
+            &PricingRules::default(),
+        );
+        let address = Address {
+            id: Some(3),
+            province: "Managua".to_string(),
+            city: "Managua".to_string(),
+            address: "Del parque central 2c al norte".to_string(),
+            ..Address::new()
+        };
+        let zone = DeliveryZone {
+            fee: 300,
+            minimum_order: 1500,
+            ..DeliveryZone::new()
+        };
+
+        assert!(matches!(
+            order.clone().deliver_to(&address, &zone),
+            Err(DeliveryError::BelowMinimumOrder(1500))
+        ));
+
+        let zone = DeliveryZone {
+            minimum_order: 1000,
+            ..zone
+        };
+        let order = order.deliver_to(&address, &zone).unwrap();
+
+        assert_eq!(order.fulfilment_type, FulfilmentType::Delivery);
+        assert_eq!(order.delivery_address_id, Some(3));
+        assert_eq!(order.pricing.delivery_fee.amount, 300);
+        assert_eq!(order.total(), 1000 + 210 + 300);
+    }
+}
@@ -0,0 +1,50 @@
+use super::order::Order;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderEventKind {
+    Created,
+    Updated,
+    /// Un pedido programado entró a la cola del barista.
+    Released,
+}
+
+impl OrderEventKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            OrderEventKind::Created => "order_created",
+            OrderEventKind::Updated => "order_updated",
+            OrderEventKind::Released => "order_released",
+        }
+    }
+}
+
+/// Notificación emitida cuando se crea un pedido, cambia su estado o asignación, o entra a la
+/// cola tras estar programado.
+#[derive(Debug, Clone)]
+pub struct OrderEvent {
+    pub kind: OrderEventKind,
+    pub order: Order,
+}
+
+impl OrderEvent {
+    pub fn created(order: Order) -> Self {
+        OrderEvent {
+            kind: OrderEventKind::Created,
+            order,
+        }
+    }
+
+    pub fn updated(order: Order) -> Self {
+        OrderEvent {
+            kind: OrderEventKind::Updated,
+            order,
+        }
+    }
+
+    pub fn released(order: Order) -> Self {
+        OrderEvent {
+            kind: OrderEventKind::Released,
+            order,
+        }
+    }
+}
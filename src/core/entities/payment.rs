@@ -0,0 +1,292 @@
+use crate::infrastructure::data::models::payment::{
+    Payment as PaymentModel, PaymentAttempt as PaymentAttemptModel,
+};
+use chrono::{DateTime, Datelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentMethod {
+    #[default]
+    Card,
+    CashOnPickup,
+}
+
+impl PaymentMethod {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "cash_on_pickup" => PaymentMethod::CashOnPickup,
+            _ => PaymentMethod::Card,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            PaymentMethod::Card => "card",
+            PaymentMethod::CashOnPickup => "cash_on_pickup",
+        }
+    }
+}
+
+/// Estados de un pago.
+///
+/// ```text
+/// pending -> authorized -> captured -> refunded
+///    |           |
+///    |           +-> voided
+///    +-> declined
+/// ```
+///
+/// El pago con tarjeta se guarda como `pending` antes de pedir la autorización a la pasarela;
+/// el efectivo pasa directo a `captured` al cobrarse en el local.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentStatus {
+    Pending,
+    Authorized,
+    Captured,
+    Voided,
+    Refunded,
+    Declined,
+}
+
+impl PaymentStatus {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "pending" => PaymentStatus::Pending,
+            "captured" => PaymentStatus::Captured,
+            "voided" => PaymentStatus::Voided,
+            "refunded" => PaymentStatus::Refunded,
+            "declined" => PaymentStatus::Declined,
+            _ => PaymentStatus::Authorized,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            PaymentStatus::Pending => "pending",
+            PaymentStatus::Authorized => "authorized",
+            PaymentStatus::Captured => "captured",
+            PaymentStatus::Voided => "voided",
+            PaymentStatus::Refunded => "refunded",
+            PaymentStatus::Declined => "declined",
+        }
+    }
+
+    /// Un pago activo es el que retiene o ya cobró el dinero del pedido, o el que espera la
+    /// respuesta de la pasarela.
+    pub fn is_active(&self) -> bool {
+        matches!(
+            self,
+            PaymentStatus::Pending | PaymentStatus::Authorized | PaymentStatus::Captured
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentOperation {
+    Authorize,
+    Capture,
+    Void,
+    Refund,
+}
+
+impl PaymentOperation {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "capture" => PaymentOperation::Capture,
+            "void" => PaymentOperation::Void,
+            "refund" => PaymentOperation::Refund,
+            _ => PaymentOperation::Authorize,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            PaymentOperation::Authorize => "authorize",
+            PaymentOperation::Capture => "capture",
+            PaymentOperation::Void => "void",
+            PaymentOperation::Refund => "refund",
+        }
+    }
+}
+
+/// Datos de la tarjeta. Nunca se persisten; del pago solo se guardan los últimos cuatro dígitos.
+#[derive(Clone)]
+pub struct PaymentCard {
+    pub number: String,
+    pub expiry_month: u32,
+    pub expiry_year: i32,
+    pub cvc: String,
+}
+
+/// Muestra solo los últimos cuatro dígitos y omite el código de seguridad para que no terminen
+/// en el log.
+impl fmt::Debug for PaymentCard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PaymentCard")
+            .field("number", &format!("***{}", self.last_four()))
+            .field("expiry_month", &self.expiry_month)
+            .field("expiry_year", &self.expiry_year)
+            .field("cvc", &"***")
+            .finish()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Payment {
+    pub id: Option<i32>,
+    pub order_id: i32,
+    pub method: PaymentMethod,
+    pub status: PaymentStatus,
+    /// Monto en centavos.
+    pub amount: i64,
+    /// Identificador de la operación en la pasarela de pago.
+    pub reference: Option<String>,
+    pub card_last_four: Option<String>,
+    pub attempts: Vec<PaymentAttempt>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Resultado de cada operación enviada a la pasarela, exitosa o no.
+#[derive(Clone, Debug)]
+pub struct PaymentAttempt {
+    pub operation: PaymentOperation,
+    pub amount: i64,
+    pub succeeded: bool,
+    pub failure_reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PaymentCard {
+    pub fn last_four(&self) -> String {
+        let digits = self.number.chars().count();
+
+        self.number.chars().skip(digits.saturating_sub(4)).collect()
+    }
+
+    pub fn is_valid(&self, now: DateTime<Utc>) -> bool {
+        let expired = (self.expiry_year, self.expiry_month) < (now.year(), now.month());
+
+        (12..=19).contains(&self.number.len())
+            && self.number.chars().all(|c| c.is_ascii_digit())
+            && (1..=12).contains(&self.expiry_month)
+            && (3..=4).contains(&self.cvc.len())
+            && !expired
+    }
+}
+
+impl Payment {
+    pub fn new() -> Payment {
+        let now = Utc::now();
+
+        Payment {
+            id: None,
+            order_id: 0,
+            method: PaymentMethod::Card,
+            status: PaymentStatus::Authorized,
+            amount: 0,
+            reference: None,
+            card_last_four: None,
+            attempts: vec![],
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn from_payment_model(row: PaymentModel, attempts: Vec<PaymentAttempt>) -> Self {
+        Payment {
+            id: row.id,
+            order_id: row.order_id,
+            method: PaymentMethod::from_code(&row.method),
+            status: PaymentStatus::from_code(&row.status),
+            amount: row.amount,
+            reference: row.reference,
+            card_last_four: row.card_last_four,
+            attempts,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+impl Default for Payment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PaymentAttempt {
+    pub fn succeeded(operation: PaymentOperation, amount: i64) -> Self {
+        PaymentAttempt {
+            operation,
+            amount,
+            succeeded: true,
+            failure_reason: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn failed(operation: PaymentOperation, amount: i64, reason: String) -> Self {
+        PaymentAttempt {
+            operation,
+            amount,
+            succeeded: false,
+            failure_reason: Some(reason),
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn from_payment_attempt_model(row: PaymentAttemptModel) -> Self {
+        PaymentAttempt {
+            operation: PaymentOperation::from_code(&row.operation),
+            amount: row.amount,
+            succeeded: row.succeeded,
+            failure_reason: row.failure_reason,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod payment_tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn card(number: &str, expiry_month: u32, expiry_year: i32) -> PaymentCard {
+        PaymentCard {
+            number: number.to_string(),
+            expiry_month,
+            expiry_year,
+            cvc: "123".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_card_last_four() {
+        assert_eq!(card("4242424242424242", 12, 2030).last_four(), "4242");
+    }
+
+    #[test]
+    fn test_card_debug_output_hides_number_and_cvc() {
+        let output = format!("{:?}", card("4242424242421234", 12, 2030));
+
+        assert!(!output.contains("424242"));
+        assert!(!output.contains("123\""));
+        assert!(output.contains("number: \"***1234\""));
+        assert!(output.contains("cvc: \"***\""));
+    }
+
+    #[test]
+    fn test_card_is_valid() {
+        let now = Utc.with_ymd_and_hms(2026, 5, 10, 0, 0, 0).unwrap();
+
+        assert!(card("4242424242424242", 5, 2026).is_valid(now));
+        assert!(!card("4242424242424242", 4, 2026).is_valid(now));
+        assert!(!card("4242-4242-4242", 12, 2030).is_valid(now));
+        assert!(!card("4242424242424242", 13, 2030).is_valid(now));
+    }
+}
@@ -0,0 +1,207 @@
+use super::money::{Currency, Money};
+use std::collections::BTreeMap;
+
+/// Reglas de precios del local. Las tasas van en puntos básicos (`2100` = 21 %).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PricingRules {
+    pub currency: Currency,
+    /// IVA aplicado a los productos cuya categoría no define una tasa propia.
+    pub default_tax_rate: i32,
+    /// Cargo por servicio sobre el subtotal con descuentos; `0` lo desactiva.
+    pub service_charge_rate: i32,
+}
+
+impl Default for PricingRules {
+    fn default() -> Self {
+        PricingRules {
+            currency: Currency::Eur,
+            default_tax_rate: 2100,
+            service_charge_rate: 0,
+        }
+    }
+}
+
+/// Línea a valorizar: subtotal sin impuestos y tasa de IVA que le corresponde.
+#[derive(Clone, Copy, Debug)]
+pub struct PricedLine {
+    pub subtotal: Money,
+    pub tax_rate: i32,
+}
+
+/// Desglose del precio de un carrito o pedido.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PriceBreakdown {
+    pub subtotal: Money,
+    pub discount: Money,
+    pub tax: Money,
+    pub service_charge: Money,
+    pub tip: Money,
+    /// Costo de envío de los pedidos a domicilio.
+    pub delivery_fee: Money,
+    pub total: Money,
+}
+
+impl PriceBreakdown {
+    pub fn zero(currency: Currency) -> Self {
+        PriceBreakdown {
+            subtotal: Money::zero(currency),
+            discount: Money::zero(currency),
+            tax: Money::zero(currency),
+            service_charge: Money::zero(currency),
+            tip: Money::zero(currency),
+            delivery_fee: Money::zero(currency),
+            total: Money::zero(currency),
+        }
+    }
+
+    /// Calcula el desglose con reglas deterministas:
+    ///
+    /// 1. El descuento nunca supera el subtotal y se reparte entre las tasas de IVA en
+    ///    proporción a su base (ver [`Money::allocate`]).
+    /// 2. El IVA se calcula una vez por tasa sobre la base ya descontada y se redondea al
+    ///    centavo.
+    /// 3. El cargo por servicio se aplica sobre el subtotal descontado, sin IVA.
+    /// 4. La propina se suma al final, sin impuestos ni cargos.
+    ///
+    /// El costo de envío se agrega después con [`PriceBreakdown::with_delivery_fee`].
+    pub fn compute(
+        lines: &[PricedLine],
+        discount: Money,
+        tip: Money,
+        rules: &PricingRules,
+    ) -> Self {
+        let currency = rules.currency;
+        let mut bases: BTreeMap<i32, Money> = BTreeMap::new();
+
+        for line in lines {
+            let base = bases.entry(line.tax_rate).or_insert(Money::zero(currency));
+            *base = *base + line.subtotal;
+        }
+
+        let subtotal = bases
+            .values()
+            .fold(Money::zero(currency), |acc, base| acc + *base);
+        let discount = discount.min(subtotal);
+        let weights: Vec<i64> = bases.values().map(|base| base.amount).collect();
+        let discounts = discount.allocate(&weights);
+
+        let tax = bases
+            .iter()
+            .zip(discounts)
+            .fold(Money::zero(currency), |acc, ((rate, base), discount)| {
+                acc + (*base - discount).percentage(*rate)
+            });
+        let service_charge = (subtotal - discount).percentage(rules.service_charge_rate);
+
+        PriceBreakdown {
+            subtotal,
+            discount,
+            tax,
+            service_charge,
+            tip,
+            delivery_fee: Money::zero(currency),
+            total: subtotal - discount + tax + service_charge + tip,
+        }
+    }
+
+    /// Suma al total el costo de envío, que no lleva IVA ni cargo por servicio ni se ve
+    /// afectado por los descuentos.
+    pub fn with_delivery_fee(self, delivery_fee: Money) -> Self {
+        PriceBreakdown {
+            delivery_fee,
+            total: self.total - self.delivery_fee + delivery_fee,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod pricing_tests {
+    use super::*;
+
+    fn eur(amount: i64) -> Money {
+        Money::new(amount, Currency::Eur)
+    }
+
+    fn line(subtotal: i64, tax_rate: i32) -> PricedLine {
+        PricedLine {
+            subtotal: eur(subtotal),
+            tax_rate,
+        }
+    }
+
+    #[test]
+    fn test_compute_taxes_each_rate_once() {
+        let rules = PricingRules::default();
+        // Dos líneas de 0,50 € al 21 %: 21 centavos (10,5 + 10,5 redondearía a 22 por línea).
+        let breakdown = PriceBreakdown::compute(
+            &[line(50, 2100), line(50, 2100), line(300, 1000)],
+            eur(0),
+            eur(0),
+            &rules,
+        );
+
+        assert_eq!(breakdown.subtotal, eur(400));
+        assert_eq!(breakdown.tax, eur(51));
+        assert_eq!(breakdown.total, eur(451));
+    }
+
+    #[test]
+    fn test_compute_applies_discount_before_tax_and_service_charge() {
+        let rules = PricingRules {
+            service_charge_rate: 500,
+            ..PricingRules::default()
+        };
+        let breakdown = PriceBreakdown::compute(&[line(1000, 2100)], eur(200), eur(150), &rules);
+
+        assert_eq!(breakdown.discount, eur(200));
+        assert_eq!(breakdown.tax, eur(168));
+        assert_eq!(breakdown.service_charge, eur(40));
+        assert_eq!(breakdown.tip, eur(150));
+        assert_eq!(breakdown.total, eur(1000 - 200 + 168 + 40 + 150));
+    }
+
+    #[test]
+    fn test_compute_caps_discount_at_subtotal() {
+        let breakdown = PriceBreakdown::compute(
+            &[line(300, 2100)],
+            eur(500),
+            eur(0),
+            &PricingRules::default(),
+        );
+
+        assert_eq!(breakdown.discount, eur(300));
+        assert_eq!(breakdown.tax, eur(0));
+        assert_eq!(breakdown.total, eur(0));
+    }
+
+    #[test]
+    fn test_compute_spreads_discount_across_tax_rates() {
+        let breakdown = PriceBreakdown::compute(
+            &[line(600, 2100), line(400, 1000)],
+            eur(100),
+            eur(0),
+            &PricingRules::default(),
+        );
+
+        // Bases descontadas: 540 al 21 % (113,4) y 360 al 10 % (36).
+        assert_eq!(breakdown.tax, eur(113 + 36));
+        assert_eq!(breakdown.total, eur(900 + 149));
+    }
+
+    #[test]
+    fn test_delivery_fee_is_added_untaxed() {
+        let breakdown = PriceBreakdown::compute(
+            &[line(1000, 2100)],
+            eur(1000),
+            eur(0),
+            &PricingRules::default(),
+        )
+        .with_delivery_fee(eur(300));
+
+        assert_eq!(breakdown.tax, eur(0));
+        assert_eq!(breakdown.delivery_fee, eur(300));
+        assert_eq!(breakdown.total, eur(300));
+        assert_eq!(breakdown.with_delivery_fee(eur(250)).total, eur(250));
+    }
+}
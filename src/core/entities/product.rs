@@ -0,0 +1,241 @@
+use super::modifier::{ModifierGroup, ModifierOption};
+use crate::{
+    core::errors::catalog_errors::CatalogError,
+    infrastructure::data::models::product::Product as ProductModel,
+};
+
+#[derive(Clone, Debug)]
+pub struct Product {
+    pub id: Option<i32>,
+    pub category_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    /// Precio en centavos.
+    pub price: i64,
+    pub image_url: Option<String>,
+    pub available: bool,
+    /// Falso si a algún insumo de la receta no le alcanza el stock para preparar una unidad.
+    pub in_stock: bool,
+    pub modifier_groups: Vec<ModifierGroup>,
+}
+
+impl Product {
+    pub fn new() -> Product {
+        Product {
+            id: None,
+            category_id: 0,
+            name: "".to_string(),
+            description: None,
+            price: 0,
+            image_url: None,
+            available: true,
+            in_stock: true,
+            modifier_groups: vec![],
+        }
+    }
+
+    pub fn from_product_model(row: ProductModel) -> Self {
+        Product {
+            id: row.id,
+            category_id: row.category,
+            name: row.name,
+            description: row.description,
+            price: row.price,
+            image_url: row.image_url,
+            available: row.available,
+            in_stock: row.in_stock,
+            modifier_groups: vec![],
+        }
+    }
+
+    pub fn from_product_model_vec(rows: Vec<ProductModel>) -> Vec<Self> {
+        rows.iter()
+            .map(|row| Self::from_product_model(row.clone()))
+            .collect()
+    }
+
+    /// Disponible para la venta: habilitado en el catálogo y con stock suficiente.
+    pub fn is_available(&self) -> bool {
+        self.available && self.in_stock
+    }
+
+    pub fn find_option(&self, option_id: i32) -> Option<&ModifierOption> {
+        self.modifier_groups
+            .iter()
+            .flat_map(|group| group.options.iter())
+            .find(|option| option.id == Some(option_id))
+    }
+
+    /// Función que valida una configuración del producto y calcula su precio final.
+    ///
+    /// Recibe los IDs de las opciones elegidas (una opción repetida cuenta como varias
+    /// selecciones, p. ej. dos shots extra). Cada opción debe pertenecer a un grupo del producto
+    /// y estar disponible, y cada grupo debe respetar su mínimo y máximo de selecciones.
+    /// Retorna el precio base más la suma de las diferencias de precio, en centavos.
+    pub fn price_configuration(&self, option_ids: &[i32]) -> Result<i64, CatalogError> {
+        if !self.is_available() {
+            return Err(CatalogError::ProductUnavailable);
+        }
+
+        let mut price = self.price;
+
+        for option_id in option_ids {
+            let option = self.find_option(*option_id).ok_or_else(|| {
+                CatalogError::InvalidConfiguration(format!(
+                    "option {} does not belong to product",
+                    option_id
+                ))
+            })?;
+
+            if !option.is_available() {
+                return Err(CatalogError::InvalidConfiguration(format!(
+                    "option {} is not available",
+                    option.name
+                )));
+            }
+
+            price += option.price_delta;
+        }
+
+        for group in &self.modifier_groups {
+            let selections = option_ids
+                .iter()
+                .filter(|option_id| {
+                    group
+                        .options
+                        .iter()
+                        .any(|option| option.id == Some(**option_id))
+                })
+                .count() as i32;
+
+            if selections < group.min_selections || selections > group.max_selections {
+                return Err(CatalogError::InvalidConfiguration(format!(
+                    "{} requires between {} and {} selections",
+                    group.name, group.min_selections, group.max_selections
+                )));
+            }
+        }
+
+        Ok(price)
+    }
+}
+
+impl Default for Product {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod product_tests {
+    use super::*;
+    use crate::core::entities::modifier::ModifierOption;
+
+    fn option(id: i32, group_id: i32, price_delta: i64) -> ModifierOption {
+        ModifierOption {
+            id: Some(id),
+            group_id,
+            name: format!("Option {}", id),
+            price_delta,
+            ..ModifierOption::new()
+        }
+    }
+
+    fn latte() -> Product {
+        Product {
+            id: Some(1),
+            name: "Latte".to_string(),
+            price: 275,
+            modifier_groups: vec![
+                ModifierGroup {
+                    id: Some(1),
+                    name: "Tamaño".to_string(),
+                    min_selections: 1,
+                    max_selections: 1,
+                    options: vec![option(1, 1, 0), option(2, 1, 50)],
+                },
+                ModifierGroup {
+                    id: Some(2),
+                    name: "Shots extra".to_string(),
+                    min_selections: 0,
+                    max_selections: 2,
+                    options: vec![option(3, 2, 60)],
+                },
+            ],
+            ..Product::new()
+        }
+    }
+
+    #[test]
+    fn test_price_configuration_adds_price_deltas() {
+        assert_eq!(latte().price_configuration(&[2, 3, 3]).unwrap(), 445);
+    }
+
+    #[test]
+    fn test_price_configuration_requires_minimum_selections() {
+        assert!(matches!(
+            latte().price_configuration(&[3]),
+            Err(CatalogError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn test_price_configuration_rejects_too_many_selections() {
+        assert!(matches!(
+            latte().price_configuration(&[1, 2]),
+            Err(CatalogError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn test_price_configuration_rejects_foreign_option() {
+        assert!(matches!(
+            latte().price_configuration(&[1, 99]),
+            Err(CatalogError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn test_price_configuration_rejects_unavailable_option() {
+        let mut product = latte();
+        product.modifier_groups[0].options[1].available = false;
+
+        assert!(matches!(
+            product.price_configuration(&[2]),
+            Err(CatalogError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[test]
+    fn test_price_configuration_rejects_unavailable_product() {
+        let mut product = latte();
+        product.available = false;
+
+        assert!(matches!(
+            product.price_configuration(&[1]),
+            Err(CatalogError::ProductUnavailable)
+        ));
+    }
+
+    #[test]
+    fn test_price_configuration_rejects_product_out_of_stock() {
+        let mut product = latte();
+        product.in_stock = false;
+
+        assert!(matches!(
+            product.price_configuration(&[1]),
+            Err(CatalogError::ProductUnavailable)
+        ));
+    }
+
+    #[test]
+    fn test_price_configuration_rejects_option_out_of_stock() {
+        let mut product = latte();
+        product.modifier_groups[1].options[0].in_stock = false;
+
+        assert!(matches!(
+            product.price_configuration(&[1, 3]),
+            Err(CatalogError::InvalidConfiguration(_))
+        ));
+    }
+}
@@ -0,0 +1,498 @@
+use super::money::{Currency, Money};
+use crate::infrastructure::data::models::promotion::{
+    Promotion as PromotionModel, PromotionRedemption as PromotionRedemptionModel,
+};
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Tipo de descuento de una promoción. Las tasas van en puntos básicos y los montos en centavos.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum PromotionKind {
+    PercentageOff {
+        rate: i32,
+    },
+    FixedAmountOff {
+        amount: i64,
+    },
+    /// 2x1: de cada dos unidades de la categoría se descuenta la más barata.
+    BuyOneGetOne {
+        category_id: i32,
+    },
+}
+
+impl PromotionKind {
+    pub fn from_model(row: &PromotionModel) -> Self {
+        match row.kind.as_str() {
+            "fixed_amount_off" => PromotionKind::FixedAmountOff {
+                amount: row.amount.unwrap_or_default(),
+            },
+            "buy_one_get_one" => PromotionKind::BuyOneGetOne {
+                category_id: row.category.unwrap_or_default(),
+            },
+            _ => PromotionKind::PercentageOff {
+                rate: row.rate.unwrap_or_default(),
+            },
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            PromotionKind::PercentageOff { .. } => "percentage_off",
+            PromotionKind::FixedAmountOff { .. } => "fixed_amount_off",
+            PromotionKind::BuyOneGetOne { .. } => "buy_one_get_one",
+        }
+    }
+}
+
+/// Franja horaria diaria en UTC, por ejemplo un happy hour. Si `ends` es anterior a `starts`
+/// la franja cruza la medianoche.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DailyWindow {
+    pub starts: NaiveTime,
+    pub ends: NaiveTime,
+}
+
+impl DailyWindow {
+    pub fn contains(&self, time: NaiveTime) -> bool {
+        if self.starts <= self.ends {
+            self.starts <= time && time < self.ends
+        } else {
+            time >= self.starts || time < self.ends
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Promotion {
+    pub id: Option<i32>,
+    pub name: String,
+    /// Código del cupón; `None` para las promociones que se aplican solas.
+    pub code: Option<String>,
+    pub kind: PromotionKind,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub daily_window: Option<DailyWindow>,
+    /// Subtotal mínimo en centavos para que la promoción aplique.
+    pub min_order_amount: i64,
+    /// Canjes permitidos entre todos los usuarios.
+    pub max_uses: Option<i32>,
+    pub max_uses_per_user: Option<i32>,
+    /// Las promociones acumulables se combinan entre sí; las demás se aplican solas.
+    pub stackable: bool,
+    pub active: bool,
+}
+
+/// Canjes ya registrados de una promoción.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PromotionUsage {
+    pub total: i64,
+    pub by_user: i64,
+}
+
+/// Unidades de una línea, tal como las evalúan las promociones.
+#[derive(Clone, Copy, Debug)]
+pub struct PromotionLine {
+    pub category_id: i32,
+    pub unit_price: Money,
+    pub quantity: i32,
+}
+
+/// Descuento de una promoción aplicado a un carrito o pedido.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AppliedDiscount {
+    pub promotion_id: i32,
+    pub name: String,
+    pub code: Option<String>,
+    /// Monto descontado en centavos.
+    pub amount: i64,
+}
+
+impl Promotion {
+    pub fn new() -> Promotion {
+        Promotion {
+            id: None,
+            name: "".to_string(),
+            code: None,
+            kind: PromotionKind::PercentageOff { rate: 0 },
+            starts_at: Utc::now(),
+            ends_at: None,
+            daily_window: None,
+            min_order_amount: 0,
+            max_uses: None,
+            max_uses_per_user: None,
+            stackable: false,
+            active: true,
+        }
+    }
+
+    pub fn from_promotion_model(row: PromotionModel) -> Self {
+        let daily_window = match (row.daily_starts, row.daily_ends) {
+            (Some(starts), Some(ends)) => Some(DailyWindow { starts, ends }),
+            _ => None,
+        };
+
+        Promotion {
+            id: row.id,
+            kind: PromotionKind::from_model(&row),
+            name: row.name,
+            code: row.code,
+            starts_at: row.starts_at,
+            ends_at: row.ends_at,
+            daily_window,
+            min_order_amount: row.min_order_amount,
+            max_uses: row.max_uses,
+            max_uses_per_user: row.max_uses_per_user,
+            stackable: row.stackable,
+            active: row.active,
+        }
+    }
+
+    pub fn from_promotion_model_vec(rows: Vec<PromotionModel>) -> Vec<Self> {
+        rows.into_iter().map(Self::from_promotion_model).collect()
+    }
+
+    /// Normaliza los códigos para que el canje no distinga mayúsculas ni espacios.
+    pub fn normalize_code(code: &str) -> String {
+        code.trim().to_uppercase()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("name is required".to_string());
+        }
+        if self
+            .code
+            .as_ref()
+            .is_some_and(|code| code.trim().is_empty())
+        {
+            return Err("code cannot be empty".to_string());
+        }
+        match self.kind {
+            PromotionKind::PercentageOff { rate } if !(1..=10_000).contains(&rate) => {
+                return Err("rate must be between 1 and 10000".to_string());
+            }
+            PromotionKind::FixedAmountOff { amount } if amount <= 0 => {
+                return Err("amount must be greater than zero".to_string());
+            }
+            _ => {}
+        }
+        if self
+            .ends_at
+            .is_some_and(|ends_at| ends_at <= self.starts_at)
+        {
+            return Err("ends_at must be after starts_at".to_string());
+        }
+        if self.min_order_amount < 0 {
+            return Err("min_order_amount cannot be negative".to_string());
+        }
+        if self.max_uses.is_some_and(|uses| uses <= 0)
+            || self.max_uses_per_user.is_some_and(|uses| uses <= 0)
+        {
+            return Err("usage limits must be greater than zero".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Comprueba si la promoción puede aplicarse ahora a un carrito con el subtotal indicado y
+    /// retorna el motivo en caso contrario.
+    pub fn check_eligibility(
+        &self,
+        now: DateTime<Utc>,
+        subtotal: i64,
+        usage: PromotionUsage,
+    ) -> Result<(), String> {
+        if !self.active {
+            return Err("promotion is not active".to_string());
+        }
+        if now < self.starts_at || self.ends_at.is_some_and(|ends_at| now >= ends_at) {
+            return Err("promotion is not valid at this date".to_string());
+        }
+        if self
+            .daily_window
+            .is_some_and(|window| !window.contains(now.time()))
+        {
+            return Err("promotion is not valid at this time".to_string());
+        }
+        if subtotal < self.min_order_amount {
+            return Err(format!(
+                "order subtotal must be at least {}",
+                self.min_order_amount
+            ));
+        }
+        self.check_usage(usage)
+    }
+
+    /// Comprueba solo los límites de uso, que la compra vuelve a verificar con la promoción
+    /// bloqueada.
+    pub fn check_usage(&self, usage: PromotionUsage) -> Result<(), String> {
+        if self.max_uses.is_some_and(|uses| usage.total >= uses as i64) {
+            return Err("promotion has reached its usage limit".to_string());
+        }
+        if self
+            .max_uses_per_user
+            .is_some_and(|uses| usage.by_user >= uses as i64)
+        {
+            return Err("promotion has already been used".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Descuento que otorga la promoción sobre las líneas, sin superar su subtotal.
+    pub fn discount(&self, lines: &[PromotionLine], currency: Currency) -> Money {
+        let subtotal = lines.iter().fold(Money::zero(currency), |total, line| {
+            total + line.unit_price.times(line.quantity)
+        });
+
+        let discount = match self.kind {
+            PromotionKind::PercentageOff { rate } => subtotal.percentage(rate),
+            PromotionKind::FixedAmountOff { amount } => Money::new(amount, currency),
+            PromotionKind::BuyOneGetOne { category_id } => {
+                let mut unit_prices: Vec<i64> = lines
+                    .iter()
+                    .filter(|line| line.category_id == category_id)
+                    .flat_map(|line| (0..line.quantity).map(|_| line.unit_price.amount))
+                    .collect();
+                unit_prices.sort_unstable_by(|a, b| b.cmp(a));
+
+                Money::new(unit_prices.iter().skip(1).step_by(2).sum(), currency)
+            }
+        };
+
+        discount.min(subtotal)
+    }
+}
+
+impl Default for Promotion {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AppliedDiscount {
+    pub fn from_promotion_redemption_model(row: PromotionRedemptionModel) -> Self {
+        AppliedDiscount {
+            promotion_id: row.promotion,
+            name: row.name,
+            code: row.code,
+            amount: row.amount,
+        }
+    }
+}
+
+/// Elige los descuentos a aplicar entre promociones ya elegibles. Las acumulables se suman
+/// entre sí y cada promoción no acumulable compite sola; gana la opción que más descuenta y,
+/// ante un empate, la combinación de acumulables. El total nunca supera el subtotal.
+pub fn select_discounts(
+    promotions: &[Promotion],
+    lines: &[PromotionLine],
+    currency: Currency,
+) -> Vec<AppliedDiscount> {
+    let subtotal: i64 = lines
+        .iter()
+        .map(|line| line.unit_price.times(line.quantity).amount)
+        .sum();
+    let applied = |promotion: &Promotion, amount: i64| AppliedDiscount {
+        promotion_id: promotion.id.unwrap_or_default(),
+        name: promotion.name.clone(),
+        code: promotion.code.clone(),
+        amount,
+    };
+
+    let mut stacked = vec![];
+    let mut remaining = subtotal;
+    for promotion in promotions.iter().filter(|promotion| promotion.stackable) {
+        let amount = promotion.discount(lines, currency).amount.min(remaining);
+        if amount > 0 {
+            remaining -= amount;
+            stacked.push(applied(promotion, amount));
+        }
+    }
+
+    let exclusive = promotions
+        .iter()
+        .filter(|promotion| !promotion.stackable)
+        .map(|promotion| (promotion, promotion.discount(lines, currency).amount))
+        .filter(|(_, amount)| *amount > 0)
+        .fold(
+            None,
+            |best: Option<(&Promotion, i64)>, candidate| match best {
+                Some(best) if best.1 >= candidate.1 => Some(best),
+                _ => Some(candidate),
+            },
+        );
+
+    match exclusive {
+        Some((promotion, amount)) if amount > subtotal - remaining => {
+            vec![applied(promotion, amount)]
+        }
+        _ => stacked,
+    }
+}
+
+#[cfg(test)]
+mod promotion_tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn eur(amount: i64) -> Money {
+        Money::new(amount, Currency::Eur)
+    }
+
+    fn line(category_id: i32, unit_price: i64, quantity: i32) -> PromotionLine {
+        PromotionLine {
+            category_id,
+            unit_price: eur(unit_price),
+            quantity,
+        }
+    }
+
+    fn promotion(id: i32, kind: PromotionKind, stackable: bool) -> Promotion {
+        Promotion {
+            id: Some(id),
+            name: format!("Promo {}", id),
+            kind,
+            stackable,
+            starts_at: Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            ..Promotion::new()
+        }
+    }
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2024, 6, 1, hour, minute, 0).unwrap()
+    }
+
+    #[test]
+    fn test_discount_percentage_and_fixed_amount_are_capped_at_subtotal() {
+        let lines = [line(1, 250, 2)];
+        let percentage = promotion(1, PromotionKind::PercentageOff { rate: 1500 }, false);
+        let fixed = promotion(2, PromotionKind::FixedAmountOff { amount: 800 }, false);
+
+        assert_eq!(percentage.discount(&lines, Currency::Eur), eur(75));
+        assert_eq!(fixed.discount(&lines, Currency::Eur), eur(500));
+    }
+
+    #[test]
+    fn test_discount_buy_one_get_one_frees_the_cheapest_of_each_pair() {
+        // Unidades de la categoría 1 ordenadas: 400, 300, 300, 200, 200 -> gratis 300 y 200.
+        let lines = [
+            line(1, 300, 2),
+            line(1, 200, 2),
+            line(1, 400, 1),
+            line(2, 900, 3),
+        ];
+        let promo = promotion(1, PromotionKind::BuyOneGetOne { category_id: 1 }, false);
+
+        assert_eq!(promo.discount(&lines, Currency::Eur), eur(500));
+    }
+
+    #[test]
+    fn test_check_eligibility_rules() {
+        let promo = Promotion {
+            ends_at: Some(at(23, 0)),
+            daily_window: Some(DailyWindow {
+                starts: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+                ends: NaiveTime::from_hms_opt(19, 0, 0).unwrap(),
+            }),
+            min_order_amount: 500,
+            max_uses: Some(10),
+            max_uses_per_user: Some(1),
+            ..promotion(1, PromotionKind::PercentageOff { rate: 2000 }, false)
+        };
+        let unused = PromotionUsage::default();
+
+        assert!(promo.check_eligibility(at(18, 0), 500, unused).is_ok());
+        assert!(promo.check_eligibility(at(16, 59), 500, unused).is_err());
+        assert!(promo.check_eligibility(at(18, 0), 499, unused).is_err());
+        assert!(promo
+            .check_eligibility(
+                at(18, 0),
+                500,
+                PromotionUsage {
+                    total: 10,
+                    by_user: 0
+                }
+            )
+            .is_err());
+        assert!(promo
+            .check_eligibility(
+                at(18, 0),
+                500,
+                PromotionUsage {
+                    total: 3,
+                    by_user: 1
+                }
+            )
+            .is_err());
+        assert!(Promotion {
+            active: false,
+            ..promo.clone()
+        }
+        .check_eligibility(at(18, 0), 500, unused)
+        .is_err());
+    }
+
+    #[test]
+    fn test_daily_window_can_cross_midnight() {
+        let window = DailyWindow {
+            starts: NaiveTime::from_hms_opt(22, 0, 0).unwrap(),
+            ends: NaiveTime::from_hms_opt(2, 0, 0).unwrap(),
+        };
+
+        assert!(window.contains(NaiveTime::from_hms_opt(23, 30, 0).unwrap()));
+        assert!(window.contains(NaiveTime::from_hms_opt(1, 0, 0).unwrap()));
+        assert!(!window.contains(NaiveTime::from_hms_opt(12, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn test_select_discounts_stacks_stackable_promotions() {
+        let lines = [line(1, 1000, 1)];
+        let promotions = [
+            promotion(1, PromotionKind::PercentageOff { rate: 1000 }, true),
+            promotion(2, PromotionKind::FixedAmountOff { amount: 150 }, true),
+            promotion(3, PromotionKind::FixedAmountOff { amount: 200 }, false),
+        ];
+
+        let discounts = select_discounts(&promotions, &lines, Currency::Eur);
+
+        assert_eq!(
+            discounts.iter().map(|d| d.promotion_id).collect::<Vec<_>>(),
+            vec![1, 2]
+        );
+        assert_eq!(discounts.iter().map(|d| d.amount).sum::<i64>(), 250);
+    }
+
+    #[test]
+    fn test_select_discounts_prefers_a_larger_exclusive_promotion() {
+        let lines = [line(1, 1000, 1)];
+        let promotions = [
+            promotion(1, PromotionKind::PercentageOff { rate: 1000 }, true),
+            promotion(2, PromotionKind::FixedAmountOff { amount: 300 }, false),
+            promotion(3, PromotionKind::FixedAmountOff { amount: 400 }, false),
+        ];
+
+        let discounts = select_discounts(&promotions, &lines, Currency::Eur);
+
+        assert_eq!(discounts.len(), 1);
+        assert_eq!(discounts[0].promotion_id, 3);
+        assert_eq!(discounts[0].amount, 400);
+    }
+
+    #[test]
+    fn test_select_discounts_never_exceeds_subtotal() {
+        let lines = [line(1, 500, 1)];
+        let promotions = [
+            promotion(1, PromotionKind::FixedAmountOff { amount: 400 }, true),
+            promotion(2, PromotionKind::FixedAmountOff { amount: 400 }, true),
+        ];
+
+        let discounts = select_discounts(&promotions, &lines, Currency::Eur);
+
+        assert_eq!(
+            discounts.iter().map(|d| d.amount).collect::<Vec<_>>(),
+            vec![400, 100]
+        );
+    }
+}
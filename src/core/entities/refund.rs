@@ -0,0 +1,128 @@
+use crate::infrastructure::data::models::payment::Refund as RefundModel;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundReason {
+    CustomerRequest,
+    WrongItem,
+    QualityIssue,
+    Duplicate,
+    Other,
+}
+
+impl RefundReason {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "customer_request" => RefundReason::CustomerRequest,
+            "wrong_item" => RefundReason::WrongItem,
+            "quality_issue" => RefundReason::QualityIssue,
+            "duplicate" => RefundReason::Duplicate,
+            _ => RefundReason::Other,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            RefundReason::CustomerRequest => "customer_request",
+            RefundReason::WrongItem => "wrong_item",
+            RefundReason::QualityIssue => "quality_issue",
+            RefundReason::Duplicate => "duplicate",
+            RefundReason::Other => "other",
+        }
+    }
+}
+
+/// Estados de una devolución. Se reserva como `pending` antes de pedirla a la pasarela, para que
+/// dos solicitudes con la misma clave no devuelvan el dinero dos veces.
+///
+/// ```text
+/// pending -> completed
+///    |
+///    +-> failed
+/// ```
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+impl RefundStatus {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "pending" => RefundStatus::Pending,
+            "failed" => RefundStatus::Failed,
+            _ => RefundStatus::Completed,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            RefundStatus::Pending => "pending",
+            RefundStatus::Completed => "completed",
+            RefundStatus::Failed => "failed",
+        }
+    }
+}
+
+/// Devolución total o de algunas líneas de un pedido.
+#[derive(Clone, Debug)]
+pub struct Refund {
+    pub id: Option<i32>,
+    pub order_id: i32,
+    pub payment_id: i32,
+    /// Monto devuelto en centavos.
+    pub amount: i64,
+    pub reason: RefundReason,
+    pub status: RefundStatus,
+    pub note: Option<String>,
+    /// Líneas devueltas; vacío cuando se devuelve el saldo completo del pedido.
+    pub item_ids: Vec<i32>,
+    /// Clave enviada por el cliente para que reintentar la misma solicitud no devuelva dos veces.
+    pub idempotency_key: String,
+    pub refunded_by: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Refund {
+    pub fn new() -> Refund {
+        Refund {
+            id: None,
+            order_id: 0,
+            payment_id: 0,
+            amount: 0,
+            reason: RefundReason::Other,
+            status: RefundStatus::Pending,
+            note: None,
+            item_ids: vec![],
+            idempotency_key: "".to_string(),
+            refunded_by: 0,
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn from_refund_model(row: RefundModel, item_ids: Vec<i32>) -> Self {
+        Refund {
+            id: row.id,
+            order_id: row.order_id,
+            payment_id: row.payment,
+            amount: row.amount,
+            reason: RefundReason::from_code(&row.reason),
+            status: RefundStatus::from_code(&row.status),
+            note: row.note,
+            item_ids,
+            idempotency_key: row.idempotency_key,
+            refunded_by: row.refunded_by,
+            created_at: row.created_at,
+        }
+    }
+}
+
+impl Default for Refund {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,518 @@
+use crate::{
+    core::errors::store_errors::StoreError,
+    infrastructure::data::models::store::{
+        OpeningHours as OpeningHoursModel, Store as StoreModel,
+        StoreException as StoreExceptionModel,
+    },
+};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+/// Días hacia adelante en los que se busca la próxima apertura de un local.
+const OPENING_LOOKAHEAD_DAYS: i64 = 31;
+const DEFAULT_PICKUP_SLOT_MINUTES: i32 = 15;
+const DEFAULT_PICKUP_SLOT_CAPACITY: i32 = 10;
+const DEFAULT_PICKUP_RELEASE_MINUTES: i32 = 10;
+
+/// Franja semanal en la que el local atiende, en su hora local. Un día puede tener varias
+/// franjas (p. ej. cerrado al mediodía).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OpeningHours {
+    pub weekday: Weekday,
+    pub opens: NaiveTime,
+    pub closes: NaiveTime,
+}
+
+impl OpeningHours {
+    /// Convierte el número ISO del día (1 = lunes, 7 = domingo).
+    pub fn weekday_from_number(number: u32) -> Option<Weekday> {
+        (1..=7)
+            .contains(&number)
+            .then(|| Weekday::try_from((number - 1) as u8).ok())
+            .flatten()
+    }
+
+    pub fn from_opening_hours_model(row: OpeningHoursModel) -> Self {
+        OpeningHours {
+            weekday: Self::weekday_from_number(row.weekday as u32).unwrap_or(Weekday::Mon),
+            opens: row.opens,
+            closes: row.closes,
+        }
+    }
+}
+
+/// Excepción al horario semanal para una fecha (feriados, horarios especiales). Sin horario,
+/// el local permanece cerrado todo el día.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StoreException {
+    pub date: NaiveDate,
+    pub opens: Option<NaiveTime>,
+    pub closes: Option<NaiveTime>,
+    pub reason: Option<String>,
+}
+
+impl StoreException {
+    pub fn from_store_exception_model(row: StoreExceptionModel) -> Self {
+        StoreException {
+            date: row.date,
+            opens: row.opens,
+            closes: row.closes,
+            reason: row.reason,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match (self.opens, self.closes) {
+            (None, None) => Ok(()),
+            (Some(opens), Some(closes)) if opens < closes => Ok(()),
+            _ => Err("special hours need an opening time before the closing time".to_string()),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Store {
+    pub id: Option<i32>,
+    pub name: String,
+    pub address: String,
+    pub timezone: Tz,
+    /// Un local inactivo no recibe pedidos ni se considera abierto.
+    pub active: bool,
+    pub opening_hours: Vec<OpeningHours>,
+    pub exceptions: Vec<StoreException>,
+    /// Productos del catálogo que este local no ofrece.
+    pub unavailable_product_ids: Vec<i32>,
+    /// Duración de cada franja de retiro; las franjas se arman dentro del horario de atención.
+    pub pickup_slot_minutes: i32,
+    /// Pedidos que se aceptan como máximo por franja de retiro.
+    pub pickup_slot_capacity: i32,
+    /// Minutos antes de su franja en que un pedido programado pasa a la cola del barista.
+    pub pickup_release_minutes: i32,
+}
+
+/// Franja de retiro de un local con su ocupación.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PickupSlot {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub capacity: i32,
+    pub booked: i64,
+}
+
+impl PickupSlot {
+    pub fn is_available(&self) -> bool {
+        self.booked < self.capacity as i64
+    }
+}
+
+impl Store {
+    pub fn new() -> Store {
+        Store {
+            id: None,
+            name: "".to_string(),
+            address: "".to_string(),
+            timezone: Tz::UTC,
+            active: true,
+            opening_hours: vec![],
+            exceptions: vec![],
+            unavailable_product_ids: vec![],
+            pickup_slot_minutes: DEFAULT_PICKUP_SLOT_MINUTES,
+            pickup_slot_capacity: DEFAULT_PICKUP_SLOT_CAPACITY,
+            pickup_release_minutes: DEFAULT_PICKUP_RELEASE_MINUTES,
+        }
+    }
+
+    pub fn from_store_model(
+        row: StoreModel,
+        opening_hours: Vec<OpeningHours>,
+        exceptions: Vec<StoreException>,
+        unavailable_product_ids: Vec<i32>,
+    ) -> Self {
+        Store {
+            id: row.id,
+            name: row.name,
+            address: row.address,
+            timezone: row.timezone.parse().unwrap_or(Tz::UTC),
+            active: row.active,
+            opening_hours,
+            exceptions,
+            unavailable_product_ids,
+            pickup_slot_minutes: row.pickup_slot_minutes,
+            pickup_slot_capacity: row.pickup_slot_capacity,
+            pickup_release_minutes: row.pickup_release_minutes,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("name is required".to_string());
+        }
+        if self
+            .opening_hours
+            .iter()
+            .any(|hours| hours.opens >= hours.closes)
+        {
+            return Err("opening hours must open before they close".to_string());
+        }
+        if self.pickup_slot_minutes <= 0 || self.pickup_slot_capacity <= 0 {
+            return Err("pickup slots need a positive length and capacity".to_string());
+        }
+        if self.pickup_release_minutes < 0 {
+            return Err("pickup release minutes cannot be negative".to_string());
+        }
+
+        Ok(())
+    }
+
+    /// Franjas de atención de la fecha local indicada, ordenadas por hora de apertura.
+    pub fn hours_on(&self, date: NaiveDate) -> Vec<(NaiveTime, NaiveTime)> {
+        let mut hours: Vec<(NaiveTime, NaiveTime)> = match self
+            .exceptions
+            .iter()
+            .find(|exception| exception.date == date)
+        {
+            Some(exception) => match (exception.opens, exception.closes) {
+                (Some(opens), Some(closes)) => vec![(opens, closes)],
+                _ => vec![],
+            },
+            None => self
+                .opening_hours
+                .iter()
+                .filter(|hours| hours.weekday == date.weekday())
+                .map(|hours| (hours.opens, hours.closes))
+                .collect(),
+        };
+        hours.sort();
+
+        hours
+    }
+
+    pub fn is_open_at(&self, now: DateTime<Utc>) -> bool {
+        let local = now.with_timezone(&self.timezone);
+        let time = local.time();
+
+        self.active
+            && self
+                .hours_on(local.date_naive())
+                .iter()
+                .any(|(opens, closes)| *opens <= time && time < *closes)
+    }
+
+    /// Próximo momento posterior a `now` en que abre el local, o `None` si no abre dentro de
+    /// los próximos días.
+    pub fn next_opening(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if !self.active {
+            return None;
+        }
+
+        let today = now.with_timezone(&self.timezone).date_naive();
+
+        (0..=OPENING_LOOKAHEAD_DAYS)
+            .map(|offset| today + Duration::days(offset))
+            .flat_map(|date| {
+                self.hours_on(date)
+                    .into_iter()
+                    .map(move |(opens, _)| date.and_time(opens))
+            })
+            .filter_map(|opens| self.timezone.from_local_datetime(&opens).earliest())
+            .map(|opens| opens.with_timezone(&Utc))
+            .find(|opens| *opens > now)
+    }
+
+    pub fn offers_product(&self, product_id: i32) -> bool {
+        !self.unavailable_product_ids.contains(&product_id)
+    }
+
+    /// Franjas de retiro de la fecha local indicada. `bookings` trae cuántos pedidos tiene ya
+    /// cada franja, identificada por su inicio.
+    pub fn pickup_slots_on(
+        &self,
+        date: NaiveDate,
+        bookings: &[(DateTime<Utc>, i64)],
+    ) -> Vec<PickupSlot> {
+        if !self.active || self.pickup_slot_minutes <= 0 {
+            return vec![];
+        }
+
+        let length = Duration::minutes(self.pickup_slot_minutes as i64);
+        let mut slots = vec![];
+
+        for (opens, closes) in self.hours_on(date) {
+            let mut starts = date.and_time(opens);
+
+            while starts + length <= date.and_time(closes) {
+                if let Some(starts_at) = self.timezone.from_local_datetime(&starts).earliest() {
+                    let starts_at = starts_at.with_timezone(&Utc);
+
+                    slots.push(PickupSlot {
+                        starts_at,
+                        ends_at: starts_at + length,
+                        capacity: self.pickup_slot_capacity,
+                        booked: bookings
+                            .iter()
+                            .find(|(booked_at, _)| *booked_at == starts_at)
+                            .map_or(0, |(_, count)| *count),
+                    });
+                }
+                starts += length;
+            }
+        }
+
+        slots
+    }
+
+    /// Decide cuándo se atiende un pedido hecho en `now`. Si el cliente eligió una franja de
+    /// retiro, debe ser una franja futura del local con cupo. Sin franja, el pedido se atiende
+    /// de inmediato si el local está abierto; si está cerrado, se rechaza salvo que el cliente
+    /// acepte programarlo para la primera franja con cupo, que es lo que se retorna.
+    pub fn schedule_order(
+        &self,
+        now: DateTime<Utc>,
+        pickup_at: Option<DateTime<Utc>>,
+        schedule_if_closed: bool,
+        bookings: &[(DateTime<Utc>, i64)],
+    ) -> Result<Option<DateTime<Utc>>, StoreError> {
+        if let Some(pickup_at) = pickup_at {
+            let date = pickup_at.with_timezone(&self.timezone).date_naive();
+            let slot = self
+                .pickup_slots_on(date, bookings)
+                .into_iter()
+                .find(|slot| slot.starts_at == pickup_at && slot.starts_at > now)
+                .ok_or(StoreError::InvalidPickupTime)?;
+
+            if !slot.is_available() {
+                return Err(StoreError::SlotFull);
+            }
+
+            return Ok(Some(slot.starts_at));
+        }
+        if self.is_open_at(now) {
+            return Ok(None);
+        }
+        if !schedule_if_closed {
+            return Err(StoreError::Closed);
+        }
+
+        let today = now.with_timezone(&self.timezone).date_naive();
+
+        (0..=OPENING_LOOKAHEAD_DAYS)
+            .flat_map(|offset| self.pickup_slots_on(today + Duration::days(offset), bookings))
+            .find(|slot| slot.starts_at > now && slot.is_available())
+            .map(|slot| Some(slot.starts_at))
+            .ok_or(StoreError::Closed)
+    }
+
+    /// Momento en que un pedido programado para `scheduled_for` entra a la cola del barista, o
+    /// `None` si ya debe estar en ella.
+    pub fn release_at(
+        &self,
+        scheduled_for: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Option<DateTime<Utc>> {
+        scheduled_for
+            .map(|scheduled_for| {
+                scheduled_for - Duration::minutes(self.pickup_release_minutes as i64)
+            })
+            .filter(|release_at| *release_at > now)
+    }
+
+    /// Comprueba que el local ofrezca todos los productos del pedido.
+    pub fn check_products(&self, product_ids: &[i32]) -> Result<(), StoreError> {
+        match product_ids
+            .iter()
+            .find(|product_id| !self.offers_product(**product_id))
+        {
+            Some(product_id) => Err(StoreError::ProductUnavailable(*product_id)),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Default for Store {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod store_tests {
+    use super::*;
+
+    fn time(hour: u32, minute: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, minute, 0).unwrap()
+    }
+
+    fn utc(date: &str, hour: u32, minute: u32) -> DateTime<Utc> {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .unwrap()
+            .and_time(time(hour, minute))
+            .and_utc()
+    }
+
+    /// Local en Managua (UTC-6) abierto de lunes a viernes de 7:00 a 19:00.
+    fn store() -> Store {
+        Store {
+            id: Some(1),
+            name: "Centro".to_string(),
+            timezone: "America/Managua".parse().unwrap(),
+            opening_hours: [
+                Weekday::Mon,
+                Weekday::Tue,
+                Weekday::Wed,
+                Weekday::Thu,
+                Weekday::Fri,
+            ]
+            .into_iter()
+            .map(|weekday| OpeningHours {
+                weekday,
+                opens: time(7, 0),
+                closes: time(19, 0),
+            })
+            .collect(),
+            ..Store::new()
+        }
+    }
+
+    #[test]
+    fn test_is_open_uses_store_timezone() {
+        // 2026-10-19 es lunes: 13:00 UTC son las 7:00 en Managua.
+        assert!(store().is_open_at(utc("2026-10-19", 13, 0)));
+        assert!(!store().is_open_at(utc("2026-10-19", 12, 59)));
+        // 00:30 UTC del martes todavía es lunes 18:30 en Managua.
+        assert!(store().is_open_at(utc("2026-10-20", 0, 30)));
+    }
+
+    #[test]
+    fn test_holiday_exception_closes_store() {
+        let mut store = store();
+        store.exceptions.push(StoreException {
+            date: NaiveDate::from_ymd_opt(2026, 10, 19).unwrap(),
+            opens: None,
+            closes: None,
+            reason: Some("Feriado".to_string()),
+        });
+
+        assert!(!store.is_open_at(utc("2026-10-19", 15, 0)));
+        assert_eq!(
+            store.next_opening(utc("2026-10-19", 15, 0)),
+            Some(utc("2026-10-20", 13, 0))
+        );
+    }
+
+    #[test]
+    fn test_next_opening_skips_weekend() {
+        // Viernes 2026-10-23 a las 20:00 en Managua.
+        assert_eq!(
+            store().next_opening(utc("2026-10-24", 2, 0)),
+            Some(utc("2026-10-26", 13, 0))
+        );
+    }
+
+    #[test]
+    fn test_schedule_order_when_closed() {
+        let now = utc("2026-10-24", 2, 0);
+
+        assert!(matches!(
+            store().schedule_order(now, None, false, &[]),
+            Err(StoreError::Closed)
+        ));
+        assert_eq!(
+            store().schedule_order(now, None, true, &[]).unwrap(),
+            Some(utc("2026-10-26", 13, 0))
+        );
+        assert_eq!(
+            store()
+                .schedule_order(utc("2026-10-19", 15, 0), None, false, &[])
+                .unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_schedule_order_when_closed_skips_full_slots() {
+        let bookings = vec![(utc("2026-10-26", 13, 0), 10)];
+
+        assert_eq!(
+            store()
+                .schedule_order(utc("2026-10-24", 2, 0), None, true, &bookings)
+                .unwrap(),
+            Some(utc("2026-10-26", 13, 15))
+        );
+    }
+
+    #[test]
+    fn test_pickup_slots_split_opening_hours() {
+        let slots = store().pickup_slots_on(NaiveDate::from_ymd_opt(2026, 10, 19).unwrap(), &[]);
+
+        // De 7:00 a 19:00 caben 48 franjas de 15 minutos.
+        assert_eq!(slots.len(), 48);
+        assert_eq!(slots[0].starts_at, utc("2026-10-19", 13, 0));
+        assert_eq!(slots[47].ends_at, utc("2026-10-20", 1, 0));
+        assert!(store()
+            .pickup_slots_on(NaiveDate::from_ymd_opt(2026, 10, 24).unwrap(), &[])
+            .is_empty());
+    }
+
+    #[test]
+    fn test_schedule_order_with_pickup_time() {
+        let now = utc("2026-10-19", 13, 30);
+        let pickup_at = utc("2026-10-19", 14, 15);
+
+        assert_eq!(
+            store()
+                .schedule_order(now, Some(pickup_at), false, &[])
+                .unwrap(),
+            Some(pickup_at)
+        );
+        assert!(matches!(
+            store().schedule_order(now, Some(pickup_at), false, &[(pickup_at, 10)]),
+            Err(StoreError::SlotFull)
+        ));
+        // No coincide con el inicio de una franja.
+        assert!(matches!(
+            store().schedule_order(now, Some(utc("2026-10-19", 14, 5)), false, &[]),
+            Err(StoreError::InvalidPickupTime)
+        ));
+        // La franja ya pasó.
+        assert!(matches!(
+            store().schedule_order(now, Some(utc("2026-10-19", 13, 15)), false, &[]),
+            Err(StoreError::InvalidPickupTime)
+        ));
+    }
+
+    #[test]
+    fn test_release_at_is_before_pickup_time() {
+        let now = utc("2026-10-19", 13, 30);
+
+        assert_eq!(
+            store().release_at(Some(utc("2026-10-19", 14, 15)), now),
+            Some(utc("2026-10-19", 14, 5))
+        );
+        assert_eq!(
+            store().release_at(Some(utc("2026-10-19", 13, 35)), now),
+            None
+        );
+        assert_eq!(store().release_at(None, now), None);
+    }
+
+    #[test]
+    fn test_inactive_store_never_opens() {
+        let mut store = store();
+        store.active = false;
+
+        assert!(!store.is_open_at(utc("2026-10-19", 15, 0)));
+        assert!(store.next_opening(utc("2026-10-19", 15, 0)).is_none());
+    }
+
+    #[test]
+    fn test_check_products_rejects_unavailable_product() {
+        let mut store = store();
+        store.unavailable_product_ids = vec![3];
+
+        assert!(store.check_products(&[1, 2]).is_ok());
+        assert!(matches!(
+            store.check_products(&[1, 3]),
+            Err(StoreError::ProductUnavailable(3))
+        ));
+    }
+}
@@ -1,6 +1,8 @@
+use super::{user_role::UserRole, user_status::UserStatus};
 use crate::infrastructure::data::models::user::User as UserModel;
+use std::fmt;
 
-#[derive(Clone, Debug)]
+#[derive(Clone)]
 pub struct User {
     pub id: Option<i32>,
     pub name: String,
@@ -8,6 +10,24 @@ pub struct User {
     pub phone_number: Option<String>,
     pub email: String,
     pub password: String,
+    pub role: UserRole,
+    pub status: UserStatus,
+}
+
+/// Omite la contraseña para que no termine en el log, aunque ya esté encriptada.
+impl fmt::Debug for User {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("User")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("surname", &self.surname)
+            .field("phone_number", &self.phone_number)
+            .field("email", &self.email)
+            .field("password", &"***")
+            .field("role", &self.role)
+            .field("status", &self.status)
+            .finish()
+    }
 }
 
 impl User {
@@ -19,6 +39,8 @@ impl User {
             phone_number: None,
             email: "".to_string(),
             password: "".to_string(),
+            role: UserRole::Client,
+            status: UserStatus::Active,
         }
     }
 
@@ -30,6 +52,8 @@ impl User {
             phone_number: row.phone_number,
             email: row.email,
             password: row.password,
+            role: UserRole::from_code(&row.role),
+            status: UserStatus::from_code(&row.status),
         }
     }
 
@@ -49,3 +73,18 @@ impl Default for User {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod user_tests {
+    use super::*;
+
+    #[test]
+    fn test_debug_output_hides_password() {
+        let user = User {
+            password: "$2b$12$hash".to_string(),
+            ..User::new()
+        };
+
+        assert!(!format!("{:?}", user).contains("hash"));
+    }
+}
@@ -1,17 +1,26 @@
+use super::user_role::UserRole;
 use chrono::{Duration, Utc};
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct UserPayload {
     id: i32,
     name: String,
     surname: String,
+    #[serde(default)]
+    role: UserRole,
     issued_at: i64,
     exp: i64,
 }
 
 impl UserPayload {
-    pub fn new(id: i32, name: String, surname: String, duration_minutes: i64) -> Self {
+    pub fn new(
+        id: i32,
+        name: String,
+        surname: String,
+        role: UserRole,
+        duration_minutes: i64,
+    ) -> Self {
         let now = Utc::now();
         let expiration = now + Duration::minutes(duration_minutes);
 
@@ -19,8 +28,17 @@ impl UserPayload {
             id,
             name,
             surname,
+            role,
             issued_at: now.timestamp(),
             exp: expiration.timestamp(),
         }
     }
+
+    pub fn get_id(&self) -> i32 {
+        self.id
+    }
+
+    pub fn get_role(&self) -> UserRole {
+        self.role
+    }
 }
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+/// Roles registrados en la tabla `user_role`.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum UserRole {
+    Administrator,
+    Employee,
+    #[default]
+    Client,
+}
+
+impl UserRole {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "A" => UserRole::Administrator,
+            "E" => UserRole::Employee,
+            _ => UserRole::Client,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            UserRole::Administrator => "A",
+            UserRole::Employee => "E",
+            UserRole::Client => "C",
+        }
+    }
+
+    /// Administradores y empleados del local.
+    pub fn is_staff(&self) -> bool {
+        matches!(self, UserRole::Administrator | UserRole::Employee)
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// Estado de una cuenta, guardado en la columna `user.status`.
+///
+/// Las cuentas dadas de baja quedan ocultas y se eliminan definitivamente al purgarlas.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum UserStatus {
+    #[default]
+    Active,
+    Suspended,
+    Deleted,
+}
+
+impl UserStatus {
+    pub fn from_code(code: &str) -> Self {
+        match code {
+            "S" => UserStatus::Suspended,
+            "B" => UserStatus::Deleted,
+            _ => UserStatus::Active,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            UserStatus::Active => "V",
+            UserStatus::Suspended => "S",
+            UserStatus::Deleted => "B",
+        }
+    }
+}
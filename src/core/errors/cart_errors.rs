@@ -0,0 +1,102 @@
+use super::{
+    catalog_errors::CatalogError, delivery_errors::DeliveryError, loyalty_errors::LoyaltyError,
+    promotion_errors::PromotionError, store_errors::StoreError,
+    transaction_errors::TransactionError,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CartError {
+    #[error("Cart item not found")]
+    ItemNotFound,
+    #[error("Product not found")]
+    ProductNotFound,
+    #[error("Invalid item: {0}")]
+    InvalidItem(String),
+    #[error("Cart is empty")]
+    EmptyCart,
+    #[error("Cart has unavailable items")]
+    UnavailableItems,
+    #[error("Cart changed during checkout")]
+    CartChanged,
+    #[error("Store not found")]
+    StoreNotFound,
+    #[error("Store is closed")]
+    StoreClosed,
+    #[error("Pickup time is not one of the store's pickup slots")]
+    InvalidPickupTime,
+    #[error("Pickup slot is full")]
+    SlotFull,
+    #[error("Address not found")]
+    AddressNotFound,
+    #[error("Invalid delivery: {0}")]
+    InvalidDelivery(String),
+    #[error("Coupon not found")]
+    CouponNotFound,
+    #[error("Invalid coupon: {0}")]
+    InvalidCoupon(String),
+    #[error("Invalid points redemption: {0}")]
+    InvalidRedemption(String),
+    #[error("An unexpected error has occurred")]
+    Unexpected,
+}
+
+impl From<CatalogError> for CartError {
+    fn from(err: CatalogError) -> Self {
+        match err {
+            CatalogError::ProductNotFound => CartError::ProductNotFound,
+            CatalogError::ProductUnavailable | CatalogError::InvalidConfiguration(_) => {
+                CartError::InvalidItem(err.to_string())
+            }
+            _ => CartError::Unexpected,
+        }
+    }
+}
+
+impl From<PromotionError> for CartError {
+    fn from(err: PromotionError) -> Self {
+        match err {
+            PromotionError::NotFound => CartError::CouponNotFound,
+            _ => CartError::Unexpected,
+        }
+    }
+}
+
+impl From<LoyaltyError> for CartError {
+    fn from(_: LoyaltyError) -> Self {
+        CartError::Unexpected
+    }
+}
+
+impl From<StoreError> for CartError {
+    fn from(err: StoreError) -> Self {
+        match err {
+            StoreError::NotFound => CartError::StoreNotFound,
+            StoreError::Closed => CartError::StoreClosed,
+            StoreError::InvalidPickupTime => CartError::InvalidPickupTime,
+            StoreError::SlotFull => CartError::SlotFull,
+            StoreError::ProductUnavailable(_) => CartError::UnavailableItems,
+            _ => CartError::Unexpected,
+        }
+    }
+}
+
+impl From<DeliveryError> for CartError {
+    fn from(err: DeliveryError) -> Self {
+        match err {
+            DeliveryError::AddressNotFound => CartError::AddressNotFound,
+            DeliveryError::Unexpected => CartError::Unexpected,
+            _ => CartError::InvalidDelivery(err.to_string()),
+        }
+    }
+}
+
+/// Con el backend en memoria, otra escritura durante la compra hace fallar la transacción.
+impl From<TransactionError> for CartError {
+    fn from(err: TransactionError) -> Self {
+        match err {
+            TransactionError::Conflict => CartError::CartChanged,
+            TransactionError::Unexpected => CartError::Unexpected,
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum CatalogError {
+    #[error("Product not found")]
+    ProductNotFound,
+    #[error("Category not found")]
+    CategoryNotFound,
+    #[error("Modifier group not found")]
+    ModifierGroupNotFound,
+    #[error("Modifier option not found")]
+    ModifierOptionNotFound,
+    #[error("Product is not available")]
+    ProductUnavailable,
+    #[error("Invalid configuration: {0}")]
+    InvalidConfiguration(String),
+    #[error("An unexpected error has occurred")]
+    Unexpected,
+}
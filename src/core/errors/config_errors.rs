@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error("Cannot read config file {path}: {reason}")]
+    File { path: String, reason: String },
+    #[error("Unknown setting `{0}` in config file")]
+    UnknownSetting(String),
+    #[error("Missing required setting {0}")]
+    Missing(String),
+    #[error("Invalid value for {name}: {reason}")]
+    Invalid { name: String, reason: String },
+}
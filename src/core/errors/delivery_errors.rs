@@ -0,0 +1,19 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DeliveryError {
+    #[error("Address not found")]
+    AddressNotFound,
+    #[error("Delivery zone not found")]
+    ZoneNotFound,
+    #[error("Invalid address: {0}")]
+    InvalidAddress(String),
+    #[error("Invalid delivery zone: {0}")]
+    InvalidZone(String),
+    #[error("Address is outside the delivery area")]
+    OutsideDeliveryArea,
+    #[error("Delivery requires a minimum order of {0}")]
+    BelowMinimumOrder(i64),
+    #[error("An unexpected error has occurred")]
+    Unexpected,
+}
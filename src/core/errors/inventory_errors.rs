@@ -0,0 +1,17 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum InventoryError {
+    #[error("Ingredient not found")]
+    IngredientNotFound,
+    #[error("Product not found")]
+    ProductNotFound,
+    #[error("Modifier option not found")]
+    ModifierOptionNotFound,
+    #[error("Invalid recipe: {0}")]
+    InvalidRecipe(String),
+    #[error("Not enough stock for this adjustment")]
+    InsufficientStock,
+    #[error("An unexpected error has occurred")]
+    Unexpected,
+}
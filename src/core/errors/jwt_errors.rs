@@ -4,6 +4,10 @@ use thiserror::Error;
 pub enum JwtError {
     #[error("Bearer not present.")]
     BearerNotPresent,
+    #[error("Token has expired.")]
+    Expired,
+    #[error("Token is not valid.")]
+    Invalid,
     #[error("An unexpected error has occurred")]
     Unexpected,
 }
@@ -0,0 +1,14 @@
+use super::transaction_errors::TransactionError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum LoyaltyError {
+    #[error("An unexpected error has occurred")]
+    Unexpected,
+}
+
+impl From<TransactionError> for LoyaltyError {
+    fn from(_: TransactionError) -> Self {
+        LoyaltyError::Unexpected
+    }
+}
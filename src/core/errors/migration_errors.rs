@@ -0,0 +1,15 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum MigrationError {
+    #[error("Migration {0} has not been applied")]
+    Pending(i64),
+    #[error("Migration {0} was modified after being applied")]
+    Modified(i64),
+    #[error("Migration {0} is applied but unknown to this build")]
+    Unknown(i64),
+    #[error("Migration {0} did not finish and must be fixed manually")]
+    Dirty(i64),
+    #[error("Cannot run migrations: {0}")]
+    Unexpected(String),
+}
@@ -1,2 +1,14 @@
+pub mod cart_errors;
+pub mod catalog_errors;
+pub mod config_errors;
+pub mod delivery_errors;
+pub mod inventory_errors;
 pub mod jwt_errors;
+pub mod loyalty_errors;
+pub mod migration_errors;
+pub mod order_errors;
+pub mod payment_errors;
+pub mod promotion_errors;
+pub mod store_errors;
+pub mod transaction_errors;
 pub mod user_errors;
@@ -0,0 +1,88 @@
+use super::{
+    catalog_errors::CatalogError, delivery_errors::DeliveryError, inventory_errors::InventoryError,
+    store_errors::StoreError, transaction_errors::TransactionError,
+};
+use crate::core::entities::order::OrderStatus;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum OrderError {
+    #[error("Order not found")]
+    NotFound,
+    #[error("Product not found")]
+    ProductNotFound,
+    #[error("Invalid order: {0}")]
+    InvalidOrder(String),
+    #[error("Order cannot change from {} to {}", .from.code(), .to.code())]
+    InvalidTransition { from: OrderStatus, to: OrderStatus },
+    #[error("Order is not in preparation")]
+    NotInPreparation,
+    #[error("Order already claimed by another employee")]
+    AlreadyClaimed,
+    #[error("Not enough stock to prepare the order")]
+    OutOfStock,
+    #[error("Store not found")]
+    StoreNotFound,
+    #[error("Store is closed")]
+    StoreClosed,
+    #[error("Pickup slot is full")]
+    SlotFull,
+    #[error("Address not found")]
+    AddressNotFound,
+    #[error("Courier must be an employee")]
+    InvalidCourier,
+    #[error("A courier must be assigned before the order goes out for delivery")]
+    CourierRequired,
+    #[error("Forbidden")]
+    Forbidden,
+    #[error("An unexpected error has occurred")]
+    Unexpected,
+}
+
+impl From<CatalogError> for OrderError {
+    fn from(err: CatalogError) -> Self {
+        match err {
+            CatalogError::ProductNotFound => OrderError::ProductNotFound,
+            CatalogError::ProductUnavailable | CatalogError::InvalidConfiguration(_) => {
+                OrderError::InvalidOrder(err.to_string())
+            }
+            _ => OrderError::Unexpected,
+        }
+    }
+}
+
+impl From<InventoryError> for OrderError {
+    fn from(_: InventoryError) -> Self {
+        OrderError::Unexpected
+    }
+}
+
+impl From<StoreError> for OrderError {
+    fn from(err: StoreError) -> Self {
+        match err {
+            StoreError::NotFound => OrderError::StoreNotFound,
+            StoreError::Closed => OrderError::StoreClosed,
+            StoreError::SlotFull => OrderError::SlotFull,
+            StoreError::ProductUnavailable(_) | StoreError::InvalidPickupTime => {
+                OrderError::InvalidOrder(err.to_string())
+            }
+            _ => OrderError::Unexpected,
+        }
+    }
+}
+
+impl From<DeliveryError> for OrderError {
+    fn from(err: DeliveryError) -> Self {
+        match err {
+            DeliveryError::AddressNotFound => OrderError::AddressNotFound,
+            DeliveryError::Unexpected => OrderError::Unexpected,
+            _ => OrderError::InvalidOrder(err.to_string()),
+        }
+    }
+}
+
+impl From<TransactionError> for OrderError {
+    fn from(_: TransactionError) -> Self {
+        OrderError::Unexpected
+    }
+}
@@ -0,0 +1,44 @@
+use super::{order_errors::OrderError, transaction_errors::TransactionError};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PaymentError {
+    #[error("Order not found")]
+    OrderNotFound,
+    #[error("Payment not found")]
+    PaymentNotFound,
+    #[error("Invalid card")]
+    InvalidCard,
+    #[error("Payment declined: {0}")]
+    Declined(String),
+    #[error("Order already paid")]
+    AlreadyPaid,
+    #[error("Invalid refund: {0}")]
+    InvalidRefund(String),
+    #[error("A refund with this idempotency key is in progress")]
+    RefundInProgress,
+    #[error("Invalid payment operation: {0}")]
+    InvalidOperation(String),
+    #[error("Forbidden")]
+    Forbidden,
+    #[error("Payment gateway error: {0}")]
+    Gateway(String),
+    #[error("An unexpected error has occurred")]
+    Unexpected,
+}
+
+impl From<OrderError> for PaymentError {
+    fn from(err: OrderError) -> Self {
+        match err {
+            OrderError::NotFound => PaymentError::OrderNotFound,
+            OrderError::Forbidden => PaymentError::Forbidden,
+            _ => PaymentError::Unexpected,
+        }
+    }
+}
+
+impl From<TransactionError> for PaymentError {
+    fn from(_: TransactionError) -> Self {
+        PaymentError::Unexpected
+    }
+}
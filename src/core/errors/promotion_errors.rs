@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PromotionError {
+    #[error("Promotion not found")]
+    NotFound,
+    #[error("Invalid promotion: {0}")]
+    InvalidPromotion(String),
+    #[error("Coupon code already in use")]
+    DuplicateCode,
+    #[error("An unexpected error has occurred")]
+    Unexpected,
+}
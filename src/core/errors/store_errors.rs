@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum StoreError {
+    #[error("Store not found")]
+    NotFound,
+    #[error("Product not found")]
+    ProductNotFound,
+    #[error("Invalid store: {0}")]
+    InvalidStore(String),
+    #[error("Store is closed")]
+    Closed,
+    #[error("Pickup time is not one of the store's pickup slots")]
+    InvalidPickupTime,
+    #[error("Pickup slot is full")]
+    SlotFull,
+    #[error("Product {0} is not available at this store")]
+    ProductUnavailable(i32),
+    #[error("An unexpected error has occurred")]
+    Unexpected,
+}
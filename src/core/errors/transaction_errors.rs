@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TransactionError {
+    #[error("The transaction conflicts with a concurrent change")]
+    Conflict,
+    #[error("An unexpected error has occurred")]
+    Unexpected,
+}
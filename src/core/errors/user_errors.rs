@@ -6,6 +6,12 @@ pub enum UserError {
     NotFound,
     #[error("Unauthorized user")]
     Unauthorized,
+    #[error("Email is already in use")]
+    EmailInUse,
+    #[error("An administrator already exists")]
+    AdministratorExists,
+    #[error("Invalid password: {0}")]
+    InvalidPassword(String),
     #[error("An unexpected error has occurred")]
     Unexpected,
 }
@@ -0,0 +1,70 @@
+use crate::core::{
+    entities::{
+        cart::{Cart, CartItem},
+        fulfilment::Fulfilment,
+        order::Order,
+        payment::PaymentMethod,
+        user_payload::UserPayload,
+    },
+    errors::cart_errors::CartError,
+    ports::unit_of_work_port::ITransaction,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ICartRepository: Send + Sync {
+    type Transaction: ITransaction;
+
+    async fn get_cart_items(&self, user_id: i32) -> Result<Vec<CartItem>, CartError>;
+    async fn insert_cart_item(&self, user_id: i32, item: CartItem) -> Result<CartItem, CartError>;
+    async fn update_cart_item(
+        &self,
+        user_id: i32,
+        item_id: i32,
+        quantity: i32,
+    ) -> Result<(), CartError>;
+    async fn delete_cart_item(&self, user_id: i32, item_id: i32) -> Result<(), CartError>;
+    /// Vacía el carrito junto con los cupones canjeados.
+    async fn clear_cart(&self, user_id: i32) -> Result<(), CartError>;
+    /// IDs de las promociones cuyos cupones se canjearon en el carrito.
+    async fn get_cart_coupons(&self, user_id: i32) -> Result<Vec<i32>, CartError>;
+    async fn insert_cart_coupon(&self, user_id: i32, promotion_id: i32) -> Result<(), CartError>;
+    async fn delete_cart_coupon(&self, user_id: i32, promotion_id: i32) -> Result<(), CartError>;
+    /// Registra el pedido con sus canjes de promociones y elimina del carrito los ítems
+    /// convertidos y los cupones, dentro de una transacción abierta por el servicio. Si el
+    /// carrito ya no tiene esos ítems retorna `CartError::CartChanged`.
+    async fn checkout_in(
+        &self,
+        tx: &mut Self::Transaction,
+        user_id: i32,
+        item_ids: Vec<i32>,
+        order: Order,
+    ) -> Result<Order, CartError>;
+}
+
+#[async_trait]
+pub trait ICartService: Send + Sync {
+    async fn get_cart(&self, user_id: i32) -> Result<Cart, CartError>;
+    async fn add_item(&self, user_id: i32, item: CartItem) -> Result<Cart, CartError>;
+    async fn update_item(
+        &self,
+        user_id: i32,
+        item_id: i32,
+        quantity: i32,
+    ) -> Result<Cart, CartError>;
+    async fn remove_item(&self, user_id: i32, item_id: i32) -> Result<Cart, CartError>;
+    async fn clear_cart(&self, user_id: i32) -> Result<(), CartError>;
+    /// Canjea un cupón; falla si la promoción no puede aplicarse al carrito en este momento.
+    async fn apply_coupon(&self, user_id: i32, code: &str) -> Result<Cart, CartError>;
+    async fn remove_coupon(&self, user_id: i32, code: &str) -> Result<Cart, CartError>;
+    /// Crea el pedido con los ítems del carrito. Los clientes pueden canjear `redeem_points`
+    /// puntos como descuento.
+    async fn checkout(
+        &self,
+        user: &UserPayload,
+        fulfilment: Fulfilment,
+        payment_method: PaymentMethod,
+        tip: i64,
+        redeem_points: i64,
+    ) -> Result<Order, CartError>;
+}
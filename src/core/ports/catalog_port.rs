@@ -0,0 +1,106 @@
+use crate::core::{
+    entities::{
+        category::Category,
+        modifier::{ModifierGroup, ModifierOption},
+        product::Product,
+    },
+    errors::catalog_errors::CatalogError,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait ICatalogRepository: Send + Sync {
+    async fn get_categories(&self) -> Result<Vec<Category>, CatalogError>;
+    async fn get_category_by_id(&self, category_id: i32) -> Result<Category, CatalogError>;
+    async fn insert_category(&self, category: Category) -> Result<Category, CatalogError>;
+    async fn update_category(
+        &self,
+        category_id: i32,
+        category: Category,
+    ) -> Result<(), CatalogError>;
+    async fn delete_category(&self, category_id: i32) -> Result<(), CatalogError>;
+
+    async fn get_products(&self, category_id: Option<i32>) -> Result<Vec<Product>, CatalogError>;
+    async fn get_product_by_id(&self, product_id: i32) -> Result<Product, CatalogError>;
+    async fn insert_product(&self, product: Product) -> Result<Product, CatalogError>;
+    async fn update_product(&self, product_id: i32, product: Product) -> Result<(), CatalogError>;
+    async fn delete_product(&self, product_id: i32) -> Result<(), CatalogError>;
+
+    async fn get_modifier_groups(&self) -> Result<Vec<ModifierGroup>, CatalogError>;
+    async fn get_modifier_groups_by_product(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ModifierGroup>, CatalogError>;
+    async fn insert_modifier_group(
+        &self,
+        group: ModifierGroup,
+    ) -> Result<ModifierGroup, CatalogError>;
+    async fn delete_modifier_group(&self, group_id: i32) -> Result<(), CatalogError>;
+    async fn insert_modifier_option(
+        &self,
+        option: ModifierOption,
+    ) -> Result<ModifierOption, CatalogError>;
+    async fn update_modifier_option(
+        &self,
+        option_id: i32,
+        option: ModifierOption,
+    ) -> Result<(), CatalogError>;
+    async fn attach_modifier_group(
+        &self,
+        product_id: i32,
+        group_id: i32,
+    ) -> Result<(), CatalogError>;
+    async fn detach_modifier_group(
+        &self,
+        product_id: i32,
+        group_id: i32,
+    ) -> Result<(), CatalogError>;
+}
+
+#[async_trait]
+pub trait ICatalogService: Send + Sync {
+    async fn get_categories(&self) -> Result<Vec<Category>, CatalogError>;
+    async fn create_category(&self, category: Category) -> Result<Category, CatalogError>;
+    async fn update_category(
+        &self,
+        category_id: i32,
+        category: Category,
+    ) -> Result<(), CatalogError>;
+    async fn delete_category(&self, category_id: i32) -> Result<(), CatalogError>;
+
+    async fn get_products(&self, category_id: Option<i32>) -> Result<Vec<Product>, CatalogError>;
+    async fn get_product_by_id(&self, product_id: i32) -> Result<Product, CatalogError>;
+    async fn create_product(&self, product: Product) -> Result<Product, CatalogError>;
+    async fn update_product(&self, product_id: i32, product: Product) -> Result<(), CatalogError>;
+    async fn delete_product(&self, product_id: i32) -> Result<(), CatalogError>;
+    async fn price_product(&self, product_id: i32, option_ids: &[i32])
+        -> Result<i64, CatalogError>;
+
+    async fn get_modifier_groups(&self) -> Result<Vec<ModifierGroup>, CatalogError>;
+    async fn create_modifier_group(
+        &self,
+        group: ModifierGroup,
+    ) -> Result<ModifierGroup, CatalogError>;
+    async fn delete_modifier_group(&self, group_id: i32) -> Result<(), CatalogError>;
+    async fn create_modifier_option(
+        &self,
+        group_id: i32,
+        option: ModifierOption,
+    ) -> Result<ModifierOption, CatalogError>;
+    async fn update_modifier_option(
+        &self,
+        group_id: i32,
+        option_id: i32,
+        option: ModifierOption,
+    ) -> Result<(), CatalogError>;
+    async fn attach_modifier_group(
+        &self,
+        product_id: i32,
+        group_id: i32,
+    ) -> Result<(), CatalogError>;
+    async fn detach_modifier_group(
+        &self,
+        product_id: i32,
+        group_id: i32,
+    ) -> Result<(), CatalogError>;
+}
@@ -0,0 +1,64 @@
+use crate::core::{
+    entities::{
+        delivery::{Address, DeliveryZone},
+        user_payload::UserPayload,
+    },
+    errors::delivery_errors::DeliveryError,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait IDeliveryRepository: Send + Sync {
+    async fn get_addresses(&self, user_id: i32) -> Result<Vec<Address>, DeliveryError>;
+    /// Dirección de la libreta del usuario; las de otros usuarios se tratan como inexistentes.
+    async fn get_address(&self, user_id: i32, address_id: i32) -> Result<Address, DeliveryError>;
+    async fn insert_address(&self, address: Address) -> Result<Address, DeliveryError>;
+    async fn update_address(&self, address: Address) -> Result<(), DeliveryError>;
+    async fn delete_address(&self, user_id: i32, address_id: i32) -> Result<(), DeliveryError>;
+    async fn get_delivery_zones(&self) -> Result<Vec<DeliveryZone>, DeliveryError>;
+    async fn get_delivery_zone_by_id(&self, zone_id: i32) -> Result<DeliveryZone, DeliveryError>;
+    async fn insert_delivery_zone(&self, zone: DeliveryZone)
+        -> Result<DeliveryZone, DeliveryError>;
+    async fn update_delivery_zone(
+        &self,
+        zone_id: i32,
+        zone: DeliveryZone,
+    ) -> Result<(), DeliveryError>;
+    async fn delete_delivery_zone(&self, zone_id: i32) -> Result<(), DeliveryError>;
+}
+
+#[async_trait]
+pub trait IDeliveryService: Send + Sync {
+    async fn get_addresses(&self, user: &UserPayload) -> Result<Vec<Address>, DeliveryError>;
+    async fn create_address(
+        &self,
+        user: &UserPayload,
+        address: Address,
+    ) -> Result<Address, DeliveryError>;
+    async fn update_address(
+        &self,
+        user: &UserPayload,
+        address_id: i32,
+        address: Address,
+    ) -> Result<Address, DeliveryError>;
+    async fn delete_address(
+        &self,
+        user: &UserPayload,
+        address_id: i32,
+    ) -> Result<(), DeliveryError>;
+    /// Zona que cubre la dirección, con su costo de envío y pedido mínimo.
+    async fn quote_delivery(
+        &self,
+        user: &UserPayload,
+        address_id: i32,
+    ) -> Result<DeliveryZone, DeliveryError>;
+    async fn get_delivery_zones(&self) -> Result<Vec<DeliveryZone>, DeliveryError>;
+    async fn create_delivery_zone(&self, zone: DeliveryZone)
+        -> Result<DeliveryZone, DeliveryError>;
+    async fn update_delivery_zone(
+        &self,
+        zone_id: i32,
+        zone: DeliveryZone,
+    ) -> Result<DeliveryZone, DeliveryError>;
+    async fn delete_delivery_zone(&self, zone_id: i32) -> Result<(), DeliveryError>;
+}
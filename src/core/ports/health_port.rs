@@ -0,0 +1,19 @@
+use crate::core::entities::health::HealthReport;
+use async_trait::async_trait;
+
+/// Comprobación de una dependencia de la que depende el servidor para atender pedidos.
+#[async_trait]
+pub trait IHealthCheck: Send + Sync {
+    /// Nombre con el que aparece en el informe, como `database`.
+    fn name(&self) -> &'static str;
+
+    /// Retorna el motivo si la dependencia no está disponible. Debe terminar en poco tiempo
+    /// aunque la dependencia no responda.
+    async fn check(&self) -> Result<(), String>;
+}
+
+#[async_trait]
+pub trait IHealthService: Send + Sync {
+    /// Ejecuta todas las comprobaciones y mide cuánto tarda cada una.
+    async fn readiness(&self) -> HealthReport;
+}
@@ -0,0 +1,84 @@
+use crate::core::{
+    entities::{
+        inventory::{Ingredient, RecipeLine, StockAdjustment},
+        user_payload::UserPayload,
+    },
+    errors::inventory_errors::InventoryError,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait IInventoryRepository: Send + Sync {
+    async fn get_ingredients(&self) -> Result<Vec<Ingredient>, InventoryError>;
+    async fn get_ingredient_by_id(&self, ingredient_id: i32) -> Result<Ingredient, InventoryError>;
+    async fn insert_ingredient(&self, ingredient: Ingredient)
+        -> Result<Ingredient, InventoryError>;
+    /// Actualiza los datos del insumo sin tocar su stock, que solo cambia mediante movimientos.
+    async fn update_ingredient(
+        &self,
+        ingredient_id: i32,
+        ingredient: Ingredient,
+    ) -> Result<(), InventoryError>;
+    /// Borra el insumo y lo quita de las recetas; sus movimientos se conservan.
+    async fn delete_ingredient(&self, ingredient_id: i32) -> Result<(), InventoryError>;
+    /// Movimientos del insumo, del más reciente al más antiguo.
+    async fn get_adjustments(
+        &self,
+        ingredient_id: i32,
+    ) -> Result<Vec<StockAdjustment>, InventoryError>;
+    /// Aplica el movimiento y lo registra en la misma transacción. Retorna
+    /// `InventoryError::InsufficientStock` si el stock quedaría negativo.
+    async fn adjust_stock(&self, adjustment: StockAdjustment)
+        -> Result<Ingredient, InventoryError>;
+    async fn get_product_recipe(&self, product_id: i32) -> Result<Vec<RecipeLine>, InventoryError>;
+    /// Reemplaza la receta completa del producto.
+    async fn set_product_recipe(
+        &self,
+        product_id: i32,
+        lines: Vec<RecipeLine>,
+    ) -> Result<(), InventoryError>;
+    async fn get_option_recipe(&self, option_id: i32) -> Result<Vec<RecipeLine>, InventoryError>;
+    /// Reemplaza la receta completa de la opción.
+    async fn set_option_recipe(
+        &self,
+        option_id: i32,
+        lines: Vec<RecipeLine>,
+    ) -> Result<(), InventoryError>;
+}
+
+#[async_trait]
+pub trait IInventoryService: Send + Sync {
+    async fn get_ingredients(&self) -> Result<Vec<Ingredient>, InventoryError>;
+    async fn create_ingredient(&self, ingredient: Ingredient)
+        -> Result<Ingredient, InventoryError>;
+    async fn update_ingredient(
+        &self,
+        ingredient_id: i32,
+        ingredient: Ingredient,
+    ) -> Result<Ingredient, InventoryError>;
+    async fn delete_ingredient(&self, ingredient_id: i32) -> Result<(), InventoryError>;
+    /// Insumos que están en su umbral de stock bajo o por debajo.
+    async fn get_low_stock_ingredients(&self) -> Result<Vec<Ingredient>, InventoryError>;
+    async fn get_adjustments(
+        &self,
+        ingredient_id: i32,
+    ) -> Result<Vec<StockAdjustment>, InventoryError>;
+    async fn adjust_stock(
+        &self,
+        user: &UserPayload,
+        ingredient_id: i32,
+        adjustment: StockAdjustment,
+    ) -> Result<Ingredient, InventoryError>;
+    async fn get_product_recipe(&self, product_id: i32) -> Result<Vec<RecipeLine>, InventoryError>;
+    async fn set_product_recipe(
+        &self,
+        product_id: i32,
+        lines: Vec<RecipeLine>,
+    ) -> Result<Vec<RecipeLine>, InventoryError>;
+    async fn get_option_recipe(&self, option_id: i32) -> Result<Vec<RecipeLine>, InventoryError>;
+    async fn set_option_recipe(
+        &self,
+        option_id: i32,
+        lines: Vec<RecipeLine>,
+    ) -> Result<Vec<RecipeLine>, InventoryError>;
+}
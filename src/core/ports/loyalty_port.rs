@@ -0,0 +1,42 @@
+use crate::core::{
+    entities::{
+        loyalty::{LoyaltyAccount, LoyaltyEntry},
+        user_payload::UserPayload,
+    },
+    errors::loyalty_errors::LoyaltyError,
+    ports::unit_of_work_port::ITransaction,
+};
+use async_trait::async_trait;
+
+/// Libro de puntos. Solo admite altas: los movimientos nunca se modifican ni se borran.
+#[async_trait]
+pub trait ILoyaltyRepository: Send + Sync {
+    type Transaction: ITransaction;
+
+    async fn get_entries(&self, user_id: i32) -> Result<Vec<LoyaltyEntry>, LoyaltyError>;
+    async fn insert_entry(&self, entry: LoyaltyEntry) -> Result<LoyaltyEntry, LoyaltyError>;
+    /// Bloquea la cuenta de puntos del usuario hasta el final de la transacción, de modo que
+    /// los canjes simultáneos descuenten el saldo de a uno.
+    async fn lock_account_in(
+        &self,
+        tx: &mut Self::Transaction,
+        user_id: i32,
+    ) -> Result<(), LoyaltyError>;
+    /// Igual que `get_entries`, pero dentro de una transacción abierta por el servicio.
+    async fn get_entries_in(
+        &self,
+        tx: &mut Self::Transaction,
+        user_id: i32,
+    ) -> Result<Vec<LoyaltyEntry>, LoyaltyError>;
+    async fn insert_entry_in(
+        &self,
+        tx: &mut Self::Transaction,
+        entry: LoyaltyEntry,
+    ) -> Result<LoyaltyEntry, LoyaltyError>;
+}
+
+#[async_trait]
+pub trait ILoyaltyService: Send + Sync {
+    /// Saldo e historial del usuario; registra antes los vencimientos pendientes.
+    async fn get_account(&self, user: &UserPayload) -> Result<LoyaltyAccount, LoyaltyError>;
+}
@@ -0,0 +1,6 @@
+use crate::core::entities::metric_event::MetricEvent;
+
+pub trait IMetricsRecorder: Send + Sync {
+    /// Cuenta el evento. No debe fallar ni bloquear: la operación que lo originó ya terminó.
+    fn record(&self, event: MetricEvent);
+}
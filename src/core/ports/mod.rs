@@ -1 +1,15 @@
+pub mod cart_port;
+pub mod catalog_port;
+pub mod delivery_port;
+pub mod health_port;
+pub mod inventory_port;
+pub mod loyalty_port;
+pub mod metrics_port;
+pub mod order_event_port;
+pub mod order_port;
+pub mod payment_port;
+pub mod promotion_port;
+pub mod stock_alert_port;
+pub mod store_port;
+pub mod unit_of_work_port;
 pub mod user_port;
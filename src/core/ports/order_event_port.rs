@@ -0,0 +1,6 @@
+use crate::core::entities::order_event::OrderEvent;
+
+pub trait IOrderEventPublisher: Send + Sync {
+    /// Publica el evento a los suscriptores conectados. No falla si no hay ninguno.
+    fn publish(&self, event: OrderEvent);
+}
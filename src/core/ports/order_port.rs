@@ -0,0 +1,110 @@
+use crate::core::{
+    entities::{
+        fulfilment::Fulfilment,
+        inventory::StockAdjustment,
+        loyalty::LoyaltyEntry,
+        order::{Order, OrderItem, OrderStatus},
+        payment::PaymentMethod,
+        user_payload::UserPayload,
+    },
+    errors::order_errors::OrderError,
+    ports::unit_of_work_port::ITransaction,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+#[async_trait]
+pub trait IOrderRepository: Send + Sync {
+    type Transaction: ITransaction;
+
+    async fn insert_order(&self, order: Order) -> Result<Order, OrderError>;
+    /// Igual que `insert_order`, pero dentro de una transacción abierta por el servicio.
+    async fn insert_order_in(
+        &self,
+        tx: &mut Self::Transaction,
+        order: Order,
+    ) -> Result<Order, OrderError>;
+    async fn get_order_by_id(&self, order_id: i32) -> Result<Order, OrderError>;
+    async fn get_orders_by_user(&self, user_id: i32) -> Result<Vec<Order>, OrderError>;
+    async fn get_orders(&self, status: Option<OrderStatus>) -> Result<Vec<Order>, OrderError>;
+    /// Pedidos en estados abiertos, del más antiguo al más reciente.
+    async fn get_open_orders(&self) -> Result<Vec<Order>, OrderError>;
+    /// Cambia el estado solo si el pedido sigue en el estado `from`; si otro proceso lo cambió
+    /// antes retorna `OrderError::InvalidTransition`. Registra el cambio en el historial junto
+    /// con los movimientos de puntos y de stock que genera, en la misma transacción; si algún
+    /// insumo no tiene stock suficiente no se aplica nada y retorna `OrderError::OutOfStock`.
+    async fn update_order_status(
+        &self,
+        order_id: i32,
+        from: OrderStatus,
+        to: OrderStatus,
+        changed_by: i32,
+        loyalty_entries: Vec<LoyaltyEntry>,
+        stock_adjustments: Vec<StockAdjustment>,
+    ) -> Result<(), OrderError>;
+    /// Igual que `update_order_status`, pero dentro de una transacción abierta por el servicio.
+    /// Ante un error el cambio puede quedar aplicado a medias: quien llamó debe descartar la
+    /// transacción.
+    #[allow(clippy::too_many_arguments)]
+    async fn update_order_status_in(
+        &self,
+        tx: &mut Self::Transaction,
+        order_id: i32,
+        from: OrderStatus,
+        to: OrderStatus,
+        changed_by: i32,
+        loyalty_entries: Vec<LoyaltyEntry>,
+        stock_adjustments: Vec<StockAdjustment>,
+    ) -> Result<(), OrderError>;
+    /// Asigna el pedido al empleado si no fue tomado por otro.
+    async fn claim_order(&self, order_id: i32, employee_id: i32) -> Result<(), OrderError>;
+    /// Asigna el repartidor del pedido; retorna `OrderError::InvalidCourier` si el usuario no es
+    /// empleado ni administrador.
+    async fn assign_courier(&self, order_id: i32, courier_id: i32) -> Result<(), OrderError>;
+    /// Pasa a la cola los pedidos abiertos cuyo momento de liberación ya llegó y los retorna.
+    /// Cada pedido se libera una sola vez aunque varios procesos lo intenten a la vez.
+    async fn release_due_orders(&self, now: DateTime<Utc>) -> Result<Vec<Order>, OrderError>;
+}
+
+#[async_trait]
+pub trait IOrderService: Send + Sync {
+    async fn place_order(
+        &self,
+        user: &UserPayload,
+        fulfilment: Fulfilment,
+        items: Vec<OrderItem>,
+        payment_method: PaymentMethod,
+        tip: i64,
+    ) -> Result<Order, OrderError>;
+    async fn get_orders_by_user(&self, user_id: i32) -> Result<Vec<Order>, OrderError>;
+    async fn get_order_by_id(&self, user: &UserPayload, order_id: i32)
+        -> Result<Order, OrderError>;
+    async fn get_orders(&self, status: Option<OrderStatus>) -> Result<Vec<Order>, OrderError>;
+    async fn cancel_order(&self, user: &UserPayload, order_id: i32) -> Result<Order, OrderError>;
+    async fn change_order_status(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+        status: OrderStatus,
+    ) -> Result<Order, OrderError>;
+
+    async fn get_queue(&self, status: Option<OrderStatus>) -> Result<Vec<Order>, OrderError>;
+    async fn claim_order(&self, user: &UserPayload, order_id: i32) -> Result<Order, OrderError>;
+    async fn advance_order(&self, user: &UserPayload, order_id: i32) -> Result<Order, OrderError>;
+    async fn mark_order_ready(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+    ) -> Result<Order, OrderError>;
+    /// Asigna el empleado que llevará el pedido a domicilio.
+    async fn assign_courier(&self, order_id: i32, courier_id: i32) -> Result<Order, OrderError>;
+    /// Marca que el pedido a domicilio salió del local con su repartidor.
+    async fn dispatch_order(&self, user: &UserPayload, order_id: i32) -> Result<Order, OrderError>;
+    async fn mark_order_delivered(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+    ) -> Result<Order, OrderError>;
+    /// Libera a la cola los pedidos programados que ya deben prepararse y avisa de cada uno.
+    async fn release_scheduled_orders(&self) -> Result<Vec<Order>, OrderError>;
+}
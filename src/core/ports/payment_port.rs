@@ -0,0 +1,136 @@
+use crate::core::{
+    entities::{
+        payment::{Payment, PaymentAttempt, PaymentCard, PaymentStatus},
+        refund::Refund,
+        user_payload::UserPayload,
+    },
+    errors::payment_errors::PaymentError,
+    ports::unit_of_work_port::ITransaction,
+};
+use async_trait::async_trait;
+
+/// Pasarela de pago externa. Los montos van en centavos.
+#[async_trait]
+pub trait IPaymentGateway: Send + Sync {
+    /// Retiene el monto en la tarjeta y retorna la referencia de la operación.
+    async fn authorize(&self, card: &PaymentCard, amount: i64) -> Result<String, PaymentError>;
+    async fn capture(&self, reference: &str, amount: i64) -> Result<(), PaymentError>;
+    async fn void(&self, reference: &str) -> Result<(), PaymentError>;
+    /// Devuelve `amount` de la operación. La pasarela ignora una segunda devolución con la misma
+    /// `idempotency_key`.
+    async fn refund(
+        &self,
+        reference: &str,
+        amount: i64,
+        idempotency_key: &str,
+    ) -> Result<(), PaymentError>;
+}
+
+#[async_trait]
+pub trait IPaymentRepository: Send + Sync {
+    type Transaction: ITransaction;
+
+    async fn insert_payment(&self, payment: Payment) -> Result<Payment, PaymentError>;
+    async fn get_payments_by_order(&self, order_id: i32) -> Result<Vec<Payment>, PaymentError>;
+    /// Lee los pagos del pedido bloqueando el pedido hasta el final de la transacción, de modo
+    /// que los cobros simultáneos del pedido se guarden de a uno.
+    async fn lock_order_payments_in(
+        &self,
+        tx: &mut Self::Transaction,
+        order_id: i32,
+    ) -> Result<Vec<Payment>, PaymentError>;
+    async fn insert_payment_in(
+        &self,
+        tx: &mut Self::Transaction,
+        payment: Payment,
+    ) -> Result<Payment, PaymentError>;
+    /// Cierra el pago reservado con la respuesta de la pasarela: guarda la referencia, el nuevo
+    /// estado y el intento en la misma transacción.
+    async fn finish_authorization(
+        &self,
+        payment_id: i32,
+        reference: Option<String>,
+        status: PaymentStatus,
+        attempt: PaymentAttempt,
+    ) -> Result<(), PaymentError>;
+    /// Registra el intento y, si se indica, actualiza el estado del pago en la misma transacción.
+    async fn record_attempt(
+        &self,
+        payment_id: i32,
+        status: Option<PaymentStatus>,
+        attempt: PaymentAttempt,
+    ) -> Result<(), PaymentError>;
+    async fn get_refund_by_key(
+        &self,
+        order_id: i32,
+        idempotency_key: &str,
+    ) -> Result<Option<Refund>, PaymentError>;
+    /// Lee el pago bloqueando su fila hasta el final de la transacción, de modo que las
+    /// devoluciones simultáneas del pedido se reserven de a una.
+    async fn lock_payment_in(
+        &self,
+        tx: &mut Self::Transaction,
+        payment_id: i32,
+    ) -> Result<Payment, PaymentError>;
+    /// Devoluciones del pedido, con sus líneas, leídas dentro de la transacción.
+    async fn get_refunds_in(
+        &self,
+        tx: &mut Self::Transaction,
+        order_id: i32,
+    ) -> Result<Vec<Refund>, PaymentError>;
+    /// Guarda la devolución como `pending`, con sus líneas. Retorna `None` si ya existe una
+    /// devolución del pedido con la misma clave o si alguna de sus líneas está en otra
+    /// devolución que no falló.
+    async fn reserve_refund_in(
+        &self,
+        tx: &mut Self::Transaction,
+        refund: Refund,
+    ) -> Result<Option<Refund>, PaymentError>;
+    /// Marca como `completed` la devolución reservada y registra el intento y, si se indica, el
+    /// nuevo estado del pago, en su propia transacción.
+    async fn complete_refund(
+        &self,
+        refund: Refund,
+        status: Option<PaymentStatus>,
+        attempt: PaymentAttempt,
+    ) -> Result<Refund, PaymentError>;
+    /// Marca como `failed` la devolución reservada y registra el intento rechazado.
+    async fn fail_refund(
+        &self,
+        refund: Refund,
+        attempt: PaymentAttempt,
+    ) -> Result<Refund, PaymentError>;
+}
+
+#[async_trait]
+pub trait IPaymentService: Send + Sync {
+    async fn pay_order(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+        card: PaymentCard,
+    ) -> Result<Payment, PaymentError>;
+    async fn get_order_payments(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+    ) -> Result<Vec<Payment>, PaymentError>;
+    async fn capture_payment(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+    ) -> Result<Payment, PaymentError>;
+    async fn void_payment(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+    ) -> Result<Payment, PaymentError>;
+    /// Devuelve las líneas indicadas en `refund.item_ids` o, si no hay ninguna, el saldo
+    /// cobrado que queda. Repetir la solicitud con la misma clave retorna la devolución original.
+    async fn refund_order(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+        refund: Refund,
+    ) -> Result<Refund, PaymentError>;
+}
@@ -0,0 +1,55 @@
+use crate::core::{
+    entities::promotion::{Promotion, PromotionUsage},
+    errors::promotion_errors::PromotionError,
+    ports::unit_of_work_port::ITransaction,
+};
+use async_trait::async_trait;
+
+#[async_trait]
+pub trait IPromotionRepository: Send + Sync {
+    type Transaction: ITransaction;
+
+    async fn get_promotions(&self) -> Result<Vec<Promotion>, PromotionError>;
+    async fn get_promotion_by_id(&self, promotion_id: i32) -> Result<Promotion, PromotionError>;
+    async fn get_promotion_by_code(&self, code: &str) -> Result<Promotion, PromotionError>;
+    /// Promociones activas sin código de cupón, que se evalúan en todos los carritos.
+    async fn get_automatic_promotions(&self) -> Result<Vec<Promotion>, PromotionError>;
+    async fn insert_promotion(&self, promotion: Promotion) -> Result<Promotion, PromotionError>;
+    async fn update_promotion(
+        &self,
+        promotion_id: i32,
+        promotion: Promotion,
+    ) -> Result<(), PromotionError>;
+    async fn delete_promotion(&self, promotion_id: i32) -> Result<(), PromotionError>;
+    async fn get_usage(
+        &self,
+        promotion_id: i32,
+        user_id: i32,
+    ) -> Result<PromotionUsage, PromotionError>;
+    /// Lee la promoción bloqueando su fila hasta el final de la transacción, de modo que las
+    /// compras que la canjean a la vez cuenten sus usos de a una.
+    async fn lock_promotion_in(
+        &self,
+        tx: &mut Self::Transaction,
+        promotion_id: i32,
+    ) -> Result<Promotion, PromotionError>;
+    /// Igual que `get_usage`, pero dentro de una transacción abierta por el servicio.
+    async fn get_usage_in(
+        &self,
+        tx: &mut Self::Transaction,
+        promotion_id: i32,
+        user_id: i32,
+    ) -> Result<PromotionUsage, PromotionError>;
+}
+
+#[async_trait]
+pub trait IPromotionService: Send + Sync {
+    async fn get_promotions(&self) -> Result<Vec<Promotion>, PromotionError>;
+    async fn create_promotion(&self, promotion: Promotion) -> Result<Promotion, PromotionError>;
+    async fn update_promotion(
+        &self,
+        promotion_id: i32,
+        promotion: Promotion,
+    ) -> Result<Promotion, PromotionError>;
+    async fn delete_promotion(&self, promotion_id: i32) -> Result<(), PromotionError>;
+}
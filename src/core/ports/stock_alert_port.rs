@@ -0,0 +1,7 @@
+use crate::core::entities::inventory::StockAlert;
+
+pub trait IStockAlertPublisher: Send + Sync {
+    /// Avisa que un insumo llegó a su umbral de stock bajo. No debe fallar: el movimiento de
+    /// stock que originó la alerta ya fue registrado.
+    fn publish(&self, alert: StockAlert);
+}
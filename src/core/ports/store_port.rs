@@ -0,0 +1,80 @@
+use crate::core::{
+    entities::store::{PickupSlot, Store, StoreException},
+    errors::store_errors::StoreError,
+    ports::unit_of_work_port::ITransaction,
+};
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+
+#[async_trait]
+pub trait IStoreRepository: Send + Sync {
+    type Transaction: ITransaction;
+
+    async fn get_stores(&self) -> Result<Vec<Store>, StoreError>;
+    async fn get_store_by_id(&self, store_id: i32) -> Result<Store, StoreError>;
+    /// Inserta el local junto con su horario semanal.
+    async fn insert_store(&self, store: Store) -> Result<Store, StoreError>;
+    /// Actualiza los datos del local y reemplaza su horario semanal.
+    async fn update_store(&self, store_id: i32, store: Store) -> Result<(), StoreError>;
+    async fn delete_store(&self, store_id: i32) -> Result<(), StoreError>;
+    /// Guarda la excepción de la fecha, reemplazando la que hubiera.
+    async fn upsert_exception(
+        &self,
+        store_id: i32,
+        exception: StoreException,
+    ) -> Result<(), StoreError>;
+    async fn delete_exception(&self, store_id: i32, date: NaiveDate) -> Result<(), StoreError>;
+    async fn set_product_availability(
+        &self,
+        store_id: i32,
+        product_id: i32,
+        available: bool,
+    ) -> Result<(), StoreError>;
+    /// Pedidos no cancelados del local programados después de `from`, agrupados por franja.
+    async fn get_pickup_bookings(
+        &self,
+        store_id: i32,
+        from: DateTime<Utc>,
+    ) -> Result<Vec<(DateTime<Utc>, i64)>, StoreError>;
+    /// Bloquea la fila del local hasta el final de la transacción, de modo que los pedidos que
+    /// reservan sus franjas a la vez cuenten el cupo de a uno.
+    async fn lock_store_in(
+        &self,
+        tx: &mut Self::Transaction,
+        store_id: i32,
+    ) -> Result<(), StoreError>;
+    /// Pedidos no cancelados del local programados para la franja `scheduled_for`.
+    async fn count_bookings_in(
+        &self,
+        tx: &mut Self::Transaction,
+        store_id: i32,
+        scheduled_for: DateTime<Utc>,
+    ) -> Result<i64, StoreError>;
+}
+
+#[async_trait]
+pub trait IStoreService: Send + Sync {
+    async fn get_stores(&self) -> Result<Vec<Store>, StoreError>;
+    async fn get_store_by_id(&self, store_id: i32) -> Result<Store, StoreError>;
+    async fn create_store(&self, store: Store) -> Result<Store, StoreError>;
+    async fn update_store(&self, store_id: i32, store: Store) -> Result<Store, StoreError>;
+    async fn delete_store(&self, store_id: i32) -> Result<(), StoreError>;
+    async fn set_exception(
+        &self,
+        store_id: i32,
+        exception: StoreException,
+    ) -> Result<Store, StoreError>;
+    async fn delete_exception(&self, store_id: i32, date: NaiveDate) -> Result<Store, StoreError>;
+    async fn set_product_availability(
+        &self,
+        store_id: i32,
+        product_id: i32,
+        available: bool,
+    ) -> Result<Store, StoreError>;
+    /// Franjas de retiro aún no iniciadas de la fecha local indicada, o de hoy si no se indica.
+    async fn get_pickup_slots(
+        &self,
+        store_id: i32,
+        date: Option<NaiveDate>,
+    ) -> Result<Vec<PickupSlot>, StoreError>;
+}
@@ -0,0 +1,20 @@
+use crate::core::errors::transaction_errors::TransactionError;
+use async_trait::async_trait;
+
+/// Abre transacciones que los servicios pasan a varios repositorios, de modo que una operación
+/// que toca varias tablas se guarde entera o no se guarde. Los repositorios que aceptan la
+/// transacción la declaran en su tipo asociado `Transaction`.
+#[async_trait]
+pub trait IUnitOfWork: Send + Sync {
+    type Transaction: ITransaction;
+
+    async fn begin(&self) -> Result<Self::Transaction, TransactionError>;
+}
+
+/// Transacción abierta. Lo escrito a través de ella solo queda guardado con `commit`; si se
+/// descarta sin confirmar se revierte, así que basta con retornar ante el primer error.
+#[async_trait]
+pub trait ITransaction: Send {
+    async fn commit(self) -> Result<(), TransactionError>;
+    async fn rollback(self) -> Result<(), TransactionError>;
+}
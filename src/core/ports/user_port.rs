@@ -1,5 +1,5 @@
 use crate::core::{
-    entities::{login::Login, user::User},
+    entities::{login::Login, user::User, user_status::UserStatus},
     errors::user_errors::UserError,
 };
 use async_trait::async_trait;
@@ -12,6 +12,10 @@ pub trait IUserRepository: Send + Sync {
     async fn get_user_by_id(&self, id: u8) -> Result<User, UserError>;
     async fn update_user(&self, user_id: i32, user: User) -> Result<(), UserError>;
     async fn delete_user(&self, user_id: i32) -> Result<(), UserError>;
+    async fn get_users_by_status(&self, status: UserStatus) -> Result<Vec<User>, UserError>;
+    async fn update_user_status(&self, user_id: i32, status: UserStatus) -> Result<(), UserError>;
+    async fn update_password(&self, user_id: i32, password: String) -> Result<(), UserError>;
+    async fn purge_deleted_users(&self, retention_days: u32) -> Result<u64, UserError>;
 }
 
 #[async_trait]
@@ -23,4 +27,13 @@ pub trait IUserService: Send + Sync {
     async fn get_user_by_id(&self, id: u8) -> Result<User, UserError>;
     async fn update_user(&self, user_id: i32, user: User) -> Result<(), UserError>;
     async fn delete_user(&self, user_id: i32) -> Result<(), UserError>;
+
+    /// Crea el primer administrador; falla si ya existe alguno.
+    async fn create_administrator(&self, user: User) -> Result<User, UserError>;
+    async fn get_users_by_status(&self, status: UserStatus) -> Result<Vec<User>, UserError>;
+    async fn suspend_user(&self, user_id: i32) -> Result<(), UserError>;
+    async fn reactivate_user(&self, user_id: i32) -> Result<(), UserError>;
+    async fn reset_password(&self, user_id: i32, password: String) -> Result<(), UserError>;
+    /// Elimina las cuentas dadas de baja hace más de `retention_days` días y retorna cuántas.
+    async fn purge_deleted_users(&self, retention_days: u32) -> Result<u64, UserError>;
 }
@@ -0,0 +1,497 @@
+use crate::core::{
+    entities::{
+        cart::{Cart, CartItem},
+        delivery::DeliveryZone,
+        fulfilment::Fulfilment,
+        loyalty::{LoyaltyAccount, LoyaltyEntry, LoyaltyRules},
+        metric_event::MetricEvent,
+        order::{Order, OrderItemOption},
+        order_event::OrderEvent,
+        payment::PaymentMethod,
+        pricing::PricingRules,
+        product::Product,
+        promotion::{self, AppliedDiscount, Promotion},
+        user_payload::UserPayload,
+        user_role::UserRole,
+    },
+    errors::{
+        cart_errors::CartError, catalog_errors::CatalogError, promotion_errors::PromotionError,
+    },
+    ports::{
+        cart_port::{ICartRepository, ICartService},
+        catalog_port::ICatalogRepository,
+        delivery_port::IDeliveryRepository,
+        loyalty_port::ILoyaltyRepository,
+        metrics_port::IMetricsRecorder,
+        order_event_port::IOrderEventPublisher,
+        promotion_port::IPromotionRepository,
+        store_port::IStoreRepository,
+        unit_of_work_port::{ITransaction, IUnitOfWork},
+    },
+    services::order_schedule::OrderSchedule,
+};
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct CartService<R, C, P, L, S, D, W>
+where
+    R: ICartRepository<Transaction = W::Transaction>,
+    C: ICatalogRepository,
+    P: IPromotionRepository<Transaction = W::Transaction>,
+    L: ILoyaltyRepository<Transaction = W::Transaction>,
+    S: IStoreRepository<Transaction = W::Transaction>,
+    D: IDeliveryRepository,
+    W: IUnitOfWork,
+{
+    cart_repository: R,
+    catalog_repository: C,
+    promotion_repository: P,
+    loyalty_repository: L,
+    store_repository: S,
+    delivery_repository: D,
+    unit_of_work: W,
+    order_events: Arc<dyn IOrderEventPublisher>,
+    metrics: Arc<dyn IMetricsRecorder>,
+    pricing_rules: PricingRules,
+    loyalty_rules: LoyaltyRules,
+}
+
+#[async_trait::async_trait]
+impl<R, C, P, L, S, D, W> ICartService for CartService<R, C, P, L, S, D, W>
+where
+    R: ICartRepository<Transaction = W::Transaction>,
+    C: ICatalogRepository,
+    P: IPromotionRepository<Transaction = W::Transaction>,
+    L: ILoyaltyRepository<Transaction = W::Transaction>,
+    S: IStoreRepository<Transaction = W::Transaction>,
+    D: IDeliveryRepository,
+    W: IUnitOfWork,
+{
+    async fn get_cart(&self, user_id: i32) -> Result<Cart, CartError> {
+        let mut cart = Cart::new(user_id);
+
+        for item in self.cart_repository.get_cart_items(user_id).await? {
+            cart.items.push(self.price_item(item).await?);
+        }
+        cart.discounts = self.apply_promotions(&mut cart).await?;
+        cart.pricing = cart.price_breakdown(&self.pricing_rules);
+
+        Ok(cart)
+    }
+
+    async fn add_item(&self, user_id: i32, item: CartItem) -> Result<Cart, CartError> {
+        if item.quantity <= 0 {
+            return Err(CartError::InvalidItem(
+                "quantity must be greater than zero".to_string(),
+            ));
+        }
+
+        self.find_product(item.product_id)
+            .await?
+            .price_configuration(&item.option_ids())?;
+
+        self.cart_repository.insert_cart_item(user_id, item).await?;
+
+        self.get_cart(user_id).await
+    }
+
+    async fn update_item(
+        &self,
+        user_id: i32,
+        item_id: i32,
+        quantity: i32,
+    ) -> Result<Cart, CartError> {
+        if quantity <= 0 {
+            return Err(CartError::InvalidItem(
+                "quantity must be greater than zero".to_string(),
+            ));
+        }
+
+        self.cart_repository
+            .update_cart_item(user_id, item_id, quantity)
+            .await?;
+
+        self.get_cart(user_id).await
+    }
+
+    async fn remove_item(&self, user_id: i32, item_id: i32) -> Result<Cart, CartError> {
+        self.cart_repository
+            .delete_cart_item(user_id, item_id)
+            .await?;
+
+        self.get_cart(user_id).await
+    }
+
+    async fn clear_cart(&self, user_id: i32) -> Result<(), CartError> {
+        self.cart_repository.clear_cart(user_id).await
+    }
+
+    async fn apply_coupon(&self, user_id: i32, code: &str) -> Result<Cart, CartError> {
+        let promotion = self
+            .promotion_repository
+            .get_promotion_by_code(&Promotion::normalize_code(code))
+            .await?;
+        let promotion_id = promotion.id.unwrap_or_default();
+        let cart = self.get_cart(user_id).await?;
+
+        let usage = self
+            .promotion_repository
+            .get_usage(promotion_id, user_id)
+            .await?;
+        promotion
+            .check_eligibility(Utc::now(), cart.pricing.subtotal.amount, usage)
+            .map_err(CartError::InvalidCoupon)?;
+
+        self.cart_repository
+            .insert_cart_coupon(user_id, promotion_id)
+            .await?;
+
+        self.get_cart(user_id).await
+    }
+
+    async fn remove_coupon(&self, user_id: i32, code: &str) -> Result<Cart, CartError> {
+        let promotion = self
+            .promotion_repository
+            .get_promotion_by_code(&Promotion::normalize_code(code))
+            .await?;
+
+        self.cart_repository
+            .delete_cart_coupon(user_id, promotion.id.unwrap_or_default())
+            .await?;
+
+        self.get_cart(user_id).await
+    }
+
+    async fn checkout(
+        &self,
+        user: &UserPayload,
+        fulfilment: Fulfilment,
+        payment_method: PaymentMethod,
+        tip: i64,
+        redeem_points: i64,
+    ) -> Result<Order, CartError> {
+        let cart = self.get_cart(user.get_id()).await?;
+
+        if cart.items.is_empty() {
+            return Err(CartError::EmptyCart);
+        }
+        if cart.has_unavailable_items() {
+            return Err(CartError::UnavailableItems);
+        }
+
+        let delivery_address_id = fulfilment
+            .delivery_address_id()
+            .map_err(CartError::InvalidDelivery)?;
+        let now = Utc::now();
+        let product_ids: Vec<i32> = cart.items.iter().map(|item| item.product_id).collect();
+        let schedule =
+            OrderSchedule::plan(&self.store_repository, &fulfilment, &product_ids, now).await?;
+
+        let mut order = schedule.apply(Order {
+            payment_method,
+            ..Order::place(
+                user.get_id(),
+                cart.items.iter().map(CartItem::to_order_item).collect(),
+                cart.discounts.clone(),
+                tip,
+                &self.pricing_rules,
+            )
+        });
+        if let Some(address_id) = delivery_address_id {
+            let address = self
+                .delivery_repository
+                .get_address(user.get_id(), address_id)
+                .await?;
+            let zones = self.delivery_repository.get_delivery_zones().await?;
+            let zone = DeliveryZone::for_address(&zones, &address)?;
+            order = order.deliver_to(&address, zone)?;
+        }
+        if redeem_points > 0 {
+            order = self.redeem_points(user, order, redeem_points).await?;
+        }
+        if user.get_role() == UserRole::Client {
+            order.points_earned = self.loyalty_rules.earned_points(&order);
+        }
+        let item_ids = cart.items.iter().filter_map(|item| item.id).collect();
+
+        // Primero se toman todos los bloqueos y después se cuenta: en MySQL la primera lectura
+        // fija la instantánea que ve el resto de la transacción.
+        let mut tx = self.unit_of_work.begin().await?;
+        schedule
+            .lock_slot_in(&self.store_repository, &mut tx)
+            .await?;
+        let promotions = self.lock_promotions_in(&mut tx, &order.discounts).await?;
+        if order.points_redeemed > 0 {
+            self.loyalty_repository
+                .lock_account_in(&mut tx, user.get_id())
+                .await?;
+        }
+        schedule
+            .check_slot_in(&self.store_repository, &mut tx)
+            .await?;
+        self.check_usage_in(&mut tx, promotions, user.get_id())
+            .await?;
+        if order.points_redeemed > 0 {
+            let entries = self
+                .loyalty_repository
+                .get_entries_in(&mut tx, user.get_id())
+                .await?;
+            Self::check_balance(user.get_id(), entries, order.points_redeemed)?;
+        }
+        let order = self
+            .cart_repository
+            .checkout_in(&mut tx, user.get_id(), item_ids, order)
+            .await?;
+        tx.commit().await?;
+
+        self.order_events
+            .publish(OrderEvent::created(order.clone()));
+        self.metrics.record(MetricEvent::OrderPlaced);
+
+        Ok(order)
+    }
+}
+
+impl<R, C, P, L, S, D, W> CartService<R, C, P, L, S, D, W>
+where
+    R: ICartRepository<Transaction = W::Transaction>,
+    C: ICatalogRepository,
+    P: IPromotionRepository<Transaction = W::Transaction>,
+    L: ILoyaltyRepository<Transaction = W::Transaction>,
+    S: IStoreRepository<Transaction = W::Transaction>,
+    D: IDeliveryRepository,
+    W: IUnitOfWork,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        cart_repository: R,
+        catalog_repository: C,
+        promotion_repository: P,
+        loyalty_repository: L,
+        store_repository: S,
+        delivery_repository: D,
+        unit_of_work: W,
+        order_events: Arc<dyn IOrderEventPublisher>,
+        metrics: Arc<dyn IMetricsRecorder>,
+        pricing_rules: PricingRules,
+        loyalty_rules: LoyaltyRules,
+    ) -> Self {
+        CartService {
+            cart_repository,
+            catalog_repository,
+            promotion_repository,
+            loyalty_repository,
+            store_repository,
+            delivery_repository,
+            unit_of_work,
+            order_events,
+            metrics,
+            pricing_rules,
+            loyalty_rules,
+        }
+    }
+
+    async fn find_product(&self, product_id: i32) -> Result<Product, CatalogError> {
+        let mut product = self
+            .catalog_repository
+            .get_product_by_id(product_id)
+            .await?;
+        product.modifier_groups = self
+            .catalog_repository
+            .get_modifier_groups_by_product(product_id)
+            .await?;
+
+        Ok(product)
+    }
+
+    async fn tax_rate(&self, product: &Product) -> Result<i32, CatalogError> {
+        let category = self
+            .catalog_repository
+            .get_category_by_id(product.category_id)
+            .await?;
+
+        Ok(category
+            .tax_rate
+            .unwrap_or(self.pricing_rules.default_tax_rate))
+    }
+
+    /// Valoriza el ítem con el catálogo actual y lo marca como no disponible si el producto o
+    /// alguna de sus opciones ya no se puede pedir.
+    async fn price_item(&self, mut item: CartItem) -> Result<CartItem, CartError> {
+        let product = match self.find_product(item.product_id).await {
+            Ok(product) => product,
+            Err(CatalogError::ProductNotFound) => {
+                item.unavailable_reason = Some("Product no longer exists".to_string());
+                return Ok(item);
+            }
+            Err(err) => return Err(CartError::from(err)),
+        };
+
+        item.product_name = product.name.clone();
+        item.category_id = product.category_id;
+        item.tax_rate = self.tax_rate(&product).await?;
+        item.options = item
+            .option_ids()
+            .into_iter()
+            .map(|option_id| match product.find_option(option_id) {
+                Some(option) => OrderItemOption {
+                    option_id,
+                    name: option.name.clone(),
+                    price_delta: option.price_delta,
+                },
+                None => OrderItemOption {
+                    option_id,
+                    name: "".to_string(),
+                    price_delta: 0,
+                },
+            })
+            .collect();
+
+        match product.price_configuration(&item.option_ids()) {
+            Ok(unit_price) => item.unit_price = unit_price,
+            Err(
+                err @ (CatalogError::ProductUnavailable | CatalogError::InvalidConfiguration(_)),
+            ) => {
+                item.unit_price =
+                    product.price + item.options.iter().map(|o| o.price_delta).sum::<i64>();
+                item.unavailable_reason = Some(err.to_string());
+            }
+            Err(err) => return Err(CartError::from(err)),
+        }
+
+        Ok(item)
+    }
+
+    /// Evalúa las promociones automáticas y los cupones del carrito, descartando las que ya no
+    /// sean válidas, y elige los descuentos según las reglas de acumulación.
+    async fn apply_promotions(&self, cart: &mut Cart) -> Result<Vec<AppliedDiscount>, CartError> {
+        let currency = self.pricing_rules.currency;
+        let lines = cart.promotion_lines(currency);
+        let subtotal = cart.price_breakdown(&self.pricing_rules).subtotal.amount;
+        let now = Utc::now();
+
+        let mut candidates = self.promotion_repository.get_automatic_promotions().await?;
+        for promotion_id in self.cart_repository.get_cart_coupons(cart.user_id).await? {
+            match self
+                .promotion_repository
+                .get_promotion_by_id(promotion_id)
+                .await
+            {
+                Ok(promotion) => {
+                    cart.coupon_codes.extend(promotion.code.clone());
+                    candidates.push(promotion);
+                }
+                Err(PromotionError::NotFound) => {}
+                Err(err) => return Err(CartError::from(err)),
+            }
+        }
+
+        let mut eligible = vec![];
+        for promotion in candidates {
+            let usage = self
+                .promotion_repository
+                .get_usage(promotion.id.unwrap_or_default(), cart.user_id)
+                .await?;
+
+            if promotion.check_eligibility(now, subtotal, usage).is_ok() {
+                eligible.push(promotion);
+            }
+        }
+
+        Ok(promotion::select_discounts(&eligible, &lines, currency))
+    }
+
+    /// Retorna `InvalidRedemption` si el usuario no tiene `points` puntos disponibles.
+    fn check_balance(
+        user_id: i32,
+        entries: Vec<LoyaltyEntry>,
+        points: i64,
+    ) -> Result<(), CartError> {
+        let account = LoyaltyAccount::from_entries(user_id, entries, Utc::now());
+        if points > account.balance {
+            return Err(CartError::InvalidRedemption(format!(
+                "only {} points available",
+                account.balance
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Bloquea las promociones del pedido hasta el final de la transacción, para que dos compras
+    /// simultáneas no canjeen el último uso. Se bloquean en orden de ID para que dos compras con
+    /// las mismas promociones no se esperen mutuamente.
+    async fn lock_promotions_in(
+        &self,
+        tx: &mut W::Transaction,
+        discounts: &[AppliedDiscount],
+    ) -> Result<Vec<Promotion>, CartError> {
+        let mut promotion_ids: Vec<i32> = discounts
+            .iter()
+            .map(|discount| discount.promotion_id)
+            .collect();
+        promotion_ids.sort_unstable();
+        promotion_ids.dedup();
+
+        let mut promotions = vec![];
+        for promotion_id in promotion_ids {
+            promotions.push(
+                self.promotion_repository
+                    .lock_promotion_in(tx, promotion_id)
+                    .await?,
+            );
+        }
+
+        Ok(promotions)
+    }
+
+    /// Vuelve a comprobar los límites de uso de las promociones ya bloqueadas.
+    async fn check_usage_in(
+        &self,
+        tx: &mut W::Transaction,
+        promotions: Vec<Promotion>,
+        user_id: i32,
+    ) -> Result<(), CartError> {
+        for promotion in promotions {
+            let usage = self
+                .promotion_repository
+                .get_usage_in(tx, promotion.id.unwrap_or_default(), user_id)
+                .await?;
+            promotion
+                .check_usage(usage)
+                .map_err(CartError::InvalidCoupon)?;
+        }
+
+        Ok(())
+    }
+
+    /// Descuenta del pedido el valor de los puntos, que no puede superar el saldo del cliente ni
+    /// el subtotal que queda después de las promociones.
+    async fn redeem_points(
+        &self,
+        user: &UserPayload,
+        order: Order,
+        points: i64,
+    ) -> Result<Order, CartError> {
+        if user.get_role() != UserRole::Client {
+            return Err(CartError::InvalidRedemption(
+                "only clients can redeem points".to_string(),
+            ));
+        }
+
+        let entries = self.loyalty_repository.get_entries(user.get_id()).await?;
+        Self::check_balance(user.get_id(), entries, points)?;
+
+        let amount = self
+            .loyalty_rules
+            .redemption_value(points, self.pricing_rules.currency);
+        if amount.amount > (order.pricing.subtotal - order.pricing.discount).amount {
+            return Err(CartError::InvalidRedemption(
+                "points exceed the order amount".to_string(),
+            ));
+        }
+
+        Ok(order.redeem_points(points, amount.amount, &self.pricing_rules))
+    }
+}
@@ -0,0 +1,115 @@
+use crate::core::{
+    entities::{
+        cart::{Cart, CartItem},
+        fulfilment::Fulfilment,
+        order::{Order, OrderItemOption},
+        payment::PaymentMethod,
+        pricing::PricingRules,
+        user_payload::UserPayload,
+    },
+    errors::cart_errors::CartError,
+    ports::cart_port::ICartService,
+};
+use async_trait::async_trait;
+
+#[derive(Clone)]
+pub struct CartServiceStub {
+    pub status_code: i32,
+}
+
+impl CartServiceStub {
+    fn respond<T>(&self, value: T) -> Result<T, CartError> {
+        match self.status_code {
+            200 => Ok(value),
+            400 => Err(CartError::EmptyCart),
+            404 => Err(CartError::ItemNotFound),
+            409 => Err(CartError::UnavailableItems),
+            _ => Err(CartError::Unexpected),
+        }
+    }
+
+    fn cart() -> Cart {
+        let cart = Cart {
+            user_id: 1,
+            items: vec![CartItem {
+                id: Some(1),
+                product_id: 1,
+                product_name: "Latte".to_string(),
+                category_id: 1,
+                quantity: 1,
+                options: vec![OrderItemOption {
+                    option_id: 1,
+                    name: "Grande".to_string(),
+                    price_delta: 50,
+                }],
+                unit_price: 325,
+                tax_rate: 1000,
+                unavailable_reason: None,
+            }],
+            ..Cart::new(1)
+        };
+
+        Cart {
+            pricing: cart.price_breakdown(&PricingRules::default()),
+            ..cart
+        }
+    }
+}
+
+#[async_trait]
+impl ICartService for CartServiceStub {
+    async fn get_cart(&self, _user_id: i32) -> Result<Cart, CartError> {
+        self.respond(Self::cart())
+    }
+
+    async fn add_item(&self, _user_id: i32, _item: CartItem) -> Result<Cart, CartError> {
+        self.respond(Self::cart())
+    }
+
+    async fn update_item(
+        &self,
+        _user_id: i32,
+        _item_id: i32,
+        _quantity: i32,
+    ) -> Result<Cart, CartError> {
+        self.respond(Self::cart())
+    }
+
+    async fn remove_item(&self, _user_id: i32, _item_id: i32) -> Result<Cart, CartError> {
+        self.respond(Cart::new(1))
+    }
+
+    async fn clear_cart(&self, _user_id: i32) -> Result<(), CartError> {
+        self.respond(())
+    }
+
+    async fn apply_coupon(&self, _user_id: i32, _code: &str) -> Result<Cart, CartError> {
+        self.respond(Self::cart())
+    }
+
+    async fn remove_coupon(&self, _user_id: i32, _code: &str) -> Result<Cart, CartError> {
+        self.respond(Self::cart())
+    }
+
+    async fn checkout(
+        &self,
+        _user: &UserPayload,
+        _fulfilment: Fulfilment,
+        _payment_method: PaymentMethod,
+        tip: i64,
+        _redeem_points: i64,
+    ) -> Result<Order, CartError> {
+        let cart = Self::cart();
+
+        self.respond(Order {
+            id: Some(1),
+            ..Order::place(
+                cart.user_id,
+                cart.items.iter().map(CartItem::to_order_item).collect(),
+                cart.discounts,
+                tip,
+                &PricingRules::default(),
+            )
+        })
+    }
+}
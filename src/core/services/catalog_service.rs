@@ -0,0 +1,178 @@
+use crate::core::{
+    entities::{
+        category::Category,
+        modifier::{ModifierGroup, ModifierOption},
+        product::Product,
+    },
+    errors::catalog_errors::CatalogError,
+    ports::catalog_port::{ICatalogRepository, ICatalogService},
+};
+
+#[derive(Clone)]
+pub struct CatalogService<R>
+where
+    R: ICatalogRepository,
+{
+    catalog_repository: R,
+}
+
+#[async_trait::async_trait]
+impl<R> ICatalogService for CatalogService<R>
+where
+    R: ICatalogRepository,
+{
+    async fn get_categories(&self) -> Result<Vec<Category>, CatalogError> {
+        self.catalog_repository.get_categories().await
+    }
+
+    async fn create_category(&self, category: Category) -> Result<Category, CatalogError> {
+        self.catalog_repository.insert_category(category).await
+    }
+
+    async fn update_category(
+        &self,
+        category_id: i32,
+        category: Category,
+    ) -> Result<(), CatalogError> {
+        self.catalog_repository
+            .update_category(category_id, category)
+            .await
+    }
+
+    async fn delete_category(&self, category_id: i32) -> Result<(), CatalogError> {
+        self.catalog_repository.delete_category(category_id).await
+    }
+
+    async fn get_products(&self, category_id: Option<i32>) -> Result<Vec<Product>, CatalogError> {
+        self.catalog_repository.get_products(category_id).await
+    }
+
+    async fn get_product_by_id(&self, product_id: i32) -> Result<Product, CatalogError> {
+        let mut product = self
+            .catalog_repository
+            .get_product_by_id(product_id)
+            .await?;
+        product.modifier_groups = self
+            .catalog_repository
+            .get_modifier_groups_by_product(product_id)
+            .await?;
+
+        Ok(product)
+    }
+
+    async fn create_product(&self, product: Product) -> Result<Product, CatalogError> {
+        self.catalog_repository
+            .get_category_by_id(product.category_id)
+            .await?;
+
+        self.catalog_repository.insert_product(product).await
+    }
+
+    async fn update_product(&self, product_id: i32, product: Product) -> Result<(), CatalogError> {
+        self.catalog_repository
+            .get_category_by_id(product.category_id)
+            .await?;
+
+        self.catalog_repository
+            .update_product(product_id, product)
+            .await
+    }
+
+    async fn delete_product(&self, product_id: i32) -> Result<(), CatalogError> {
+        self.catalog_repository.delete_product(product_id).await
+    }
+
+    async fn price_product(
+        &self,
+        product_id: i32,
+        option_ids: &[i32],
+    ) -> Result<i64, CatalogError> {
+        self.get_product_by_id(product_id)
+            .await?
+            .price_configuration(option_ids)
+    }
+
+    async fn get_modifier_groups(&self) -> Result<Vec<ModifierGroup>, CatalogError> {
+        self.catalog_repository.get_modifier_groups().await
+    }
+
+    async fn create_modifier_group(
+        &self,
+        group: ModifierGroup,
+    ) -> Result<ModifierGroup, CatalogError> {
+        self.catalog_repository.insert_modifier_group(group).await
+    }
+
+    async fn delete_modifier_group(&self, group_id: i32) -> Result<(), CatalogError> {
+        self.catalog_repository
+            .delete_modifier_group(group_id)
+            .await
+    }
+
+    async fn create_modifier_option(
+        &self,
+        group_id: i32,
+        mut option: ModifierOption,
+    ) -> Result<ModifierOption, CatalogError> {
+        self.find_modifier_group(group_id).await?;
+        option.group_id = group_id;
+
+        self.catalog_repository.insert_modifier_option(option).await
+    }
+
+    async fn update_modifier_option(
+        &self,
+        group_id: i32,
+        option_id: i32,
+        mut option: ModifierOption,
+    ) -> Result<(), CatalogError> {
+        option.group_id = group_id;
+
+        self.catalog_repository
+            .update_modifier_option(option_id, option)
+            .await
+    }
+
+    async fn attach_modifier_group(
+        &self,
+        product_id: i32,
+        group_id: i32,
+    ) -> Result<(), CatalogError> {
+        self.catalog_repository
+            .get_product_by_id(product_id)
+            .await?;
+        self.find_modifier_group(group_id).await?;
+
+        self.catalog_repository
+            .attach_modifier_group(product_id, group_id)
+            .await
+    }
+
+    async fn detach_modifier_group(
+        &self,
+        product_id: i32,
+        group_id: i32,
+    ) -> Result<(), CatalogError> {
+        self.catalog_repository
+            .detach_modifier_group(product_id, group_id)
+            .await
+    }
+}
+
+impl<R> CatalogService<R>
+where
+    R: ICatalogRepository,
+{
+    pub fn new(catalog_repository: R) -> Self {
+        CatalogService { catalog_repository }
+    }
+
+    async fn find_modifier_group(&self, group_id: i32) -> Result<ModifierGroup, CatalogError> {
+        self.catalog_repository
+            .get_modifier_groups()
+            .await?
+            .into_iter()
+            .find(|group| group.id == Some(group_id))
+            .ok_or(CatalogError::ModifierGroupNotFound)
+    }
+}
@@ -0,0 +1,177 @@
+use crate::core::{
+    entities::{
+        category::Category,
+        modifier::{ModifierGroup, ModifierOption},
+        product::Product,
+    },
+    errors::catalog_errors::CatalogError,
+    ports::catalog_port::ICatalogService,
+};
+use async_trait::async_trait;
+
+#[derive(Clone)]
+pub struct CatalogServiceStub {
+    pub status_code: i32,
+}
+
+impl CatalogServiceStub {
+    fn respond<T>(&self, value: T, not_found: CatalogError) -> Result<T, CatalogError> {
+        if self.status_code == 200 {
+            Ok(value)
+        } else if self.status_code == 404 {
+            Err(not_found)
+        } else {
+            Err(CatalogError::Unexpected)
+        }
+    }
+
+    fn category() -> Category {
+        Category {
+            id: Some(1),
+            name: "Cafés".to_string(),
+            description: Some("Bebidas a base de espresso".to_string()),
+            tax_rate: Some(1000),
+        }
+    }
+
+    fn product() -> Product {
+        Product {
+            id: Some(1),
+            category_id: 1,
+            name: "Latte".to_string(),
+            description: Some("Espresso con leche vaporizada".to_string()),
+            price: 275,
+            image_url: None,
+            available: true,
+            in_stock: true,
+            modifier_groups: vec![Self::modifier_group()],
+        }
+    }
+
+    fn modifier_group() -> ModifierGroup {
+        ModifierGroup {
+            id: Some(1),
+            name: "Tamaño".to_string(),
+            min_selections: 1,
+            max_selections: 1,
+            options: vec![Self::modifier_option()],
+        }
+    }
+
+    fn modifier_option() -> ModifierOption {
+        ModifierOption {
+            id: Some(1),
+            group_id: 1,
+            name: "Grande".to_string(),
+            price_delta: 50,
+            available: true,
+            in_stock: true,
+        }
+    }
+}
+
+#[async_trait]
+impl ICatalogService for CatalogServiceStub {
+    async fn get_categories(&self) -> Result<Vec<Category>, CatalogError> {
+        self.respond(vec![Self::category()], CatalogError::CategoryNotFound)
+    }
+
+    async fn create_category(&self, _category: Category) -> Result<Category, CatalogError> {
+        self.respond(Self::category(), CatalogError::CategoryNotFound)
+    }
+
+    async fn update_category(
+        &self,
+        _category_id: i32,
+        _category: Category,
+    ) -> Result<(), CatalogError> {
+        self.respond((), CatalogError::CategoryNotFound)
+    }
+
+    async fn delete_category(&self, _category_id: i32) -> Result<(), CatalogError> {
+        self.respond((), CatalogError::CategoryNotFound)
+    }
+
+    async fn get_products(&self, _category_id: Option<i32>) -> Result<Vec<Product>, CatalogError> {
+        self.respond(vec![Self::product()], CatalogError::ProductNotFound)
+    }
+
+    async fn get_product_by_id(&self, _product_id: i32) -> Result<Product, CatalogError> {
+        self.respond(Self::product(), CatalogError::ProductNotFound)
+    }
+
+    async fn create_product(&self, _product: Product) -> Result<Product, CatalogError> {
+        self.respond(Self::product(), CatalogError::ProductNotFound)
+    }
+
+    async fn update_product(
+        &self,
+        _product_id: i32,
+        _product: Product,
+    ) -> Result<(), CatalogError> {
+        self.respond((), CatalogError::ProductNotFound)
+    }
+
+    async fn delete_product(&self, _product_id: i32) -> Result<(), CatalogError> {
+        self.respond((), CatalogError::ProductNotFound)
+    }
+
+    async fn price_product(
+        &self,
+        _product_id: i32,
+        _option_ids: &[i32],
+    ) -> Result<i64, CatalogError> {
+        self.respond(325, CatalogError::ProductNotFound)
+    }
+
+    async fn get_modifier_groups(&self) -> Result<Vec<ModifierGroup>, CatalogError> {
+        self.respond(
+            vec![Self::modifier_group()],
+            CatalogError::ModifierGroupNotFound,
+        )
+    }
+
+    async fn create_modifier_group(
+        &self,
+        _group: ModifierGroup,
+    ) -> Result<ModifierGroup, CatalogError> {
+        self.respond(Self::modifier_group(), CatalogError::ModifierGroupNotFound)
+    }
+
+    async fn delete_modifier_group(&self, _group_id: i32) -> Result<(), CatalogError> {
+        self.respond((), CatalogError::ModifierGroupNotFound)
+    }
+
+    async fn create_modifier_option(
+        &self,
+        _group_id: i32,
+        _option: ModifierOption,
+    ) -> Result<ModifierOption, CatalogError> {
+        self.respond(Self::modifier_option(), CatalogError::ModifierGroupNotFound)
+    }
+
+    async fn update_modifier_option(
+        &self,
+        _group_id: i32,
+        _option_id: i32,
+        _option: ModifierOption,
+    ) -> Result<(), CatalogError> {
+        self.respond((), CatalogError::ModifierOptionNotFound)
+    }
+
+    async fn attach_modifier_group(
+        &self,
+        _product_id: i32,
+        _group_id: i32,
+    ) -> Result<(), CatalogError> {
+        self.respond((), CatalogError::ModifierGroupNotFound)
+    }
+
+    async fn detach_modifier_group(
+        &self,
+        _product_id: i32,
+        _group_id: i32,
+    ) -> Result<(), CatalogError> {
+        self.respond((), CatalogError::ModifierGroupNotFound)
+    }
+}
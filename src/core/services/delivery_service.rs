@@ -0,0 +1,136 @@
+use crate::core::{
+    entities::{
+        delivery::{Address, DeliveryZone},
+        user_payload::UserPayload,
+    },
+    errors::delivery_errors::DeliveryError,
+    ports::delivery_port::{IDeliveryRepository, IDeliveryService},
+};
+
+#[derive(Clone)]
+pub struct DeliveryService<D>
+where
+    D: IDeliveryRepository,
+{
+    delivery_repository: D,
+}
+
+#[async_trait::async_trait]
+impl<D> IDeliveryService for DeliveryService<D>
+where
+    D: IDeliveryRepository,
+{
+    async fn get_addresses(&self, user: &UserPayload) -> Result<Vec<Address>, DeliveryError> {
+        self.delivery_repository.get_addresses(user.get_id()).await
+    }
+
+    async fn create_address(
+        &self,
+        user: &UserPayload,
+        address: Address,
+    ) -> Result<Address, DeliveryError> {
+        address.validate().map_err(DeliveryError::InvalidAddress)?;
+
+        self.delivery_repository
+            .insert_address(Address {
+                id: None,
+                user_id: user.get_id(),
+                ..address
+            })
+            .await
+    }
+
+    async fn update_address(
+        &self,
+        user: &UserPayload,
+        address_id: i32,
+        address: Address,
+    ) -> Result<Address, DeliveryError> {
+        address.validate().map_err(DeliveryError::InvalidAddress)?;
+        self.delivery_repository
+            .get_address(user.get_id(), address_id)
+            .await?;
+
+        let address = Address {
+            id: Some(address_id),
+            user_id: user.get_id(),
+            ..address
+        };
+        self.delivery_repository
+            .update_address(address.clone())
+            .await?;
+
+        Ok(address)
+    }
+
+    async fn delete_address(
+        &self,
+        user: &UserPayload,
+        address_id: i32,
+    ) -> Result<(), DeliveryError> {
+        self.delivery_repository
+            .delete_address(user.get_id(), address_id)
+            .await
+    }
+
+    async fn quote_delivery(
+        &self,
+        user: &UserPayload,
+        address_id: i32,
+    ) -> Result<DeliveryZone, DeliveryError> {
+        let address = self
+            .delivery_repository
+            .get_address(user.get_id(), address_id)
+            .await?;
+        let zones = self.delivery_repository.get_delivery_zones().await?;
+
+        DeliveryZone::for_address(&zones, &address).cloned()
+    }
+
+    async fn get_delivery_zones(&self) -> Result<Vec<DeliveryZone>, DeliveryError> {
+        self.delivery_repository.get_delivery_zones().await
+    }
+
+    async fn create_delivery_zone(
+        &self,
+        zone: DeliveryZone,
+    ) -> Result<DeliveryZone, DeliveryError> {
+        zone.validate().map_err(DeliveryError::InvalidZone)?;
+
+        self.delivery_repository.insert_delivery_zone(zone).await
+    }
+
+    async fn update_delivery_zone(
+        &self,
+        zone_id: i32,
+        zone: DeliveryZone,
+    ) -> Result<DeliveryZone, DeliveryError> {
+        zone.validate().map_err(DeliveryError::InvalidZone)?;
+        self.delivery_repository
+            .get_delivery_zone_by_id(zone_id)
+            .await?;
+
+        self.delivery_repository
+            .update_delivery_zone(zone_id, zone)
+            .await?;
+
+        self.delivery_repository
+            .get_delivery_zone_by_id(zone_id)
+            .await
+    }
+
+    async fn delete_delivery_zone(&self, zone_id: i32) -> Result<(), DeliveryError> {
+        self.delivery_repository.delete_delivery_zone(zone_id).await
+    }
+}
+
+impl<D> DeliveryService<D>
+where
+    D: IDeliveryRepository,
+{
+    pub fn new(delivery_repository: D) -> Self {
+        DeliveryService {
+            delivery_repository,
+        }
+    }
+}
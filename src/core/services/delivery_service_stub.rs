@@ -0,0 +1,111 @@
+use crate::core::{
+    entities::{
+        delivery::{Address, DeliveryZone},
+        user_payload::UserPayload,
+    },
+    errors::delivery_errors::DeliveryError,
+    ports::delivery_port::IDeliveryService,
+};
+use async_trait::async_trait;
+
+#[derive(Clone)]
+pub struct DeliveryServiceStub {
+    pub status_code: i32,
+}
+
+impl DeliveryServiceStub {
+    fn respond<T>(&self, value: T) -> Result<T, DeliveryError> {
+        match self.status_code {
+            200 => Ok(value),
+            400 => Err(DeliveryError::InvalidAddress(
+                "province is required".to_string(),
+            )),
+            404 => Err(DeliveryError::AddressNotFound),
+            409 => Err(DeliveryError::OutsideDeliveryArea),
+            _ => Err(DeliveryError::Unexpected),
+        }
+    }
+
+    fn address() -> Address {
+        Address {
+            id: Some(1),
+            user_id: 1,
+            province: "Managua".to_string(),
+            city: "Managua".to_string(),
+            address: "Del Hotel Crowne Plaza 1c al sur".to_string(),
+        }
+    }
+
+    fn zone() -> DeliveryZone {
+        DeliveryZone {
+            id: Some(1),
+            province: "Managua".to_string(),
+            fee: 300,
+            minimum_order: 1000,
+            ..DeliveryZone::new()
+        }
+    }
+}
+
+#[async_trait]
+impl IDeliveryService for DeliveryServiceStub {
+    async fn get_addresses(&self, _user: &UserPayload) -> Result<Vec<Address>, DeliveryError> {
+        self.respond(vec![Self::address()])
+    }
+
+    async fn create_address(
+        &self,
+        _user: &UserPayload,
+        _address: Address,
+    ) -> Result<Address, DeliveryError> {
+        self.respond(Self::address())
+    }
+
+    async fn update_address(
+        &self,
+        _user: &UserPayload,
+        _address_id: i32,
+        _address: Address,
+    ) -> Result<Address, DeliveryError> {
+        self.respond(Self::address())
+    }
+
+    async fn delete_address(
+        &self,
+        _user: &UserPayload,
+        _address_id: i32,
+    ) -> Result<(), DeliveryError> {
+        self.respond(())
+    }
+
+    async fn quote_delivery(
+        &self,
+        _user: &UserPayload,
+        _address_id: i32,
+    ) -> Result<DeliveryZone, DeliveryError> {
+        self.respond(Self::zone())
+    }
+
+    async fn get_delivery_zones(&self) -> Result<Vec<DeliveryZone>, DeliveryError> {
+        self.respond(vec![Self::zone()])
+    }
+
+    async fn create_delivery_zone(
+        &self,
+        _zone: DeliveryZone,
+    ) -> Result<DeliveryZone, DeliveryError> {
+        self.respond(Self::zone())
+    }
+
+    async fn update_delivery_zone(
+        &self,
+        _zone_id: i32,
+        _zone: DeliveryZone,
+    ) -> Result<DeliveryZone, DeliveryError> {
+        self.respond(Self::zone())
+    }
+
+    async fn delete_delivery_zone(&self, _zone_id: i32) -> Result<(), DeliveryError> {
+        self.respond(())
+    }
+}
@@ -0,0 +1,39 @@
+use crate::core::{
+    entities::health::{ComponentHealth, HealthReport, HealthStatus},
+    ports::health_port::{IHealthCheck, IHealthService},
+};
+use std::{sync::Arc, time::Instant};
+
+#[derive(Clone)]
+pub struct HealthService {
+    checks: Vec<Arc<dyn IHealthCheck>>,
+}
+
+#[async_trait::async_trait]
+impl IHealthService for HealthService {
+    async fn readiness(&self) -> HealthReport {
+        let components = futures::future::join_all(self.checks.iter().map(|check| async move {
+            let started = Instant::now();
+            let result = check.check().await;
+
+            ComponentHealth {
+                name: check.name().to_string(),
+                status: match result {
+                    Ok(()) => HealthStatus::Up,
+                    Err(_) => HealthStatus::Down,
+                },
+                latency: started.elapsed(),
+                error: result.err(),
+            }
+        }))
+        .await;
+
+        HealthReport::from_components(components)
+    }
+}
+
+impl HealthService {
+    pub fn new(checks: Vec<Arc<dyn IHealthCheck>>) -> Self {
+        HealthService { checks }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::core::{
+    entities::health::{ComponentHealth, HealthReport, HealthStatus},
+    ports::health_port::IHealthService,
+};
+use async_trait::async_trait;
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct HealthServiceStub {
+    pub status_code: i32,
+}
+
+#[async_trait]
+impl IHealthService for HealthServiceStub {
+    async fn readiness(&self) -> HealthReport {
+        let (status, error) = if self.status_code == 200 {
+            (HealthStatus::Up, None)
+        } else {
+            (HealthStatus::Down, Some("connection refused".to_string()))
+        };
+
+        HealthReport::from_components(vec![ComponentHealth {
+            name: "database".to_string(),
+            status,
+            latency: Duration::from_micros(1500),
+            error,
+        }])
+    }
+}
@@ -0,0 +1,239 @@
+use crate::core::{
+    entities::{
+        inventory::{Ingredient, RecipeLine, StockAdjustment, StockAlert},
+        user_payload::UserPayload,
+    },
+    errors::{catalog_errors::CatalogError, inventory_errors::InventoryError},
+    ports::{
+        catalog_port::ICatalogRepository,
+        inventory_port::{IInventoryRepository, IInventoryService},
+        stock_alert_port::IStockAlertPublisher,
+    },
+};
+use chrono::Utc;
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct InventoryService<I, C>
+where
+    I: IInventoryRepository,
+    C: ICatalogRepository,
+{
+    inventory_repository: I,
+    catalog_repository: C,
+    stock_alerts: Arc<dyn IStockAlertPublisher>,
+}
+
+#[async_trait::async_trait]
+impl<I, C> IInventoryService for InventoryService<I, C>
+where
+    I: IInventoryRepository,
+    C: ICatalogRepository,
+{
+    async fn get_ingredients(&self) -> Result<Vec<Ingredient>, InventoryError> {
+        self.inventory_repository.get_ingredients().await
+    }
+
+    async fn create_ingredient(
+        &self,
+        ingredient: Ingredient,
+    ) -> Result<Ingredient, InventoryError> {
+        // El stock inicial se carga con un movimiento para que quede en la auditoría.
+        self.inventory_repository
+            .insert_ingredient(Ingredient {
+                stock: 0,
+                ..ingredient
+            })
+            .await
+    }
+
+    async fn update_ingredient(
+        &self,
+        ingredient_id: i32,
+        ingredient: Ingredient,
+    ) -> Result<Ingredient, InventoryError> {
+        let current = self
+            .inventory_repository
+            .get_ingredient_by_id(ingredient_id)
+            .await?;
+
+        self.inventory_repository
+            .update_ingredient(ingredient_id, ingredient.clone())
+            .await?;
+
+        Ok(Ingredient {
+            id: Some(ingredient_id),
+            stock: current.stock,
+            ..ingredient
+        })
+    }
+
+    async fn delete_ingredient(&self, ingredient_id: i32) -> Result<(), InventoryError> {
+        self.inventory_repository
+            .delete_ingredient(ingredient_id)
+            .await
+    }
+
+    async fn get_low_stock_ingredients(&self) -> Result<Vec<Ingredient>, InventoryError> {
+        let ingredients = self.inventory_repository.get_ingredients().await?;
+
+        Ok(ingredients
+            .into_iter()
+            .filter(Ingredient::is_low_stock)
+            .collect())
+    }
+
+    async fn get_adjustments(
+        &self,
+        ingredient_id: i32,
+    ) -> Result<Vec<StockAdjustment>, InventoryError> {
+        self.inventory_repository
+            .get_ingredient_by_id(ingredient_id)
+            .await?;
+
+        self.inventory_repository
+            .get_adjustments(ingredient_id)
+            .await
+    }
+
+    async fn adjust_stock(
+        &self,
+        user: &UserPayload,
+        ingredient_id: i32,
+        adjustment: StockAdjustment,
+    ) -> Result<Ingredient, InventoryError> {
+        let now = Utc::now();
+        let delta = adjustment.delta;
+
+        let ingredient = self
+            .inventory_repository
+            .adjust_stock(StockAdjustment {
+                id: None,
+                ingredient_id,
+                order_id: None,
+                adjusted_by: user.get_id(),
+                created_at: now,
+                ..adjustment
+            })
+            .await?;
+
+        if let Some(alert) = StockAlert::crossed(&ingredient, delta, now) {
+            self.stock_alerts.publish(alert);
+        }
+
+        Ok(ingredient)
+    }
+
+    async fn get_product_recipe(&self, product_id: i32) -> Result<Vec<RecipeLine>, InventoryError> {
+        self.check_product(product_id).await?;
+
+        self.inventory_repository
+            .get_product_recipe(product_id)
+            .await
+    }
+
+    async fn set_product_recipe(
+        &self,
+        product_id: i32,
+        lines: Vec<RecipeLine>,
+    ) -> Result<Vec<RecipeLine>, InventoryError> {
+        self.check_product(product_id).await?;
+        self.check_recipe(&lines).await?;
+
+        self.inventory_repository
+            .set_product_recipe(product_id, lines.clone())
+            .await?;
+
+        Ok(lines)
+    }
+
+    async fn get_option_recipe(&self, option_id: i32) -> Result<Vec<RecipeLine>, InventoryError> {
+        self.check_option(option_id).await?;
+
+        self.inventory_repository.get_option_recipe(option_id).await
+    }
+
+    async fn set_option_recipe(
+        &self,
+        option_id: i32,
+        lines: Vec<RecipeLine>,
+    ) -> Result<Vec<RecipeLine>, InventoryError> {
+        self.check_option(option_id).await?;
+        self.check_recipe(&lines).await?;
+
+        self.inventory_repository
+            .set_option_recipe(option_id, lines.clone())
+            .await?;
+
+        Ok(lines)
+    }
+}
+
+impl<I, C> InventoryService<I, C>
+where
+    I: IInventoryRepository,
+    C: ICatalogRepository,
+{
+    pub fn new(
+        inventory_repository: I,
+        catalog_repository: C,
+        stock_alerts: Arc<dyn IStockAlertPublisher>,
+    ) -> Self {
+        InventoryService {
+            inventory_repository,
+            catalog_repository,
+            stock_alerts,
+        }
+    }
+
+    async fn check_product(&self, product_id: i32) -> Result<(), InventoryError> {
+        match self.catalog_repository.get_product_by_id(product_id).await {
+            Ok(_) => Ok(()),
+            Err(CatalogError::ProductNotFound) => Err(InventoryError::ProductNotFound),
+            Err(_) => Err(InventoryError::Unexpected),
+        }
+    }
+
+    async fn check_option(&self, option_id: i32) -> Result<(), InventoryError> {
+        let groups = self
+            .catalog_repository
+            .get_modifier_groups()
+            .await
+            .map_err(|_| InventoryError::Unexpected)?;
+
+        groups
+            .iter()
+            .flat_map(|group| group.options.iter())
+            .any(|option| option.id == Some(option_id))
+            .then_some(())
+            .ok_or(InventoryError::ModifierOptionNotFound)
+    }
+
+    /// Comprueba que cada insumo exista y aparezca una sola vez en la receta.
+    async fn check_recipe(&self, lines: &[RecipeLine]) -> Result<(), InventoryError> {
+        let ingredients = self.inventory_repository.get_ingredients().await?;
+
+        for (index, line) in lines.iter().enumerate() {
+            if !ingredients
+                .iter()
+                .any(|ingredient| ingredient.id == Some(line.ingredient_id))
+            {
+                return Err(InventoryError::InvalidRecipe(format!(
+                    "ingredient {} not found",
+                    line.ingredient_id
+                )));
+            }
+            if lines[..index]
+                .iter()
+                .any(|other| other.ingredient_id == line.ingredient_id)
+            {
+                return Err(InventoryError::InvalidRecipe(format!(
+                    "ingredient {} is repeated",
+                    line.ingredient_id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,125 @@
+use crate::core::{
+    entities::{
+        inventory::{
+            Ingredient, IngredientUnit, RecipeLine, StockAdjustment, StockAdjustmentReason,
+        },
+        user_payload::UserPayload,
+    },
+    errors::inventory_errors::InventoryError,
+    ports::inventory_port::IInventoryService,
+};
+use async_trait::async_trait;
+
+#[derive(Clone)]
+pub struct InventoryServiceStub {
+    pub status_code: i32,
+}
+
+impl InventoryServiceStub {
+    fn respond<T>(&self, value: T) -> Result<T, InventoryError> {
+        match self.status_code {
+            200 => Ok(value),
+            400 => Err(InventoryError::InvalidRecipe("ingredient".to_string())),
+            404 => Err(InventoryError::IngredientNotFound),
+            409 => Err(InventoryError::InsufficientStock),
+            _ => Err(InventoryError::Unexpected),
+        }
+    }
+
+    fn ingredient() -> Ingredient {
+        Ingredient {
+            id: Some(1),
+            name: "Leche entera".to_string(),
+            unit: IngredientUnit::Milliliter,
+            stock: 800,
+            low_stock_threshold: 1000,
+        }
+    }
+
+    fn recipe() -> Vec<RecipeLine> {
+        vec![RecipeLine {
+            ingredient_id: 1,
+            quantity: 200,
+        }]
+    }
+}
+
+#[async_trait]
+impl IInventoryService for InventoryServiceStub {
+    async fn get_ingredients(&self) -> Result<Vec<Ingredient>, InventoryError> {
+        self.respond(vec![Self::ingredient()])
+    }
+
+    async fn create_ingredient(
+        &self,
+        _ingredient: Ingredient,
+    ) -> Result<Ingredient, InventoryError> {
+        self.respond(Self::ingredient())
+    }
+
+    async fn update_ingredient(
+        &self,
+        _ingredient_id: i32,
+        _ingredient: Ingredient,
+    ) -> Result<Ingredient, InventoryError> {
+        self.respond(Self::ingredient())
+    }
+
+    async fn delete_ingredient(&self, _ingredient_id: i32) -> Result<(), InventoryError> {
+        self.respond(())
+    }
+
+    async fn get_low_stock_ingredients(&self) -> Result<Vec<Ingredient>, InventoryError> {
+        self.respond(vec![Self::ingredient()])
+    }
+
+    async fn get_adjustments(
+        &self,
+        ingredient_id: i32,
+    ) -> Result<Vec<StockAdjustment>, InventoryError> {
+        self.respond(vec![StockAdjustment {
+            id: Some(1),
+            ingredient_id,
+            delta: 1000,
+            reason: StockAdjustmentReason::Restock,
+            adjusted_by: 1,
+            ..StockAdjustment::new()
+        }])
+    }
+
+    async fn adjust_stock(
+        &self,
+        _user: &UserPayload,
+        _ingredient_id: i32,
+        _adjustment: StockAdjustment,
+    ) -> Result<Ingredient, InventoryError> {
+        self.respond(Self::ingredient())
+    }
+
+    async fn get_product_recipe(
+        &self,
+        _product_id: i32,
+    ) -> Result<Vec<RecipeLine>, InventoryError> {
+        self.respond(Self::recipe())
+    }
+
+    async fn set_product_recipe(
+        &self,
+        _product_id: i32,
+        lines: Vec<RecipeLine>,
+    ) -> Result<Vec<RecipeLine>, InventoryError> {
+        self.respond(lines)
+    }
+
+    async fn get_option_recipe(&self, _option_id: i32) -> Result<Vec<RecipeLine>, InventoryError> {
+        self.respond(Self::recipe())
+    }
+
+    async fn set_option_recipe(
+        &self,
+        _option_id: i32,
+        lines: Vec<RecipeLine>,
+    ) -> Result<Vec<RecipeLine>, InventoryError> {
+        self.respond(lines)
+    }
+}
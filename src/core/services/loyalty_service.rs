@@ -0,0 +1,72 @@
+use crate::core::{
+    entities::{loyalty::LoyaltyAccount, user_payload::UserPayload},
+    errors::loyalty_errors::LoyaltyError,
+    ports::{
+        loyalty_port::{ILoyaltyRepository, ILoyaltyService},
+        unit_of_work_port::{ITransaction, IUnitOfWork},
+    },
+};
+use chrono::Utc;
+
+#[derive(Clone)]
+pub struct LoyaltyService<L, W>
+where
+    L: ILoyaltyRepository<Transaction = W::Transaction>,
+    W: IUnitOfWork,
+{
+    loyalty_repository: L,
+    unit_of_work: W,
+}
+
+#[async_trait::async_trait]
+impl<L, W> ILoyaltyService for LoyaltyService<L, W>
+where
+    L: ILoyaltyRepository<Transaction = W::Transaction>,
+    W: IUnitOfWork,
+{
+    async fn get_account(&self, user: &UserPayload) -> Result<LoyaltyAccount, LoyaltyError> {
+        let now = Utc::now();
+        let entries = self.loyalty_repository.get_entries(user.get_id()).await?;
+        let account = LoyaltyAccount::from_entries(user.get_id(), entries, now);
+        if account.expiration_entry(now).is_none() {
+            return Ok(account);
+        }
+
+        // El vencimiento se registra con la cuenta bloqueada y sobre el libro releído: otra
+        // consulta simultánea puede haberlo registrado ya.
+        let mut tx = self.unit_of_work.begin().await?;
+        self.loyalty_repository
+            .lock_account_in(&mut tx, user.get_id())
+            .await?;
+        let mut entries = self
+            .loyalty_repository
+            .get_entries_in(&mut tx, user.get_id())
+            .await?;
+        let account = LoyaltyAccount::from_entries(user.get_id(), entries.clone(), now);
+        let Some(expiration) = account.expiration_entry(now) else {
+            return Ok(account);
+        };
+
+        entries.push(
+            self.loyalty_repository
+                .insert_entry_in(&mut tx, expiration)
+                .await?,
+        );
+        tx.commit().await?;
+
+        Ok(LoyaltyAccount::from_entries(user.get_id(), entries, now))
+    }
+}
+
+impl<L, W> LoyaltyService<L, W>
+where
+    L: ILoyaltyRepository<Transaction = W::Transaction>,
+    W: IUnitOfWork,
+{
+    pub fn new(loyalty_repository: L, unit_of_work: W) -> Self {
+        LoyaltyService {
+            loyalty_repository,
+            unit_of_work,
+        }
+    }
+}
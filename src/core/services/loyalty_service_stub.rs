@@ -0,0 +1,37 @@
+use crate::core::{
+    entities::{
+        loyalty::{LoyaltyAccount, LoyaltyEntry, LoyaltyEntryKind},
+        user_payload::UserPayload,
+    },
+    errors::loyalty_errors::LoyaltyError,
+    ports::loyalty_port::ILoyaltyService,
+};
+use async_trait::async_trait;
+use chrono::{Duration, Utc};
+
+#[derive(Clone)]
+pub struct LoyaltyServiceStub {
+    pub status_code: i32,
+}
+
+#[async_trait]
+impl ILoyaltyService for LoyaltyServiceStub {
+    async fn get_account(&self, user: &UserPayload) -> Result<LoyaltyAccount, LoyaltyError> {
+        if self.status_code != 200 {
+            return Err(LoyaltyError::Unexpected);
+        }
+
+        let now = Utc::now();
+        let entries = vec![LoyaltyEntry {
+            id: Some(1),
+            user_id: user.get_id(),
+            kind: LoyaltyEntryKind::Earned,
+            points: 42,
+            order_id: Some(1),
+            expires_at: Some(now + Duration::days(365)),
+            created_at: now,
+        }];
+
+        Ok(LoyaltyAccount::from_entries(user.get_id(), entries, now))
+    }
+}
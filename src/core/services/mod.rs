@@ -1,2 +1,23 @@
+pub mod cart_service;
+pub mod cart_service_stub;
+pub mod catalog_service;
+pub mod catalog_service_stub;
+pub mod delivery_service;
+pub mod delivery_service_stub;
+pub mod health_service;
+pub mod health_service_stub;
+pub mod inventory_service;
+pub mod inventory_service_stub;
+pub mod loyalty_service;
+pub mod loyalty_service_stub;
+pub mod order_schedule;
+pub mod order_service;
+pub mod order_service_stub;
+pub mod payment_service;
+pub mod payment_service_stub;
+pub mod promotion_service;
+pub mod promotion_service_stub;
+pub mod store_service;
+pub mod store_service_stub;
 pub mod user_service;
 pub mod user_service_stub;
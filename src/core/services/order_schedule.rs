@@ -0,0 +1,92 @@
+use crate::core::{
+    entities::{fulfilment::Fulfilment, order::Order},
+    errors::store_errors::StoreError,
+    ports::store_port::IStoreRepository,
+};
+use chrono::{DateTime, Utc};
+
+/// Local y franja de un pedido nuevo, compartidos por la compra directa y la del carrito.
+pub struct OrderSchedule {
+    store_id: i32,
+    scheduled_for: Option<DateTime<Utc>>,
+    release_at: Option<DateTime<Utc>>,
+    slot_capacity: i32,
+}
+
+impl OrderSchedule {
+    /// Comprueba que el local ofrezca los productos y elige cuándo se atiende el pedido según
+    /// las franjas ya reservadas. El cupo de la franja se vuelve a comprobar al guardar el
+    /// pedido, con `lock_slot_in` y `check_slot_in`.
+    pub async fn plan<S: IStoreRepository>(
+        store_repository: &S,
+        fulfilment: &Fulfilment,
+        product_ids: &[i32],
+        now: DateTime<Utc>,
+    ) -> Result<OrderSchedule, StoreError> {
+        let store = store_repository
+            .get_store_by_id(fulfilment.store_id)
+            .await?;
+        store.check_products(product_ids)?;
+        let bookings = store_repository
+            .get_pickup_bookings(fulfilment.store_id, now)
+            .await?;
+        let scheduled_for = store.schedule_order(
+            now,
+            fulfilment.pickup_at,
+            fulfilment.schedule_if_closed,
+            &bookings,
+        )?;
+
+        Ok(OrderSchedule {
+            store_id: fulfilment.store_id,
+            scheduled_for,
+            release_at: store.release_at(scheduled_for, now),
+            slot_capacity: store.pickup_slot_capacity,
+        })
+    }
+
+    /// Asigna el local y la franja al pedido.
+    pub fn apply(&self, order: Order) -> Order {
+        Order {
+            store_id: Some(self.store_id),
+            scheduled_for: self.scheduled_for,
+            release_at: self.release_at,
+            ..order
+        }
+    }
+
+    /// Bloquea el local hasta el final de la transacción si el pedido ocupa una franja. Debe
+    /// llamarse antes de cualquier lectura de la transacción: en MySQL la primera lectura fija
+    /// la instantánea que ve el resto.
+    pub async fn lock_slot_in<S: IStoreRepository>(
+        &self,
+        store_repository: &S,
+        tx: &mut S::Transaction,
+    ) -> Result<(), StoreError> {
+        if self.scheduled_for.is_none() {
+            return Ok(());
+        }
+
+        store_repository.lock_store_in(tx, self.store_id).await
+    }
+
+    /// Retorna `SlotFull` si la franja del pedido se llenó desde `plan`.
+    pub async fn check_slot_in<S: IStoreRepository>(
+        &self,
+        store_repository: &S,
+        tx: &mut S::Transaction,
+    ) -> Result<(), StoreError> {
+        let Some(scheduled_for) = self.scheduled_for else {
+            return Ok(());
+        };
+
+        let booked = store_repository
+            .count_bookings_in(tx, self.store_id, scheduled_for)
+            .await?;
+        if booked >= self.slot_capacity as i64 {
+            return Err(StoreError::SlotFull);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,492 @@
+use crate::core::{
+    entities::{
+        delivery::DeliveryZone,
+        fulfilment::{Fulfilment, FulfilmentType},
+        inventory::{StockAdjustment, StockAlert},
+        loyalty::LoyaltyRules,
+        metric_event::MetricEvent,
+        order::{Order, OrderItem, OrderItemOption, OrderStatus, OrderStatusChange},
+        order_event::OrderEvent,
+        payment::PaymentMethod,
+        pricing::PricingRules,
+        product::Product,
+        user_payload::UserPayload,
+        user_role::UserRole,
+    },
+    errors::order_errors::OrderError,
+    ports::{
+        catalog_port::ICatalogRepository,
+        delivery_port::IDeliveryRepository,
+        inventory_port::IInventoryRepository,
+        metrics_port::IMetricsRecorder,
+        order_event_port::IOrderEventPublisher,
+        order_port::{IOrderRepository, IOrderService},
+        stock_alert_port::IStockAlertPublisher,
+        store_port::IStoreRepository,
+        unit_of_work_port::{ITransaction, IUnitOfWork},
+    },
+    services::order_schedule::OrderSchedule,
+};
+use chrono::{DateTime, Utc};
+use std::sync::Arc;
+
+#[derive(Clone)]
+pub struct OrderService<O, C, I, S, D, W>
+where
+    O: IOrderRepository<Transaction = W::Transaction>,
+    C: ICatalogRepository,
+    I: IInventoryRepository,
+    S: IStoreRepository<Transaction = W::Transaction>,
+    D: IDeliveryRepository,
+    W: IUnitOfWork,
+{
+    order_repository: O,
+    catalog_repository: C,
+    inventory_repository: I,
+    store_repository: S,
+    delivery_repository: D,
+    unit_of_work: W,
+    order_events: Arc<dyn IOrderEventPublisher>,
+    stock_alerts: Arc<dyn IStockAlertPublisher>,
+    metrics: Arc<dyn IMetricsRecorder>,
+    pricing_rules: PricingRules,
+    loyalty_rules: LoyaltyRules,
+}
+
+#[async_trait::async_trait]
+impl<O, C, I, S, D, W> IOrderService for OrderService<O, C, I, S, D, W>
+where
+    O: IOrderRepository<Transaction = W::Transaction>,
+    C: ICatalogRepository,
+    I: IInventoryRepository,
+    S: IStoreRepository<Transaction = W::Transaction>,
+    D: IDeliveryRepository,
+    W: IUnitOfWork,
+{
+    async fn place_order(
+        &self,
+        user: &UserPayload,
+        fulfilment: Fulfilment,
+        items: Vec<OrderItem>,
+        payment_method: PaymentMethod,
+        tip: i64,
+    ) -> Result<Order, OrderError> {
+        let now = Utc::now();
+        let delivery_address_id = fulfilment
+            .delivery_address_id()
+            .map_err(OrderError::InvalidOrder)?;
+        let product_ids: Vec<i32> = items.iter().map(|item| item.product_id).collect();
+        let schedule =
+            OrderSchedule::plan(&self.store_repository, &fulfilment, &product_ids, now).await?;
+
+        let items = self.price_items(items).await?;
+        let mut order = schedule.apply(Order {
+            payment_method,
+            ..Order::place(user.get_id(), items, vec![], tip, &self.pricing_rules)
+        });
+        if let Some(address_id) = delivery_address_id {
+            order = self.deliver_to(user, order, address_id).await?;
+        }
+        if user.get_role() == UserRole::Client {
+            order.points_earned = self.loyalty_rules.earned_points(&order);
+        }
+
+        let mut tx = self.unit_of_work.begin().await?;
+        schedule
+            .lock_slot_in(&self.store_repository, &mut tx)
+            .await?;
+        schedule
+            .check_slot_in(&self.store_repository, &mut tx)
+            .await?;
+        let order = self
+            .order_repository
+            .insert_order_in(&mut tx, order)
+            .await?;
+        tx.commit().await?;
+
+        self.order_events
+            .publish(OrderEvent::created(order.clone()));
+        self.metrics.record(MetricEvent::OrderPlaced);
+
+        Ok(order)
+    }
+
+    async fn get_orders_by_user(&self, user_id: i32) -> Result<Vec<Order>, OrderError> {
+        self.order_repository.get_orders_by_user(user_id).await
+    }
+
+    async fn get_order_by_id(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+    ) -> Result<Order, OrderError> {
+        let order = self.order_repository.get_order_by_id(order_id).await?;
+
+        if !order.is_owned_by(user.get_id()) && !user.get_role().is_staff() {
+            return Err(OrderError::Forbidden);
+        }
+
+        Ok(order)
+    }
+
+    async fn get_orders(&self, status: Option<OrderStatus>) -> Result<Vec<Order>, OrderError> {
+        self.order_repository.get_orders(status).await
+    }
+
+    async fn cancel_order(&self, user: &UserPayload, order_id: i32) -> Result<Order, OrderError> {
+        let order = self.get_order_by_id(user, order_id).await?;
+
+        // Los clientes solo pueden cancelar mientras el local no haya aceptado el pedido.
+        if !user.get_role().is_staff() && order.status != OrderStatus::Placed {
+            return Err(OrderError::InvalidTransition {
+                from: order.status,
+                to: OrderStatus::Cancelled,
+            });
+        }
+
+        self.transition(user, order, OrderStatus::Cancelled).await
+    }
+
+    async fn change_order_status(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+        status: OrderStatus,
+    ) -> Result<Order, OrderError> {
+        let order = self.order_repository.get_order_by_id(order_id).await?;
+        // Solo `PaymentService::refund_order` marca el pedido como devuelto, y únicamente cuando
+        // la devolución cubre el total cobrado.
+        if status == OrderStatus::Refunded {
+            return Err(OrderError::InvalidTransition {
+                from: order.status,
+                to: status,
+            });
+        }
+
+        self.transition(user, order, status).await
+    }
+
+    async fn get_queue(&self, status: Option<OrderStatus>) -> Result<Vec<Order>, OrderError> {
+        let now = Utc::now();
+        let orders = self.order_repository.get_open_orders().await?;
+
+        Ok(orders
+            .into_iter()
+            .filter(|order| order.is_released(now))
+            .filter(|order| status.is_none_or(|status| order.status == status))
+            .collect())
+    }
+
+    async fn release_scheduled_orders(&self) -> Result<Vec<Order>, OrderError> {
+        let orders = self.order_repository.release_due_orders(Utc::now()).await?;
+
+        for order in &orders {
+            self.order_events
+                .publish(OrderEvent::released(order.clone()));
+        }
+
+        Ok(orders)
+    }
+
+    async fn claim_order(&self, user: &UserPayload, order_id: i32) -> Result<Order, OrderError> {
+        let mut order = self.order_repository.get_order_by_id(order_id).await?;
+
+        if !OrderStatus::open_statuses().contains(&order.status) {
+            return Err(OrderError::NotInPreparation);
+        }
+
+        self.order_repository
+            .claim_order(order_id, user.get_id())
+            .await?;
+        if order.claimed_by != Some(user.get_id()) {
+            order.claimed_by = Some(user.get_id());
+            self.order_events
+                .publish(OrderEvent::updated(order.clone()));
+        }
+
+        Ok(order)
+    }
+
+    async fn advance_order(&self, user: &UserPayload, order_id: i32) -> Result<Order, OrderError> {
+        let order = self.claim_order(user, order_id).await?;
+        let next = order
+            .status
+            .next_preparation_status()
+            .ok_or(OrderError::NotInPreparation)?;
+
+        self.transition(user, order, next).await
+    }
+
+    async fn mark_order_ready(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+    ) -> Result<Order, OrderError> {
+        let order = self.claim_order(user, order_id).await?;
+
+        self.transition(user, order, OrderStatus::Ready).await
+    }
+
+    async fn assign_courier(&self, order_id: i32, courier_id: i32) -> Result<Order, OrderError> {
+        let mut order = self.order_repository.get_order_by_id(order_id).await?;
+
+        if order.fulfilment_type != FulfilmentType::Delivery {
+            return Err(OrderError::InvalidOrder(
+                "order is not for delivery".to_string(),
+            ));
+        }
+        if !order.is_open() {
+            return Err(OrderError::NotInPreparation);
+        }
+
+        self.order_repository
+            .assign_courier(order_id, courier_id)
+            .await?;
+        order.courier_id = Some(courier_id);
+        order.updated_at = Utc::now();
+        self.order_events
+            .publish(OrderEvent::updated(order.clone()));
+
+        Ok(order)
+    }
+
+    async fn dispatch_order(&self, user: &UserPayload, order_id: i32) -> Result<Order, OrderError> {
+        let order = self.order_repository.get_order_by_id(order_id).await?;
+
+        self.transition(user, order, OrderStatus::OutForDelivery)
+            .await
+    }
+
+    async fn mark_order_delivered(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+    ) -> Result<Order, OrderError> {
+        let order = self.order_repository.get_order_by_id(order_id).await?;
+
+        self.transition(user, order, OrderStatus::Delivered).await
+    }
+}
+
+impl<O, C, I, S, D, W> OrderService<O, C, I, S, D, W>
+where
+    O: IOrderRepository<Transaction = W::Transaction>,
+    C: ICatalogRepository,
+    I: IInventoryRepository,
+    S: IStoreRepository<Transaction = W::Transaction>,
+    D: IDeliveryRepository,
+    W: IUnitOfWork,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        order_repository: O,
+        catalog_repository: C,
+        inventory_repository: I,
+        store_repository: S,
+        delivery_repository: D,
+        unit_of_work: W,
+        order_events: Arc<dyn IOrderEventPublisher>,
+        stock_alerts: Arc<dyn IStockAlertPublisher>,
+        metrics: Arc<dyn IMetricsRecorder>,
+        pricing_rules: PricingRules,
+        loyalty_rules: LoyaltyRules,
+    ) -> Self {
+        OrderService {
+            order_repository,
+            catalog_repository,
+            inventory_repository,
+            store_repository,
+            delivery_repository,
+            unit_of_work,
+            order_events,
+            stock_alerts,
+            metrics,
+            pricing_rules,
+            loyalty_rules,
+        }
+    }
+
+    async fn find_product(&self, product_id: i32) -> Result<Product, OrderError> {
+        let mut product = self
+            .catalog_repository
+            .get_product_by_id(product_id)
+            .await?;
+        product.modifier_groups = self
+            .catalog_repository
+            .get_modifier_groups_by_product(product_id)
+            .await?;
+
+        Ok(product)
+    }
+
+    /// Completa el nombre y precio de cada línea con los datos actuales del catálogo.
+    async fn price_items(&self, items: Vec<OrderItem>) -> Result<Vec<OrderItem>, OrderError> {
+        if items.is_empty() {
+            return Err(OrderError::InvalidOrder("order has no items".to_string()));
+        }
+
+        let mut priced_items = vec![];
+
+        for item in items {
+            if item.quantity <= 0 {
+                return Err(OrderError::InvalidOrder(
+                    "quantity must be greater than zero".to_string(),
+                ));
+            }
+
+            let product = self.find_product(item.product_id).await?;
+            let option_ids = item.option_ids();
+            let unit_price = product.price_configuration(&option_ids)?;
+            let tax_rate = self
+                .catalog_repository
+                .get_category_by_id(product.category_id)
+                .await?
+                .tax_rate
+                .unwrap_or(self.pricing_rules.default_tax_rate);
+
+            let options = option_ids
+                .iter()
+                .filter_map(|option_id| product.find_option(*option_id))
+                .map(|option| OrderItemOption {
+                    option_id: option.id.unwrap_or_default(),
+                    name: option.name.clone(),
+                    price_delta: option.price_delta,
+                })
+                .collect();
+
+            priced_items.push(OrderItem {
+                product_name: product.name,
+                unit_price,
+                tax_rate,
+                options,
+                ..item
+            });
+        }
+
+        Ok(priced_items)
+    }
+
+    /// Convierte el pedido en uno a domicilio a la dirección del usuario, con el costo de envío
+    /// de la zona que la cubre.
+    async fn deliver_to(
+        &self,
+        user: &UserPayload,
+        order: Order,
+        address_id: i32,
+    ) -> Result<Order, OrderError> {
+        let address = self
+            .delivery_repository
+            .get_address(user.get_id(), address_id)
+            .await?;
+        let zones = self.delivery_repository.get_delivery_zones().await?;
+        let zone = DeliveryZone::for_address(&zones, &address)?;
+
+        Ok(order.deliver_to(&address, zone)?)
+    }
+
+    /// Aplica la máquina de estados y registra qué usuario realizó el cambio.
+    async fn transition(
+        &self,
+        user: &UserPayload,
+        mut order: Order,
+        status: OrderStatus,
+    ) -> Result<Order, OrderError> {
+        if !order.can_transition_to(status) {
+            return Err(OrderError::InvalidTransition {
+                from: order.status,
+                to: status,
+            });
+        }
+        if status == OrderStatus::OutForDelivery && order.courier_id.is_none() {
+            return Err(OrderError::CourierRequired);
+        }
+
+        let now = Utc::now();
+        // Los insumos se descuentan cuando el local acepta el pedido.
+        let stock_adjustments = if status == OrderStatus::Accepted {
+            self.stock_consumption(user, &order, now).await?
+        } else {
+            vec![]
+        };
+
+        self.order_repository
+            .update_order_status(
+                order.id.unwrap_or_default(),
+                order.status,
+                status,
+                user.get_id(),
+                self.loyalty_rules
+                    .entries_for_transition(&order, status, now),
+                stock_adjustments.clone(),
+            )
+            .await?;
+        self.raise_stock_alerts(&stock_adjustments, now).await;
+
+        order.status = status;
+        order.updated_at = now;
+        order.history.push(OrderStatusChange {
+            status,
+            changed_by: user.get_id(),
+            changed_at: now,
+        });
+
+        self.order_events
+            .publish(OrderEvent::updated(order.clone()));
+
+        Ok(order)
+    }
+
+    /// Movimientos que descuentan del inventario los insumos de las recetas del pedido.
+    async fn stock_consumption(
+        &self,
+        user: &UserPayload,
+        order: &Order,
+        now: DateTime<Utc>,
+    ) -> Result<Vec<StockAdjustment>, OrderError> {
+        let mut item_recipes = vec![];
+
+        for item in &order.items {
+            let mut recipe = self
+                .inventory_repository
+                .get_product_recipe(item.product_id)
+                .await?;
+            for option in &item.options {
+                recipe.extend(
+                    self.inventory_repository
+                        .get_option_recipe(option.option_id)
+                        .await?,
+                );
+            }
+            item_recipes.push(recipe);
+        }
+
+        Ok(StockAdjustment::for_order(
+            order,
+            &item_recipes,
+            user.get_id(),
+            now,
+        ))
+    }
+
+    /// Avisa de los insumos que el pedido dejó en su umbral de stock bajo. El pedido ya cambió
+    /// de estado, así que si no se puede consultar el inventario simplemente no se avisa.
+    async fn raise_stock_alerts(&self, adjustments: &[StockAdjustment], now: DateTime<Utc>) {
+        if adjustments.is_empty() {
+            return;
+        }
+        let Ok(ingredients) = self.inventory_repository.get_ingredients().await else {
+            return;
+        };
+
+        for adjustment in adjustments {
+            let alert = ingredients
+                .iter()
+                .find(|ingredient| ingredient.id == Some(adjustment.ingredient_id))
+                .and_then(|ingredient| StockAlert::crossed(ingredient, adjustment.delta, now));
+
+            if let Some(alert) = alert {
+                self.stock_alerts.publish(alert);
+            }
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use crate::core::{
+    entities::{
+        fulfilment::{Fulfilment, FulfilmentType},
+        order::{Order, OrderItem, OrderItemOption, OrderStatus},
+        payment::PaymentMethod,
+        pricing::PricingRules,
+        user_payload::UserPayload,
+    },
+    errors::order_errors::OrderError,
+    ports::order_port::IOrderService,
+};
+use async_trait::async_trait;
+
+#[derive(Clone)]
+pub struct OrderServiceStub {
+    pub status_code: i32,
+}
+
+impl OrderServiceStub {
+    fn respond<T>(&self, value: T) -> Result<T, OrderError> {
+        match self.status_code {
+            200 => Ok(value),
+            400 => Err(OrderError::InvalidOrder("order has no items".to_string())),
+            403 => Err(OrderError::Forbidden),
+            404 => Err(OrderError::NotFound),
+            409 => Err(OrderError::InvalidTransition {
+                from: OrderStatus::Ready,
+                to: OrderStatus::Cancelled,
+            }),
+            _ => Err(OrderError::Unexpected),
+        }
+    }
+
+    fn order() -> Order {
+        let items = vec![OrderItem {
+            id: Some(1),
+            product_id: 1,
+            product_name: "Latte".to_string(),
+            quantity: 2,
+            unit_price: 325,
+            tax_rate: 1000,
+            options: vec![OrderItemOption {
+                option_id: 1,
+                name: "Grande".to_string(),
+                price_delta: 50,
+            }],
+        }];
+
+        Order {
+            id: Some(1),
+            ..Order::place(1, items, vec![], 0, &PricingRules::default())
+        }
+    }
+}
+
+#[async_trait]
+impl IOrderService for OrderServiceStub {
+    async fn place_order(
+        &self,
+        _user: &UserPayload,
+        _fulfilment: Fulfilment,
+        _items: Vec<OrderItem>,
+        _payment_method: PaymentMethod,
+        _tip: i64,
+    ) -> Result<Order, OrderError> {
+        self.respond(Self::order())
+    }
+
+    async fn get_orders_by_user(&self, _user_id: i32) -> Result<Vec<Order>, OrderError> {
+        self.respond(vec![Self::order()])
+    }
+
+    async fn get_order_by_id(
+        &self,
+        _user: &UserPayload,
+        _order_id: i32,
+    ) -> Result<Order, OrderError> {
+        self.respond(Self::order())
+    }
+
+    async fn get_orders(&self, _status: Option<OrderStatus>) -> Result<Vec<Order>, OrderError> {
+        self.respond(vec![Self::order()])
+    }
+
+    async fn cancel_order(&self, _user: &UserPayload, _order_id: i32) -> Result<Order, OrderError> {
+        self.respond(Order {
+            status: OrderStatus::Cancelled,
+            ..Self::order()
+        })
+    }
+
+    async fn change_order_status(
+        &self,
+        _user: &UserPayload,
+        _order_id: i32,
+        status: OrderStatus,
+    ) -> Result<Order, OrderError> {
+        self.respond(Order {
+            status,
+            ..Self::order()
+        })
+    }
+
+    async fn get_queue(&self, _status: Option<OrderStatus>) -> Result<Vec<Order>, OrderError> {
+        self.respond(vec![Self::order()])
+    }
+
+    async fn claim_order(&self, user: &UserPayload, _order_id: i32) -> Result<Order, OrderError> {
+        self.respond(Order {
+            claimed_by: Some(user.get_id()),
+            ..Self::order()
+        })
+    }
+
+    async fn advance_order(&self, user: &UserPayload, _order_id: i32) -> Result<Order, OrderError> {
+        self.respond(Order {
+            status: OrderStatus::Accepted,
+            claimed_by: Some(user.get_id()),
+            ..Self::order()
+        })
+    }
+
+    async fn mark_order_ready(
+        &self,
+        user: &UserPayload,
+        _order_id: i32,
+    ) -> Result<Order, OrderError> {
+        self.respond(Order {
+            status: OrderStatus::Ready,
+            claimed_by: Some(user.get_id()),
+            ..Self::order()
+        })
+    }
+
+    async fn assign_courier(&self, _order_id: i32, courier_id: i32) -> Result<Order, OrderError> {
+        self.respond(Order {
+            status: OrderStatus::Ready,
+            fulfilment_type: FulfilmentType::Delivery,
+            courier_id: Some(courier_id),
+            ..Self::order()
+        })
+    }
+
+    async fn dispatch_order(
+        &self,
+        _user: &UserPayload,
+        _order_id: i32,
+    ) -> Result<Order, OrderError> {
+        self.respond(Order {
+            status: OrderStatus::OutForDelivery,
+            fulfilment_type: FulfilmentType::Delivery,
+            courier_id: Some(2),
+            ..Self::order()
+        })
+    }
+
+    async fn mark_order_delivered(
+        &self,
+        _user: &UserPayload,
+        _order_id: i32,
+    ) -> Result<Order, OrderError> {
+        self.respond(Order {
+            status: OrderStatus::Delivered,
+            fulfilment_type: FulfilmentType::Delivery,
+            courier_id: Some(2),
+            ..Self::order()
+        })
+    }
+
+    async fn release_scheduled_orders(&self) -> Result<Vec<Order>, OrderError> {
+        self.respond(vec![Self::order()])
+    }
+}
@@ -0,0 +1,497 @@
+use crate::core::{
+    entities::{
+        loyalty::LoyaltyRules,
+        order::{Order, OrderStatus},
+        payment::{
+            Payment, PaymentAttempt, PaymentCard, PaymentMethod, PaymentOperation, PaymentStatus,
+        },
+        refund::{Refund, RefundStatus},
+        user_payload::UserPayload,
+    },
+    errors::{order_errors::OrderError, payment_errors::PaymentError},
+    ports::{
+        order_port::IOrderRepository,
+        payment_port::{IPaymentGateway, IPaymentRepository, IPaymentService},
+        unit_of_work_port::{ITransaction, IUnitOfWork},
+    },
+};
+use chrono::Utc;
+
+#[derive(Clone)]
+pub struct PaymentService<P, O, G, W>
+where
+    P: IPaymentRepository<Transaction = W::Transaction>,
+    O: IOrderRepository,
+    G: IPaymentGateway,
+    W: IUnitOfWork,
+{
+    payment_repository: P,
+    order_repository: O,
+    payment_gateway: G,
+    unit_of_work: W,
+    loyalty_rules: LoyaltyRules,
+}
+
+#[async_trait::async_trait]
+impl<P, O, G, W> IPaymentService for PaymentService<P, O, G, W>
+where
+    P: IPaymentRepository<Transaction = W::Transaction>,
+    O: IOrderRepository,
+    G: IPaymentGateway,
+    W: IUnitOfWork,
+{
+    async fn pay_order(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+        card: PaymentCard,
+    ) -> Result<Payment, PaymentError> {
+        let order = self.order_repository.get_order_by_id(order_id).await?;
+
+        if !order.is_owned_by(user.get_id()) {
+            return Err(PaymentError::Forbidden);
+        }
+        if order.payment_method == PaymentMethod::CashOnPickup {
+            return Err(PaymentError::InvalidOperation(
+                "order is paid in cash on pickup".to_string(),
+            ));
+        }
+        if !order.is_open() {
+            return Err(PaymentError::InvalidOperation(
+                "order is no longer open".to_string(),
+            ));
+        }
+        if !card.is_valid(Utc::now()) {
+            return Err(PaymentError::InvalidCard);
+        }
+
+        // El pago se reserva antes de llamar a la pasarela: otra solicitud simultánea encuentra
+        // el pago pendiente y nunca llega a autorizar la tarjeta.
+        let payment = self
+            .insert_if_unpaid(Payment {
+                order_id,
+                status: PaymentStatus::Pending,
+                amount: order.total(),
+                card_last_four: Some(card.last_four()),
+                ..Payment::new()
+            })
+            .await?;
+        let payment_id = payment.id.unwrap_or_default();
+
+        // Los rechazos también se guardan para dejar constancia de cada intento.
+        match self.payment_gateway.authorize(&card, order.total()).await {
+            Ok(reference) => {
+                let attempt = PaymentAttempt::succeeded(PaymentOperation::Authorize, order.total());
+                self.payment_repository
+                    .finish_authorization(
+                        payment_id,
+                        Some(reference.clone()),
+                        PaymentStatus::Authorized,
+                        attempt.clone(),
+                    )
+                    .await?;
+
+                Ok(Payment {
+                    status: PaymentStatus::Authorized,
+                    reference: Some(reference),
+                    updated_at: attempt.created_at,
+                    attempts: vec![attempt],
+                    ..payment
+                })
+            }
+            Err(err) => {
+                self.payment_repository
+                    .finish_authorization(
+                        payment_id,
+                        None,
+                        PaymentStatus::Declined,
+                        PaymentAttempt::failed(
+                            PaymentOperation::Authorize,
+                            order.total(),
+                            err.to_string(),
+                        ),
+                    )
+                    .await?;
+
+                Err(err)
+            }
+        }
+    }
+
+    async fn get_order_payments(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+    ) -> Result<Vec<Payment>, PaymentError> {
+        let order = self.order_repository.get_order_by_id(order_id).await?;
+
+        if !order.is_owned_by(user.get_id()) && !user.get_role().is_staff() {
+            return Err(PaymentError::Forbidden);
+        }
+
+        self.payment_repository
+            .get_payments_by_order(order_id)
+            .await
+    }
+
+    async fn capture_payment(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+    ) -> Result<Payment, PaymentError> {
+        let order = self.get_staff_order(user, order_id).await?;
+
+        match self.active_payment(order_id).await? {
+            Some(payment) if payment.status == PaymentStatus::Pending => {
+                Err(Self::authorization_in_progress())
+            }
+            Some(payment) if payment.status == PaymentStatus::Captured => {
+                Err(PaymentError::AlreadyPaid)
+            }
+            Some(payment) => {
+                let result = self
+                    .payment_gateway
+                    .capture(
+                        payment.reference.as_deref().unwrap_or_default(),
+                        payment.amount,
+                    )
+                    .await;
+
+                self.apply(
+                    payment,
+                    PaymentOperation::Capture,
+                    PaymentStatus::Captured,
+                    result,
+                )
+                .await
+            }
+            None if order.payment_method == PaymentMethod::CashOnPickup => {
+                self.insert_if_unpaid(Payment {
+                    order_id,
+                    method: PaymentMethod::CashOnPickup,
+                    status: PaymentStatus::Captured,
+                    amount: order.total(),
+                    attempts: vec![PaymentAttempt::succeeded(
+                        PaymentOperation::Capture,
+                        order.total(),
+                    )],
+                    ..Payment::new()
+                })
+                .await
+            }
+            None => Err(PaymentError::PaymentNotFound),
+        }
+    }
+
+    async fn void_payment(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+    ) -> Result<Payment, PaymentError> {
+        self.get_staff_order(user, order_id).await?;
+
+        match self.active_payment(order_id).await? {
+            Some(payment) if payment.status == PaymentStatus::Pending => {
+                Err(Self::authorization_in_progress())
+            }
+            Some(payment) if payment.status == PaymentStatus::Captured => Err(
+                PaymentError::InvalidOperation("captured payments must be refunded".to_string()),
+            ),
+            Some(payment) => {
+                let result = self
+                    .payment_gateway
+                    .void(payment.reference.as_deref().unwrap_or_default())
+                    .await;
+
+                self.apply(
+                    payment,
+                    PaymentOperation::Void,
+                    PaymentStatus::Voided,
+                    result,
+                )
+                .await
+            }
+            None => Err(PaymentError::PaymentNotFound),
+        }
+    }
+
+    async fn refund_order(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+        refund: Refund,
+    ) -> Result<Refund, PaymentError> {
+        let order = self.get_staff_order(user, order_id).await?;
+
+        if refund.idempotency_key.trim().is_empty() {
+            return Err(PaymentError::InvalidRefund(
+                "idempotency key is required".to_string(),
+            ));
+        }
+        if let Some(existing) = self
+            .payment_repository
+            .get_refund_by_key(order_id, &refund.idempotency_key)
+            .await?
+        {
+            return Self::replay(existing);
+        }
+
+        let payment_id = self
+            .payment_repository
+            .get_payments_by_order(order_id)
+            .await?
+            .into_iter()
+            .find(|payment| payment.status == PaymentStatus::Captured)
+            .and_then(|payment| payment.id)
+            .ok_or(PaymentError::InvalidOperation(
+                "order has no captured payment".to_string(),
+            ))?;
+
+        // El pago queda bloqueado mientras se calcula y reserva la devolución, así que las
+        // devoluciones simultáneas del pedido ven las reservas de las anteriores. La reserva se
+        // confirma antes de llamar a la pasarela: otra solicitud con la misma clave la encuentra
+        // y nunca llega a devolver el dinero.
+        let mut tx = self.unit_of_work.begin().await?;
+        let payment = self
+            .payment_repository
+            .lock_payment_in(&mut tx, payment_id)
+            .await?;
+        if payment.status != PaymentStatus::Captured {
+            return Err(PaymentError::InvalidOperation(
+                "order has no captured payment".to_string(),
+            ));
+        }
+        let refunds = self
+            .payment_repository
+            .get_refunds_in(&mut tx, order_id)
+            .await?;
+        if let Some(existing) = refunds
+            .iter()
+            .find(|existing| existing.idempotency_key == refund.idempotency_key)
+        {
+            return Self::replay(existing.clone());
+        }
+        let order = Order { refunds, ..order };
+        let amount = order
+            .refundable_amount(&refund.item_ids, payment.amount)
+            .map_err(PaymentError::InvalidRefund)?;
+
+        let reserved = self
+            .payment_repository
+            .reserve_refund_in(
+                &mut tx,
+                Refund {
+                    order_id,
+                    payment_id,
+                    amount,
+                    refunded_by: user.get_id(),
+                    created_at: Utc::now(),
+                    idempotency_key: refund.idempotency_key.clone(),
+                    ..refund
+                },
+            )
+            .await?;
+        let Some(reserved) = reserved else {
+            drop(tx);
+
+            return match self
+                .payment_repository
+                .get_refund_by_key(order_id, &refund.idempotency_key)
+                .await?
+            {
+                Some(existing) => Self::replay(existing),
+                None => Err(PaymentError::InvalidRefund(
+                    "item already refunded".to_string(),
+                )),
+            };
+        };
+        tx.commit().await?;
+
+        // El efectivo se devuelve en el local, sin pasar por la pasarela.
+        if payment.method == PaymentMethod::Card {
+            let result = self
+                .payment_gateway
+                .refund(
+                    payment.reference.as_deref().unwrap_or_default(),
+                    amount,
+                    &reserved.idempotency_key,
+                )
+                .await;
+
+            if let Err(err) = result {
+                let attempt =
+                    PaymentAttempt::failed(PaymentOperation::Refund, amount, err.to_string());
+                self.payment_repository
+                    .fail_refund(reserved, attempt)
+                    .await?;
+
+                return Err(err);
+            }
+        }
+
+        // La devolución se guarda antes de tocar el pedido: la pasarela ya devolvió el dinero,
+        // así que debe quedar registrada aunque el cambio de estado falle.
+        let fully_refunded = order.refunded_total() + amount == payment.amount;
+        let refund = self
+            .payment_repository
+            .complete_refund(
+                reserved,
+                fully_refunded.then_some(PaymentStatus::Refunded),
+                PaymentAttempt::succeeded(PaymentOperation::Refund, amount),
+            )
+            .await?;
+
+        if fully_refunded && order.status.can_transition_to(OrderStatus::Refunded) {
+            let loyalty_entries = self.loyalty_rules.entries_for_transition(
+                &order,
+                OrderStatus::Refunded,
+                Utc::now(),
+            );
+
+            let result = self
+                .order_repository
+                .update_order_status(
+                    order_id,
+                    order.status,
+                    OrderStatus::Refunded,
+                    user.get_id(),
+                    loyalty_entries,
+                    vec![],
+                )
+                .await;
+
+            // Si otro proceso cambió el estado del pedido entretanto, la devolución sigue siendo
+            // válida y el pedido se deja como quedó.
+            match result {
+                Ok(()) | Err(OrderError::InvalidTransition { .. }) => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        Ok(refund)
+    }
+}
+
+impl<P, O, G, W> PaymentService<P, O, G, W>
+where
+    P: IPaymentRepository<Transaction = W::Transaction>,
+    O: IOrderRepository,
+    G: IPaymentGateway,
+    W: IUnitOfWork,
+{
+    pub fn new(
+        payment_repository: P,
+        order_repository: O,
+        payment_gateway: G,
+        unit_of_work: W,
+        loyalty_rules: LoyaltyRules,
+    ) -> Self {
+        PaymentService {
+            payment_repository,
+            order_repository,
+            payment_gateway,
+            unit_of_work,
+            loyalty_rules,
+        }
+    }
+
+    /// Resultado de repetir una solicitud cuya clave ya tiene una devolución.
+    fn replay(refund: Refund) -> Result<Refund, PaymentError> {
+        match refund.status {
+            RefundStatus::Completed => Ok(refund),
+            RefundStatus::Pending => Err(PaymentError::RefundInProgress),
+            RefundStatus::Failed => Err(PaymentError::InvalidOperation(
+                "refund failed; retry with a new idempotency key".to_string(),
+            )),
+        }
+    }
+
+    fn authorization_in_progress() -> PaymentError {
+        PaymentError::InvalidOperation("payment authorization is in progress".to_string())
+    }
+
+    /// Guarda el pago si el pedido no tiene otro activo. El pedido queda bloqueado mientras se
+    /// comprueba, así que dos cobros simultáneos no pueden guardar dos pagos.
+    async fn insert_if_unpaid(&self, payment: Payment) -> Result<Payment, PaymentError> {
+        let mut tx = self.unit_of_work.begin().await?;
+        let payments = self
+            .payment_repository
+            .lock_order_payments_in(&mut tx, payment.order_id)
+            .await?;
+
+        match payments.iter().find(|payment| payment.status.is_active()) {
+            Some(active) if active.status == PaymentStatus::Pending => {
+                return Err(Self::authorization_in_progress())
+            }
+            Some(_) => return Err(PaymentError::AlreadyPaid),
+            None => {}
+        }
+
+        let payment = self
+            .payment_repository
+            .insert_payment_in(&mut tx, payment)
+            .await?;
+        tx.commit().await?;
+
+        Ok(payment)
+    }
+
+    async fn get_staff_order(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+    ) -> Result<Order, PaymentError> {
+        if !user.get_role().is_staff() {
+            return Err(PaymentError::Forbidden);
+        }
+
+        Ok(self.order_repository.get_order_by_id(order_id).await?)
+    }
+
+    /// Pago autorizado o capturado del pedido, si lo hay.
+    async fn active_payment(&self, order_id: i32) -> Result<Option<Payment>, PaymentError> {
+        let payments = self
+            .payment_repository
+            .get_payments_by_order(order_id)
+            .await?;
+
+        Ok(payments
+            .into_iter()
+            .find(|payment| payment.status.is_active()))
+    }
+
+    /// Registra el resultado de la operación en la pasarela y actualiza el estado del pago si
+    /// tuvo éxito.
+    async fn apply(
+        &self,
+        mut payment: Payment,
+        operation: PaymentOperation,
+        status: PaymentStatus,
+        result: Result<(), PaymentError>,
+    ) -> Result<Payment, PaymentError> {
+        let payment_id = payment.id.unwrap_or_default();
+
+        match result {
+            Ok(()) => {
+                let attempt = PaymentAttempt::succeeded(operation, payment.amount);
+                self.payment_repository
+                    .record_attempt(payment_id, Some(status), attempt.clone())
+                    .await?;
+
+                payment.status = status;
+                payment.updated_at = attempt.created_at;
+                payment.attempts.push(attempt);
+
+                Ok(payment)
+            }
+            Err(err) => {
+                let attempt = PaymentAttempt::failed(operation, payment.amount, err.to_string());
+                self.payment_repository
+                    .record_attempt(payment_id, None, attempt)
+                    .await?;
+
+                Err(err)
+            }
+        }
+    }
+}
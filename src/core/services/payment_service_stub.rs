@@ -0,0 +1,94 @@
+use crate::core::{
+    entities::{
+        payment::{Payment, PaymentAttempt, PaymentCard, PaymentOperation, PaymentStatus},
+        refund::Refund,
+        user_payload::UserPayload,
+    },
+    errors::payment_errors::PaymentError,
+    ports::payment_port::IPaymentService,
+};
+use async_trait::async_trait;
+
+#[derive(Clone)]
+pub struct PaymentServiceStub {
+    pub status_code: i32,
+}
+
+impl PaymentServiceStub {
+    fn respond<T>(&self, value: T) -> Result<T, PaymentError> {
+        match self.status_code {
+            200 => Ok(value),
+            400 => Err(PaymentError::InvalidCard),
+            402 => Err(PaymentError::Declined("card declined".to_string())),
+            403 => Err(PaymentError::Forbidden),
+            404 => Err(PaymentError::OrderNotFound),
+            409 => Err(PaymentError::AlreadyPaid),
+            _ => Err(PaymentError::Unexpected),
+        }
+    }
+
+    fn payment(status: PaymentStatus) -> Payment {
+        Payment {
+            id: Some(1),
+            order_id: 1,
+            status,
+            amount: 650,
+            reference: Some("fake_auth_000001".to_string()),
+            card_last_four: Some("4242".to_string()),
+            attempts: vec![PaymentAttempt::succeeded(PaymentOperation::Authorize, 650)],
+            ..Payment::new()
+        }
+    }
+}
+
+#[async_trait]
+impl IPaymentService for PaymentServiceStub {
+    async fn pay_order(
+        &self,
+        _user: &UserPayload,
+        _order_id: i32,
+        _card: PaymentCard,
+    ) -> Result<Payment, PaymentError> {
+        self.respond(Self::payment(PaymentStatus::Authorized))
+    }
+
+    async fn get_order_payments(
+        &self,
+        _user: &UserPayload,
+        _order_id: i32,
+    ) -> Result<Vec<Payment>, PaymentError> {
+        self.respond(vec![Self::payment(PaymentStatus::Authorized)])
+    }
+
+    async fn capture_payment(
+        &self,
+        _user: &UserPayload,
+        _order_id: i32,
+    ) -> Result<Payment, PaymentError> {
+        self.respond(Self::payment(PaymentStatus::Captured))
+    }
+
+    async fn void_payment(
+        &self,
+        _user: &UserPayload,
+        _order_id: i32,
+    ) -> Result<Payment, PaymentError> {
+        self.respond(Self::payment(PaymentStatus::Voided))
+    }
+
+    async fn refund_order(
+        &self,
+        user: &UserPayload,
+        order_id: i32,
+        refund: Refund,
+    ) -> Result<Refund, PaymentError> {
+        self.respond(Refund {
+            id: Some(1),
+            order_id,
+            payment_id: 1,
+            amount: 325,
+            refunded_by: user.get_id(),
+            ..refund
+        })
+    }
+}
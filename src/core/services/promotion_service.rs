@@ -0,0 +1,114 @@
+use crate::core::{
+    entities::promotion::{Promotion, PromotionKind},
+    errors::{catalog_errors::CatalogError, promotion_errors::PromotionError},
+    ports::{
+        catalog_port::ICatalogRepository,
+        promotion_port::{IPromotionRepository, IPromotionService},
+    },
+};
+
+#[derive(Clone)]
+pub struct PromotionService<P, C>
+where
+    P: IPromotionRepository,
+    C: ICatalogRepository,
+{
+    promotion_repository: P,
+    catalog_repository: C,
+}
+
+#[async_trait::async_trait]
+impl<P, C> IPromotionService for PromotionService<P, C>
+where
+    P: IPromotionRepository,
+    C: ICatalogRepository,
+{
+    async fn get_promotions(&self) -> Result<Vec<Promotion>, PromotionError> {
+        self.promotion_repository.get_promotions().await
+    }
+
+    async fn create_promotion(&self, promotion: Promotion) -> Result<Promotion, PromotionError> {
+        let promotion = self.prepare(None, promotion).await?;
+
+        self.promotion_repository.insert_promotion(promotion).await
+    }
+
+    async fn update_promotion(
+        &self,
+        promotion_id: i32,
+        promotion: Promotion,
+    ) -> Result<Promotion, PromotionError> {
+        self.promotion_repository
+            .get_promotion_by_id(promotion_id)
+            .await?;
+        let promotion = self.prepare(Some(promotion_id), promotion).await?;
+
+        self.promotion_repository
+            .update_promotion(promotion_id, promotion.clone())
+            .await?;
+
+        Ok(Promotion {
+            id: Some(promotion_id),
+            ..promotion
+        })
+    }
+
+    async fn delete_promotion(&self, promotion_id: i32) -> Result<(), PromotionError> {
+        self.promotion_repository
+            .delete_promotion(promotion_id)
+            .await
+    }
+}
+
+impl<P, C> PromotionService<P, C>
+where
+    P: IPromotionRepository,
+    C: ICatalogRepository,
+{
+    pub fn new(promotion_repository: P, catalog_repository: C) -> Self {
+        PromotionService {
+            promotion_repository,
+            catalog_repository,
+        }
+    }
+
+    /// Valida la promoción, normaliza su código y comprueba que no lo use otra promoción.
+    async fn prepare(
+        &self,
+        promotion_id: Option<i32>,
+        mut promotion: Promotion,
+    ) -> Result<Promotion, PromotionError> {
+        promotion
+            .validate()
+            .map_err(PromotionError::InvalidPromotion)?;
+        promotion.code = promotion.code.as_deref().map(Promotion::normalize_code);
+
+        if let PromotionKind::BuyOneGetOne { category_id } = promotion.kind {
+            match self
+                .catalog_repository
+                .get_category_by_id(category_id)
+                .await
+            {
+                Ok(_) => {}
+                Err(CatalogError::CategoryNotFound) => {
+                    return Err(PromotionError::InvalidPromotion(
+                        "category not found".to_string(),
+                    ))
+                }
+                Err(_) => return Err(PromotionError::Unexpected),
+            }
+        }
+
+        if let Some(code) = &promotion.code {
+            match self.promotion_repository.get_promotion_by_code(code).await {
+                Ok(existing) if existing.id != promotion_id => {
+                    return Err(PromotionError::DuplicateCode)
+                }
+                Ok(_) | Err(PromotionError::NotFound) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(promotion)
+    }
+}
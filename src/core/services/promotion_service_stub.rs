@@ -0,0 +1,57 @@
+use crate::core::{
+    entities::promotion::{Promotion, PromotionKind},
+    errors::promotion_errors::PromotionError,
+    ports::promotion_port::IPromotionService,
+};
+use async_trait::async_trait;
+
+#[derive(Clone)]
+pub struct PromotionServiceStub {
+    pub status_code: i32,
+}
+
+impl PromotionServiceStub {
+    fn respond<T>(&self, value: T) -> Result<T, PromotionError> {
+        match self.status_code {
+            200 => Ok(value),
+            400 => Err(PromotionError::InvalidPromotion("rate".to_string())),
+            404 => Err(PromotionError::NotFound),
+            409 => Err(PromotionError::DuplicateCode),
+            _ => Err(PromotionError::Unexpected),
+        }
+    }
+
+    fn promotion() -> Promotion {
+        Promotion {
+            id: Some(1),
+            name: "Bienvenida".to_string(),
+            code: Some("HOLA10".to_string()),
+            kind: PromotionKind::PercentageOff { rate: 1000 },
+            max_uses_per_user: Some(1),
+            ..Promotion::new()
+        }
+    }
+}
+
+#[async_trait]
+impl IPromotionService for PromotionServiceStub {
+    async fn get_promotions(&self) -> Result<Vec<Promotion>, PromotionError> {
+        self.respond(vec![Self::promotion()])
+    }
+
+    async fn create_promotion(&self, _promotion: Promotion) -> Result<Promotion, PromotionError> {
+        self.respond(Self::promotion())
+    }
+
+    async fn update_promotion(
+        &self,
+        _promotion_id: i32,
+        _promotion: Promotion,
+    ) -> Result<Promotion, PromotionError> {
+        self.respond(Self::promotion())
+    }
+
+    async fn delete_promotion(&self, _promotion_id: i32) -> Result<(), PromotionError> {
+        self.respond(())
+    }
+}
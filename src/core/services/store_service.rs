@@ -0,0 +1,129 @@
+use crate::core::{
+    entities::store::{PickupSlot, Store, StoreException},
+    errors::{catalog_errors::CatalogError, store_errors::StoreError},
+    ports::{
+        catalog_port::ICatalogRepository,
+        store_port::{IStoreRepository, IStoreService},
+    },
+};
+use chrono::{NaiveDate, Utc};
+
+#[derive(Clone)]
+pub struct StoreService<S, C>
+where
+    S: IStoreRepository,
+    C: ICatalogRepository,
+{
+    store_repository: S,
+    catalog_repository: C,
+}
+
+#[async_trait::async_trait]
+impl<S, C> IStoreService for StoreService<S, C>
+where
+    S: IStoreRepository,
+    C: ICatalogRepository,
+{
+    async fn get_stores(&self) -> Result<Vec<Store>, StoreError> {
+        self.store_repository.get_stores().await
+    }
+
+    async fn get_store_by_id(&self, store_id: i32) -> Result<Store, StoreError> {
+        self.store_repository.get_store_by_id(store_id).await
+    }
+
+    async fn create_store(&self, store: Store) -> Result<Store, StoreError> {
+        store.validate().map_err(StoreError::InvalidStore)?;
+
+        self.store_repository.insert_store(store).await
+    }
+
+    async fn update_store(&self, store_id: i32, store: Store) -> Result<Store, StoreError> {
+        store.validate().map_err(StoreError::InvalidStore)?;
+        self.store_repository.get_store_by_id(store_id).await?;
+
+        self.store_repository.update_store(store_id, store).await?;
+
+        self.store_repository.get_store_by_id(store_id).await
+    }
+
+    async fn delete_store(&self, store_id: i32) -> Result<(), StoreError> {
+        self.store_repository.delete_store(store_id).await
+    }
+
+    async fn set_exception(
+        &self,
+        store_id: i32,
+        exception: StoreException,
+    ) -> Result<Store, StoreError> {
+        exception.validate().map_err(StoreError::InvalidStore)?;
+        self.store_repository.get_store_by_id(store_id).await?;
+
+        self.store_repository
+            .upsert_exception(store_id, exception)
+            .await?;
+
+        self.store_repository.get_store_by_id(store_id).await
+    }
+
+    async fn delete_exception(&self, store_id: i32, date: NaiveDate) -> Result<Store, StoreError> {
+        self.store_repository
+            .delete_exception(store_id, date)
+            .await?;
+
+        self.store_repository.get_store_by_id(store_id).await
+    }
+
+    async fn set_product_availability(
+        &self,
+        store_id: i32,
+        product_id: i32,
+        available: bool,
+    ) -> Result<Store, StoreError> {
+        self.store_repository.get_store_by_id(store_id).await?;
+        match self.catalog_repository.get_product_by_id(product_id).await {
+            Ok(_) => {}
+            Err(CatalogError::ProductNotFound) => return Err(StoreError::ProductNotFound),
+            Err(_) => return Err(StoreError::Unexpected),
+        }
+
+        self.store_repository
+            .set_product_availability(store_id, product_id, available)
+            .await?;
+
+        self.store_repository.get_store_by_id(store_id).await
+    }
+
+    async fn get_pickup_slots(
+        &self,
+        store_id: i32,
+        date: Option<NaiveDate>,
+    ) -> Result<Vec<PickupSlot>, StoreError> {
+        let store = self.store_repository.get_store_by_id(store_id).await?;
+        let now = Utc::now();
+        let date = date.unwrap_or_else(|| now.with_timezone(&store.timezone).date_naive());
+        let bookings = self
+            .store_repository
+            .get_pickup_bookings(store_id, now)
+            .await?;
+
+        Ok(store
+            .pickup_slots_on(date, &bookings)
+            .into_iter()
+            .filter(|slot| slot.starts_at > now)
+            .collect())
+    }
+}
+
+impl<S, C> StoreService<S, C>
+where
+    S: IStoreRepository,
+    C: ICatalogRepository,
+{
+    pub fn new(store_repository: S, catalog_repository: C) -> Self {
+        StoreService {
+            store_repository,
+            catalog_repository,
+        }
+    }
+}
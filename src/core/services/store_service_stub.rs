@@ -0,0 +1,101 @@
+use crate::core::{
+    entities::store::{OpeningHours, PickupSlot, Store, StoreException},
+    errors::store_errors::StoreError,
+    ports::store_port::IStoreService,
+};
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate, NaiveTime, Utc, Weekday};
+
+#[derive(Clone)]
+pub struct StoreServiceStub {
+    pub status_code: i32,
+}
+
+impl StoreServiceStub {
+    fn respond<T>(&self, value: T) -> Result<T, StoreError> {
+        match self.status_code {
+            200 => Ok(value),
+            400 => Err(StoreError::InvalidStore("name is required".to_string())),
+            404 => Err(StoreError::NotFound),
+            _ => Err(StoreError::Unexpected),
+        }
+    }
+
+    fn store() -> Store {
+        Store {
+            id: Some(1),
+            name: "Centro".to_string(),
+            address: "Del Hotel Crowne Plaza 1c al sur".to_string(),
+            timezone: "America/Managua".parse().unwrap(),
+            opening_hours: vec![OpeningHours {
+                weekday: Weekday::Mon,
+                opens: NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+                closes: NaiveTime::from_hms_opt(19, 0, 0).unwrap(),
+            }],
+            ..Store::new()
+        }
+    }
+}
+
+#[async_trait]
+impl IStoreService for StoreServiceStub {
+    async fn get_stores(&self) -> Result<Vec<Store>, StoreError> {
+        self.respond(vec![Self::store()])
+    }
+
+    async fn get_store_by_id(&self, _store_id: i32) -> Result<Store, StoreError> {
+        self.respond(Self::store())
+    }
+
+    async fn create_store(&self, _store: Store) -> Result<Store, StoreError> {
+        self.respond(Self::store())
+    }
+
+    async fn update_store(&self, _store_id: i32, _store: Store) -> Result<Store, StoreError> {
+        self.respond(Self::store())
+    }
+
+    async fn delete_store(&self, _store_id: i32) -> Result<(), StoreError> {
+        self.respond(())
+    }
+
+    async fn set_exception(
+        &self,
+        _store_id: i32,
+        _exception: StoreException,
+    ) -> Result<Store, StoreError> {
+        self.respond(Self::store())
+    }
+
+    async fn delete_exception(
+        &self,
+        _store_id: i32,
+        _date: NaiveDate,
+    ) -> Result<Store, StoreError> {
+        self.respond(Self::store())
+    }
+
+    async fn set_product_availability(
+        &self,
+        _store_id: i32,
+        _product_id: i32,
+        _available: bool,
+    ) -> Result<Store, StoreError> {
+        self.respond(Self::store())
+    }
+
+    async fn get_pickup_slots(
+        &self,
+        _store_id: i32,
+        _date: Option<NaiveDate>,
+    ) -> Result<Vec<PickupSlot>, StoreError> {
+        let starts_at = Utc::now() + Duration::minutes(30);
+
+        self.respond(vec![PickupSlot {
+            starts_at,
+            ends_at: starts_at + Duration::minutes(15),
+            capacity: 10,
+            booked: 3,
+        }])
+    }
+}
@@ -1,11 +1,21 @@
 use crate::{
     core::{
-        entities::{login::Login, user::User, user_payload::UserPayload},
+        entities::{
+            login::Login, metric_event::MetricEvent, user::User, user_payload::UserPayload,
+            user_role::UserRole, user_status::UserStatus,
+        },
         errors::user_errors::UserError,
-        ports::user_port::{IUserRepository, IUserService},
+        ports::{
+            metrics_port::IMetricsRecorder,
+            user_port::{IUserRepository, IUserService},
+        },
     },
     utils::security_util::{create_jwt_token, crypt_password, verify_password},
 };
+use std::sync::Arc;
+
+/// Largo mínimo de las contraseñas que se asignan desde la administración.
+const MIN_PASSWORD_LENGTH: usize = 8;
 
 #[derive(Clone)]
 pub struct UserService<R>
@@ -13,6 +23,8 @@ where
     R: IUserRepository,
 {
     user_repository: R,
+    jwt_secret: String,
+    metrics: Arc<dyn IMetricsRecorder>,
 }
 
 #[async_trait::async_trait]
@@ -31,10 +43,12 @@ where
 
         match self.user_repository.insert_user(user).await {
             Ok(user) => {
+                self.metrics.record(MetricEvent::UserRegistered);
                 login.set_user(user.clone());
 
-                let payload = UserPayload::new(user.id.unwrap(), user.name, user.surname, 30);
-                if let Ok(token) = create_jwt_token(payload) {
+                let payload =
+                    UserPayload::new(user.id.unwrap(), user.name, user.surname, user.role, 30);
+                if let Ok(token) = create_jwt_token(payload, &self.jwt_secret) {
                     login.set_token(token);
                     Ok(login)
                 } else {
@@ -46,18 +60,112 @@ where
     }
 
     async fn login(&self, email: String, password: String) -> Result<Login, UserError> {
+        let result = self.authenticate(email, password).await;
+
+        self.metrics.record(if result.is_ok() {
+            MetricEvent::LoginSucceeded
+        } else {
+            MetricEvent::LoginFailed
+        });
+        result
+    }
+
+    async fn get_users(&self) -> Result<Vec<User>, UserError> {
+        self.user_repository.get_users().await
+    }
+
+    async fn get_user_by_id(&self, id: u8) -> Result<User, UserError> {
+        self.user_repository.get_user_by_id(id).await
+    }
+
+    async fn update_user(&self, user_id: i32, user: User) -> Result<(), UserError> {
+        self.user_repository.update_user(user_id, user).await
+    }
+
+    async fn delete_user(&self, user_id: i32) -> Result<(), UserError> {
+        self.user_repository.delete_user(user_id).await
+    }
+
+    async fn create_administrator(&self, mut user: User) -> Result<User, UserError> {
+        Self::validate_password(&user.password)?;
+
+        let users = self.user_repository.get_users().await?;
+        if users.iter().any(|u| u.role == UserRole::Administrator) {
+            return Err(UserError::AdministratorExists);
+        }
+        if users
+            .iter()
+            .any(|u| u.email.eq_ignore_ascii_case(&user.email))
+        {
+            return Err(UserError::EmailInUse);
+        }
+
+        user.role = UserRole::Administrator;
+        user.status = UserStatus::Active;
+        user.set_password(crypt_password(&user.password)?);
+
+        self.user_repository.insert_user(user).await
+    }
+
+    async fn get_users_by_status(&self, status: UserStatus) -> Result<Vec<User>, UserError> {
+        self.user_repository.get_users_by_status(status).await
+    }
+
+    async fn suspend_user(&self, user_id: i32) -> Result<(), UserError> {
+        self.user_repository
+            .update_user_status(user_id, UserStatus::Suspended)
+            .await
+    }
+
+    async fn reactivate_user(&self, user_id: i32) -> Result<(), UserError> {
+        self.user_repository
+            .update_user_status(user_id, UserStatus::Active)
+            .await
+    }
+
+    async fn reset_password(&self, user_id: i32, password: String) -> Result<(), UserError> {
+        Self::validate_password(&password)?;
+        let crypted_password = crypt_password(&password)?;
+
+        self.user_repository
+            .update_password(user_id, crypted_password)
+            .await
+    }
+
+    async fn purge_deleted_users(&self, retention_days: u32) -> Result<u64, UserError> {
+        self.user_repository
+            .purge_deleted_users(retention_days)
+            .await
+    }
+}
+
+impl<R> UserService<R>
+where
+    R: IUserRepository,
+{
+    pub fn new(user_repository: R, jwt_secret: String, metrics: Arc<dyn IMetricsRecorder>) -> Self {
+        UserService {
+            user_repository,
+            jwt_secret,
+            metrics,
+        }
+    }
+
+    /// Verifica las credenciales y emite el token de la sesión.
+    async fn authenticate(&self, email: String, password: String) -> Result<Login, UserError> {
         let mut login = Login::new();
 
         match self.user_repository.get_user_by_email(email).await {
             Ok(user) => {
-                if !verify_password(password, &user.password) {
+                if user.status != UserStatus::Active || !verify_password(password, &user.password) {
                     return Err(UserError::Unauthorized);
                 }
 
                 login.set_user(user.clone());
 
-                let payload = UserPayload::new(user.id.unwrap(), user.name, user.surname, 30);
-                if let Ok(token) = create_jwt_token(payload) {
+                let payload =
+                    UserPayload::new(user.id.unwrap(), user.name, user.surname, user.role, 30);
+                if let Ok(token) = create_jwt_token(payload, &self.jwt_secret) {
                     login.set_token(token);
                     Ok(login)
                 } else {
@@ -68,28 +176,167 @@ where
         }
     }
 
-    async fn get_users(&self) -> Result<Vec<User>, UserError> {
-        self.user_repository.get_users().await
+    fn validate_password(password: &str) -> Result<(), UserError> {
+        if password.chars().count() < MIN_PASSWORD_LENGTH {
+            return Err(UserError::InvalidPassword(format!(
+                "must have at least {} characters",
+                MIN_PASSWORD_LENGTH
+            )));
+        }
+
+        Ok(())
     }
+}
 
-    async fn get_user_by_id(&self, id: u8) -> Result<User, UserError> {
-        self.user_repository.get_user_by_id(id).await
+#[cfg(test)]
+mod user_service_tests {
+    use super::*;
+    use crate::infrastructure::{
+        data::memory::{user_repository::MemoryUserRepository, MemoryDatabase},
+        metrics::noop_metrics_recorder::NoopMetricsRecorder,
+    };
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordedMetrics(Mutex<Vec<MetricEvent>>);
+
+    impl IMetricsRecorder for RecordedMetrics {
+        fn record(&self, event: MetricEvent) {
+            self.0.lock().unwrap().push(event);
+        }
     }
 
-    async fn update_user(&self, user_id: i32, user: User) -> Result<(), UserError> {
-        self.user_repository.update_user(user_id, user).await
+    fn user_service() -> UserService<MemoryUserRepository> {
+        UserService::new(
+            MemoryUserRepository::new(MemoryDatabase::new()),
+            "secret".to_string(),
+            Arc::new(NoopMetricsRecorder),
+        )
     }
 
-    async fn delete_user(&self, user_id: i32) -> Result<(), UserError> {
-        self.user_repository.delete_user(user_id).await
+    fn client(email: &str) -> User {
+        User {
+            name: "Ana".to_string(),
+            surname: "García".to_string(),
+            email: email.to_string(),
+            password: "cortado123".to_string(),
+            ..User::new()
+        }
     }
-}
 
-impl<R> UserService<R>
-where
-    R: IUserRepository,
-{
-    pub fn new(user_repository: R) -> Self {
-        UserService { user_repository }
+    #[actix_web::test]
+    async fn test_register_hashes_password_and_issues_token() {
+        let service = user_service();
+
+        let login = service.register(client("ana@cafe.com")).await.unwrap();
+        assert!(!login.get_token().is_empty());
+
+        let stored = service.get_user_by_id(1).await.unwrap();
+        assert_ne!(stored.password, "cortado123");
+        assert!(verify_password("cortado123".to_string(), &stored.password));
+    }
+
+    #[actix_web::test]
+    async fn test_registrations_and_logins_are_recorded() {
+        let metrics = Arc::new(RecordedMetrics::default());
+        let service = UserService::new(
+            MemoryUserRepository::new(MemoryDatabase::new()),
+            "secret".to_string(),
+            metrics.clone(),
+        );
+
+        service.register(client("ana@cafe.com")).await.unwrap();
+        let _ = service
+            .login("ana@cafe.com".to_string(), "cortado123".to_string())
+            .await;
+        let _ = service
+            .login("ana@cafe.com".to_string(), "latte".to_string())
+            .await;
+
+        assert_eq!(
+            *metrics.0.lock().unwrap(),
+            vec![
+                MetricEvent::UserRegistered,
+                MetricEvent::LoginSucceeded,
+                MetricEvent::LoginFailed
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_login_checks_password_and_status() {
+        let service = user_service();
+        service.register(client("ana@cafe.com")).await.unwrap();
+
+        assert!(service
+            .login("ana@cafe.com".to_string(), "cortado123".to_string())
+            .await
+            .is_ok());
+        assert!(matches!(
+            service
+                .login("ana@cafe.com".to_string(), "latte".to_string())
+                .await,
+            Err(UserError::Unauthorized)
+        ));
+
+        service.suspend_user(1).await.unwrap();
+        assert!(matches!(
+            service
+                .login("ana@cafe.com".to_string(), "cortado123".to_string())
+                .await,
+            Err(UserError::Unauthorized)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_missing_user_is_not_found() {
+        let service = user_service();
+
+        assert!(matches!(
+            service.get_user_by_id(7).await,
+            Err(UserError::NotFound)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_only_one_administrator_can_be_created() {
+        let service = user_service();
+
+        let admin = service
+            .create_administrator(client("admin@cafe.com"))
+            .await
+            .unwrap();
+        assert_eq!(admin.role, UserRole::Administrator);
+
+        assert!(matches!(
+            service.create_administrator(client("otro@cafe.com")).await,
+            Err(UserError::AdministratorExists)
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_reset_password_requires_minimum_length() {
+        let service = user_service();
+        service.register(client("ana@cafe.com")).await.unwrap();
+
+        assert!(matches!(
+            service.reset_password(1, "corto".to_string()).await,
+            Err(UserError::InvalidPassword(_))
+        ));
+    }
+
+    #[actix_web::test]
+    async fn test_deleted_users_are_hidden_then_purged() {
+        let service = user_service();
+        service.register(client("ana@cafe.com")).await.unwrap();
+
+        service.delete_user(1).await.unwrap();
+        assert!(matches!(
+            service.get_user_by_id(1).await,
+            Err(UserError::NotFound)
+        ));
+
+        assert_eq!(service.purge_deleted_users(0).await.unwrap(), 1);
+        assert_eq!(service.purge_deleted_users(0).await.unwrap(), 0);
     }
 }
@@ -1,5 +1,5 @@
 use crate::core::{
-    entities::{login::Login, user::User},
+    entities::{login::Login, user::User, user_role::UserRole, user_status::UserStatus},
     errors::user_errors::UserError,
     ports::user_port::IUserService,
 };
@@ -37,6 +37,8 @@ impl IUserService for UserServiceStub {
                 phone_number: Some("0988059308".to_string()),
                 email: "kenethriera@gmail.com".to_string(),
                 password: "password".to_string(),
+                role: UserRole::Client,
+                status: UserStatus::Active,
             }])
         } else if self.status_code == 404 {
             Err(UserError::NotFound)
@@ -54,6 +56,8 @@ impl IUserService for UserServiceStub {
                 phone_number: Some("0988059308".to_string()),
                 email: "kenethriera@gmail.com".to_string(),
                 password: "password".to_string(),
+                role: UserRole::Client,
+                status: UserStatus::Active,
             })
         } else if self.status_code == 404 {
             Err(UserError::NotFound)
@@ -81,4 +85,57 @@ impl IUserService for UserServiceStub {
             Err(UserError::Unexpected)
         }
     }
+
+    async fn create_administrator(&self, user: User) -> Result<User, UserError> {
+        match self.status_code {
+            200 => Ok(User {
+                id: Some(1),
+                role: UserRole::Administrator,
+                ..user
+            }),
+            409 => Err(UserError::AdministratorExists),
+            _ => Err(UserError::Unexpected),
+        }
+    }
+
+    async fn get_users_by_status(&self, status: UserStatus) -> Result<Vec<User>, UserError> {
+        if self.status_code == 200 {
+            Ok(vec![User {
+                id: Some(1),
+                status,
+                ..User::new()
+            }])
+        } else {
+            Err(UserError::Unexpected)
+        }
+    }
+
+    async fn suspend_user(&self, _user_id: i32) -> Result<(), UserError> {
+        self.respond()
+    }
+
+    async fn reactivate_user(&self, _user_id: i32) -> Result<(), UserError> {
+        self.respond()
+    }
+
+    async fn reset_password(&self, _user_id: i32, _password: String) -> Result<(), UserError> {
+        match self.status_code {
+            400 => Err(UserError::InvalidPassword("too short".to_string())),
+            _ => self.respond(),
+        }
+    }
+
+    async fn purge_deleted_users(&self, _retention_days: u32) -> Result<u64, UserError> {
+        self.respond().map(|_| 1)
+    }
+}
+
+impl UserServiceStub {
+    fn respond(&self) -> Result<(), UserError> {
+        match self.status_code {
+            200 => Ok(()),
+            404 => Err(UserError::NotFound),
+            _ => Err(UserError::Unexpected),
+        }
+    }
 }
@@ -0,0 +1,123 @@
+use crate::core::entities::{
+    cart::CartItem as CartItemCore,
+    fulfilment::{Fulfilment as FulfilmentCore, FulfilmentType},
+    order::OrderItemOption,
+    payment::PaymentMethod,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct CartItemRequest {
+    pub product_id: i32,
+    pub quantity: i32,
+    #[serde(default)]
+    pub option_ids: Vec<i32>,
+}
+
+impl CartItemRequest {
+    pub fn to_cart_item_core(&self) -> CartItemCore {
+        CartItemCore {
+            product_id: self.product_id,
+            quantity: self.quantity,
+            options: self
+                .option_ids
+                .iter()
+                .map(|option_id| OrderItemOption {
+                    option_id: *option_id,
+                    name: "".to_string(),
+                    price_delta: 0,
+                })
+                .collect(),
+            ..CartItemCore::new()
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.quantity > 0
+    }
+
+    pub fn dummy() -> Self {
+        CartItemRequest {
+            product_id: 1,
+            quantity: 1,
+            option_ids: vec![1],
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct CartItemQuantityRequest {
+    pub quantity: i32,
+}
+
+impl CartItemQuantityRequest {
+    pub fn is_valid(&self) -> bool {
+        self.quantity > 0
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct CheckoutRequest {
+    pub store_id: i32,
+    #[serde(default)]
+    pub payment_method: PaymentMethod,
+    /// Propina en centavos.
+    #[serde(default)]
+    pub tip: i64,
+    /// Puntos a canjear como descuento.
+    #[serde(default)]
+    pub redeem_points: i64,
+    #[serde(default)]
+    pub fulfilment_type: FulfilmentType,
+    /// Dirección de la libreta del usuario; obligatoria para los pedidos a domicilio.
+    pub address_id: Option<i32>,
+    /// Inicio de una de las franjas de retiro del local, para programar el pedido.
+    pub pickup_at: Option<DateTime<Utc>>,
+    /// Si el local está cerrado, programa el pedido para la primera franja con cupo en lugar
+    /// de rechazarlo.
+    #[serde(default)]
+    pub schedule_if_closed: bool,
+}
+
+impl CheckoutRequest {
+    pub fn to_fulfilment_core(&self) -> FulfilmentCore {
+        FulfilmentCore {
+            store_id: self.store_id,
+            fulfilment_type: self.fulfilment_type,
+            address_id: self.address_id,
+            pickup_at: self.pickup_at,
+            schedule_if_closed: self.schedule_if_closed,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.tip >= 0
+            && self.redeem_points >= 0
+            && self.to_fulfilment_core().delivery_address_id().is_ok()
+    }
+
+    pub fn dummy() -> Self {
+        CheckoutRequest {
+            store_id: 1,
+            payment_method: PaymentMethod::Card,
+            tip: 0,
+            redeem_points: 0,
+            fulfilment_type: FulfilmentType::Pickup,
+            address_id: None,
+            pickup_at: None,
+            schedule_if_closed: false,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct CouponRequest {
+    pub code: String,
+}
+
+impl CouponRequest {
+    pub fn is_valid(&self) -> bool {
+        !self.code.trim().is_empty()
+    }
+}
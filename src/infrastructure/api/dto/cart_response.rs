@@ -0,0 +1,68 @@
+use super::{
+    order_response::OrderItemOptionResponse, pricing_response::PriceBreakdownResponse,
+    promotion_response::AppliedDiscountResponse,
+};
+use crate::core::entities::cart::{Cart as CartCore, CartItem as CartItemCore};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct CartResponse {
+    pub items: Vec<CartItemResponse>,
+    pub coupon_codes: Vec<String>,
+    pub discounts: Vec<AppliedDiscountResponse>,
+    pub total: i64,
+    pub pricing: PriceBreakdownResponse,
+}
+
+#[derive(Serialize)]
+pub struct CartItemResponse {
+    pub id: i32,
+    pub product_id: i32,
+    pub product_name: String,
+    pub category_id: i32,
+    pub quantity: i32,
+    pub unit_price: i64,
+    pub tax_rate: i32,
+    pub subtotal: i64,
+    pub options: Vec<OrderItemOptionResponse>,
+    pub available: bool,
+    pub unavailable_reason: Option<String>,
+}
+
+impl CartResponse {
+    pub fn from_cart_core(cart: CartCore) -> Self {
+        CartResponse {
+            coupon_codes: cart.coupon_codes,
+            discounts: AppliedDiscountResponse::from_applied_discount_core_vec(cart.discounts),
+            total: cart.pricing.total.amount,
+            pricing: PriceBreakdownResponse::from_price_breakdown_core(cart.pricing),
+            items: cart
+                .items
+                .into_iter()
+                .map(CartItemResponse::from_cart_item_core)
+                .collect(),
+        }
+    }
+}
+
+impl CartItemResponse {
+    pub fn from_cart_item_core(item: CartItemCore) -> Self {
+        CartItemResponse {
+            id: item.id.unwrap_or_default(),
+            product_id: item.product_id,
+            product_name: item.product_name.clone(),
+            category_id: item.category_id,
+            quantity: item.quantity,
+            unit_price: item.unit_price,
+            tax_rate: item.tax_rate,
+            subtotal: item.subtotal(),
+            available: item.is_available(),
+            options: item
+                .options
+                .into_iter()
+                .map(OrderItemOptionResponse::from_order_item_option_core)
+                .collect(),
+            unavailable_reason: item.unavailable_reason,
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::core::entities::category::Category as CategoryCore;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct CategoryRequest {
+    pub name: String,
+    pub description: Option<String>,
+    /// IVA en puntos básicos (`2100` = 21 %).
+    pub tax_rate: Option<i32>,
+}
+
+impl CategoryRequest {
+    pub fn to_category_core(&self) -> CategoryCore {
+        CategoryCore {
+            id: None,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            tax_rate: self.tax_rate,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.name.trim().is_empty()
+            && self
+                .tax_rate
+                .is_none_or(|tax_rate| (0..=10_000).contains(&tax_rate))
+    }
+
+    pub fn dummy() -> Self {
+        CategoryRequest {
+            name: "Cafés".to_string(),
+            description: Some("Bebidas a base de espresso".to_string()),
+            tax_rate: Some(1000),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use crate::core::entities::category::Category as CategoryCore;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct CategoryResponse {
+    pub id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub tax_rate: Option<i32>,
+}
+
+impl CategoryResponse {
+    pub fn from_category_core(category: CategoryCore) -> Self {
+        CategoryResponse {
+            id: category.id.unwrap_or_default(),
+            name: category.name,
+            description: category.description,
+            tax_rate: category.tax_rate,
+        }
+    }
+
+    pub fn from_category_core_vec(categories: Vec<CategoryCore>) -> Vec<Self> {
+        categories
+            .iter()
+            .map(|c| Self::from_category_core(c.clone()))
+            .collect()
+    }
+}
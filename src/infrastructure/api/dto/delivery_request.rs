@@ -0,0 +1,80 @@
+use crate::core::entities::delivery::{Address as AddressCore, DeliveryZone as DeliveryZoneCore};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct AddressRequest {
+    pub province: String,
+    pub city: String,
+    pub address: String,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct DeliveryZoneRequest {
+    pub province: String,
+    /// Sin ciudad, la zona cubre toda la provincia.
+    pub city: Option<String>,
+    pub fee: i64,
+    #[serde(default)]
+    pub minimum_order: i64,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+impl AddressRequest {
+    pub fn to_address_core(&self) -> AddressCore {
+        AddressCore {
+            province: self.province.trim().to_string(),
+            city: self.city.trim().to_string(),
+            address: self.address.trim().to_string(),
+            ..AddressCore::new()
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.to_address_core().validate().is_ok()
+    }
+
+    pub fn dummy() -> Self {
+        AddressRequest {
+            province: "Managua".to_string(),
+            city: "Managua".to_string(),
+            address: "Del Hotel Crowne Plaza 1c al sur".to_string(),
+        }
+    }
+}
+
+impl DeliveryZoneRequest {
+    pub fn to_delivery_zone_core(&self) -> DeliveryZoneCore {
+        DeliveryZoneCore {
+            province: self.province.trim().to_string(),
+            city: self
+                .city
+                .as_deref()
+                .map(str::trim)
+                .filter(|city| !city.is_empty())
+                .map(str::to_string),
+            fee: self.fee,
+            minimum_order: self.minimum_order,
+            active: self.active,
+            ..DeliveryZoneCore::new()
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.to_delivery_zone_core().validate().is_ok()
+    }
+
+    pub fn dummy() -> Self {
+        DeliveryZoneRequest {
+            province: "Managua".to_string(),
+            city: None,
+            fee: 300,
+            minimum_order: 1000,
+            active: true,
+        }
+    }
+}
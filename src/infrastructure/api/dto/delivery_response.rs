@@ -0,0 +1,55 @@
+use crate::core::entities::delivery::{Address as AddressCore, DeliveryZone as DeliveryZoneCore};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct AddressResponse {
+    pub id: i32,
+    pub province: String,
+    pub city: String,
+    pub address: String,
+}
+
+#[derive(Serialize)]
+pub struct DeliveryZoneResponse {
+    pub id: i32,
+    pub province: String,
+    pub city: Option<String>,
+    pub fee: i64,
+    pub minimum_order: i64,
+    pub active: bool,
+}
+
+impl AddressResponse {
+    pub fn from_address_core(address: AddressCore) -> Self {
+        AddressResponse {
+            id: address.id.unwrap_or_default(),
+            province: address.province,
+            city: address.city,
+            address: address.address,
+        }
+    }
+
+    pub fn from_address_core_vec(addresses: Vec<AddressCore>) -> Vec<Self> {
+        addresses.into_iter().map(Self::from_address_core).collect()
+    }
+}
+
+impl DeliveryZoneResponse {
+    pub fn from_delivery_zone_core(zone: DeliveryZoneCore) -> Self {
+        DeliveryZoneResponse {
+            id: zone.id.unwrap_or_default(),
+            province: zone.province,
+            city: zone.city,
+            fee: zone.fee,
+            minimum_order: zone.minimum_order,
+            active: zone.active,
+        }
+    }
+
+    pub fn from_delivery_zone_core_vec(zones: Vec<DeliveryZoneCore>) -> Vec<Self> {
+        zones
+            .into_iter()
+            .map(Self::from_delivery_zone_core)
+            .collect()
+    }
+}
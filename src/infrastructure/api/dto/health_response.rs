@@ -0,0 +1,42 @@
+use crate::core::entities::health::{
+    ComponentHealth as ComponentHealthCore, HealthReport as HealthReportCore, HealthStatus,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct HealthResponse {
+    pub status: HealthStatus,
+    pub components: Vec<ComponentHealthResponse>,
+}
+
+#[derive(Serialize)]
+pub struct ComponentHealthResponse {
+    pub name: String,
+    pub status: HealthStatus,
+    pub latency_ms: f64,
+    pub error: Option<String>,
+}
+
+impl HealthResponse {
+    pub fn from_health_report_core(report: HealthReportCore) -> Self {
+        HealthResponse {
+            status: report.status,
+            components: report
+                .components
+                .into_iter()
+                .map(ComponentHealthResponse::from_component_health_core)
+                .collect(),
+        }
+    }
+}
+
+impl ComponentHealthResponse {
+    pub fn from_component_health_core(component: ComponentHealthCore) -> Self {
+        ComponentHealthResponse {
+            name: component.name,
+            status: component.status,
+            latency_ms: component.latency.as_micros() as f64 / 1000.0,
+            error: component.error,
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use crate::core::entities::inventory::{
+    Ingredient as IngredientCore, IngredientUnit, RecipeLine as RecipeLineCore,
+    StockAdjustment as StockAdjustmentCore, StockAdjustmentReason,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct IngredientRequest {
+    pub name: String,
+    pub unit: IngredientUnit,
+    #[serde(default)]
+    pub low_stock_threshold: i64,
+}
+
+impl IngredientRequest {
+    pub fn to_ingredient_core(&self) -> IngredientCore {
+        IngredientCore {
+            name: self.name.trim().to_string(),
+            unit: self.unit,
+            low_stock_threshold: self.low_stock_threshold,
+            ..IngredientCore::new()
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.name.trim().is_empty() && self.low_stock_threshold >= 0
+    }
+
+    pub fn dummy() -> Self {
+        IngredientRequest {
+            name: "Leche entera".to_string(),
+            unit: IngredientUnit::Milliliter,
+            low_stock_threshold: 1000,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct StockAdjustmentRequest {
+    /// Cantidad a sumar al stock; negativa para mermas o correcciones a la baja.
+    pub delta: i64,
+    pub reason: StockAdjustmentReason,
+    pub note: Option<String>,
+}
+
+impl StockAdjustmentRequest {
+    pub fn to_stock_adjustment_core(&self) -> StockAdjustmentCore {
+        StockAdjustmentCore {
+            delta: self.delta,
+            reason: self.reason,
+            note: self.note.clone(),
+            ..StockAdjustmentCore::new()
+        }
+    }
+
+    /// El consumo por pedidos solo lo registra el sistema al aceptar un pedido.
+    pub fn is_valid(&self) -> bool {
+        self.delta != 0 && self.reason != StockAdjustmentReason::OrderConsumption
+    }
+
+    pub fn dummy() -> Self {
+        StockAdjustmentRequest {
+            delta: 5000,
+            reason: StockAdjustmentReason::Restock,
+            note: Some("Entrega del proveedor".to_string()),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct RecipeRequest {
+    pub ingredients: Vec<RecipeLineRequest>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct RecipeLineRequest {
+    pub ingredient_id: i32,
+    /// Cantidad que consume una unidad, en la unidad del insumo.
+    pub quantity: i64,
+}
+
+impl RecipeRequest {
+    pub fn to_recipe_line_core_vec(&self) -> Vec<RecipeLineCore> {
+        self.ingredients
+            .iter()
+            .map(|line| RecipeLineCore {
+                ingredient_id: line.ingredient_id,
+                quantity: line.quantity,
+            })
+            .collect()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.ingredients.iter().all(|line| line.quantity > 0)
+    }
+
+    pub fn dummy() -> Self {
+        RecipeRequest {
+            ingredients: vec![RecipeLineRequest {
+                ingredient_id: 1,
+                quantity: 200,
+            }],
+        }
+    }
+}
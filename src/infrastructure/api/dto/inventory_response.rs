@@ -0,0 +1,88 @@
+use crate::core::entities::inventory::{
+    Ingredient as IngredientCore, IngredientUnit, RecipeLine as RecipeLineCore,
+    StockAdjustment as StockAdjustmentCore, StockAdjustmentReason,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct IngredientResponse {
+    pub id: i32,
+    pub name: String,
+    pub unit: IngredientUnit,
+    pub stock: i64,
+    pub low_stock_threshold: i64,
+    pub low_stock: bool,
+}
+
+#[derive(Serialize)]
+pub struct StockAdjustmentResponse {
+    pub id: i32,
+    pub ingredient_id: i32,
+    pub delta: i64,
+    pub reason: StockAdjustmentReason,
+    pub note: Option<String>,
+    pub order_id: Option<i32>,
+    pub adjusted_by: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+pub struct RecipeLineResponse {
+    pub ingredient_id: i32,
+    pub quantity: i64,
+}
+
+impl IngredientResponse {
+    pub fn from_ingredient_core(ingredient: IngredientCore) -> Self {
+        IngredientResponse {
+            id: ingredient.id.unwrap_or_default(),
+            low_stock: ingredient.is_low_stock(),
+            name: ingredient.name,
+            unit: ingredient.unit,
+            stock: ingredient.stock,
+            low_stock_threshold: ingredient.low_stock_threshold,
+        }
+    }
+
+    pub fn from_ingredient_core_vec(ingredients: Vec<IngredientCore>) -> Vec<Self> {
+        ingredients
+            .into_iter()
+            .map(Self::from_ingredient_core)
+            .collect()
+    }
+}
+
+impl StockAdjustmentResponse {
+    pub fn from_stock_adjustment_core(adjustment: StockAdjustmentCore) -> Self {
+        StockAdjustmentResponse {
+            id: adjustment.id.unwrap_or_default(),
+            ingredient_id: adjustment.ingredient_id,
+            delta: adjustment.delta,
+            reason: adjustment.reason,
+            note: adjustment.note,
+            order_id: adjustment.order_id,
+            adjusted_by: adjustment.adjusted_by,
+            created_at: adjustment.created_at,
+        }
+    }
+
+    pub fn from_stock_adjustment_core_vec(adjustments: Vec<StockAdjustmentCore>) -> Vec<Self> {
+        adjustments
+            .into_iter()
+            .map(Self::from_stock_adjustment_core)
+            .collect()
+    }
+}
+
+impl RecipeLineResponse {
+    pub fn from_recipe_line_core_vec(lines: Vec<RecipeLineCore>) -> Vec<Self> {
+        lines
+            .into_iter()
+            .map(|line| RecipeLineResponse {
+                ingredient_id: line.ingredient_id,
+                quantity: line.quantity,
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,45 @@
+use crate::core::entities::loyalty::{
+    LoyaltyAccount as LoyaltyAccountCore, LoyaltyEntry as LoyaltyEntryCore, LoyaltyEntryKind,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct LoyaltyResponse {
+    pub balance: i64,
+    pub history: Vec<LoyaltyEntryResponse>,
+}
+
+#[derive(Serialize)]
+pub struct LoyaltyEntryResponse {
+    pub kind: LoyaltyEntryKind,
+    pub points: i64,
+    pub order_id: Option<i32>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LoyaltyResponse {
+    pub fn from_loyalty_account_core(account: LoyaltyAccountCore) -> Self {
+        LoyaltyResponse {
+            balance: account.balance,
+            history: account
+                .entries
+                .into_iter()
+                .map(LoyaltyEntryResponse::from_loyalty_entry_core)
+                .collect(),
+        }
+    }
+}
+
+impl LoyaltyEntryResponse {
+    pub fn from_loyalty_entry_core(entry: LoyaltyEntryCore) -> Self {
+        LoyaltyEntryResponse {
+            kind: entry.kind,
+            points: entry.points,
+            order_id: entry.order_id,
+            expires_at: entry.expires_at,
+            created_at: entry.created_at,
+        }
+    }
+}
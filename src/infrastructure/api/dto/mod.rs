@@ -1,2 +1,27 @@
+pub mod cart_request;
+pub mod cart_response;
+pub mod category_request;
+pub mod category_response;
+pub mod delivery_request;
+pub mod delivery_response;
+pub mod health_response;
+pub mod inventory_request;
+pub mod inventory_response;
+pub mod loyalty_response;
+pub mod modifier_request;
+pub mod modifier_response;
+pub mod order_request;
+pub mod order_response;
+pub mod payment_request;
+pub mod payment_response;
+pub mod pricing_response;
+pub mod product_request;
+pub mod product_response;
+pub mod promotion_request;
+pub mod promotion_response;
+pub mod refund_request;
+pub mod refund_response;
+pub mod store_request;
+pub mod store_response;
 pub mod user_request;
 pub mod user_response;
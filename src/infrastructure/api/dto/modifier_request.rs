@@ -0,0 +1,89 @@
+use crate::core::entities::modifier::{
+    ModifierGroup as ModifierGroupCore, ModifierOption as ModifierOptionCore,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct ModifierGroupRequest {
+    pub name: String,
+    pub min_selections: i32,
+    pub max_selections: i32,
+    #[serde(default)]
+    pub options: Vec<ModifierOptionRequest>,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ModifierOptionRequest {
+    pub name: String,
+    pub price_delta: i64,
+    pub available: Option<bool>,
+}
+
+impl ModifierGroupRequest {
+    pub fn to_modifier_group_core(&self) -> ModifierGroupCore {
+        ModifierGroupCore {
+            id: None,
+            name: self.name.clone(),
+            min_selections: self.min_selections,
+            max_selections: self.max_selections,
+            options: self
+                .options
+                .iter()
+                .map(ModifierOptionRequest::to_modifier_option_core)
+                .collect(),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.name.trim().is_empty()
+            && self.min_selections >= 0
+            && self.max_selections >= 1
+            && self.min_selections <= self.max_selections
+            && self.options.iter().all(|option| option.is_valid())
+    }
+
+    pub fn dummy() -> Self {
+        ModifierGroupRequest {
+            name: "Tamaño".to_string(),
+            min_selections: 1,
+            max_selections: 1,
+            options: vec![
+                ModifierOptionRequest {
+                    name: "Mediano".to_string(),
+                    price_delta: 0,
+                    available: None,
+                },
+                ModifierOptionRequest {
+                    name: "Grande".to_string(),
+                    price_delta: 50,
+                    available: None,
+                },
+            ],
+        }
+    }
+}
+
+impl ModifierOptionRequest {
+    pub fn to_modifier_option_core(&self) -> ModifierOptionCore {
+        ModifierOptionCore {
+            id: None,
+            group_id: 0,
+            name: self.name.clone(),
+            price_delta: self.price_delta,
+            available: self.available.unwrap_or(true),
+            in_stock: true,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.name.trim().is_empty()
+    }
+
+    pub fn dummy() -> Self {
+        ModifierOptionRequest {
+            name: "Leche de avena".to_string(),
+            price_delta: 40,
+            available: None,
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::core::entities::modifier::{
+    ModifierGroup as ModifierGroupCore, ModifierOption as ModifierOptionCore,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct ModifierGroupResponse {
+    pub id: i32,
+    pub name: String,
+    pub min_selections: i32,
+    pub max_selections: i32,
+    pub options: Vec<ModifierOptionResponse>,
+}
+
+#[derive(Serialize)]
+pub struct ModifierOptionResponse {
+    pub id: i32,
+    pub name: String,
+    pub price_delta: i64,
+    pub available: bool,
+    pub in_stock: bool,
+}
+
+impl ModifierGroupResponse {
+    pub fn from_modifier_group_core(group: ModifierGroupCore) -> Self {
+        ModifierGroupResponse {
+            id: group.id.unwrap_or_default(),
+            name: group.name,
+            min_selections: group.min_selections,
+            max_selections: group.max_selections,
+            options: group
+                .options
+                .into_iter()
+                .map(ModifierOptionResponse::from_modifier_option_core)
+                .collect(),
+        }
+    }
+
+    pub fn from_modifier_group_core_vec(groups: Vec<ModifierGroupCore>) -> Vec<Self> {
+        groups
+            .into_iter()
+            .map(Self::from_modifier_group_core)
+            .collect()
+    }
+}
+
+impl ModifierOptionResponse {
+    pub fn from_modifier_option_core(option: ModifierOptionCore) -> Self {
+        ModifierOptionResponse {
+            id: option.id.unwrap_or_default(),
+            name: option.name,
+            price_delta: option.price_delta,
+            available: option.available,
+            in_stock: option.in_stock,
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use crate::core::entities::{
+    fulfilment::{Fulfilment as FulfilmentCore, FulfilmentType},
+    order::{OrderItem as OrderItemCore, OrderItemOption as OrderItemOptionCore, OrderStatus},
+    payment::PaymentMethod,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct OrderRequest {
+    pub store_id: i32,
+    pub items: Vec<OrderItemRequest>,
+    #[serde(default)]
+    pub payment_method: PaymentMethod,
+    /// Propina en centavos.
+    #[serde(default)]
+    pub tip: i64,
+    #[serde(default)]
+    pub fulfilment_type: FulfilmentType,
+    /// Dirección de la libreta del usuario; obligatoria para los pedidos a domicilio.
+    pub address_id: Option<i32>,
+    /// Inicio de una de las franjas de retiro del local, para programar el pedido.
+    pub pickup_at: Option<DateTime<Utc>>,
+    /// Si el local está cerrado, programa el pedido para la primera franja con cupo en lugar
+    /// de rechazarlo.
+    #[serde(default)]
+    pub schedule_if_closed: bool,
+}
+
+/// Empleado que llevará un pedido a domicilio.
+#[derive(Deserialize, Serialize)]
+pub struct CourierRequest {
+    pub courier_id: i32,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct OrderItemRequest {
+    pub product_id: i32,
+    pub quantity: i32,
+    #[serde(default)]
+    pub option_ids: Vec<i32>,
+}
+
+impl OrderRequest {
+    pub fn to_fulfilment_core(&self) -> FulfilmentCore {
+        FulfilmentCore {
+            store_id: self.store_id,
+            fulfilment_type: self.fulfilment_type,
+            address_id: self.address_id,
+            pickup_at: self.pickup_at,
+            schedule_if_closed: self.schedule_if_closed,
+        }
+    }
+
+    pub fn to_order_item_core_vec(&self) -> Vec<OrderItemCore> {
+        self.items
+            .iter()
+            .map(OrderItemRequest::to_order_item_core)
+            .collect()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.items.is_empty()
+            && self.items.iter().all(|item| item.quantity > 0)
+            && self.tip >= 0
+            && self.to_fulfilment_core().delivery_address_id().is_ok()
+    }
+
+    pub fn dummy() -> Self {
+        OrderRequest {
+            store_id: 1,
+            items: vec![OrderItemRequest {
+                product_id: 1,
+                quantity: 2,
+                option_ids: vec![1],
+            }],
+            payment_method: PaymentMethod::Card,
+            tip: 100,
+            fulfilment_type: FulfilmentType::Pickup,
+            address_id: None,
+            pickup_at: None,
+            schedule_if_closed: false,
+        }
+    }
+}
+
+impl OrderItemRequest {
+    pub fn to_order_item_core(&self) -> OrderItemCore {
+        OrderItemCore {
+            product_id: self.product_id,
+            quantity: self.quantity,
+            options: self
+                .option_ids
+                .iter()
+                .map(|option_id| OrderItemOptionCore {
+                    option_id: *option_id,
+                    name: "".to_string(),
+                    price_delta: 0,
+                })
+                .collect(),
+            ..OrderItemCore::new()
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct OrderStatusRequest {
+    pub status: OrderStatus,
+}
+
+#[derive(Deserialize)]
+pub struct OrderFilter {
+    pub status: Option<OrderStatus>,
+}
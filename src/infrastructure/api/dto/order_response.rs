@@ -0,0 +1,169 @@
+use crate::core::entities::order::{
+    Order as OrderCore, OrderItem as OrderItemCore, OrderItemOption as OrderItemOptionCore,
+    OrderStatus, OrderStatusChange as OrderStatusChangeCore,
+};
+use crate::core::entities::{fulfilment::FulfilmentType, payment::PaymentMethod};
+use crate::infrastructure::api::dto::{
+    pricing_response::PriceBreakdownResponse, promotion_response::AppliedDiscountResponse,
+    refund_response::RefundResponse,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct OrderResponse {
+    pub id: i32,
+    pub user_id: i32,
+    pub status: OrderStatus,
+    pub items: Vec<OrderItemResponse>,
+    pub total: i64,
+    pub pricing: PriceBreakdownResponse,
+    pub discounts: Vec<AppliedDiscountResponse>,
+    pub points_earned: i64,
+    pub points_redeemed: i64,
+    pub payment_method: PaymentMethod,
+    pub claimed_by: Option<i32>,
+    pub store_id: Option<i32>,
+    pub scheduled_for: Option<DateTime<Utc>>,
+    pub release_at: Option<DateTime<Utc>>,
+    pub fulfilment_type: FulfilmentType,
+    pub delivery_address_id: Option<i32>,
+    pub delivery_address: Option<String>,
+    pub courier_id: Option<i32>,
+    pub history: Vec<OrderStatusChangeResponse>,
+    pub refunds: Vec<RefundResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+pub struct OrderStatusChangeResponse {
+    pub status: OrderStatus,
+    pub changed_by: i32,
+    pub changed_at: DateTime<Utc>,
+}
+
+/// Pedido en la cola del barista junto con su antigüedad.
+#[derive(Serialize)]
+pub struct QueueOrderResponse {
+    #[serde(flatten)]
+    pub order: OrderResponse,
+    pub age_seconds: i64,
+}
+
+#[derive(Serialize)]
+pub struct OrderItemResponse {
+    pub id: i32,
+    pub product_id: i32,
+    pub product_name: String,
+    pub quantity: i32,
+    pub unit_price: i64,
+    pub tax_rate: i32,
+    pub options: Vec<OrderItemOptionResponse>,
+}
+
+#[derive(Serialize)]
+pub struct OrderItemOptionResponse {
+    pub option_id: i32,
+    pub name: String,
+    pub price_delta: i64,
+}
+
+impl OrderResponse {
+    pub fn from_order_core(order: OrderCore) -> Self {
+        OrderResponse {
+            id: order.id.unwrap_or_default(),
+            user_id: order.user_id,
+            status: order.status,
+            items: order
+                .items
+                .into_iter()
+                .map(OrderItemResponse::from_order_item_core)
+                .collect(),
+            total: order.pricing.total.amount,
+            pricing: PriceBreakdownResponse::from_price_breakdown_core(order.pricing),
+            discounts: AppliedDiscountResponse::from_applied_discount_core_vec(order.discounts),
+            points_earned: order.points_earned,
+            points_redeemed: order.points_redeemed,
+            payment_method: order.payment_method,
+            claimed_by: order.claimed_by,
+            store_id: order.store_id,
+            scheduled_for: order.scheduled_for,
+            release_at: order.release_at,
+            fulfilment_type: order.fulfilment_type,
+            delivery_address_id: order.delivery_address_id,
+            delivery_address: order.delivery_address,
+            courier_id: order.courier_id,
+            history: order
+                .history
+                .into_iter()
+                .map(OrderStatusChangeResponse::from_order_status_change_core)
+                .collect(),
+            refunds: order
+                .refunds
+                .into_iter()
+                .map(RefundResponse::from_refund_core)
+                .collect(),
+            created_at: order.created_at,
+            updated_at: order.updated_at,
+        }
+    }
+
+    pub fn from_order_core_vec(orders: Vec<OrderCore>) -> Vec<Self> {
+        orders.into_iter().map(Self::from_order_core).collect()
+    }
+}
+
+impl OrderStatusChangeResponse {
+    pub fn from_order_status_change_core(change: OrderStatusChangeCore) -> Self {
+        OrderStatusChangeResponse {
+            status: change.status,
+            changed_by: change.changed_by,
+            changed_at: change.changed_at,
+        }
+    }
+}
+
+impl QueueOrderResponse {
+    pub fn from_order_core(order: OrderCore, now: DateTime<Utc>) -> Self {
+        QueueOrderResponse {
+            age_seconds: (now - order.created_at).num_seconds(),
+            order: OrderResponse::from_order_core(order),
+        }
+    }
+
+    pub fn from_order_core_vec(orders: Vec<OrderCore>, now: DateTime<Utc>) -> Vec<Self> {
+        orders
+            .into_iter()
+            .map(|order| Self::from_order_core(order, now))
+            .collect()
+    }
+}
+
+impl OrderItemResponse {
+    pub fn from_order_item_core(item: OrderItemCore) -> Self {
+        OrderItemResponse {
+            id: item.id.unwrap_or_default(),
+            product_id: item.product_id,
+            product_name: item.product_name,
+            quantity: item.quantity,
+            unit_price: item.unit_price,
+            tax_rate: item.tax_rate,
+            options: item
+                .options
+                .into_iter()
+                .map(OrderItemOptionResponse::from_order_item_option_core)
+                .collect(),
+        }
+    }
+}
+
+impl OrderItemOptionResponse {
+    pub fn from_order_item_option_core(option: OrderItemOptionCore) -> Self {
+        OrderItemOptionResponse {
+            option_id: option.option_id,
+            name: option.name,
+            price_delta: option.price_delta,
+        }
+    }
+}
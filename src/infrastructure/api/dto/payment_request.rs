@@ -0,0 +1,34 @@
+use crate::core::entities::payment::PaymentCard;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct PaymentRequest {
+    pub card_number: String,
+    pub expiry_month: u32,
+    pub expiry_year: i32,
+    pub cvc: String,
+}
+
+impl PaymentRequest {
+    pub fn to_payment_card_core(&self) -> PaymentCard {
+        PaymentCard {
+            number: self.card_number.replace(' ', ""),
+            expiry_month: self.expiry_month,
+            expiry_year: self.expiry_year,
+            cvc: self.cvc.clone(),
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.card_number.trim().is_empty() && !self.cvc.trim().is_empty()
+    }
+
+    pub fn dummy() -> Self {
+        PaymentRequest {
+            card_number: "4242424242424242".to_string(),
+            expiry_month: 12,
+            expiry_year: 2099,
+            cvc: "123".to_string(),
+        }
+    }
+}
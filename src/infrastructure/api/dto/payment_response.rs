@@ -0,0 +1,64 @@
+use crate::core::entities::payment::{
+    Payment as PaymentCore, PaymentAttempt as PaymentAttemptCore, PaymentMethod, PaymentOperation,
+    PaymentStatus,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct PaymentResponse {
+    pub id: i32,
+    pub order_id: i32,
+    pub method: PaymentMethod,
+    pub status: PaymentStatus,
+    pub amount: i64,
+    pub card_last_four: Option<String>,
+    pub attempts: Vec<PaymentAttemptResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize)]
+pub struct PaymentAttemptResponse {
+    pub operation: PaymentOperation,
+    pub amount: i64,
+    pub succeeded: bool,
+    pub failure_reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl PaymentResponse {
+    pub fn from_payment_core(payment: PaymentCore) -> Self {
+        PaymentResponse {
+            id: payment.id.unwrap_or_default(),
+            order_id: payment.order_id,
+            method: payment.method,
+            status: payment.status,
+            amount: payment.amount,
+            card_last_four: payment.card_last_four,
+            attempts: payment
+                .attempts
+                .into_iter()
+                .map(PaymentAttemptResponse::from_payment_attempt_core)
+                .collect(),
+            created_at: payment.created_at,
+            updated_at: payment.updated_at,
+        }
+    }
+
+    pub fn from_payment_core_vec(payments: Vec<PaymentCore>) -> Vec<Self> {
+        payments.into_iter().map(Self::from_payment_core).collect()
+    }
+}
+
+impl PaymentAttemptResponse {
+    pub fn from_payment_attempt_core(attempt: PaymentAttemptCore) -> Self {
+        PaymentAttemptResponse {
+            operation: attempt.operation,
+            amount: attempt.amount,
+            succeeded: attempt.succeeded,
+            failure_reason: attempt.failure_reason,
+            created_at: attempt.created_at,
+        }
+    }
+}
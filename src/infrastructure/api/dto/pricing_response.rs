@@ -0,0 +1,30 @@
+use crate::core::entities::{money::Currency, pricing::PriceBreakdown};
+use serde::Serialize;
+
+/// Desglose de precios; todos los montos van en centavos de `currency`.
+#[derive(Serialize)]
+pub struct PriceBreakdownResponse {
+    pub currency: Currency,
+    pub subtotal: i64,
+    pub discount: i64,
+    pub tax: i64,
+    pub service_charge: i64,
+    pub tip: i64,
+    pub delivery_fee: i64,
+    pub total: i64,
+}
+
+impl PriceBreakdownResponse {
+    pub fn from_price_breakdown_core(pricing: PriceBreakdown) -> Self {
+        PriceBreakdownResponse {
+            currency: pricing.total.currency,
+            subtotal: pricing.subtotal.amount,
+            discount: pricing.discount.amount,
+            tax: pricing.tax.amount,
+            service_charge: pricing.service_charge.amount,
+            tip: pricing.tip.amount,
+            delivery_fee: pricing.delivery_fee.amount,
+            total: pricing.total.amount,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use crate::core::entities::product::Product as ProductCore;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct ProductRequest {
+    pub category_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub price: i64,
+    pub image_url: Option<String>,
+    pub available: Option<bool>,
+}
+
+impl ProductRequest {
+    pub fn to_product_core(&self) -> ProductCore {
+        ProductCore {
+            id: None,
+            category_id: self.category_id,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            price: self.price,
+            image_url: self.image_url.clone(),
+            available: self.available.unwrap_or(true),
+            in_stock: true,
+            modifier_groups: vec![],
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.name.trim().is_empty() && self.price >= 0
+    }
+
+    pub fn dummy() -> Self {
+        ProductRequest {
+            category_id: 1,
+            name: "Capuccino".to_string(),
+            description: Some("Espresso con espuma de leche".to_string()),
+            price: 250,
+            image_url: None,
+            available: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct ProductFilter {
+    pub category: Option<i32>,
+}
@@ -0,0 +1,41 @@
+use super::modifier_response::ModifierGroupResponse;
+use crate::core::entities::product::Product as ProductCore;
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct ProductResponse {
+    pub id: i32,
+    pub category_id: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub price: i64,
+    pub image_url: Option<String>,
+    pub available: bool,
+    pub in_stock: bool,
+    pub modifier_groups: Vec<ModifierGroupResponse>,
+}
+
+impl ProductResponse {
+    pub fn from_product_core(product: ProductCore) -> Self {
+        ProductResponse {
+            id: product.id.unwrap_or_default(),
+            category_id: product.category_id,
+            name: product.name,
+            description: product.description,
+            price: product.price,
+            image_url: product.image_url,
+            available: product.available,
+            in_stock: product.in_stock,
+            modifier_groups: ModifierGroupResponse::from_modifier_group_core_vec(
+                product.modifier_groups,
+            ),
+        }
+    }
+
+    pub fn from_product_core_vec(products: Vec<ProductCore>) -> Vec<Self> {
+        products
+            .iter()
+            .map(|p| Self::from_product_core(p.clone()))
+            .collect()
+    }
+}
@@ -0,0 +1,75 @@
+use crate::core::entities::promotion::{DailyWindow, Promotion as PromotionCore, PromotionKind};
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct PromotionRequest {
+    pub name: String,
+    /// Código del cupón; sin código la promoción se aplica sola a todos los carritos.
+    pub code: Option<String>,
+    pub kind: PromotionKind,
+    /// Inicio de la vigencia; por defecto, el momento de la creación.
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    /// Franja diaria en UTC (happy hour). Se indican ambos extremos o ninguno.
+    pub daily_starts: Option<NaiveTime>,
+    pub daily_ends: Option<NaiveTime>,
+    #[serde(default)]
+    pub min_order_amount: i64,
+    pub max_uses: Option<i32>,
+    pub max_uses_per_user: Option<i32>,
+    #[serde(default)]
+    pub stackable: bool,
+    #[serde(default = "default_active")]
+    pub active: bool,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+impl PromotionRequest {
+    pub fn to_promotion_core(&self) -> PromotionCore {
+        let daily_window = match (self.daily_starts, self.daily_ends) {
+            (Some(starts), Some(ends)) => Some(DailyWindow { starts, ends }),
+            _ => None,
+        };
+
+        PromotionCore {
+            id: None,
+            name: self.name.clone(),
+            code: self.code.clone(),
+            kind: self.kind,
+            starts_at: self.starts_at.unwrap_or_else(Utc::now),
+            ends_at: self.ends_at,
+            daily_window,
+            min_order_amount: self.min_order_amount,
+            max_uses: self.max_uses,
+            max_uses_per_user: self.max_uses_per_user,
+            stackable: self.stackable,
+            active: self.active,
+        }
+    }
+
+    /// El resto de las reglas se valida en el servicio.
+    pub fn is_valid(&self) -> bool {
+        self.daily_starts.is_some() == self.daily_ends.is_some()
+    }
+
+    pub fn dummy() -> Self {
+        PromotionRequest {
+            name: "Happy hour".to_string(),
+            code: None,
+            kind: PromotionKind::PercentageOff { rate: 2000 },
+            starts_at: None,
+            ends_at: None,
+            daily_starts: NaiveTime::from_hms_opt(17, 0, 0),
+            daily_ends: NaiveTime::from_hms_opt(19, 0, 0),
+            min_order_amount: 0,
+            max_uses: None,
+            max_uses_per_user: None,
+            stackable: false,
+            active: true,
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use crate::core::entities::promotion::{
+    AppliedDiscount as AppliedDiscountCore, Promotion as PromotionCore, PromotionKind,
+};
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct PromotionResponse {
+    pub id: i32,
+    pub name: String,
+    pub code: Option<String>,
+    pub kind: PromotionKind,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub daily_starts: Option<NaiveTime>,
+    pub daily_ends: Option<NaiveTime>,
+    pub min_order_amount: i64,
+    pub max_uses: Option<i32>,
+    pub max_uses_per_user: Option<i32>,
+    pub stackable: bool,
+    pub active: bool,
+}
+
+#[derive(Serialize)]
+pub struct AppliedDiscountResponse {
+    pub promotion_id: i32,
+    pub name: String,
+    pub code: Option<String>,
+    pub amount: i64,
+}
+
+impl PromotionResponse {
+    pub fn from_promotion_core(promotion: PromotionCore) -> Self {
+        PromotionResponse {
+            id: promotion.id.unwrap_or_default(),
+            name: promotion.name,
+            code: promotion.code,
+            kind: promotion.kind,
+            starts_at: promotion.starts_at,
+            ends_at: promotion.ends_at,
+            daily_starts: promotion.daily_window.map(|window| window.starts),
+            daily_ends: promotion.daily_window.map(|window| window.ends),
+            min_order_amount: promotion.min_order_amount,
+            max_uses: promotion.max_uses,
+            max_uses_per_user: promotion.max_uses_per_user,
+            stackable: promotion.stackable,
+            active: promotion.active,
+        }
+    }
+
+    pub fn from_promotion_core_vec(promotions: Vec<PromotionCore>) -> Vec<Self> {
+        promotions
+            .into_iter()
+            .map(Self::from_promotion_core)
+            .collect()
+    }
+}
+
+impl AppliedDiscountResponse {
+    pub fn from_applied_discount_core(discount: AppliedDiscountCore) -> Self {
+        AppliedDiscountResponse {
+            promotion_id: discount.promotion_id,
+            name: discount.name,
+            code: discount.code,
+            amount: discount.amount,
+        }
+    }
+
+    pub fn from_applied_discount_core_vec(discounts: Vec<AppliedDiscountCore>) -> Vec<Self> {
+        discounts
+            .into_iter()
+            .map(Self::from_applied_discount_core)
+            .collect()
+    }
+}
@@ -0,0 +1,39 @@
+use crate::core::entities::refund::{Refund as RefundCore, RefundReason};
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct RefundRequest {
+    pub reason: RefundReason,
+    pub note: Option<String>,
+    /// Líneas a devolver; si se omite se devuelve el saldo completo del pedido.
+    #[serde(default)]
+    pub item_ids: Vec<i32>,
+    pub idempotency_key: String,
+}
+
+impl RefundRequest {
+    pub fn to_refund_core(&self) -> RefundCore {
+        RefundCore {
+            reason: self.reason,
+            note: self.note.clone(),
+            item_ids: self.item_ids.clone(),
+            idempotency_key: self.idempotency_key.trim().to_string(),
+            ..RefundCore::new()
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        let key = self.idempotency_key.trim();
+
+        !key.is_empty() && key.len() <= 64
+    }
+
+    pub fn dummy() -> Self {
+        RefundRequest {
+            reason: RefundReason::WrongItem,
+            note: Some("Leche equivocada".to_string()),
+            item_ids: vec![1],
+            idempotency_key: "2f1d6c2e-refund-1".to_string(),
+        }
+    }
+}
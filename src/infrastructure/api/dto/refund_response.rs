@@ -0,0 +1,32 @@
+use crate::core::entities::refund::{Refund as RefundCore, RefundReason, RefundStatus};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct RefundResponse {
+    pub id: i32,
+    pub payment_id: i32,
+    pub amount: i64,
+    pub reason: RefundReason,
+    pub status: RefundStatus,
+    pub note: Option<String>,
+    pub item_ids: Vec<i32>,
+    pub refunded_by: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl RefundResponse {
+    pub fn from_refund_core(refund: RefundCore) -> Self {
+        RefundResponse {
+            id: refund.id.unwrap_or_default(),
+            payment_id: refund.payment_id,
+            amount: refund.amount,
+            reason: refund.reason,
+            status: refund.status,
+            note: refund.note,
+            item_ids: refund.item_ids,
+            refunded_by: refund.refunded_by,
+            created_at: refund.created_at,
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate::core::entities::store::{
+    OpeningHours as OpeningHoursCore, Store as StoreCore, StoreException as StoreExceptionCore,
+};
+use chrono::{NaiveDate, NaiveTime};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize)]
+pub struct StoreRequest {
+    pub name: String,
+    pub address: String,
+    /// Zona horaria IANA del local, p. ej. `America/Managua`.
+    pub timezone: String,
+    #[serde(default = "default_active")]
+    pub active: bool,
+    #[serde(default)]
+    pub opening_hours: Vec<OpeningHoursRequest>,
+    #[serde(default = "default_pickup_slot_minutes")]
+    pub pickup_slot_minutes: i32,
+    #[serde(default = "default_pickup_slot_capacity")]
+    pub pickup_slot_capacity: i32,
+    /// Minutos antes de su franja en que un pedido programado pasa a la cola del barista.
+    #[serde(default = "default_pickup_release_minutes")]
+    pub pickup_release_minutes: i32,
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct OpeningHoursRequest {
+    /// Día de la semana ISO: 1 = lunes, 7 = domingo.
+    pub weekday: u32,
+    pub opens: NaiveTime,
+    pub closes: NaiveTime,
+}
+
+fn default_active() -> bool {
+    true
+}
+
+fn default_pickup_slot_minutes() -> i32 {
+    StoreCore::new().pickup_slot_minutes
+}
+
+fn default_pickup_slot_capacity() -> i32 {
+    StoreCore::new().pickup_slot_capacity
+}
+
+fn default_pickup_release_minutes() -> i32 {
+    StoreCore::new().pickup_release_minutes
+}
+
+impl StoreRequest {
+    pub fn to_store_core(&self) -> StoreCore {
+        StoreCore {
+            name: self.name.trim().to_string(),
+            address: self.address.trim().to_string(),
+            timezone: self.timezone.parse().unwrap_or(Tz::UTC),
+            active: self.active,
+            opening_hours: self
+                .opening_hours
+                .iter()
+                .filter_map(|hours| {
+                    Some(OpeningHoursCore {
+                        weekday: OpeningHoursCore::weekday_from_number(hours.weekday)?,
+                        opens: hours.opens,
+                        closes: hours.closes,
+                    })
+                })
+                .collect(),
+            pickup_slot_minutes: self.pickup_slot_minutes,
+            pickup_slot_capacity: self.pickup_slot_capacity,
+            pickup_release_minutes: self.pickup_release_minutes,
+            ..StoreCore::new()
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        !self.name.trim().is_empty()
+            && self.timezone.parse::<Tz>().is_ok()
+            && self
+                .opening_hours
+                .iter()
+                .all(|hours| OpeningHoursCore::weekday_from_number(hours.weekday).is_some())
+            && self.pickup_slot_minutes > 0
+            && self.pickup_slot_capacity > 0
+            && self.pickup_release_minutes >= 0
+    }
+
+    pub fn dummy() -> Self {
+        StoreRequest {
+            name: "Centro".to_string(),
+            address: "Del Hotel Crowne Plaza 1c al sur".to_string(),
+            timezone: "America/Managua".to_string(),
+            active: true,
+            opening_hours: vec![OpeningHoursRequest {
+                weekday: 1,
+                opens: NaiveTime::from_hms_opt(7, 0, 0).unwrap(),
+                closes: NaiveTime::from_hms_opt(19, 0, 0).unwrap(),
+            }],
+            pickup_slot_minutes: 15,
+            pickup_slot_capacity: 10,
+            pickup_release_minutes: 10,
+        }
+    }
+}
+
+/// Horario especial de una fecha. Sin horas, el local cierra todo el día.
+#[derive(Deserialize, Serialize)]
+pub struct StoreExceptionRequest {
+    pub opens: Option<NaiveTime>,
+    pub closes: Option<NaiveTime>,
+    pub reason: Option<String>,
+}
+
+impl StoreExceptionRequest {
+    pub fn to_store_exception_core(&self, date: NaiveDate) -> StoreExceptionCore {
+        StoreExceptionCore {
+            date,
+            opens: self.opens,
+            closes: self.closes,
+            reason: self.reason.clone(),
+        }
+    }
+
+    pub fn dummy() -> Self {
+        StoreExceptionRequest {
+            opens: None,
+            closes: None,
+            reason: Some("Navidad".to_string()),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+pub struct ProductAvailabilityRequest {
+    pub available: bool,
+}
+
+impl ProductAvailabilityRequest {
+    pub fn dummy() -> Self {
+        ProductAvailabilityRequest { available: false }
+    }
+}
+
+#[derive(Deserialize)]
+pub struct PickupSlotFilter {
+    /// Fecha local del local; por defecto, hoy.
+    pub date: Option<NaiveDate>,
+}
@@ -0,0 +1,119 @@
+use crate::core::entities::store::{
+    PickupSlot as PickupSlotCore, Store as StoreCore, StoreException as StoreExceptionCore,
+};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use serde::Serialize;
+
+#[derive(Serialize)]
+pub struct StoreResponse {
+    pub id: i32,
+    pub name: String,
+    pub address: String,
+    pub timezone: String,
+    pub active: bool,
+    pub is_open: bool,
+    /// Próxima apertura, solo cuando el local está cerrado.
+    pub next_opening_at: Option<DateTime<Utc>>,
+    pub opening_hours: Vec<OpeningHoursResponse>,
+    pub exceptions: Vec<StoreExceptionResponse>,
+    pub unavailable_product_ids: Vec<i32>,
+    pub pickup_slot_minutes: i32,
+    pub pickup_slot_capacity: i32,
+    pub pickup_release_minutes: i32,
+}
+
+#[derive(Serialize)]
+pub struct OpeningHoursResponse {
+    pub weekday: u32,
+    pub opens: NaiveTime,
+    pub closes: NaiveTime,
+}
+
+#[derive(Serialize)]
+pub struct PickupSlotResponse {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub capacity: i32,
+    pub booked: i64,
+    pub available: bool,
+}
+
+#[derive(Serialize)]
+pub struct StoreExceptionResponse {
+    pub date: NaiveDate,
+    pub opens: Option<NaiveTime>,
+    pub closes: Option<NaiveTime>,
+    pub reason: Option<String>,
+}
+
+impl StoreResponse {
+    pub fn from_store_core(store: StoreCore, now: DateTime<Utc>) -> Self {
+        let is_open = store.is_open_at(now);
+
+        StoreResponse {
+            id: store.id.unwrap_or_default(),
+            is_open,
+            next_opening_at: if is_open {
+                None
+            } else {
+                store.next_opening(now)
+            },
+            timezone: store.timezone.name().to_string(),
+            opening_hours: store
+                .opening_hours
+                .iter()
+                .map(|hours| OpeningHoursResponse {
+                    weekday: hours.weekday.number_from_monday(),
+                    opens: hours.opens,
+                    closes: hours.closes,
+                })
+                .collect(),
+            exceptions: store
+                .exceptions
+                .into_iter()
+                .map(StoreExceptionResponse::from_store_exception_core)
+                .collect(),
+            name: store.name,
+            address: store.address,
+            active: store.active,
+            unavailable_product_ids: store.unavailable_product_ids,
+            pickup_slot_minutes: store.pickup_slot_minutes,
+            pickup_slot_capacity: store.pickup_slot_capacity,
+            pickup_release_minutes: store.pickup_release_minutes,
+        }
+    }
+
+    pub fn from_store_core_vec(stores: Vec<StoreCore>, now: DateTime<Utc>) -> Vec<Self> {
+        stores
+            .into_iter()
+            .map(|store| Self::from_store_core(store, now))
+            .collect()
+    }
+}
+
+impl StoreExceptionResponse {
+    pub fn from_store_exception_core(exception: StoreExceptionCore) -> Self {
+        StoreExceptionResponse {
+            date: exception.date,
+            opens: exception.opens,
+            closes: exception.closes,
+            reason: exception.reason,
+        }
+    }
+}
+
+impl PickupSlotResponse {
+    pub fn from_pickup_slot_core(slot: PickupSlotCore) -> Self {
+        PickupSlotResponse {
+            starts_at: slot.starts_at,
+            ends_at: slot.ends_at,
+            capacity: slot.capacity,
+            booked: slot.booked,
+            available: slot.is_available(),
+        }
+    }
+
+    pub fn from_pickup_slot_core_vec(slots: Vec<PickupSlotCore>) -> Vec<Self> {
+        slots.into_iter().map(Self::from_pickup_slot_core).collect()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::core::entities::user::User as UserCore;
+use crate::core::entities::{user::User as UserCore, user_role::UserRole, user_status::UserStatus};
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize, Serialize)]
@@ -19,6 +19,8 @@ impl UserRequest {
             phone_number: self.phone_number.clone(),
             email: self.email.clone(),
             password: self.password.clone(),
+            role: UserRole::Client,
+            status: UserStatus::Active,
         }
     }
 
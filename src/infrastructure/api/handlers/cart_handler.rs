@@ -0,0 +1,425 @@
+use crate::{
+    core::{
+        entities::user_payload::UserPayload, errors::cart_errors::CartError,
+        ports::cart_port::ICartService,
+    },
+    infrastructure::api::{
+        dto::{
+            cart_request::{
+                CartItemQuantityRequest, CartItemRequest, CheckoutRequest, CouponRequest,
+            },
+            cart_response::CartResponse,
+            order_response::OrderResponse,
+        },
+        middlewares::auth_middleware::AuthenticateMiddlewareFactory,
+    },
+};
+use actix_web::{web, HttpResponse, Responder};
+
+/// Rutas del carrito. Se deben registrar antes que el scope `/users`, que de lo contrario
+/// capturaría las peticiones a `/users/me/...`.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/users/me/cart")
+            .wrap(AuthenticateMiddlewareFactory::new())
+            .route("", web::get().to(get_cart))
+            .route("", web::delete().to(clear_cart))
+            .route("/items", web::post().to(add_item))
+            .route("/items/{item_id}", web::put().to(update_item))
+            .route("/items/{item_id}", web::delete().to(remove_item))
+            .route("/coupons", web::post().to(apply_coupon))
+            .route("/coupons/{code}", web::delete().to(remove_coupon))
+            .route("/checkout", web::post().to(checkout)),
+    );
+}
+
+type CartService = web::Data<std::sync::Arc<dyn ICartService>>;
+
+fn error_response(err: CartError) -> HttpResponse {
+    log::error!("{:?}", err.to_string());
+
+    match &err {
+        CartError::ItemNotFound | CartError::CouponNotFound => {
+            HttpResponse::NotFound().body(err.to_string())
+        }
+        CartError::ProductNotFound
+        | CartError::StoreNotFound
+        | CartError::AddressNotFound
+        | CartError::InvalidDelivery(_)
+        | CartError::InvalidPickupTime
+        | CartError::InvalidItem(_)
+        | CartError::InvalidCoupon(_)
+        | CartError::InvalidRedemption(_)
+        | CartError::EmptyCart => HttpResponse::BadRequest().body(err.to_string()),
+        CartError::UnavailableItems
+        | CartError::CartChanged
+        | CartError::StoreClosed
+        | CartError::SlotFull => HttpResponse::Conflict().body(err.to_string()),
+        _ => HttpResponse::InternalServerError().body(err.to_string()),
+    }
+}
+
+pub async fn get_cart(
+    cart_service: CartService,
+    user: web::ReqData<UserPayload>,
+) -> impl Responder {
+    match cart_service.get_cart(user.get_id()).await {
+        Ok(cart) => HttpResponse::Ok().json(CartResponse::from_cart_core(cart)),
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn add_item(
+    cart_service: CartService,
+    user: web::ReqData<UserPayload>,
+    item_request: web::Json<CartItemRequest>,
+) -> impl Responder {
+    if !item_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match cart_service
+        .add_item(user.get_id(), item_request.to_cart_item_core())
+        .await
+    {
+        Ok(cart) => HttpResponse::Created().json(CartResponse::from_cart_core(cart)),
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn update_item(
+    cart_service: CartService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+    quantity_request: web::Json<CartItemQuantityRequest>,
+) -> impl Responder {
+    let item_id = path.into_inner();
+
+    if !quantity_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match cart_service
+        .update_item(user.get_id(), item_id, quantity_request.quantity)
+        .await
+    {
+        Ok(cart) => HttpResponse::Ok().json(CartResponse::from_cart_core(cart)),
+        Err(err) => {
+            log::debug!("Cart item ID: {:?}", item_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn remove_item(
+    cart_service: CartService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let item_id = path.into_inner();
+
+    match cart_service.remove_item(user.get_id(), item_id).await {
+        Ok(cart) => HttpResponse::Ok().json(CartResponse::from_cart_core(cart)),
+        Err(err) => {
+            log::debug!("Cart item ID: {:?}", item_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn clear_cart(
+    cart_service: CartService,
+    user: web::ReqData<UserPayload>,
+) -> impl Responder {
+    match cart_service.clear_cart(user.get_id()).await {
+        Ok(_) => HttpResponse::Ok().json("Cart cleared."),
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn apply_coupon(
+    cart_service: CartService,
+    user: web::ReqData<UserPayload>,
+    coupon_request: web::Json<CouponRequest>,
+) -> impl Responder {
+    if !coupon_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match cart_service
+        .apply_coupon(user.get_id(), &coupon_request.code)
+        .await
+    {
+        Ok(cart) => HttpResponse::Ok().json(CartResponse::from_cart_core(cart)),
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn remove_coupon(
+    cart_service: CartService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let code = path.into_inner();
+
+    match cart_service.remove_coupon(user.get_id(), &code).await {
+        Ok(cart) => HttpResponse::Ok().json(CartResponse::from_cart_core(cart)),
+        Err(err) => {
+            log::debug!("Coupon code: {:?}", code);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn checkout(
+    cart_service: CartService,
+    user: web::ReqData<UserPayload>,
+    checkout_request: web::Json<CheckoutRequest>,
+) -> impl Responder {
+    if !checkout_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match cart_service
+        .checkout(
+            &user,
+            checkout_request.to_fulfilment_core(),
+            checkout_request.payment_method,
+            checkout_request.tip,
+            checkout_request.redeem_points,
+        )
+        .await
+    {
+        Ok(order) => HttpResponse::Created().json(OrderResponse::from_order_core(order)),
+        Err(err) => error_response(err),
+    }
+}
+
+#[cfg(test)]
+mod cart_handler_tests {
+    use super::*;
+    use crate::core::{
+        entities::user_role::UserRole, services::cart_service_stub::CartServiceStub,
+    };
+    use actix_web::{
+        dev::ServiceResponse,
+        http::StatusCode,
+        test::{call_service, init_service, TestRequest},
+        web, App, HttpMessage, Route,
+    };
+
+    async fn process_test(
+        path: &str,
+        req: TestRequest,
+        route: Route,
+        status_code: i32,
+    ) -> ServiceResponse {
+        let cart_service: std::sync::Arc<dyn ICartService> =
+            std::sync::Arc::new(CartServiceStub { status_code });
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(cart_service.clone()))
+                .route(path, route),
+        )
+        .await;
+
+        let req = req.to_request();
+        req.extensions_mut().insert(UserPayload::new(
+            1,
+            "Keneth".to_string(),
+            "Riera".to_string(),
+            UserRole::Client,
+            30,
+        ));
+
+        call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_get_cart_ok() {
+        let resp = process_test(
+            "/users/me/cart",
+            TestRequest::get().uri("/users/me/cart"),
+            web::get().to(get_cart),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_add_item_ok() {
+        let resp = process_test(
+            "/users/me/cart/items",
+            TestRequest::post()
+                .uri("/users/me/cart/items")
+                .set_json(CartItemRequest::dummy()),
+            web::post().to(add_item),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_add_item_bad_request_when_quantity_is_zero() {
+        let mut item_request = CartItemRequest::dummy();
+        item_request.quantity = 0;
+
+        let resp = process_test(
+            "/users/me/cart/items",
+            TestRequest::post()
+                .uri("/users/me/cart/items")
+                .set_json(item_request),
+            web::post().to(add_item),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_update_item_not_found_error() {
+        let resp = process_test(
+            "/users/me/cart/items/{item_id}",
+            TestRequest::put()
+                .uri("/users/me/cart/items/1")
+                .set_json(CartItemQuantityRequest { quantity: 3 }),
+            web::put().to(update_item),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_remove_item_ok() {
+        let resp = process_test(
+            "/users/me/cart/items/{item_id}",
+            TestRequest::delete().uri("/users/me/cart/items/1"),
+            web::delete().to(remove_item),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_clear_cart_internal_server_error() {
+        let resp = process_test(
+            "/users/me/cart",
+            TestRequest::delete().uri("/users/me/cart"),
+            web::delete().to(clear_cart),
+            500,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[actix_web::test]
+    async fn test_apply_coupon_ok() {
+        let resp = process_test(
+            "/users/me/cart/coupons",
+            TestRequest::post()
+                .uri("/users/me/cart/coupons")
+                .set_json(CouponRequest {
+                    code: "HOLA10".to_string(),
+                }),
+            web::post().to(apply_coupon),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_apply_coupon_bad_request_when_code_is_blank() {
+        let resp = process_test(
+            "/users/me/cart/coupons",
+            TestRequest::post()
+                .uri("/users/me/cart/coupons")
+                .set_json(CouponRequest {
+                    code: " ".to_string(),
+                }),
+            web::post().to(apply_coupon),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_remove_coupon_not_found_error() {
+        let resp = process_test(
+            "/users/me/cart/coupons/{code}",
+            TestRequest::delete().uri("/users/me/cart/coupons/HOLA10"),
+            web::delete().to(remove_coupon),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_checkout_ok() {
+        let resp = process_test(
+            "/users/me/cart/checkout",
+            TestRequest::post()
+                .uri("/users/me/cart/checkout")
+                .set_json(CheckoutRequest::dummy()),
+            web::post().to(checkout),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_checkout_bad_request_when_tip_is_negative() {
+        let resp = process_test(
+            "/users/me/cart/checkout",
+            TestRequest::post()
+                .uri("/users/me/cart/checkout")
+                .set_json(CheckoutRequest {
+                    tip: -100,
+                    ..CheckoutRequest::dummy()
+                }),
+            web::post().to(checkout),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_checkout_bad_request_when_redeem_points_is_negative() {
+        let resp = process_test(
+            "/users/me/cart/checkout",
+            TestRequest::post()
+                .uri("/users/me/cart/checkout")
+                .set_json(CheckoutRequest {
+                    redeem_points: -1,
+                    ..CheckoutRequest::dummy()
+                }),
+            web::post().to(checkout),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_checkout_conflict_when_items_are_unavailable() {
+        let resp = process_test(
+            "/users/me/cart/checkout",
+            TestRequest::post()
+                .uri("/users/me/cart/checkout")
+                .set_json(CheckoutRequest::dummy()),
+            web::post().to(checkout),
+            409,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+}
@@ -0,0 +1,662 @@
+use crate::{
+    core::{
+        entities::user_role::UserRole, errors::catalog_errors::CatalogError,
+        ports::catalog_port::ICatalogService,
+    },
+    infrastructure::api::{
+        dto::{
+            category_request::CategoryRequest,
+            category_response::CategoryResponse,
+            modifier_request::{ModifierGroupRequest, ModifierOptionRequest},
+            modifier_response::{ModifierGroupResponse, ModifierOptionResponse},
+            product_request::{ProductFilter, ProductRequest},
+            product_response::ProductResponse,
+        },
+        middlewares::auth_middleware::AuthenticateMiddlewareFactory,
+    },
+};
+use actix_web::{web, HttpResponse, Responder};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/products")
+            .route("", web::get().to(get_products))
+            .route("/{product_id}", web::get().to(get_product_by_id)),
+    )
+    .service(web::scope("/categories").route("", web::get().to(get_categories)))
+    .service(
+        web::scope("/admin/products")
+            .wrap(AuthenticateMiddlewareFactory::with_roles(vec![
+                UserRole::Administrator,
+            ]))
+            .route("", web::post().to(create_product))
+            .route("/{product_id}", web::put().to(update_product))
+            .route("/{product_id}", web::delete().to(delete_product))
+            .route(
+                "/{product_id}/modifiers/{group_id}",
+                web::post().to(attach_modifier_group),
+            )
+            .route(
+                "/{product_id}/modifiers/{group_id}",
+                web::delete().to(detach_modifier_group),
+            ),
+    )
+    .service(
+        web::scope("/admin/modifiers")
+            .wrap(AuthenticateMiddlewareFactory::with_roles(vec![
+                UserRole::Administrator,
+            ]))
+            .route("", web::get().to(get_modifier_groups))
+            .route("", web::post().to(create_modifier_group))
+            .route("/{group_id}", web::delete().to(delete_modifier_group))
+            .route(
+                "/{group_id}/options",
+                web::post().to(create_modifier_option),
+            )
+            .route(
+                "/{group_id}/options/{option_id}",
+                web::put().to(update_modifier_option),
+            ),
+    )
+    .service(
+        web::scope("/admin/categories")
+            .wrap(AuthenticateMiddlewareFactory::with_roles(vec![
+                UserRole::Administrator,
+            ]))
+            .route("", web::post().to(create_category))
+            .route("/{category_id}", web::put().to(update_category))
+            .route("/{category_id}", web::delete().to(delete_category)),
+    );
+}
+
+type CatalogService = web::Data<std::sync::Arc<dyn ICatalogService>>;
+
+pub async fn get_categories(catalog_service: CatalogService) -> impl Responder {
+    match catalog_service.get_categories().await {
+        Ok(categories) => {
+            HttpResponse::Ok().json(CategoryResponse::from_category_core_vec(categories))
+        }
+        Err(err) => {
+            log::error!("{:?}", err.to_string());
+            HttpResponse::InternalServerError().body(err.to_string())
+        }
+    }
+}
+
+pub async fn create_category(
+    catalog_service: CatalogService,
+    category_request: web::Json<CategoryRequest>,
+) -> impl Responder {
+    if !category_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match catalog_service
+        .create_category(category_request.to_category_core())
+        .await
+    {
+        Ok(category) => {
+            HttpResponse::Created().json(CategoryResponse::from_category_core(category))
+        }
+        Err(err) => {
+            log::error!("{:?}", err.to_string());
+            HttpResponse::InternalServerError().body(err.to_string())
+        }
+    }
+}
+
+pub async fn update_category(
+    catalog_service: CatalogService,
+    path: web::Path<i32>,
+    category_request: web::Json<CategoryRequest>,
+) -> impl Responder {
+    let category_id = path.into_inner();
+
+    if !category_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match catalog_service
+        .update_category(category_id, category_request.to_category_core())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json("Category updated."),
+        Err(err) => match &err {
+            CatalogError::CategoryNotFound => {
+                log::debug!("Category ID: {:?}", category_id);
+                log::error!("{:?}", err.to_string());
+                HttpResponse::NotFound().body(err.to_string())
+            }
+            _ => {
+                log::error!("{:?}", err.to_string());
+                HttpResponse::InternalServerError().body(err.to_string())
+            }
+        },
+    }
+}
+
+pub async fn delete_category(
+    catalog_service: CatalogService,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let category_id = path.into_inner();
+
+    match catalog_service.delete_category(category_id).await {
+        Ok(_) => HttpResponse::Ok().json("Category deleted."),
+        Err(err) => match &err {
+            CatalogError::CategoryNotFound => {
+                log::debug!("Category ID: {:?}", category_id);
+                log::error!("{:?}", err.to_string());
+                HttpResponse::NotFound().body(err.to_string())
+            }
+            _ => {
+                log::error!("{:?}", err.to_string());
+                HttpResponse::InternalServerError().body(err.to_string())
+            }
+        },
+    }
+}
+
+pub async fn get_products(
+    catalog_service: CatalogService,
+    filter: web::Query<ProductFilter>,
+) -> impl Responder {
+    match catalog_service.get_products(filter.category).await {
+        Ok(products) => HttpResponse::Ok().json(ProductResponse::from_product_core_vec(products)),
+        Err(err) => {
+            log::error!("{:?}", err.to_string());
+            HttpResponse::InternalServerError().body(err.to_string())
+        }
+    }
+}
+
+pub async fn get_product_by_id(
+    catalog_service: CatalogService,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let product_id = path.into_inner();
+
+    match catalog_service.get_product_by_id(product_id).await {
+        Ok(product) => HttpResponse::Ok().json(ProductResponse::from_product_core(product)),
+        Err(err) => match &err {
+            CatalogError::ProductNotFound => {
+                log::debug!("Product ID: {:?}", product_id);
+                log::error!("{:?}", err.to_string());
+                HttpResponse::NotFound().body(err.to_string())
+            }
+            _ => {
+                log::error!("{:?}", err.to_string());
+                HttpResponse::InternalServerError().body(err.to_string())
+            }
+        },
+    }
+}
+
+pub async fn create_product(
+    catalog_service: CatalogService,
+    product_request: web::Json<ProductRequest>,
+) -> impl Responder {
+    if !product_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match catalog_service
+        .create_product(product_request.to_product_core())
+        .await
+    {
+        Ok(product) => HttpResponse::Created().json(ProductResponse::from_product_core(product)),
+        Err(err) => match &err {
+            CatalogError::CategoryNotFound => {
+                log::error!("{:?}", err.to_string());
+                HttpResponse::NotFound().body(err.to_string())
+            }
+            _ => {
+                log::error!("{:?}", err.to_string());
+                HttpResponse::InternalServerError().body(err.to_string())
+            }
+        },
+    }
+}
+
+pub async fn update_product(
+    catalog_service: CatalogService,
+    path: web::Path<i32>,
+    product_request: web::Json<ProductRequest>,
+) -> impl Responder {
+    let product_id = path.into_inner();
+
+    if !product_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match catalog_service
+        .update_product(product_id, product_request.to_product_core())
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json("Product updated."),
+        Err(err) => match &err {
+            CatalogError::ProductNotFound | CatalogError::CategoryNotFound => {
+                log::debug!("Product ID: {:?}", product_id);
+                log::error!("{:?}", err.to_string());
+                HttpResponse::NotFound().body(err.to_string())
+            }
+            _ => {
+                log::error!("{:?}", err.to_string());
+                HttpResponse::InternalServerError().body(err.to_string())
+            }
+        },
+    }
+}
+
+pub async fn delete_product(
+    catalog_service: CatalogService,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let product_id = path.into_inner();
+
+    match catalog_service.delete_product(product_id).await {
+        Ok(_) => HttpResponse::Ok().json("Product deleted."),
+        Err(err) => match &err {
+            CatalogError::ProductNotFound => {
+                log::debug!("Product ID: {:?}", product_id);
+                log::error!("{:?}", err.to_string());
+                HttpResponse::NotFound().body(err.to_string())
+            }
+            _ => {
+                log::error!("{:?}", err.to_string());
+                HttpResponse::InternalServerError().body(err.to_string())
+            }
+        },
+    }
+}
+
+pub async fn get_modifier_groups(catalog_service: CatalogService) -> impl Responder {
+    match catalog_service.get_modifier_groups().await {
+        Ok(groups) => {
+            HttpResponse::Ok().json(ModifierGroupResponse::from_modifier_group_core_vec(groups))
+        }
+        Err(err) => {
+            log::error!("{:?}", err.to_string());
+            HttpResponse::InternalServerError().body(err.to_string())
+        }
+    }
+}
+
+pub async fn create_modifier_group(
+    catalog_service: CatalogService,
+    group_request: web::Json<ModifierGroupRequest>,
+) -> impl Responder {
+    if !group_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match catalog_service
+        .create_modifier_group(group_request.to_modifier_group_core())
+        .await
+    {
+        Ok(group) => {
+            HttpResponse::Created().json(ModifierGroupResponse::from_modifier_group_core(group))
+        }
+        Err(err) => {
+            log::error!("{:?}", err.to_string());
+            HttpResponse::InternalServerError().body(err.to_string())
+        }
+    }
+}
+
+pub async fn delete_modifier_group(
+    catalog_service: CatalogService,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let group_id = path.into_inner();
+
+    match catalog_service.delete_modifier_group(group_id).await {
+        Ok(_) => HttpResponse::Ok().json("Modifier group deleted."),
+        Err(err) => match &err {
+            CatalogError::ModifierGroupNotFound => {
+                log::debug!("Modifier group ID: {:?}", group_id);
+                log::error!("{:?}", err.to_string());
+                HttpResponse::NotFound().body(err.to_string())
+            }
+            _ => {
+                log::error!("{:?}", err.to_string());
+                HttpResponse::InternalServerError().body(err.to_string())
+            }
+        },
+    }
+}
+
+pub async fn create_modifier_option(
+    catalog_service: CatalogService,
+    path: web::Path<i32>,
+    option_request: web::Json<ModifierOptionRequest>,
+) -> impl Responder {
+    let group_id = path.into_inner();
+
+    if !option_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match catalog_service
+        .create_modifier_option(group_id, option_request.to_modifier_option_core())
+        .await
+    {
+        Ok(option) => {
+            HttpResponse::Created().json(ModifierOptionResponse::from_modifier_option_core(option))
+        }
+        Err(err) => match &err {
+            CatalogError::ModifierGroupNotFound => {
+                log::debug!("Modifier group ID: {:?}", group_id);
+                log::error!("{:?}", err.to_string());
+                HttpResponse::NotFound().body(err.to_string())
+            }
+            _ => {
+                log::error!("{:?}", err.to_string());
+                HttpResponse::InternalServerError().body(err.to_string())
+            }
+        },
+    }
+}
+
+pub async fn update_modifier_option(
+    catalog_service: CatalogService,
+    path: web::Path<(i32, i32)>,
+    option_request: web::Json<ModifierOptionRequest>,
+) -> impl Responder {
+    let (group_id, option_id) = path.into_inner();
+
+    if !option_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match catalog_service
+        .update_modifier_option(
+            group_id,
+            option_id,
+            option_request.to_modifier_option_core(),
+        )
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json("Modifier option updated."),
+        Err(err) => match &err {
+            CatalogError::ModifierOptionNotFound => {
+                log::debug!("Modifier option ID: {:?}", option_id);
+                log::error!("{:?}", err.to_string());
+                HttpResponse::NotFound().body(err.to_string())
+            }
+            _ => {
+                log::error!("{:?}", err.to_string());
+                HttpResponse::InternalServerError().body(err.to_string())
+            }
+        },
+    }
+}
+
+pub async fn attach_modifier_group(
+    catalog_service: CatalogService,
+    path: web::Path<(i32, i32)>,
+) -> impl Responder {
+    let (product_id, group_id) = path.into_inner();
+
+    match catalog_service
+        .attach_modifier_group(product_id, group_id)
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json("Modifier group attached."),
+        Err(err) => match &err {
+            CatalogError::ProductNotFound | CatalogError::ModifierGroupNotFound => {
+                log::debug!("Product ID: {:?}, group ID: {:?}", product_id, group_id);
+                log::error!("{:?}", err.to_string());
+                HttpResponse::NotFound().body(err.to_string())
+            }
+            _ => {
+                log::error!("{:?}", err.to_string());
+                HttpResponse::InternalServerError().body(err.to_string())
+            }
+        },
+    }
+}
+
+pub async fn detach_modifier_group(
+    catalog_service: CatalogService,
+    path: web::Path<(i32, i32)>,
+) -> impl Responder {
+    let (product_id, group_id) = path.into_inner();
+
+    match catalog_service
+        .detach_modifier_group(product_id, group_id)
+        .await
+    {
+        Ok(_) => HttpResponse::Ok().json("Modifier group detached."),
+        Err(err) => match &err {
+            CatalogError::ModifierGroupNotFound => {
+                log::debug!("Product ID: {:?}, group ID: {:?}", product_id, group_id);
+                log::error!("{:?}", err.to_string());
+                HttpResponse::NotFound().body(err.to_string())
+            }
+            _ => {
+                log::error!("{:?}", err.to_string());
+                HttpResponse::InternalServerError().body(err.to_string())
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod catalog_handler_tests {
+    use super::*;
+    use crate::core::services::catalog_service_stub::CatalogServiceStub;
+    use actix_web::{
+        dev::ServiceResponse,
+        http::StatusCode,
+        test::{call_service, init_service, TestRequest},
+        web, App, Route,
+    };
+
+    async fn process_test(
+        path: &str,
+        req: TestRequest,
+        route: Route,
+        status_code: i32,
+    ) -> ServiceResponse {
+        let catalog_service: std::sync::Arc<dyn ICatalogService> =
+            std::sync::Arc::new(CatalogServiceStub { status_code });
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(catalog_service.clone()))
+                .route(path, route),
+        )
+        .await;
+
+        call_service(&app, req.to_request()).await
+    }
+
+    #[actix_web::test]
+    async fn test_get_categories_ok() {
+        let resp = process_test(
+            "/categories",
+            TestRequest::get().uri("/categories"),
+            web::get().to(get_categories),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_create_category_ok() {
+        let resp = process_test(
+            "/admin/categories",
+            TestRequest::post()
+                .uri("/admin/categories")
+                .set_json(CategoryRequest::dummy()),
+            web::post().to(create_category),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_category_internal_server_error() {
+        let resp = process_test(
+            "/admin/categories/{category_id}",
+            TestRequest::delete().uri("/admin/categories/1"),
+            web::delete().to(delete_category),
+            500,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[actix_web::test]
+    async fn test_get_products_ok() {
+        let resp = process_test(
+            "/products",
+            TestRequest::get().uri("/products?category=1"),
+            web::get().to(get_products),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_product_by_id_ok() {
+        let resp = process_test(
+            "/products/{product_id}",
+            TestRequest::get().uri("/products/1"),
+            web::get().to(get_product_by_id),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_product_by_id_not_found_error() {
+        let resp = process_test(
+            "/products/{product_id}",
+            TestRequest::get().uri("/products/1"),
+            web::get().to(get_product_by_id),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_create_product_ok() {
+        let resp = process_test(
+            "/admin/products",
+            TestRequest::post()
+                .uri("/admin/products")
+                .set_json(ProductRequest::dummy()),
+            web::post().to(create_product),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_create_product_bad_request_when_price_is_negative() {
+        let mut product_request = ProductRequest::dummy();
+        product_request.price = -1;
+
+        let resp = process_test(
+            "/admin/products",
+            TestRequest::post()
+                .uri("/admin/products")
+                .set_json(product_request),
+            web::post().to(create_product),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_update_product_not_found_error() {
+        let resp = process_test(
+            "/admin/products/{product_id}",
+            TestRequest::put()
+                .uri("/admin/products/1")
+                .set_json(ProductRequest::dummy()),
+            web::put().to(update_product),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_product_internal_server_error() {
+        let resp = process_test(
+            "/admin/products/{product_id}",
+            TestRequest::delete().uri("/admin/products/1"),
+            web::delete().to(delete_product),
+            500,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[actix_web::test]
+    async fn test_create_modifier_group_ok() {
+        let resp = process_test(
+            "/admin/modifiers",
+            TestRequest::post()
+                .uri("/admin/modifiers")
+                .set_json(ModifierGroupRequest::dummy()),
+            web::post().to(create_modifier_group),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_create_modifier_group_bad_request_when_max_is_lower_than_min() {
+        let mut group_request = ModifierGroupRequest::dummy();
+        group_request.min_selections = 2;
+
+        let resp = process_test(
+            "/admin/modifiers",
+            TestRequest::post()
+                .uri("/admin/modifiers")
+                .set_json(group_request),
+            web::post().to(create_modifier_group),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_create_modifier_option_not_found_error() {
+        let resp = process_test(
+            "/admin/modifiers/{group_id}/options",
+            TestRequest::post()
+                .uri("/admin/modifiers/1/options")
+                .set_json(ModifierOptionRequest::dummy()),
+            web::post().to(create_modifier_option),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_attach_modifier_group_ok() {
+        let resp = process_test(
+            "/admin/products/{product_id}/modifiers/{group_id}",
+            TestRequest::post().uri("/admin/products/1/modifiers/1"),
+            web::post().to(attach_modifier_group),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
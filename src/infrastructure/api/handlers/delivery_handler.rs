@@ -0,0 +1,420 @@
+use crate::{
+    core::{
+        entities::{user_payload::UserPayload, user_role::UserRole},
+        errors::delivery_errors::DeliveryError,
+        ports::delivery_port::IDeliveryService,
+    },
+    infrastructure::api::{
+        dto::{
+            delivery_request::{AddressRequest, DeliveryZoneRequest},
+            delivery_response::{AddressResponse, DeliveryZoneResponse},
+        },
+        middlewares::auth_middleware::AuthenticateMiddlewareFactory,
+    },
+};
+use actix_web::{web, HttpResponse, Responder};
+
+/// Rutas de la libreta de direcciones y de las zonas de reparto. Igual que las del carrito, se
+/// deben registrar antes que el scope `/users`.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/users/me/addresses")
+            .wrap(AuthenticateMiddlewareFactory::new())
+            .route("", web::get().to(get_addresses))
+            .route("", web::post().to(create_address))
+            .route("/{address_id}", web::put().to(update_address))
+            .route("/{address_id}", web::delete().to(delete_address))
+            .route(
+                "/{address_id}/delivery-quote",
+                web::get().to(quote_delivery),
+            ),
+    )
+    .service(
+        web::scope("/admin/delivery-zones")
+            .wrap(AuthenticateMiddlewareFactory::with_roles(vec![
+                UserRole::Administrator,
+            ]))
+            .route("", web::get().to(get_delivery_zones))
+            .route("", web::post().to(create_delivery_zone))
+            .route("/{zone_id}", web::put().to(update_delivery_zone))
+            .route("/{zone_id}", web::delete().to(delete_delivery_zone)),
+    );
+}
+
+type DeliveryService = web::Data<std::sync::Arc<dyn IDeliveryService>>;
+
+fn error_response(err: DeliveryError) -> HttpResponse {
+    log::error!("{:?}", err.to_string());
+
+    match &err {
+        DeliveryError::AddressNotFound | DeliveryError::ZoneNotFound => {
+            HttpResponse::NotFound().body(err.to_string())
+        }
+        DeliveryError::InvalidAddress(_) | DeliveryError::InvalidZone(_) => {
+            HttpResponse::BadRequest().body(err.to_string())
+        }
+        DeliveryError::OutsideDeliveryArea | DeliveryError::BelowMinimumOrder(_) => {
+            HttpResponse::Conflict().body(err.to_string())
+        }
+        _ => HttpResponse::InternalServerError().body(err.to_string()),
+    }
+}
+
+pub async fn get_addresses(
+    delivery_service: DeliveryService,
+    user: web::ReqData<UserPayload>,
+) -> impl Responder {
+    match delivery_service.get_addresses(&user).await {
+        Ok(addresses) => HttpResponse::Ok().json(AddressResponse::from_address_core_vec(addresses)),
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn create_address(
+    delivery_service: DeliveryService,
+    user: web::ReqData<UserPayload>,
+    address_request: web::Json<AddressRequest>,
+) -> impl Responder {
+    if !address_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match delivery_service
+        .create_address(&user, address_request.to_address_core())
+        .await
+    {
+        Ok(address) => HttpResponse::Created().json(AddressResponse::from_address_core(address)),
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn update_address(
+    delivery_service: DeliveryService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+    address_request: web::Json<AddressRequest>,
+) -> impl Responder {
+    let address_id = path.into_inner();
+
+    if !address_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match delivery_service
+        .update_address(&user, address_id, address_request.to_address_core())
+        .await
+    {
+        Ok(address) => HttpResponse::Ok().json(AddressResponse::from_address_core(address)),
+        Err(err) => {
+            log::debug!("Address ID: {:?}", address_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn delete_address(
+    delivery_service: DeliveryService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let address_id = path.into_inner();
+
+    match delivery_service.delete_address(&user, address_id).await {
+        Ok(_) => HttpResponse::Ok().json("Address deleted."),
+        Err(err) => {
+            log::debug!("Address ID: {:?}", address_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn quote_delivery(
+    delivery_service: DeliveryService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let address_id = path.into_inner();
+
+    match delivery_service.quote_delivery(&user, address_id).await {
+        Ok(zone) => HttpResponse::Ok().json(DeliveryZoneResponse::from_delivery_zone_core(zone)),
+        Err(err) => {
+            log::debug!("Address ID: {:?}", address_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn get_delivery_zones(delivery_service: DeliveryService) -> impl Responder {
+    match delivery_service.get_delivery_zones().await {
+        Ok(zones) => {
+            HttpResponse::Ok().json(DeliveryZoneResponse::from_delivery_zone_core_vec(zones))
+        }
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn create_delivery_zone(
+    delivery_service: DeliveryService,
+    zone_request: web::Json<DeliveryZoneRequest>,
+) -> impl Responder {
+    if !zone_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match delivery_service
+        .create_delivery_zone(zone_request.to_delivery_zone_core())
+        .await
+    {
+        Ok(zone) => {
+            HttpResponse::Created().json(DeliveryZoneResponse::from_delivery_zone_core(zone))
+        }
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn update_delivery_zone(
+    delivery_service: DeliveryService,
+    path: web::Path<i32>,
+    zone_request: web::Json<DeliveryZoneRequest>,
+) -> impl Responder {
+    let zone_id = path.into_inner();
+
+    if !zone_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match delivery_service
+        .update_delivery_zone(zone_id, zone_request.to_delivery_zone_core())
+        .await
+    {
+        Ok(zone) => HttpResponse::Ok().json(DeliveryZoneResponse::from_delivery_zone_core(zone)),
+        Err(err) => {
+            log::debug!("Delivery zone ID: {:?}", zone_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn delete_delivery_zone(
+    delivery_service: DeliveryService,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let zone_id = path.into_inner();
+
+    match delivery_service.delete_delivery_zone(zone_id).await {
+        Ok(_) => HttpResponse::Ok().json("Delivery zone deleted."),
+        Err(err) => {
+            log::debug!("Delivery zone ID: {:?}", zone_id);
+            error_response(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod delivery_handler_tests {
+    use super::*;
+    use crate::core::services::delivery_service_stub::DeliveryServiceStub;
+    use actix_web::{
+        dev::ServiceResponse,
+        http::StatusCode,
+        test::{call_service, init_service, read_body_json, TestRequest},
+        web, App, HttpMessage, Route,
+    };
+
+    async fn process_test(
+        path: &str,
+        req: TestRequest,
+        route: Route,
+        status_code: i32,
+    ) -> ServiceResponse {
+        let delivery_service: std::sync::Arc<dyn IDeliveryService> =
+            std::sync::Arc::new(DeliveryServiceStub { status_code });
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(delivery_service.clone()))
+                .route(path, route),
+        )
+        .await;
+
+        let req = req.to_request();
+        req.extensions_mut().insert(UserPayload::new(
+            1,
+            "Keneth".to_string(),
+            "Riera".to_string(),
+            UserRole::Client,
+            30,
+        ));
+
+        call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_get_addresses_ok() {
+        let resp = process_test(
+            "/users/me/addresses",
+            TestRequest::get().uri("/users/me/addresses"),
+            web::get().to(get_addresses),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body: serde_json::Value = read_body_json(resp).await;
+        assert_eq!(body[0]["city"], "Managua");
+    }
+
+    #[actix_web::test]
+    async fn test_create_address_created() {
+        let resp = process_test(
+            "/users/me/addresses",
+            TestRequest::post()
+                .uri("/users/me/addresses")
+                .set_json(AddressRequest::dummy()),
+            web::post().to(create_address),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_create_address_bad_request() {
+        let mut address_request = AddressRequest::dummy();
+        address_request.city = " ".to_string();
+
+        let resp = process_test(
+            "/users/me/addresses",
+            TestRequest::post()
+                .uri("/users/me/addresses")
+                .set_json(address_request),
+            web::post().to(create_address),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_update_address_not_found() {
+        let resp = process_test(
+            "/users/me/addresses/{address_id}",
+            TestRequest::put()
+                .uri("/users/me/addresses/2")
+                .set_json(AddressRequest::dummy()),
+            web::put().to(update_address),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_address_ok() {
+        let resp = process_test(
+            "/users/me/addresses/{address_id}",
+            TestRequest::delete().uri("/users/me/addresses/1"),
+            web::delete().to(delete_address),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_quote_delivery_ok() {
+        let resp = process_test(
+            "/users/me/addresses/{address_id}/delivery-quote",
+            TestRequest::get().uri("/users/me/addresses/1/delivery-quote"),
+            web::get().to(quote_delivery),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body: serde_json::Value = read_body_json(resp).await;
+        assert_eq!(body["fee"], 300);
+        assert_eq!(body["minimum_order"], 1000);
+    }
+
+    #[actix_web::test]
+    async fn test_quote_delivery_outside_area() {
+        let resp = process_test(
+            "/users/me/addresses/{address_id}/delivery-quote",
+            TestRequest::get().uri("/users/me/addresses/1/delivery-quote"),
+            web::get().to(quote_delivery),
+            409,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn test_get_delivery_zones_ok() {
+        let resp = process_test(
+            "/admin/delivery-zones",
+            TestRequest::get().uri("/admin/delivery-zones"),
+            web::get().to(get_delivery_zones),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_create_delivery_zone_created() {
+        let resp = process_test(
+            "/admin/delivery-zones",
+            TestRequest::post()
+                .uri("/admin/delivery-zones")
+                .set_json(DeliveryZoneRequest::dummy()),
+            web::post().to(create_delivery_zone),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_create_delivery_zone_bad_request() {
+        let mut zone_request = DeliveryZoneRequest::dummy();
+        zone_request.fee = -1;
+
+        let resp = process_test(
+            "/admin/delivery-zones",
+            TestRequest::post()
+                .uri("/admin/delivery-zones")
+                .set_json(zone_request),
+            web::post().to(create_delivery_zone),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_update_delivery_zone_ok() {
+        let resp = process_test(
+            "/admin/delivery-zones/{zone_id}",
+            TestRequest::put()
+                .uri("/admin/delivery-zones/1")
+                .set_json(DeliveryZoneRequest::dummy()),
+            web::put().to(update_delivery_zone),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_delivery_zone_internal_server_error() {
+        let resp = process_test(
+            "/admin/delivery-zones/{zone_id}",
+            TestRequest::delete().uri("/admin/delivery-zones/1"),
+            web::delete().to(delete_delivery_zone),
+            500,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    core::{entities::health::HealthReport, ports::health_port::IHealthService},
+    infrastructure::api::dto::health_response::HealthResponse,
+};
+use actix_web::{web, HttpResponse, Responder};
+
+/// Sondas del orquestador. No pasan por el middleware de autenticación: deben responder aunque
+/// no haya usuarios ni tokens.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/health")
+            .route("/live", web::get().to(live))
+            .route("/ready", web::get().to(ready)),
+    );
+}
+
+type HealthService = web::Data<std::sync::Arc<dyn IHealthService>>;
+
+/// El proceso responde; no consulta ninguna dependencia.
+pub async fn live() -> impl Responder {
+    HttpResponse::Ok().json(HealthResponse::from_health_report_core(
+        HealthReport::from_components(vec![]),
+    ))
+}
+
+/// Responde 503 mientras alguna dependencia no esté disponible, para que no se le envíe tráfico.
+pub async fn ready(health_service: HealthService) -> impl Responder {
+    let report = health_service.readiness().await;
+
+    if report.is_up() {
+        HttpResponse::Ok().json(HealthResponse::from_health_report_core(report))
+    } else {
+        log::warn!(
+            "Not ready: {}",
+            report
+                .components
+                .iter()
+                .filter_map(|component| component
+                    .error
+                    .as_ref()
+                    .map(|err| format!("{}: {}", component.name, err)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        HttpResponse::ServiceUnavailable().json(HealthResponse::from_health_report_core(report))
+    }
+}
+
+#[cfg(test)]
+mod health_handler_tests {
+    use super::*;
+    use crate::core::services::health_service_stub::HealthServiceStub;
+    use actix_web::{
+        dev::ServiceResponse,
+        http::StatusCode,
+        test::{call_service, init_service, read_body_json, TestRequest},
+        App,
+    };
+
+    async fn process_test(path: &str, status_code: i32) -> ServiceResponse {
+        let health_service: std::sync::Arc<dyn IHealthService> =
+            std::sync::Arc::new(HealthServiceStub { status_code });
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(health_service))
+                .configure(routes),
+        )
+        .await;
+
+        call_service(&app, TestRequest::get().uri(path).to_request()).await
+    }
+
+    #[actix_web::test]
+    async fn test_live_ok() {
+        let resp = process_test("/health/live", 503).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body: serde_json::Value = read_body_json(resp).await;
+        assert_eq!(body["status"], "up");
+    }
+
+    #[actix_web::test]
+    async fn test_ready_ok() {
+        let resp = process_test("/health/ready", 200).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body: serde_json::Value = read_body_json(resp).await;
+        assert_eq!(body["status"], "up");
+        assert_eq!(body["components"][0]["name"], "database");
+        assert_eq!(body["components"][0]["latency_ms"], 1.5);
+    }
+
+    #[actix_web::test]
+    async fn test_ready_service_unavailable() {
+        let resp = process_test("/health/ready", 503).await;
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        let body: serde_json::Value = read_body_json(resp).await;
+        assert_eq!(body["status"], "down");
+        assert_eq!(body["components"][0]["error"], "connection refused");
+    }
+}
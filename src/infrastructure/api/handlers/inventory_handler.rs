@@ -0,0 +1,454 @@
+use crate::{
+    core::{
+        entities::{user_payload::UserPayload, user_role::UserRole},
+        errors::inventory_errors::InventoryError,
+        ports::inventory_port::IInventoryService,
+    },
+    infrastructure::api::{
+        dto::{
+            inventory_request::{IngredientRequest, RecipeRequest, StockAdjustmentRequest},
+            inventory_response::{IngredientResponse, RecipeLineResponse, StockAdjustmentResponse},
+        },
+        middlewares::auth_middleware::AuthenticateMiddlewareFactory,
+    },
+};
+use actix_web::{web, HttpResponse, Responder};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/admin/inventory")
+            .wrap(AuthenticateMiddlewareFactory::with_roles(vec![
+                UserRole::Administrator,
+            ]))
+            .route("/ingredients", web::get().to(get_ingredients))
+            .route("/ingredients", web::post().to(create_ingredient))
+            .route(
+                "/ingredients/{ingredient_id}",
+                web::put().to(update_ingredient),
+            )
+            .route(
+                "/ingredients/{ingredient_id}",
+                web::delete().to(delete_ingredient),
+            )
+            .route(
+                "/ingredients/{ingredient_id}/adjustments",
+                web::get().to(get_adjustments),
+            )
+            .route(
+                "/ingredients/{ingredient_id}/adjustments",
+                web::post().to(adjust_stock),
+            )
+            .route("/alerts", web::get().to(get_low_stock_ingredients))
+            .route(
+                "/products/{product_id}/recipe",
+                web::get().to(get_product_recipe),
+            )
+            .route(
+                "/products/{product_id}/recipe",
+                web::put().to(set_product_recipe),
+            )
+            .route(
+                "/options/{option_id}/recipe",
+                web::get().to(get_option_recipe),
+            )
+            .route(
+                "/options/{option_id}/recipe",
+                web::put().to(set_option_recipe),
+            ),
+    );
+}
+
+type InventoryService = web::Data<std::sync::Arc<dyn IInventoryService>>;
+
+fn error_response(err: InventoryError) -> HttpResponse {
+    log::error!("{:?}", err.to_string());
+
+    match &err {
+        InventoryError::IngredientNotFound
+        | InventoryError::ProductNotFound
+        | InventoryError::ModifierOptionNotFound => HttpResponse::NotFound().body(err.to_string()),
+        InventoryError::InvalidRecipe(_) => HttpResponse::BadRequest().body(err.to_string()),
+        InventoryError::InsufficientStock => HttpResponse::Conflict().body(err.to_string()),
+        _ => HttpResponse::InternalServerError().body(err.to_string()),
+    }
+}
+
+pub async fn get_ingredients(inventory_service: InventoryService) -> impl Responder {
+    match inventory_service.get_ingredients().await {
+        Ok(ingredients) => {
+            HttpResponse::Ok().json(IngredientResponse::from_ingredient_core_vec(ingredients))
+        }
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn create_ingredient(
+    inventory_service: InventoryService,
+    ingredient_request: web::Json<IngredientRequest>,
+) -> impl Responder {
+    if !ingredient_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match inventory_service
+        .create_ingredient(ingredient_request.to_ingredient_core())
+        .await
+    {
+        Ok(ingredient) => {
+            HttpResponse::Created().json(IngredientResponse::from_ingredient_core(ingredient))
+        }
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn update_ingredient(
+    inventory_service: InventoryService,
+    path: web::Path<i32>,
+    ingredient_request: web::Json<IngredientRequest>,
+) -> impl Responder {
+    let ingredient_id = path.into_inner();
+
+    if !ingredient_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match inventory_service
+        .update_ingredient(ingredient_id, ingredient_request.to_ingredient_core())
+        .await
+    {
+        Ok(ingredient) => {
+            HttpResponse::Ok().json(IngredientResponse::from_ingredient_core(ingredient))
+        }
+        Err(err) => {
+            log::debug!("Ingredient ID: {:?}", ingredient_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn delete_ingredient(
+    inventory_service: InventoryService,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let ingredient_id = path.into_inner();
+
+    match inventory_service.delete_ingredient(ingredient_id).await {
+        Ok(_) => HttpResponse::Ok().json("Ingredient deleted."),
+        Err(err) => {
+            log::debug!("Ingredient ID: {:?}", ingredient_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn get_low_stock_ingredients(inventory_service: InventoryService) -> impl Responder {
+    match inventory_service.get_low_stock_ingredients().await {
+        Ok(ingredients) => {
+            HttpResponse::Ok().json(IngredientResponse::from_ingredient_core_vec(ingredients))
+        }
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn get_adjustments(
+    inventory_service: InventoryService,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let ingredient_id = path.into_inner();
+
+    match inventory_service.get_adjustments(ingredient_id).await {
+        Ok(adjustments) => HttpResponse::Ok().json(
+            StockAdjustmentResponse::from_stock_adjustment_core_vec(adjustments),
+        ),
+        Err(err) => {
+            log::debug!("Ingredient ID: {:?}", ingredient_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn adjust_stock(
+    inventory_service: InventoryService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+    adjustment_request: web::Json<StockAdjustmentRequest>,
+) -> impl Responder {
+    let ingredient_id = path.into_inner();
+
+    if !adjustment_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match inventory_service
+        .adjust_stock(
+            &user,
+            ingredient_id,
+            adjustment_request.to_stock_adjustment_core(),
+        )
+        .await
+    {
+        Ok(ingredient) => {
+            HttpResponse::Ok().json(IngredientResponse::from_ingredient_core(ingredient))
+        }
+        Err(err) => {
+            log::debug!("Ingredient ID: {:?}", ingredient_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn get_product_recipe(
+    inventory_service: InventoryService,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let product_id = path.into_inner();
+
+    match inventory_service.get_product_recipe(product_id).await {
+        Ok(lines) => HttpResponse::Ok().json(RecipeLineResponse::from_recipe_line_core_vec(lines)),
+        Err(err) => {
+            log::debug!("Product ID: {:?}", product_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn set_product_recipe(
+    inventory_service: InventoryService,
+    path: web::Path<i32>,
+    recipe_request: web::Json<RecipeRequest>,
+) -> impl Responder {
+    let product_id = path.into_inner();
+
+    if !recipe_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match inventory_service
+        .set_product_recipe(product_id, recipe_request.to_recipe_line_core_vec())
+        .await
+    {
+        Ok(lines) => HttpResponse::Ok().json(RecipeLineResponse::from_recipe_line_core_vec(lines)),
+        Err(err) => {
+            log::debug!("Product ID: {:?}", product_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn get_option_recipe(
+    inventory_service: InventoryService,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let option_id = path.into_inner();
+
+    match inventory_service.get_option_recipe(option_id).await {
+        Ok(lines) => HttpResponse::Ok().json(RecipeLineResponse::from_recipe_line_core_vec(lines)),
+        Err(err) => {
+            log::debug!("Option ID: {:?}", option_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn set_option_recipe(
+    inventory_service: InventoryService,
+    path: web::Path<i32>,
+    recipe_request: web::Json<RecipeRequest>,
+) -> impl Responder {
+    let option_id = path.into_inner();
+
+    if !recipe_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match inventory_service
+        .set_option_recipe(option_id, recipe_request.to_recipe_line_core_vec())
+        .await
+    {
+        Ok(lines) => HttpResponse::Ok().json(RecipeLineResponse::from_recipe_line_core_vec(lines)),
+        Err(err) => {
+            log::debug!("Option ID: {:?}", option_id);
+            error_response(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod inventory_handler_tests {
+    use super::*;
+    use crate::core::{
+        entities::inventory::StockAdjustmentReason,
+        services::inventory_service_stub::InventoryServiceStub,
+    };
+    use actix_web::{
+        dev::ServiceResponse,
+        http::StatusCode,
+        test::{call_service, init_service, TestRequest},
+        web, App, HttpMessage, Route,
+    };
+
+    async fn process_test(
+        path: &str,
+        req: TestRequest,
+        route: Route,
+        status_code: i32,
+    ) -> ServiceResponse {
+        let inventory_service: std::sync::Arc<dyn IInventoryService> =
+            std::sync::Arc::new(InventoryServiceStub { status_code });
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(inventory_service.clone()))
+                .route(path, route),
+        )
+        .await;
+
+        let req = req.to_request();
+        req.extensions_mut().insert(UserPayload::new(
+            1,
+            "Keneth".to_string(),
+            "Riera".to_string(),
+            UserRole::Administrator,
+            30,
+        ));
+
+        call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_get_ingredients_ok() {
+        let resp = process_test(
+            "/admin/inventory/ingredients",
+            TestRequest::get().uri("/admin/inventory/ingredients"),
+            web::get().to(get_ingredients),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_create_ingredient_ok() {
+        let resp = process_test(
+            "/admin/inventory/ingredients",
+            TestRequest::post()
+                .uri("/admin/inventory/ingredients")
+                .set_json(IngredientRequest::dummy()),
+            web::post().to(create_ingredient),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_update_ingredient_not_found_error() {
+        let resp = process_test(
+            "/admin/inventory/ingredients/{ingredient_id}",
+            TestRequest::put()
+                .uri("/admin/inventory/ingredients/1")
+                .set_json(IngredientRequest::dummy()),
+            web::put().to(update_ingredient),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_adjust_stock_ok() {
+        let resp = process_test(
+            "/admin/inventory/ingredients/{ingredient_id}/adjustments",
+            TestRequest::post()
+                .uri("/admin/inventory/ingredients/1/adjustments")
+                .set_json(StockAdjustmentRequest::dummy()),
+            web::post().to(adjust_stock),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_adjust_stock_bad_request_when_reason_is_order_consumption() {
+        let mut adjustment_request = StockAdjustmentRequest::dummy();
+        adjustment_request.reason = StockAdjustmentReason::OrderConsumption;
+
+        let resp = process_test(
+            "/admin/inventory/ingredients/{ingredient_id}/adjustments",
+            TestRequest::post()
+                .uri("/admin/inventory/ingredients/1/adjustments")
+                .set_json(adjustment_request),
+            web::post().to(adjust_stock),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_adjust_stock_conflict_when_stock_is_insufficient() {
+        let resp = process_test(
+            "/admin/inventory/ingredients/{ingredient_id}/adjustments",
+            TestRequest::post()
+                .uri("/admin/inventory/ingredients/1/adjustments")
+                .set_json(StockAdjustmentRequest::dummy()),
+            web::post().to(adjust_stock),
+            409,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn test_get_adjustments_ok() {
+        let resp = process_test(
+            "/admin/inventory/ingredients/{ingredient_id}/adjustments",
+            TestRequest::get().uri("/admin/inventory/ingredients/1/adjustments"),
+            web::get().to(get_adjustments),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_low_stock_ingredients_ok() {
+        let resp = process_test(
+            "/admin/inventory/alerts",
+            TestRequest::get().uri("/admin/inventory/alerts"),
+            web::get().to(get_low_stock_ingredients),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_set_product_recipe_bad_request_when_ingredient_is_invalid() {
+        let resp = process_test(
+            "/admin/inventory/products/{product_id}/recipe",
+            TestRequest::put()
+                .uri("/admin/inventory/products/1/recipe")
+                .set_json(RecipeRequest::dummy()),
+            web::put().to(set_product_recipe),
+            400,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_set_option_recipe_ok() {
+        let resp = process_test(
+            "/admin/inventory/options/{option_id}/recipe",
+            TestRequest::put()
+                .uri("/admin/inventory/options/1/recipe")
+                .set_json(RecipeRequest::dummy()),
+            web::put().to(set_option_recipe),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
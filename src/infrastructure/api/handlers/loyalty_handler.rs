@@ -0,0 +1,103 @@
+use crate::{
+    core::{entities::user_payload::UserPayload, ports::loyalty_port::ILoyaltyService},
+    infrastructure::api::{
+        dto::loyalty_response::LoyaltyResponse,
+        middlewares::auth_middleware::AuthenticateMiddlewareFactory,
+    },
+};
+use actix_web::{web, HttpResponse, Responder};
+
+/// Rutas del programa de puntos. Igual que las del carrito, se deben registrar antes que el
+/// scope `/users`.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/users/me/loyalty")
+            .wrap(AuthenticateMiddlewareFactory::new())
+            .route("", web::get().to(get_loyalty)),
+    );
+}
+
+type LoyaltyService = web::Data<std::sync::Arc<dyn ILoyaltyService>>;
+
+pub async fn get_loyalty(
+    loyalty_service: LoyaltyService,
+    user: web::ReqData<UserPayload>,
+) -> impl Responder {
+    match loyalty_service.get_account(&user).await {
+        Ok(account) => HttpResponse::Ok().json(LoyaltyResponse::from_loyalty_account_core(account)),
+        Err(err) => {
+            log::error!("{:?}", err.to_string());
+            HttpResponse::InternalServerError().body(err.to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod loyalty_handler_tests {
+    use super::*;
+    use crate::core::{
+        entities::user_role::UserRole, services::loyalty_service_stub::LoyaltyServiceStub,
+    };
+    use actix_web::{
+        dev::ServiceResponse,
+        http::StatusCode,
+        test::{call_service, init_service, read_body_json, TestRequest},
+        web, App, HttpMessage, Route,
+    };
+
+    async fn process_test(
+        path: &str,
+        req: TestRequest,
+        route: Route,
+        status_code: i32,
+    ) -> ServiceResponse {
+        let loyalty_service: std::sync::Arc<dyn ILoyaltyService> =
+            std::sync::Arc::new(LoyaltyServiceStub { status_code });
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(loyalty_service.clone()))
+                .route(path, route),
+        )
+        .await;
+
+        let req = req.to_request();
+        req.extensions_mut().insert(UserPayload::new(
+            1,
+            "Keneth".to_string(),
+            "Riera".to_string(),
+            UserRole::Client,
+            30,
+        ));
+
+        call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_get_loyalty_ok() {
+        let resp = process_test(
+            "/users/me/loyalty",
+            TestRequest::get().uri("/users/me/loyalty"),
+            web::get().to(get_loyalty),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body: serde_json::Value = read_body_json(resp).await;
+        assert_eq!(body["balance"], 42);
+        assert_eq!(body["history"][0]["kind"], "earned");
+    }
+
+    #[actix_web::test]
+    async fn test_get_loyalty_internal_server_error() {
+        let resp = process_test(
+            "/users/me/loyalty",
+            TestRequest::get().uri("/users/me/loyalty"),
+            web::get().to(get_loyalty),
+            500,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::infrastructure::metrics::prometheus_metrics::PrometheusMetrics;
+use actix_web::{web, HttpResponse, Responder};
+
+/// Métricas para Prometheus. Igual que las sondas de salud, no pasan por la autenticación.
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.route("/metrics", web::get().to(get_metrics));
+}
+
+pub async fn get_metrics(metrics: web::Data<PrometheusMetrics>) -> impl Responder {
+    HttpResponse::Ok()
+        .content_type(prometheus::TEXT_FORMAT)
+        .body(metrics.render())
+}
+
+#[cfg(test)]
+mod metrics_handler_tests {
+    use super::*;
+    use crate::infrastructure::api::middlewares::metrics_middleware::MetricsMiddlewareFactory;
+    use actix_web::{
+        http::{header, StatusCode},
+        test::{call_service, init_service, read_body, TestRequest},
+        App,
+    };
+
+    #[actix_web::test]
+    async fn test_get_metrics_counts_requests_by_route_pattern() {
+        let metrics = PrometheusMetrics::new();
+        let app = init_service(
+            App::new()
+                .wrap(MetricsMiddlewareFactory::new(metrics.clone()))
+                .app_data(web::Data::new(metrics))
+                .route("/orders/{id}", web::get().to(HttpResponse::Ok))
+                .configure(routes),
+        )
+        .await;
+
+        for uri in ["/orders/1", "/orders/2", "/missing"] {
+            call_service(&app, TestRequest::get().uri(uri).to_request()).await;
+        }
+        let resp = call_service(&app, TestRequest::get().uri("/metrics").to_request()).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(header::CONTENT_TYPE).unwrap(),
+            prometheus::TEXT_FORMAT
+        );
+
+        let body = String::from_utf8(read_body(resp).await.to_vec()).unwrap();
+        assert!(body.contains(
+            "cafelatte_http_requests_total{method=\"GET\",route=\"/orders/{id}\",status=\"200\"} 2"
+        ));
+        assert!(body.contains(
+            "cafelatte_http_requests_total{method=\"GET\",route=\"unmatched\",status=\"404\"} 1"
+        ));
+    }
+}
@@ -1 +1,13 @@
+pub mod cart_handler;
+pub mod catalog_handler;
+pub mod delivery_handler;
+pub mod health_handler;
+pub mod inventory_handler;
+pub mod loyalty_handler;
+pub mod metrics_handler;
+pub mod order_handler;
+pub mod payment_handler;
+pub mod promotion_handler;
+pub mod queue_handler;
+pub mod store_handler;
 pub mod user_handler;
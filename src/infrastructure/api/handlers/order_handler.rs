@@ -0,0 +1,365 @@
+use crate::{
+    core::{
+        entities::{user_payload::UserPayload, user_role::UserRole},
+        errors::order_errors::OrderError,
+        ports::order_port::IOrderService,
+    },
+    infrastructure::{
+        api::{
+            dto::{
+                order_request::{OrderFilter, OrderRequest, OrderStatusRequest},
+                order_response::OrderResponse,
+            },
+            middlewares::auth_middleware::AuthenticateMiddlewareFactory,
+            sse::order_event_stream,
+        },
+        events::order_event_broadcaster::OrderEventBroadcaster,
+    },
+};
+use actix_web::{web, HttpResponse, Responder};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/orders")
+            .wrap(AuthenticateMiddlewareFactory::new())
+            .route("", web::post().to(place_order))
+            .route("", web::get().to(get_my_orders))
+            .route("/events", web::get().to(stream_my_orders))
+            .route("/{order_id}", web::get().to(get_order_by_id))
+            .route("/{order_id}/cancel", web::post().to(cancel_order)),
+    )
+    .service(
+        web::scope("/admin/orders")
+            .wrap(AuthenticateMiddlewareFactory::with_roles(vec![
+                UserRole::Administrator,
+            ]))
+            .route("", web::get().to(get_orders))
+            .route("/{order_id}/status", web::put().to(change_order_status)),
+    );
+}
+
+type OrderService = web::Data<std::sync::Arc<dyn IOrderService>>;
+
+pub(crate) fn error_response(err: OrderError) -> HttpResponse {
+    log::error!("{:?}", err.to_string());
+
+    match &err {
+        OrderError::NotFound => HttpResponse::NotFound().body(err.to_string()),
+        OrderError::ProductNotFound
+        | OrderError::StoreNotFound
+        | OrderError::AddressNotFound
+        | OrderError::InvalidCourier
+        | OrderError::InvalidOrder(_) => HttpResponse::BadRequest().body(err.to_string()),
+        OrderError::Forbidden => HttpResponse::Forbidden().body(err.to_string()),
+        OrderError::InvalidTransition { .. }
+        | OrderError::NotInPreparation
+        | OrderError::AlreadyClaimed
+        | OrderError::OutOfStock
+        | OrderError::StoreClosed
+        | OrderError::SlotFull
+        | OrderError::CourierRequired => HttpResponse::Conflict().body(err.to_string()),
+        _ => HttpResponse::InternalServerError().body(err.to_string()),
+    }
+}
+
+pub async fn place_order(
+    order_service: OrderService,
+    user: web::ReqData<UserPayload>,
+    order_request: web::Json<OrderRequest>,
+) -> impl Responder {
+    if !order_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match order_service
+        .place_order(
+            &user,
+            order_request.to_fulfilment_core(),
+            order_request.to_order_item_core_vec(),
+            order_request.payment_method,
+            order_request.tip,
+        )
+        .await
+    {
+        Ok(order) => HttpResponse::Created().json(OrderResponse::from_order_core(order)),
+        Err(err) => error_response(err),
+    }
+}
+
+/// Suscribe al cliente a los cambios de estado de sus propios pedidos.
+pub async fn stream_my_orders(
+    order_events: web::Data<OrderEventBroadcaster>,
+    user: web::ReqData<UserPayload>,
+) -> impl Responder {
+    let user_id = user.get_id();
+
+    order_event_stream(order_events.subscribe(), move |event| {
+        event.order.is_owned_by(user_id)
+    })
+}
+
+pub async fn get_my_orders(
+    order_service: OrderService,
+    user: web::ReqData<UserPayload>,
+) -> impl Responder {
+    match order_service.get_orders_by_user(user.get_id()).await {
+        Ok(orders) => HttpResponse::Ok().json(OrderResponse::from_order_core_vec(orders)),
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn get_order_by_id(
+    order_service: OrderService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let order_id = path.into_inner();
+
+    match order_service.get_order_by_id(&user, order_id).await {
+        Ok(order) => HttpResponse::Ok().json(OrderResponse::from_order_core(order)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn cancel_order(
+    order_service: OrderService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let order_id = path.into_inner();
+
+    match order_service.cancel_order(&user, order_id).await {
+        Ok(order) => HttpResponse::Ok().json(OrderResponse::from_order_core(order)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn get_orders(
+    order_service: OrderService,
+    filter: web::Query<OrderFilter>,
+) -> impl Responder {
+    match order_service.get_orders(filter.status).await {
+        Ok(orders) => HttpResponse::Ok().json(OrderResponse::from_order_core_vec(orders)),
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn change_order_status(
+    order_service: OrderService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+    status_request: web::Json<OrderStatusRequest>,
+) -> impl Responder {
+    let order_id = path.into_inner();
+
+    match order_service
+        .change_order_status(&user, order_id, status_request.status)
+        .await
+    {
+        Ok(order) => HttpResponse::Ok().json(OrderResponse::from_order_core(order)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod order_handler_tests {
+    use super::*;
+    use crate::core::{
+        entities::order::OrderStatus, services::order_service_stub::OrderServiceStub,
+    };
+    use actix_web::{
+        dev::ServiceResponse,
+        http::StatusCode,
+        test::{call_service, init_service, TestRequest},
+        web, App, HttpMessage, Route,
+    };
+
+    async fn process_test(
+        path: &str,
+        req: TestRequest,
+        route: Route,
+        status_code: i32,
+    ) -> ServiceResponse {
+        let order_service: std::sync::Arc<dyn IOrderService> =
+            std::sync::Arc::new(OrderServiceStub { status_code });
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(order_service.clone()))
+                .app_data(web::Data::new(OrderEventBroadcaster::default()))
+                .route(path, route),
+        )
+        .await;
+
+        let req = req.to_request();
+        req.extensions_mut().insert(UserPayload::new(
+            1,
+            "Keneth".to_string(),
+            "Riera".to_string(),
+            UserRole::Client,
+            30,
+        ));
+
+        call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_stream_my_orders_ok() {
+        let resp = process_test(
+            "/orders/events",
+            TestRequest::get().uri("/orders/events"),
+            web::get().to(stream_my_orders),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get("content-type").unwrap(),
+            "text/event-stream"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_place_order_ok() {
+        let resp = process_test(
+            "/orders",
+            TestRequest::post()
+                .uri("/orders")
+                .set_json(OrderRequest::dummy()),
+            web::post().to(place_order),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_place_order_bad_request_when_order_is_empty() {
+        let resp = process_test(
+            "/orders",
+            TestRequest::post().uri("/orders").set_json(OrderRequest {
+                items: vec![],
+                ..OrderRequest::dummy()
+            }),
+            web::post().to(place_order),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_place_order_bad_request_when_configuration_is_invalid() {
+        let resp = process_test(
+            "/orders",
+            TestRequest::post()
+                .uri("/orders")
+                .set_json(OrderRequest::dummy()),
+            web::post().to(place_order),
+            400,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_get_my_orders_ok() {
+        let resp = process_test(
+            "/orders",
+            TestRequest::get().uri("/orders"),
+            web::get().to(get_my_orders),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_order_by_id_not_found_error() {
+        let resp = process_test(
+            "/orders/{order_id}",
+            TestRequest::get().uri("/orders/1"),
+            web::get().to(get_order_by_id),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_get_order_by_id_forbidden_error() {
+        let resp = process_test(
+            "/orders/{order_id}",
+            TestRequest::get().uri("/orders/1"),
+            web::get().to(get_order_by_id),
+            403,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_cancel_order_conflict_error() {
+        let resp = process_test(
+            "/orders/{order_id}/cancel",
+            TestRequest::post().uri("/orders/1/cancel"),
+            web::post().to(cancel_order),
+            409,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn test_get_orders_ok() {
+        let resp = process_test(
+            "/admin/orders",
+            TestRequest::get().uri("/admin/orders?status=placed"),
+            web::get().to(get_orders),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_change_order_status_ok() {
+        let resp = process_test(
+            "/admin/orders/{order_id}/status",
+            TestRequest::put()
+                .uri("/admin/orders/1/status")
+                .set_json(OrderStatusRequest {
+                    status: OrderStatus::Accepted,
+                }),
+            web::put().to(change_order_status),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_change_order_status_conflict_error() {
+        let resp = process_test(
+            "/admin/orders/{order_id}/status",
+            TestRequest::put()
+                .uri("/admin/orders/1/status")
+                .set_json(OrderStatusRequest {
+                    status: OrderStatus::Cancelled,
+                }),
+            web::put().to(change_order_status),
+            409,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+}
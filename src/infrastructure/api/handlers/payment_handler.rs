@@ -0,0 +1,326 @@
+use crate::{
+    core::{
+        entities::user_payload::UserPayload, errors::payment_errors::PaymentError,
+        ports::payment_port::IPaymentService,
+    },
+    infrastructure::api::{
+        dto::{
+            payment_request::PaymentRequest, payment_response::PaymentResponse,
+            refund_request::RefundRequest, refund_response::RefundResponse,
+        },
+        middlewares::auth_middleware::AuthenticateMiddlewareFactory,
+    },
+};
+use actix_web::{web, HttpResponse, Responder};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/payments")
+            .wrap(AuthenticateMiddlewareFactory::new())
+            .route("/{order_id}", web::post().to(pay_order))
+            .route("/{order_id}", web::get().to(get_order_payments))
+            .route("/{order_id}/capture", web::post().to(capture_payment))
+            .route("/{order_id}/void", web::post().to(void_payment))
+            .route("/{order_id}/refunds", web::post().to(refund_order)),
+    );
+}
+
+type PaymentService = web::Data<std::sync::Arc<dyn IPaymentService>>;
+
+fn error_response(err: PaymentError) -> HttpResponse {
+    log::error!("{:?}", err.to_string());
+
+    match &err {
+        PaymentError::OrderNotFound | PaymentError::PaymentNotFound => {
+            HttpResponse::NotFound().body(err.to_string())
+        }
+        PaymentError::InvalidCard | PaymentError::InvalidRefund(_) => {
+            HttpResponse::BadRequest().body(err.to_string())
+        }
+        PaymentError::Declined(_) => HttpResponse::PaymentRequired().body(err.to_string()),
+        PaymentError::Forbidden => HttpResponse::Forbidden().body(err.to_string()),
+        PaymentError::AlreadyPaid
+        | PaymentError::RefundInProgress
+        | PaymentError::InvalidOperation(_) => HttpResponse::Conflict().body(err.to_string()),
+        PaymentError::Gateway(_) => HttpResponse::BadGateway().body(err.to_string()),
+        _ => HttpResponse::InternalServerError().body(err.to_string()),
+    }
+}
+
+pub async fn pay_order(
+    payment_service: PaymentService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+    payment_request: web::Json<PaymentRequest>,
+) -> impl Responder {
+    if !payment_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    let order_id = path.into_inner();
+
+    match payment_service
+        .pay_order(&user, order_id, payment_request.to_payment_card_core())
+        .await
+    {
+        Ok(payment) => HttpResponse::Created().json(PaymentResponse::from_payment_core(payment)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn get_order_payments(
+    payment_service: PaymentService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let order_id = path.into_inner();
+
+    match payment_service.get_order_payments(&user, order_id).await {
+        Ok(payments) => HttpResponse::Ok().json(PaymentResponse::from_payment_core_vec(payments)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn capture_payment(
+    payment_service: PaymentService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let order_id = path.into_inner();
+
+    match payment_service.capture_payment(&user, order_id).await {
+        Ok(payment) => HttpResponse::Ok().json(PaymentResponse::from_payment_core(payment)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn void_payment(
+    payment_service: PaymentService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let order_id = path.into_inner();
+
+    match payment_service.void_payment(&user, order_id).await {
+        Ok(payment) => HttpResponse::Ok().json(PaymentResponse::from_payment_core(payment)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn refund_order(
+    payment_service: PaymentService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+    refund_request: web::Json<RefundRequest>,
+) -> impl Responder {
+    if !refund_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    let order_id = path.into_inner();
+
+    match payment_service
+        .refund_order(&user, order_id, refund_request.to_refund_core())
+        .await
+    {
+        Ok(refund) => HttpResponse::Created().json(RefundResponse::from_refund_core(refund)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod payment_handler_tests {
+    use super::*;
+    use crate::core::{
+        entities::user_role::UserRole, services::payment_service_stub::PaymentServiceStub,
+    };
+    use actix_web::{
+        dev::ServiceResponse,
+        http::StatusCode,
+        test::{call_service, init_service, TestRequest},
+        web, App, HttpMessage, Route,
+    };
+
+    async fn process_test(
+        path: &str,
+        req: TestRequest,
+        route: Route,
+        status_code: i32,
+    ) -> ServiceResponse {
+        let payment_service: std::sync::Arc<dyn IPaymentService> =
+            std::sync::Arc::new(PaymentServiceStub { status_code });
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(payment_service.clone()))
+                .route(path, route),
+        )
+        .await;
+
+        let req = req.to_request();
+        req.extensions_mut().insert(UserPayload::new(
+            1,
+            "Keneth".to_string(),
+            "Riera".to_string(),
+            UserRole::Client,
+            30,
+        ));
+
+        call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_pay_order_ok() {
+        let resp = process_test(
+            "/payments/{order_id}",
+            TestRequest::post()
+                .uri("/payments/1")
+                .set_json(PaymentRequest::dummy()),
+            web::post().to(pay_order),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_pay_order_payment_required_when_declined() {
+        let resp = process_test(
+            "/payments/{order_id}",
+            TestRequest::post()
+                .uri("/payments/1")
+                .set_json(PaymentRequest::dummy()),
+            web::post().to(pay_order),
+            402,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::PAYMENT_REQUIRED);
+    }
+
+    #[actix_web::test]
+    async fn test_pay_order_bad_request_when_card_is_missing() {
+        let resp = process_test(
+            "/payments/{order_id}",
+            TestRequest::post()
+                .uri("/payments/1")
+                .set_json(PaymentRequest {
+                    card_number: "".to_string(),
+                    ..PaymentRequest::dummy()
+                }),
+            web::post().to(pay_order),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_pay_order_conflict_when_already_paid() {
+        let resp = process_test(
+            "/payments/{order_id}",
+            TestRequest::post()
+                .uri("/payments/1")
+                .set_json(PaymentRequest::dummy()),
+            web::post().to(pay_order),
+            409,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn test_get_order_payments_ok() {
+        let resp = process_test(
+            "/payments/{order_id}",
+            TestRequest::get().uri("/payments/1"),
+            web::get().to(get_order_payments),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_capture_payment_forbidden_error() {
+        let resp = process_test(
+            "/payments/{order_id}/capture",
+            TestRequest::post().uri("/payments/1/capture"),
+            web::post().to(capture_payment),
+            403,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn test_void_payment_not_found_error() {
+        let resp = process_test(
+            "/payments/{order_id}/void",
+            TestRequest::post().uri("/payments/1/void"),
+            web::post().to(void_payment),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_refund_order_ok() {
+        let resp = process_test(
+            "/payments/{order_id}/refunds",
+            TestRequest::post()
+                .uri("/payments/1/refunds")
+                .set_json(RefundRequest::dummy()),
+            web::post().to(refund_order),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_refund_order_bad_request_without_idempotency_key() {
+        let resp = process_test(
+            "/payments/{order_id}/refunds",
+            TestRequest::post()
+                .uri("/payments/1/refunds")
+                .set_json(RefundRequest {
+                    idempotency_key: " ".to_string(),
+                    ..RefundRequest::dummy()
+                }),
+            web::post().to(refund_order),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_refund_order_forbidden_error() {
+        let resp = process_test(
+            "/payments/{order_id}/refunds",
+            TestRequest::post()
+                .uri("/payments/1/refunds")
+                .set_json(RefundRequest::dummy()),
+            web::post().to(refund_order),
+            403,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -0,0 +1,217 @@
+use crate::{
+    core::{
+        entities::user_role::UserRole, errors::promotion_errors::PromotionError,
+        ports::promotion_port::IPromotionService,
+    },
+    infrastructure::api::{
+        dto::{promotion_request::PromotionRequest, promotion_response::PromotionResponse},
+        middlewares::auth_middleware::AuthenticateMiddlewareFactory,
+    },
+};
+use actix_web::{web, HttpResponse, Responder};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/admin/promotions")
+            .wrap(AuthenticateMiddlewareFactory::with_roles(vec![
+                UserRole::Administrator,
+            ]))
+            .route("", web::get().to(get_promotions))
+            .route("", web::post().to(create_promotion))
+            .route("/{promotion_id}", web::put().to(update_promotion))
+            .route("/{promotion_id}", web::delete().to(delete_promotion)),
+    );
+}
+
+type PromotionService = web::Data<std::sync::Arc<dyn IPromotionService>>;
+
+fn error_response(err: PromotionError) -> HttpResponse {
+    log::error!("{:?}", err.to_string());
+
+    match &err {
+        PromotionError::NotFound => HttpResponse::NotFound().body(err.to_string()),
+        PromotionError::InvalidPromotion(_) => HttpResponse::BadRequest().body(err.to_string()),
+        PromotionError::DuplicateCode => HttpResponse::Conflict().body(err.to_string()),
+        _ => HttpResponse::InternalServerError().body(err.to_string()),
+    }
+}
+
+pub async fn get_promotions(promotion_service: PromotionService) -> impl Responder {
+    match promotion_service.get_promotions().await {
+        Ok(promotions) => {
+            HttpResponse::Ok().json(PromotionResponse::from_promotion_core_vec(promotions))
+        }
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn create_promotion(
+    promotion_service: PromotionService,
+    promotion_request: web::Json<PromotionRequest>,
+) -> impl Responder {
+    if !promotion_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match promotion_service
+        .create_promotion(promotion_request.to_promotion_core())
+        .await
+    {
+        Ok(promotion) => {
+            HttpResponse::Created().json(PromotionResponse::from_promotion_core(promotion))
+        }
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn update_promotion(
+    promotion_service: PromotionService,
+    path: web::Path<i32>,
+    promotion_request: web::Json<PromotionRequest>,
+) -> impl Responder {
+    let promotion_id = path.into_inner();
+
+    if !promotion_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match promotion_service
+        .update_promotion(promotion_id, promotion_request.to_promotion_core())
+        .await
+    {
+        Ok(promotion) => HttpResponse::Ok().json(PromotionResponse::from_promotion_core(promotion)),
+        Err(err) => {
+            log::debug!("Promotion ID: {:?}", promotion_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn delete_promotion(
+    promotion_service: PromotionService,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let promotion_id = path.into_inner();
+
+    match promotion_service.delete_promotion(promotion_id).await {
+        Ok(_) => HttpResponse::Ok().json("Promotion deleted."),
+        Err(err) => {
+            log::debug!("Promotion ID: {:?}", promotion_id);
+            error_response(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod promotion_handler_tests {
+    use super::*;
+    use crate::core::services::promotion_service_stub::PromotionServiceStub;
+    use actix_web::{
+        dev::ServiceResponse,
+        http::StatusCode,
+        test::{call_service, init_service, TestRequest},
+        web, App, Route,
+    };
+
+    async fn process_test(
+        path: &str,
+        req: TestRequest,
+        route: Route,
+        status_code: i32,
+    ) -> ServiceResponse {
+        let promotion_service: std::sync::Arc<dyn IPromotionService> =
+            std::sync::Arc::new(PromotionServiceStub { status_code });
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(promotion_service.clone()))
+                .route(path, route),
+        )
+        .await;
+
+        call_service(&app, req.to_request()).await
+    }
+
+    #[actix_web::test]
+    async fn test_get_promotions_ok() {
+        let resp = process_test(
+            "/admin/promotions",
+            TestRequest::get().uri("/admin/promotions"),
+            web::get().to(get_promotions),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_create_promotion_ok() {
+        let resp = process_test(
+            "/admin/promotions",
+            TestRequest::post()
+                .uri("/admin/promotions")
+                .set_json(PromotionRequest::dummy()),
+            web::post().to(create_promotion),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_create_promotion_bad_request_when_daily_window_is_incomplete() {
+        let mut promotion_request = PromotionRequest::dummy();
+        promotion_request.daily_ends = None;
+
+        let resp = process_test(
+            "/admin/promotions",
+            TestRequest::post()
+                .uri("/admin/promotions")
+                .set_json(promotion_request),
+            web::post().to(create_promotion),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_create_promotion_conflict_when_code_is_duplicated() {
+        let resp = process_test(
+            "/admin/promotions",
+            TestRequest::post()
+                .uri("/admin/promotions")
+                .set_json(PromotionRequest::dummy()),
+            web::post().to(create_promotion),
+            409,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn test_update_promotion_not_found_error() {
+        let resp = process_test(
+            "/admin/promotions/{promotion_id}",
+            TestRequest::put()
+                .uri("/admin/promotions/1")
+                .set_json(PromotionRequest::dummy()),
+            web::put().to(update_promotion),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_promotion_ok() {
+        let resp = process_test(
+            "/admin/promotions/{promotion_id}",
+            TestRequest::delete().uri("/admin/promotions/1"),
+            web::delete().to(delete_promotion),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
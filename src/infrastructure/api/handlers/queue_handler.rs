@@ -0,0 +1,365 @@
+use super::order_handler::error_response;
+use crate::{
+    core::{
+        entities::{user_payload::UserPayload, user_role::UserRole},
+        ports::order_port::IOrderService,
+    },
+    infrastructure::{
+        api::{
+            dto::{
+                order_request::{CourierRequest, OrderFilter},
+                order_response::{OrderResponse, QueueOrderResponse},
+            },
+            middlewares::auth_middleware::AuthenticateMiddlewareFactory,
+            sse::order_event_stream,
+        },
+        events::order_event_broadcaster::OrderEventBroadcaster,
+    },
+};
+use actix_web::{web, HttpResponse, Responder};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/queue")
+            .wrap(AuthenticateMiddlewareFactory::with_roles(vec![
+                UserRole::Employee,
+                UserRole::Administrator,
+            ]))
+            .route("", web::get().to(get_queue))
+            .route("/events", web::get().to(stream_queue))
+            .route("/{order_id}/claim", web::post().to(claim_order))
+            .route("/{order_id}/advance", web::post().to(advance_order))
+            .route("/{order_id}/ready", web::post().to(mark_order_ready))
+            .route("/{order_id}/courier", web::put().to(assign_courier))
+            .route("/{order_id}/dispatch", web::post().to(dispatch_order))
+            .route(
+                "/{order_id}/delivered",
+                web::post().to(mark_order_delivered),
+            ),
+    );
+}
+
+type OrderService = web::Data<std::sync::Arc<dyn IOrderService>>;
+
+pub async fn get_queue(
+    order_service: OrderService,
+    filter: web::Query<OrderFilter>,
+) -> impl Responder {
+    match order_service.get_queue(filter.status).await {
+        Ok(orders) => HttpResponse::Ok().json(QueueOrderResponse::from_order_core_vec(
+            orders,
+            chrono::Utc::now(),
+        )),
+        Err(err) => error_response(err),
+    }
+}
+
+/// Tablero en vivo: los pedidos nuevos y actualizados que ya están en la cola. Los pedidos
+/// programados aparecen con el evento `order_released`.
+pub async fn stream_queue(order_events: web::Data<OrderEventBroadcaster>) -> impl Responder {
+    order_event_stream(order_events.subscribe(), |event| {
+        event.order.is_released(chrono::Utc::now())
+    })
+}
+
+pub async fn claim_order(
+    order_service: OrderService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let order_id = path.into_inner();
+
+    match order_service.claim_order(&user, order_id).await {
+        Ok(order) => HttpResponse::Ok().json(OrderResponse::from_order_core(order)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn advance_order(
+    order_service: OrderService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let order_id = path.into_inner();
+
+    match order_service.advance_order(&user, order_id).await {
+        Ok(order) => HttpResponse::Ok().json(OrderResponse::from_order_core(order)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn mark_order_ready(
+    order_service: OrderService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let order_id = path.into_inner();
+
+    match order_service.mark_order_ready(&user, order_id).await {
+        Ok(order) => HttpResponse::Ok().json(OrderResponse::from_order_core(order)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn assign_courier(
+    order_service: OrderService,
+    path: web::Path<i32>,
+    courier_request: web::Json<CourierRequest>,
+) -> impl Responder {
+    let order_id = path.into_inner();
+
+    match order_service
+        .assign_courier(order_id, courier_request.courier_id)
+        .await
+    {
+        Ok(order) => HttpResponse::Ok().json(OrderResponse::from_order_core(order)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn dispatch_order(
+    order_service: OrderService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let order_id = path.into_inner();
+
+    match order_service.dispatch_order(&user, order_id).await {
+        Ok(order) => HttpResponse::Ok().json(OrderResponse::from_order_core(order)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn mark_order_delivered(
+    order_service: OrderService,
+    user: web::ReqData<UserPayload>,
+    path: web::Path<i32>,
+) -> impl Responder {
+    let order_id = path.into_inner();
+
+    match order_service.mark_order_delivered(&user, order_id).await {
+        Ok(order) => HttpResponse::Ok().json(OrderResponse::from_order_core(order)),
+        Err(err) => {
+            log::debug!("Order ID: {:?}", order_id);
+            error_response(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod queue_handler_tests {
+    use super::*;
+    use crate::core::services::order_service_stub::OrderServiceStub;
+    use actix_web::{
+        dev::ServiceResponse,
+        http::StatusCode,
+        test::{call_service, init_service, read_body_json, TestRequest},
+        web, App, HttpMessage, Route,
+    };
+
+    async fn process_test(
+        path: &str,
+        req: TestRequest,
+        route: Route,
+        status_code: i32,
+    ) -> ServiceResponse {
+        let order_service: std::sync::Arc<dyn IOrderService> =
+            std::sync::Arc::new(OrderServiceStub { status_code });
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(order_service.clone()))
+                .app_data(web::Data::new(OrderEventBroadcaster::default()))
+                .route(path, route),
+        )
+        .await;
+
+        let req = req.to_request();
+        req.extensions_mut().insert(UserPayload::new(
+            2,
+            "Maximiliano".to_string(),
+            "Riera".to_string(),
+            UserRole::Employee,
+            30,
+        ));
+
+        call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_get_queue_ok() {
+        let resp = process_test(
+            "/queue",
+            TestRequest::get().uri("/queue?status=placed"),
+            web::get().to(get_queue),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_queue_internal_server_error() {
+        let resp = process_test(
+            "/queue",
+            TestRequest::get().uri("/queue"),
+            web::get().to(get_queue),
+            500,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[actix_web::test]
+    async fn test_stream_queue_ok() {
+        let resp = process_test(
+            "/queue/events",
+            TestRequest::get().uri("/queue/events"),
+            web::get().to(stream_queue),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get("content-type").unwrap(),
+            "text/event-stream"
+        );
+    }
+
+    #[actix_web::test]
+    async fn test_claim_order_ok() {
+        let resp = process_test(
+            "/queue/{order_id}/claim",
+            TestRequest::post().uri("/queue/1/claim"),
+            web::post().to(claim_order),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_claim_order_not_found_error() {
+        let resp = process_test(
+            "/queue/{order_id}/claim",
+            TestRequest::post().uri("/queue/1/claim"),
+            web::post().to(claim_order),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_advance_order_ok() {
+        let resp = process_test(
+            "/queue/{order_id}/advance",
+            TestRequest::post().uri("/queue/1/advance"),
+            web::post().to(advance_order),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_mark_order_ready_conflict_error() {
+        let resp = process_test(
+            "/queue/{order_id}/ready",
+            TestRequest::post().uri("/queue/1/ready"),
+            web::post().to(mark_order_ready),
+            409,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn test_assign_courier_ok() {
+        let resp = process_test(
+            "/queue/{order_id}/courier",
+            TestRequest::put()
+                .uri("/queue/1/courier")
+                .set_json(CourierRequest { courier_id: 2 }),
+            web::put().to(assign_courier),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body: serde_json::Value = read_body_json(resp).await;
+        assert_eq!(body["courier_id"], 2);
+        assert_eq!(body["fulfilment_type"], "delivery");
+    }
+
+    #[actix_web::test]
+    async fn test_assign_courier_bad_request() {
+        let resp = process_test(
+            "/queue/{order_id}/courier",
+            TestRequest::put()
+                .uri("/queue/1/courier")
+                .set_json(CourierRequest { courier_id: 2 }),
+            web::put().to(assign_courier),
+            400,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_dispatch_order_ok() {
+        let resp = process_test(
+            "/queue/{order_id}/dispatch",
+            TestRequest::post().uri("/queue/1/dispatch"),
+            web::post().to(dispatch_order),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body: serde_json::Value = read_body_json(resp).await;
+        assert_eq!(body["status"], "out_for_delivery");
+    }
+
+    #[actix_web::test]
+    async fn test_dispatch_order_conflict() {
+        let resp = process_test(
+            "/queue/{order_id}/dispatch",
+            TestRequest::post().uri("/queue/1/dispatch"),
+            web::post().to(dispatch_order),
+            409,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CONFLICT);
+    }
+
+    #[actix_web::test]
+    async fn test_mark_order_delivered_ok() {
+        let resp = process_test(
+            "/queue/{order_id}/delivered",
+            TestRequest::post().uri("/queue/1/delivered"),
+            web::post().to(mark_order_delivered),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body: serde_json::Value = read_body_json(resp).await;
+        assert_eq!(body["status"], "delivered");
+    }
+}
@@ -0,0 +1,385 @@
+use crate::{
+    core::{
+        entities::user_role::UserRole, errors::store_errors::StoreError,
+        ports::store_port::IStoreService,
+    },
+    infrastructure::api::{
+        dto::{
+            store_request::{
+                PickupSlotFilter, ProductAvailabilityRequest, StoreExceptionRequest, StoreRequest,
+            },
+            store_response::{PickupSlotResponse, StoreResponse},
+        },
+        middlewares::auth_middleware::AuthenticateMiddlewareFactory,
+    },
+};
+use actix_web::{web, HttpResponse, Responder};
+use chrono::{NaiveDate, Utc};
+
+pub fn routes(cfg: &mut web::ServiceConfig) {
+    cfg.service(
+        web::scope("/stores")
+            .route("", web::get().to(get_stores))
+            .route("/{store_id}", web::get().to(get_store_by_id))
+            .route("/{store_id}/pickup-slots", web::get().to(get_pickup_slots)),
+    )
+    .service(
+        web::scope("/admin/stores")
+            .wrap(AuthenticateMiddlewareFactory::with_roles(vec![
+                UserRole::Administrator,
+            ]))
+            .route("", web::post().to(create_store))
+            .route("/{store_id}", web::put().to(update_store))
+            .route("/{store_id}", web::delete().to(delete_store))
+            .route(
+                "/{store_id}/exceptions/{date}",
+                web::put().to(set_exception),
+            )
+            .route(
+                "/{store_id}/exceptions/{date}",
+                web::delete().to(delete_exception),
+            )
+            .route(
+                "/{store_id}/products/{product_id}",
+                web::put().to(set_product_availability),
+            ),
+    );
+}
+
+type StoreService = web::Data<std::sync::Arc<dyn IStoreService>>;
+
+fn error_response(err: StoreError) -> HttpResponse {
+    log::error!("{:?}", err.to_string());
+
+    match &err {
+        StoreError::NotFound | StoreError::ProductNotFound => {
+            HttpResponse::NotFound().body(err.to_string())
+        }
+        StoreError::InvalidStore(_) | StoreError::InvalidPickupTime => {
+            HttpResponse::BadRequest().body(err.to_string())
+        }
+        StoreError::Closed | StoreError::ProductUnavailable(_) | StoreError::SlotFull => {
+            HttpResponse::Conflict().body(err.to_string())
+        }
+        _ => HttpResponse::InternalServerError().body(err.to_string()),
+    }
+}
+
+pub async fn get_stores(store_service: StoreService) -> impl Responder {
+    match store_service.get_stores().await {
+        Ok(stores) => {
+            HttpResponse::Ok().json(StoreResponse::from_store_core_vec(stores, Utc::now()))
+        }
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn get_store_by_id(store_service: StoreService, path: web::Path<i32>) -> impl Responder {
+    let store_id = path.into_inner();
+
+    match store_service.get_store_by_id(store_id).await {
+        Ok(store) => HttpResponse::Ok().json(StoreResponse::from_store_core(store, Utc::now())),
+        Err(err) => {
+            log::debug!("Store ID: {:?}", store_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn get_pickup_slots(
+    store_service: StoreService,
+    path: web::Path<i32>,
+    filter: web::Query<PickupSlotFilter>,
+) -> impl Responder {
+    let store_id = path.into_inner();
+
+    match store_service.get_pickup_slots(store_id, filter.date).await {
+        Ok(slots) => HttpResponse::Ok().json(PickupSlotResponse::from_pickup_slot_core_vec(slots)),
+        Err(err) => {
+            log::debug!("Store ID: {:?}", store_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn create_store(
+    store_service: StoreService,
+    store_request: web::Json<StoreRequest>,
+) -> impl Responder {
+    if !store_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match store_service
+        .create_store(store_request.to_store_core())
+        .await
+    {
+        Ok(store) => {
+            HttpResponse::Created().json(StoreResponse::from_store_core(store, Utc::now()))
+        }
+        Err(err) => error_response(err),
+    }
+}
+
+pub async fn update_store(
+    store_service: StoreService,
+    path: web::Path<i32>,
+    store_request: web::Json<StoreRequest>,
+) -> impl Responder {
+    let store_id = path.into_inner();
+
+    if !store_request.is_valid() {
+        return HttpResponse::BadRequest().body("Invalid fields.");
+    }
+
+    match store_service
+        .update_store(store_id, store_request.to_store_core())
+        .await
+    {
+        Ok(store) => HttpResponse::Ok().json(StoreResponse::from_store_core(store, Utc::now())),
+        Err(err) => {
+            log::debug!("Store ID: {:?}", store_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn delete_store(store_service: StoreService, path: web::Path<i32>) -> impl Responder {
+    let store_id = path.into_inner();
+
+    match store_service.delete_store(store_id).await {
+        Ok(_) => HttpResponse::Ok().json("Store deleted."),
+        Err(err) => {
+            log::debug!("Store ID: {:?}", store_id);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn set_exception(
+    store_service: StoreService,
+    path: web::Path<(i32, NaiveDate)>,
+    exception_request: web::Json<StoreExceptionRequest>,
+) -> impl Responder {
+    let (store_id, date) = path.into_inner();
+
+    match store_service
+        .set_exception(store_id, exception_request.to_store_exception_core(date))
+        .await
+    {
+        Ok(store) => HttpResponse::Ok().json(StoreResponse::from_store_core(store, Utc::now())),
+        Err(err) => {
+            log::debug!("Store ID: {:?}, date: {:?}", store_id, date);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn delete_exception(
+    store_service: StoreService,
+    path: web::Path<(i32, NaiveDate)>,
+) -> impl Responder {
+    let (store_id, date) = path.into_inner();
+
+    match store_service.delete_exception(store_id, date).await {
+        Ok(store) => HttpResponse::Ok().json(StoreResponse::from_store_core(store, Utc::now())),
+        Err(err) => {
+            log::debug!("Store ID: {:?}, date: {:?}", store_id, date);
+            error_response(err)
+        }
+    }
+}
+
+pub async fn set_product_availability(
+    store_service: StoreService,
+    path: web::Path<(i32, i32)>,
+    availability_request: web::Json<ProductAvailabilityRequest>,
+) -> impl Responder {
+    let (store_id, product_id) = path.into_inner();
+
+    match store_service
+        .set_product_availability(store_id, product_id, availability_request.available)
+        .await
+    {
+        Ok(store) => HttpResponse::Ok().json(StoreResponse::from_store_core(store, Utc::now())),
+        Err(err) => {
+            log::debug!("Store ID: {:?}, product ID: {:?}", store_id, product_id);
+            error_response(err)
+        }
+    }
+}
+
+#[cfg(test)]
+mod store_handler_tests {
+    use super::*;
+    use crate::core::{
+        entities::user_payload::UserPayload, services::store_service_stub::StoreServiceStub,
+    };
+    use actix_web::{
+        dev::ServiceResponse,
+        http::StatusCode,
+        test::{call_service, init_service, TestRequest},
+        web, App, HttpMessage, Route,
+    };
+
+    async fn process_test(
+        path: &str,
+        req: TestRequest,
+        route: Route,
+        status_code: i32,
+    ) -> ServiceResponse {
+        let store_service: std::sync::Arc<dyn IStoreService> =
+            std::sync::Arc::new(StoreServiceStub { status_code });
+
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(store_service.clone()))
+                .route(path, route),
+        )
+        .await;
+
+        let req = req.to_request();
+        req.extensions_mut().insert(UserPayload::new(
+            1,
+            "Keneth".to_string(),
+            "Riera".to_string(),
+            UserRole::Administrator,
+            30,
+        ));
+
+        call_service(&app, req).await
+    }
+
+    #[actix_web::test]
+    async fn test_get_stores_ok() {
+        let resp = process_test(
+            "/stores",
+            TestRequest::get().uri("/stores"),
+            web::get().to(get_stores),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_store_by_id_not_found_error() {
+        let resp = process_test(
+            "/stores/{store_id}",
+            TestRequest::get().uri("/stores/1"),
+            web::get().to(get_store_by_id),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_get_pickup_slots_ok() {
+        let resp = process_test(
+            "/stores/{store_id}/pickup-slots",
+            TestRequest::get().uri("/stores/1/pickup-slots?date=2026-10-19"),
+            web::get().to(get_pickup_slots),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_get_pickup_slots_not_found_error() {
+        let resp = process_test(
+            "/stores/{store_id}/pickup-slots",
+            TestRequest::get().uri("/stores/1/pickup-slots"),
+            web::get().to(get_pickup_slots),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_create_store_ok() {
+        let resp = process_test(
+            "/admin/stores",
+            TestRequest::post()
+                .uri("/admin/stores")
+                .set_json(StoreRequest::dummy()),
+            web::post().to(create_store),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::CREATED);
+    }
+
+    #[actix_web::test]
+    async fn test_create_store_bad_request_when_timezone_is_unknown() {
+        let mut store_request = StoreRequest::dummy();
+        store_request.timezone = "America/Atlantis".to_string();
+
+        let resp = process_test(
+            "/admin/stores",
+            TestRequest::post()
+                .uri("/admin/stores")
+                .set_json(store_request),
+            web::post().to(create_store),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_update_store_bad_request_when_hours_are_invalid() {
+        let resp = process_test(
+            "/admin/stores/{store_id}",
+            TestRequest::put()
+                .uri("/admin/stores/1")
+                .set_json(StoreRequest::dummy()),
+            web::put().to(update_store),
+            400,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn test_set_exception_ok() {
+        let resp = process_test(
+            "/admin/stores/{store_id}/exceptions/{date}",
+            TestRequest::put()
+                .uri("/admin/stores/1/exceptions/2026-12-25")
+                .set_json(StoreExceptionRequest::dummy()),
+            web::put().to(set_exception),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn test_delete_exception_not_found_error() {
+        let resp = process_test(
+            "/admin/stores/{store_id}/exceptions/{date}",
+            TestRequest::delete().uri("/admin/stores/1/exceptions/2026-12-25"),
+            web::delete().to(delete_exception),
+            404,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[actix_web::test]
+    async fn test_set_product_availability_ok() {
+        let resp = process_test(
+            "/admin/stores/{store_id}/products/{product_id}",
+            TestRequest::put()
+                .uri("/admin/stores/1/products/1")
+                .set_json(ProductAvailabilityRequest::dummy()),
+            web::put().to(set_product_availability),
+            200,
+        )
+        .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
@@ -1,15 +1,24 @@
-use crate::utils::security_util::verify_jwt_token;
+use crate::{
+    core::{
+        entities::{metric_event::MetricEvent, user_role::UserRole},
+        errors::jwt_errors::JwtError,
+        ports::metrics_port::IMetricsRecorder,
+    },
+    infrastructure::config::Config,
+    utils::security_util::verify_jwt_token,
+};
 use actix_service::Transform;
 use actix_web::{
     dev::{Service, ServiceRequest, ServiceResponse},
-    error::ErrorUnauthorized,
-    Error,
+    error::{ErrorForbidden, ErrorInternalServerError, ErrorUnauthorized},
+    web, Error, HttpMessage,
 };
 use futures::future::{ready, LocalBoxFuture, Ready};
-use std::rc::Rc;
+use std::{rc::Rc, sync::Arc};
 
 pub struct AuthenticateMiddleware<S> {
     service: Rc<S>,
+    roles: Rc<Vec<UserRole>>,
 }
 
 impl<S, B> Service<ServiceRequest> for AuthenticateMiddleware<S>
@@ -24,16 +33,51 @@ where
 
     fn call(&self, req: ServiceRequest) -> Self::Future {
         let srv = self.service.clone();
+        let roles = self.roles.clone();
 
         futures::FutureExt::boxed_local(async move {
+            // La clave se toma de la configuración registrada en la aplicación con `app_data`.
+            let Some(config) = req.app_data::<web::Data<Config>>() else {
+                log::error!("Config is not registered as app data.");
+                return Err(ErrorInternalServerError("Authentication is not configured"));
+            };
+
+            // Los rechazos se cuentan por motivo si la aplicación registró las métricas.
+            let reject = |reason: &'static str| {
+                if let Some(metrics) = req.app_data::<web::Data<Arc<dyn IMetricsRecorder>>>() {
+                    metrics.record(MetricEvent::TokenRejected(reason));
+                }
+            };
+
             match req.headers().get("Authorization") {
                 Some(auth_header) => {
-                    let is_valid = verify_jwt_token(auth_header.to_str().unwrap().to_string());
-                    if let Err(err) = is_valid {
-                        return Err(ErrorUnauthorized(err));
+                    // Una cabecera con bytes que no son ASCII visible no puede traer un token.
+                    let Ok(auth_header) = auth_header.to_str() else {
+                        reject("malformed");
+                        return Err(ErrorUnauthorized("Malformed authorization header"));
+                    };
+
+                    match verify_jwt_token(auth_header.to_string(), &config.security.jwt_secret) {
+                        Ok(payload) => {
+                            if !roles.is_empty() && !roles.contains(&payload.get_role()) {
+                                return Err(ErrorForbidden("Insufficient permissions"));
+                            }
+                            req.extensions_mut().insert(payload);
+                        }
+                        Err(err) => {
+                            reject(match err {
+                                JwtError::BearerNotPresent => "bearer_not_present",
+                                JwtError::Expired => "expired",
+                                JwtError::Invalid | JwtError::Unexpected => "invalid",
+                            });
+                            return Err(ErrorUnauthorized(err));
+                        }
                     }
                 }
-                None => return Err(ErrorUnauthorized("Authorization header not found")),
+                None => {
+                    reject("header_not_found");
+                    return Err(ErrorUnauthorized("Authorization header not found"));
+                }
             }
 
             let res = srv.call(req).await?;
@@ -43,11 +87,18 @@ where
     }
 }
 
-pub struct AuthenticateMiddlewareFactory {}
+pub struct AuthenticateMiddlewareFactory {
+    roles: Vec<UserRole>,
+}
 
 impl AuthenticateMiddlewareFactory {
     pub fn new() -> Self {
-        AuthenticateMiddlewareFactory {}
+        AuthenticateMiddlewareFactory { roles: vec![] }
+    }
+
+    /// Restringe el acceso a los usuarios autenticados que tengan alguno de los roles indicados.
+    pub fn with_roles(roles: Vec<UserRole>) -> Self {
+        AuthenticateMiddlewareFactory { roles }
     }
 }
 
@@ -64,6 +115,7 @@ where
     fn new_transform(&self, service: S) -> Self::Future {
         ready(Ok(AuthenticateMiddleware {
             service: Rc::new(service),
+            roles: Rc::new(self.roles.clone()),
         }))
     }
 }
@@ -73,3 +125,56 @@ impl Default for AuthenticateMiddlewareFactory {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod auth_middleware_tests {
+    use super::*;
+    use crate::infrastructure::metrics::prometheus_metrics::PrometheusMetrics;
+    use actix_web::{
+        http::{header::HeaderValue, StatusCode},
+        test::{init_service, try_call_service, TestRequest},
+        App, HttpResponse,
+    };
+
+    #[actix_web::test]
+    async fn test_malformed_header_is_rejected() {
+        let config = Config::from_sources(
+            |name| match name {
+                "DATABASE_URL" => Some("memory://".to_string()),
+                "JWT_SECRET" => Some("secret".to_string()),
+                _ => None,
+            },
+            &toml::Table::new(),
+        )
+        .unwrap();
+        let metrics = PrometheusMetrics::new();
+        let recorder: Arc<dyn IMetricsRecorder> = Arc::new(metrics.clone());
+        let app = init_service(
+            App::new()
+                .app_data(web::Data::new(config))
+                .app_data(web::Data::new(recorder))
+                .wrap(AuthenticateMiddlewareFactory::new())
+                .route("/", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        let err = try_call_service(
+            &app,
+            TestRequest::get()
+                .uri("/")
+                .insert_header((
+                    "Authorization",
+                    HeaderValue::from_bytes(b"Bearer \xff").unwrap(),
+                ))
+                .to_request(),
+        )
+        .await
+        .unwrap_err();
+
+        assert_eq!(
+            err.as_response_error().status_code(),
+            StatusCode::UNAUTHORIZED
+        );
+        assert!(metrics.render().contains("reason=\"malformed\"} 1"));
+    }
+}
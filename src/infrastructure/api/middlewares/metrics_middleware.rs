@@ -0,0 +1,74 @@
+use super::response_status;
+use crate::infrastructure::metrics::prometheus_metrics::PrometheusMetrics;
+use actix_service::Transform;
+use actix_web::{
+    dev::{Service, ServiceRequest, ServiceResponse},
+    Error,
+};
+use futures::future::{ready, LocalBoxFuture, Ready};
+use std::{rc::Rc, time::Instant};
+
+pub struct MetricsMiddleware<S> {
+    service: Rc<S>,
+    metrics: PrometheusMetrics,
+}
+
+impl<S, B> Service<ServiceRequest> for MetricsMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_service::forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let srv = self.service.clone();
+        let metrics = self.metrics.clone();
+        let method = req.method().to_string();
+        // Las rutas inexistentes comparten una sola serie para no crear una por cada URL.
+        let route = req
+            .match_pattern()
+            .unwrap_or_else(|| "unmatched".to_string());
+        let started = Instant::now();
+
+        futures::FutureExt::boxed_local(async move {
+            let res = srv.call(req).await;
+
+            let status = response_status(&res);
+            metrics.observe_request(&method, &route, status.as_u16(), started.elapsed());
+
+            res
+        })
+    }
+}
+
+/// Cuenta cada petición y mide cuánto tarda, por método, ruta y estado de la respuesta.
+pub struct MetricsMiddlewareFactory {
+    metrics: PrometheusMetrics,
+}
+
+impl MetricsMiddlewareFactory {
+    pub fn new(metrics: PrometheusMetrics) -> Self {
+        MetricsMiddlewareFactory { metrics }
+    }
+}
+
+impl<S, B> Transform<S, ServiceRequest> for MetricsMiddlewareFactory
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = MetricsMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(MetricsMiddleware {
+            service: Rc::new(service),
+            metrics: self.metrics.clone(),
+        }))
+    }
+}
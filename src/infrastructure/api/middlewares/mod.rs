@@ -1 +1,15 @@
+use actix_web::{dev::ServiceResponse, http::StatusCode, Error};
+
 pub mod auth_middleware;
+pub mod metrics_middleware;
+pub mod request_id_middleware;
+pub mod tracing_middleware;
+
+/// Estado con el que termina la petición. Los errores de los middlewares internos, como un 401,
+/// todavía no son respuestas, así que el estado se toma del error.
+fn response_status<B>(res: &Result<ServiceResponse<B>, Error>) -> StatusCode {
+    match res {
+        Ok(res) => res.status(),
+        Err(err) => err.as_response_error().status_code(),
+    }
+}
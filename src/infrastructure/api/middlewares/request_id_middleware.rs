@@ -0,0 +1,191 @@
+use crate::infrastructure::logging::{self, RequestContext};
+use actix_service::Transform;
+use actix_web::{
+    dev::{Service, ServiceRequest, ServiceResponse},
+    error::InternalError,
+    http::header::{HeaderName, HeaderValue},
+    Error, HttpMessage,
+};
+use futures::future::{ready, LocalBoxFuture, Ready};
+use std::rc::Rc;
+
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Largo máximo de un id recibido; uno más largo o con otros caracteres se reemplaza.
+const MAX_REQUEST_ID_LENGTH: usize = 128;
+
+/// Id de la petición, disponible para los handlers en las extensiones de la petición.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RequestId(pub String);
+
+pub struct RequestIdMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for RequestIdMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_service::forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let srv = self.service.clone();
+        let id = req
+            .headers()
+            .get(&REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|id| is_valid(id))
+            .map(str::to_string)
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        let context = RequestContext {
+            id: id.clone(),
+            method: req.method().to_string(),
+            path: req.path().to_string(),
+        };
+        req.extensions_mut().insert(RequestId(id.clone()));
+
+        futures::FutureExt::boxed_local(logging::with_request(context, async move {
+            let value = HeaderValue::from_str(&id).ok();
+            match srv.call(req).await {
+                Ok(mut res) => {
+                    if let Some(value) = value {
+                        res.headers_mut().insert(REQUEST_ID_HEADER, value);
+                    }
+                    Ok(res)
+                }
+                // Los errores de los middlewares internos, como un 401, se reemplazan por su
+                // respuesta con la cabecera para que también lleven el id.
+                Err(err) => {
+                    let mut response = err.error_response();
+                    if let Some(value) = value {
+                        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+                    }
+                    Err(InternalError::from_response(err.to_string(), response).into())
+                }
+            }
+        }))
+    }
+}
+
+fn is_valid(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= MAX_REQUEST_ID_LENGTH
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+}
+
+/// Toma el id de la cabecera `X-Request-Id` o genera uno, lo devuelve en la respuesta y lo
+/// agrega a todos los registros de log que se emitan mientras se atiende la petición.
+pub struct RequestIdMiddlewareFactory;
+
+impl<S, B> Transform<S, ServiceRequest> for RequestIdMiddlewareFactory
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = RequestIdMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(RequestIdMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod request_id_middleware_tests {
+    use super::*;
+    use crate::infrastructure::logging::current_request;
+    use actix_web::{
+        error::ErrorUnauthorized,
+        test::{call_service, init_service, read_body, try_call_service, TestRequest},
+        web, App, HttpResponse,
+    };
+
+    async fn echo_request_id() -> HttpResponse {
+        HttpResponse::Ok().body(
+            current_request()
+                .map(|request| request.id)
+                .unwrap_or_default(),
+        )
+    }
+
+    #[actix_web::test]
+    async fn test_request_id_is_kept_and_visible_to_logs() {
+        let app = init_service(
+            App::new()
+                .wrap(RequestIdMiddlewareFactory)
+                .route("/", web::get().to(echo_request_id)),
+        )
+        .await;
+
+        let resp = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/")
+                .insert_header((REQUEST_ID_HEADER, "abc-123"))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.headers().get(REQUEST_ID_HEADER).unwrap(), "abc-123");
+        assert_eq!(read_body(resp).await, "abc-123");
+    }
+
+    #[actix_web::test]
+    async fn test_invalid_or_missing_request_id_is_generated() {
+        let app = init_service(
+            App::new()
+                .wrap(RequestIdMiddlewareFactory)
+                .route("/", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        for req in [
+            TestRequest::get().uri("/"),
+            TestRequest::get()
+                .uri("/")
+                .insert_header((REQUEST_ID_HEADER, "bad id\twith spaces")),
+        ] {
+            let resp = call_service(&app, req.to_request()).await;
+            let id = resp.headers().get(REQUEST_ID_HEADER).unwrap();
+
+            assert!(uuid::Uuid::parse_str(id.to_str().unwrap()).is_ok());
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_errors_from_inner_services_carry_the_request_id() {
+        let app = init_service(
+            App::new()
+                .wrap_fn(|_, _| {
+                    futures::future::err::<ServiceResponse, _>(ErrorUnauthorized("Token expired"))
+                })
+                .wrap(RequestIdMiddlewareFactory)
+                .route("/", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        let err = try_call_service(
+            &app,
+            TestRequest::get()
+                .uri("/")
+                .insert_header((REQUEST_ID_HEADER, "abc-123"))
+                .to_request(),
+        )
+        .await
+        .unwrap_err();
+        let resp = err.error_response();
+
+        assert_eq!(resp.status(), 401);
+        assert_eq!(resp.headers().get(REQUEST_ID_HEADER).unwrap(), "abc-123");
+    }
+}
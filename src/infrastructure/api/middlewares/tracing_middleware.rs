@@ -0,0 +1,170 @@
+use super::{request_id_middleware::RequestId, response_status};
+use actix_service::Transform;
+use actix_web::{
+    dev::{Service, ServiceRequest, ServiceResponse},
+    http::header::HeaderMap,
+    Error, HttpMessage,
+};
+use futures::future::{ready, LocalBoxFuture, Ready};
+use opentelemetry::propagation::{Extractor, TextMapPropagator};
+use opentelemetry_sdk::propagation::TraceContextPropagator;
+use std::rc::Rc;
+use tracing::{field::Empty, Instrument};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Lee las cabeceras `traceparent` y `tracestate` de la petición.
+struct HeaderExtractor<'a>(&'a HeaderMap);
+
+impl Extractor for HeaderExtractor<'_> {
+    fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).and_then(|value| value.to_str().ok())
+    }
+
+    fn keys(&self) -> Vec<&str> {
+        self.0.keys().map(|key| key.as_str()).collect()
+    }
+}
+
+pub struct TracingMiddleware<S> {
+    service: Rc<S>,
+}
+
+impl<S, B> Service<ServiceRequest> for TracingMiddleware<S>
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Future = LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_service::forward_ready!(service);
+
+    fn call(&self, req: ServiceRequest) -> Self::Future {
+        let srv = self.service.clone();
+        let route = req
+            .match_pattern()
+            .unwrap_or_else(|| "unmatched".to_string());
+        let request_id = req
+            .extensions()
+            .get::<RequestId>()
+            .map(|request_id| request_id.0.clone());
+        let span = tracing::info_span!(
+            "HTTP request",
+            otel.name = %format_args!("{} {}", req.method(), route),
+            otel.kind = "server",
+            otel.status_description = Empty,
+            http.request.method = %req.method(),
+            http.route = %route,
+            url.path = req.path(),
+            http.response.status_code = Empty,
+            request_id = request_id,
+        );
+        // Si quien llama ya abrió una traza, la petición queda como parte de ella.
+        let parent = TraceContextPropagator::new().extract(&HeaderExtractor(req.headers()));
+        let _ = span.set_parent(parent);
+
+        futures::FutureExt::boxed_local(
+            async move {
+                let res = srv.call(req).await;
+
+                let status = response_status(&res);
+                let span = tracing::Span::current();
+                span.record("http.response.status_code", status.as_u16());
+                if status.is_server_error() {
+                    span.record("otel.status_description", status.to_string());
+                }
+
+                res
+            }
+            .instrument(span),
+        )
+    }
+}
+
+/// Abre un span por petición, continuando la traza de la cabecera `traceparent` si viene.
+pub struct TracingMiddlewareFactory;
+
+impl<S, B> Transform<S, ServiceRequest> for TracingMiddlewareFactory
+where
+    S: Service<ServiceRequest, Response = ServiceResponse<B>, Error = Error> + 'static,
+{
+    type Response = ServiceResponse<B>;
+    type Error = Error;
+    type Transform = TracingMiddleware<S>;
+    type InitError = ();
+    type Future = Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        ready(Ok(TracingMiddleware {
+            service: Rc::new(service),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tracing_middleware_tests {
+    use super::*;
+    use crate::infrastructure::telemetry::otlp::{subscriber, RecordingExporter};
+    use actix_web::{
+        test::{call_service, init_service, TestRequest},
+        web, App, HttpResponse,
+    };
+    use opentelemetry::trace::{SpanKind, Status, TraceId};
+
+    const TRACE_ID: &str = "4bf92f3577b34da6a3ce929d0e0e4736";
+
+    #[actix_web::test]
+    async fn test_requests_continue_the_incoming_trace() {
+        let exporter = RecordingExporter::default();
+        let provider = exporter.provider();
+        let _guard = tracing::subscriber::set_default(subscriber(&provider));
+        let app = init_service(
+            App::new()
+                .wrap(TracingMiddlewareFactory)
+                .route("/users/{id}", web::get().to(HttpResponse::Ok)),
+        )
+        .await;
+
+        call_service(
+            &app,
+            TestRequest::get()
+                .uri("/users/7")
+                .insert_header((
+                    "traceparent",
+                    format!("00-{}-00f067aa0ba902b7-01", TRACE_ID),
+                ))
+                .to_request(),
+        )
+        .await;
+
+        let spans = exporter.spans();
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].name, "GET /users/{id}");
+        assert_eq!(spans[0].span_kind, SpanKind::Server);
+        assert_eq!(
+            spans[0].span_context.trace_id(),
+            TraceId::from_hex(TRACE_ID).unwrap()
+        );
+        assert_eq!(spans[0].status, Status::Unset);
+    }
+
+    #[actix_web::test]
+    async fn test_server_errors_mark_the_span() {
+        let exporter = RecordingExporter::default();
+        let provider = exporter.provider();
+        let _guard = tracing::subscriber::set_default(subscriber(&provider));
+        let app = init_service(
+            App::new()
+                .wrap(TracingMiddlewareFactory)
+                .route("/", web::get().to(HttpResponse::InternalServerError)),
+        )
+        .await;
+
+        call_service(&app, TestRequest::get().uri("/").to_request()).await;
+
+        let spans = exporter.spans();
+        assert_eq!(spans.len(), 1);
+        assert_ne!(spans[0].span_context.trace_id(), TraceId::INVALID);
+        assert!(matches!(spans[0].status, Status::Error { .. }));
+    }
+}
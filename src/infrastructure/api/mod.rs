@@ -1,3 +1,4 @@
 pub mod dto;
 pub mod handlers;
 pub mod middlewares;
+pub mod sse;
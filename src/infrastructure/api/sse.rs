@@ -0,0 +1,45 @@
+use crate::{
+    core::entities::order_event::OrderEvent,
+    infrastructure::api::dto::order_response::OrderResponse,
+};
+use actix_web::{http::header, web::Bytes, HttpResponse};
+use futures::stream::{self, StreamExt};
+use tokio::sync::broadcast::{error::RecvError, Receiver};
+
+/// Respuesta Server-Sent Events con los eventos de `receiver` que cumplen `filter`.
+pub fn order_event_stream<F>(receiver: Receiver<OrderEvent>, filter: F) -> HttpResponse
+where
+    F: Fn(&OrderEvent) -> bool + 'static,
+{
+    // El comentario inicial envía las cabeceras de inmediato para que el cliente sepa que la
+    // suscripción quedó abierta.
+    let connected = stream::once(async { Bytes::from_static(b": connected\n\n") });
+
+    let events = stream::unfold((receiver, filter), |(mut receiver, filter)| async move {
+        loop {
+            match receiver.recv().await {
+                Ok(event) if filter(&event) => {
+                    return Some((format_event(event), (receiver, filter)));
+                }
+                Ok(_) => continue,
+                Err(RecvError::Lagged(skipped)) => {
+                    log::warn!("Order event subscriber lagged, {} events skipped", skipped);
+                    continue;
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    });
+
+    HttpResponse::Ok()
+        .insert_header((header::CONTENT_TYPE, "text/event-stream"))
+        .insert_header((header::CACHE_CONTROL, "no-cache"))
+        .streaming(connected.chain(events).map(Ok::<_, actix_web::Error>))
+}
+
+fn format_event(event: OrderEvent) -> Bytes {
+    let data =
+        serde_json::to_string(&OrderResponse::from_order_core(event.order)).unwrap_or_default();
+
+    Bytes::from(format!("event: {}\ndata: {}\n\n", event.kind.name(), data))
+}
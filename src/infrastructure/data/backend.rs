@@ -0,0 +1,226 @@
+//! Lo que cambia entre los motores SQL. Los repositorios escriben cada consulta una sola vez, con
+//! `?` como marcador y los identificadores reservados entre acentos graves (`` `user` ``), y cada
+//! motor la adapta con `sql`.
+use futures::future::BoxFuture;
+use sqlx::{database::HasArguments, query::Query, Database, Executor, MySql};
+use std::borrow::Cow;
+
+pub type SqlQuery<'q, DB> = Query<'q, DB, <DB as HasArguments<'q>>::Arguments>;
+
+/// Filas afectadas por una sentencia. sqlx lo ofrece en el resultado de cada motor, pero no en
+/// `Database::QueryResult`.
+pub trait RowsAffected {
+    fn rows_affected(&self) -> u64;
+}
+
+pub trait Backend: Database<QueryResult: RowsAffected> {
+    /// Valor del atributo `db.system` en las trazas.
+    const SYSTEM: &'static str;
+
+    /// Adapta la consulta al dialecto del motor.
+    fn sql(query: &str) -> Cow<'_, str> {
+        Cow::Borrowed(query)
+    }
+
+    /// Igual que `sql`, para un `INSERT` cuyo id se obtiene con `insert_id`.
+    fn insert_sql(insert: &str) -> Cow<'_, str> {
+        Self::sql(insert)
+    }
+
+    /// Ejecuta un `INSERT` preparado con `insert_sql` y retorna el id de la fila nueva.
+    fn insert_id<'e, 'q: 'e, E>(
+        query: SqlQuery<'q, Self>,
+        executor: E,
+    ) -> BoxFuture<'e, Result<i32, sqlx::Error>>
+    where
+        E: 'e + Executor<'e, Database = Self>;
+
+    /// `INSERT` que no hace nada si ya existe una fila con la misma clave.
+    fn insert_ignore(insert: &str) -> String;
+
+    /// `INSERT` que, si ya existe una fila con la clave `key`, actualiza `columns`.
+    fn upsert(insert: &str, key: &[&str], columns: &[&str]) -> String;
+
+    /// `SELECT` que bloquea las filas leídas hasta el final de la transacción.
+    fn for_update(select: &str) -> String {
+        format!("{} FOR UPDATE", select)
+    }
+}
+
+impl RowsAffected for sqlx::mysql::MySqlQueryResult {
+    fn rows_affected(&self) -> u64 {
+        self.rows_affected()
+    }
+}
+
+impl Backend for MySql {
+    const SYSTEM: &'static str = "mysql";
+
+    fn insert_id<'e, 'q: 'e, E>(
+        query: SqlQuery<'q, Self>,
+        executor: E,
+    ) -> BoxFuture<'e, Result<i32, sqlx::Error>>
+    where
+        E: 'e + Executor<'e, Database = Self>,
+    {
+        Box::pin(async move { Ok(query.execute(executor).await?.last_insert_id() as i32) })
+    }
+
+    fn insert_ignore(insert: &str) -> String {
+        insert.replacen("INSERT INTO", "INSERT IGNORE INTO", 1)
+    }
+
+    fn upsert(insert: &str, _key: &[&str], columns: &[&str]) -> String {
+        let updates: Vec<String> = columns
+            .iter()
+            .map(|column| format!("{column}=VALUES({column})"))
+            .collect();
+
+        format!("{} ON DUPLICATE KEY UPDATE {}", insert, updates.join(", "))
+    }
+}
+
+/// `ON CONFLICT`, común a PostgreSQL y SQLite.
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
+fn on_conflict_update(insert: &str, key: &[&str], columns: &[&str]) -> String {
+    let updates: Vec<String> = columns
+        .iter()
+        .map(|column| format!("{column}=excluded.{column}"))
+        .collect();
+
+    format!(
+        "{} ON CONFLICT ({}) DO UPDATE SET {}",
+        insert,
+        key.join(", "),
+        updates.join(", ")
+    )
+}
+
+#[cfg(feature = "postgres")]
+impl RowsAffected for sqlx::postgres::PgQueryResult {
+    fn rows_affected(&self) -> u64 {
+        self.rows_affected()
+    }
+}
+
+/// PostgreSQL numera los parámetros (`$1`), no acepta acentos graves y obtiene los ids con
+/// `RETURNING id`.
+#[cfg(feature = "postgres")]
+impl Backend for sqlx::Postgres {
+    const SYSTEM: &'static str = "postgresql";
+
+    fn sql(query: &str) -> Cow<'_, str> {
+        let mut sql = String::with_capacity(query.len() + 16);
+        let mut parameter = 0;
+        let mut in_literal = false;
+
+        for c in query.chars() {
+            match c {
+                '\'' => {
+                    in_literal = !in_literal;
+                    sql.push(c);
+                }
+                '?' if !in_literal => {
+                    parameter += 1;
+                    sql.push('$');
+                    sql.push_str(&parameter.to_string());
+                }
+                '`' if !in_literal => sql.push('"'),
+                _ => sql.push(c),
+            }
+        }
+
+        Cow::Owned(sql)
+    }
+
+    fn insert_sql(insert: &str) -> Cow<'_, str> {
+        Cow::Owned(format!("{} RETURNING id", Self::sql(insert)))
+    }
+
+    fn insert_id<'e, 'q: 'e, E>(
+        query: SqlQuery<'q, Self>,
+        executor: E,
+    ) -> BoxFuture<'e, Result<i32, sqlx::Error>>
+    where
+        E: 'e + Executor<'e, Database = Self>,
+    {
+        Box::pin(async move {
+            let row = query.fetch_one(executor).await?;
+            sqlx::Row::try_get(&row, 0)
+        })
+    }
+
+    fn insert_ignore(insert: &str) -> String {
+        format!("{} ON CONFLICT DO NOTHING", insert)
+    }
+
+    fn upsert(insert: &str, key: &[&str], columns: &[&str]) -> String {
+        on_conflict_update(insert, key, columns)
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl RowsAffected for sqlx::sqlite::SqliteQueryResult {
+    fn rows_affected(&self) -> u64 {
+        self.rows_affected()
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl Backend for sqlx::Sqlite {
+    const SYSTEM: &'static str = "sqlite";
+
+    fn insert_id<'e, 'q: 'e, E>(
+        query: SqlQuery<'q, Self>,
+        executor: E,
+    ) -> BoxFuture<'e, Result<i32, sqlx::Error>>
+    where
+        E: 'e + Executor<'e, Database = Self>,
+    {
+        Box::pin(async move { Ok(query.execute(executor).await?.last_insert_rowid() as i32) })
+    }
+
+    fn insert_ignore(insert: &str) -> String {
+        format!("{} ON CONFLICT DO NOTHING", insert)
+    }
+
+    fn upsert(insert: &str, key: &[&str], columns: &[&str]) -> String {
+        on_conflict_update(insert, key, columns)
+    }
+
+    /// SQLite no bloquea filas: una sola transacción puede escribir a la vez, y si dos leyeron
+    /// lo mismo antes de escribir, la segunda falla con `SQLITE_BUSY`.
+    fn for_update(select: &str) -> String {
+        select.to_string()
+    }
+}
+
+#[cfg(test)]
+mod backend_tests {
+    use super::*;
+
+    #[test]
+    fn test_mysql_keeps_the_query() {
+        assert_eq!(
+            MySql::sql("SELECT * FROM `user` WHERE id=?"),
+            "SELECT * FROM `user` WHERE id=?"
+        );
+        assert_eq!(
+            MySql::insert_ignore("INSERT INTO cart_coupon (`user`, promotion) VALUES (?,?)"),
+            "INSERT IGNORE INTO cart_coupon (`user`, promotion) VALUES (?,?)"
+        );
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_postgres_numbers_parameters_and_quotes_identifiers() {
+        assert_eq!(
+            sqlx::Postgres::sql("UPDATE `user` SET status='?' WHERE id=? AND role=?"),
+            "UPDATE \"user\" SET status='?' WHERE id=$1 AND role=$2"
+        );
+        assert_eq!(
+            sqlx::Postgres::insert_sql("INSERT INTO store (name) VALUES (?)"),
+            "INSERT INTO store (name) VALUES ($1) RETURNING id"
+        );
+    }
+}
@@ -0,0 +1,1578 @@
+//! Suite de conformidad compartida por todos los backends. Cada uno la ejecuta contra una base
+//! recién migrada con [`check_repositories`], de modo que los repositorios de MySQL, PostgreSQL,
+//! SQLite y memoria se comporten igual frente a los servicios.
+use super::Repositories;
+use crate::core::{
+    entities::{
+        cart::CartItem,
+        category::Category,
+        delivery::{Address, DeliveryZone},
+        inventory::{Ingredient, RecipeLine, StockAdjustment, StockAdjustmentReason},
+        loyalty::{LoyaltyEntry, LoyaltyEntryKind},
+        modifier::{ModifierGroup, ModifierOption},
+        order::{Order, OrderItem, OrderStatus, OrderStatusChange},
+        payment::{Payment, PaymentAttempt, PaymentOperation, PaymentStatus},
+        product::Product,
+        promotion::{AppliedDiscount, Promotion, PromotionKind},
+        refund::{Refund, RefundStatus},
+        store::{Store, StoreException},
+        user::User,
+        user_role::UserRole,
+        user_status::UserStatus,
+    },
+    errors::{
+        cart_errors::CartError, catalog_errors::CatalogError, delivery_errors::DeliveryError,
+        inventory_errors::InventoryError, order_errors::OrderError, payment_errors::PaymentError,
+        promotion_errors::PromotionError, store_errors::StoreError, user_errors::UserError,
+    },
+    ports::{
+        cart_port::ICartRepository,
+        catalog_port::ICatalogRepository,
+        delivery_port::IDeliveryRepository,
+        inventory_port::IInventoryRepository,
+        loyalty_port::ILoyaltyRepository,
+        order_port::IOrderRepository,
+        payment_port::IPaymentRepository,
+        promotion_port::IPromotionRepository,
+        store_port::IStoreRepository,
+        unit_of_work_port::{ITransaction, IUnitOfWork},
+        user_port::IUserRepository,
+    },
+};
+use chrono::{Duration, Utc};
+
+/// Datos creados por las primeras comprobaciones y reutilizados por las siguientes.
+struct Fixture {
+    client_id: i32,
+    employee_id: i32,
+    category_id: i32,
+    product_id: i32,
+    ingredient_id: i32,
+}
+
+/// Ejecuta todas las comprobaciones sobre los repositorios recibidos. La base debe estar vacía
+/// salvo por los datos que cargan las migraciones.
+pub(crate) async fn check_repositories<U, Ct, L, I, S, D, O, Py, Pr, Ca, W>(
+    repos: Repositories<U, Ct, L, I, S, D, O, Py, Pr, Ca, W>,
+) where
+    U: IUserRepository,
+    Ct: ICatalogRepository,
+    L: ILoyaltyRepository<Transaction = W::Transaction>,
+    I: IInventoryRepository,
+    S: IStoreRepository<Transaction = W::Transaction>,
+    D: IDeliveryRepository,
+    O: IOrderRepository<Transaction = W::Transaction>,
+    Py: IPaymentRepository<Transaction = W::Transaction>,
+    Pr: IPromotionRepository<Transaction = W::Transaction>,
+    Ca: ICartRepository<Transaction = W::Transaction>,
+    W: IUnitOfWork,
+{
+    let (client_id, employee_id) = check_users(&repos.user).await;
+    let (category_id, product_id) = check_catalog(&repos.catalog).await;
+    let ingredient_id = check_inventory(&repos.inventory, &repos.catalog, product_id).await;
+
+    let fixture = Fixture {
+        client_id,
+        employee_id,
+        category_id,
+        product_id,
+        ingredient_id,
+    };
+
+    check_orders(&repos.order, &repos.inventory, &repos.loyalty, &fixture).await;
+    check_payments(&repos.unit_of_work, &repos.payment, &repos.order, &fixture).await;
+    check_payment_authorization(&repos.unit_of_work, &repos.payment, &repos.order, &fixture).await;
+    check_refund_after_failed_transition(
+        &repos.unit_of_work,
+        &repos.payment,
+        &repos.order,
+        &fixture,
+    )
+    .await;
+    check_unit_of_work(&repos.unit_of_work, &repos.order, &fixture).await;
+    check_promotions(
+        &repos.unit_of_work,
+        &repos.promotion,
+        &repos.order,
+        &fixture,
+    )
+    .await;
+    check_loyalty(&repos.unit_of_work, &repos.loyalty, &fixture).await;
+    check_cart(
+        &repos.unit_of_work,
+        &repos.cart,
+        &repos.order,
+        &repos.promotion,
+        &fixture,
+    )
+    .await;
+    check_stores(&repos.unit_of_work, &repos.store, &repos.order, &fixture).await;
+    check_delivery(&repos.delivery, &fixture).await;
+}
+
+fn user(email: &str, role: UserRole) -> User {
+    User {
+        name: "Ana".to_string(),
+        surname: "García".to_string(),
+        email: email.to_string(),
+        password: "hash".to_string(),
+        role,
+        ..User::new()
+    }
+}
+
+fn order(fixture: &Fixture, quantity: i32) -> Order {
+    let now = Utc::now();
+
+    Order {
+        user_id: fixture.client_id,
+        items: vec![OrderItem {
+            product_id: fixture.product_id,
+            product_name: "Cortado".to_string(),
+            quantity,
+            unit_price: 250,
+            tax_rate: 2100,
+            ..OrderItem::new()
+        }],
+        history: vec![OrderStatusChange {
+            status: OrderStatus::Placed,
+            changed_by: fixture.client_id,
+            changed_at: now,
+        }],
+        ..Order::new()
+    }
+}
+
+async fn check_users(repo: &impl IUserRepository) -> (i32, i32) {
+    let client = repo
+        .insert_user(user("ana@cafe.com", UserRole::Client))
+        .await
+        .unwrap();
+    let client_id = client.id.unwrap();
+    let employee_id = repo
+        .insert_user(user("barista@cafe.com", UserRole::Employee))
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+
+    let found = repo
+        .get_user_by_email("ANA@cafe.com".to_string())
+        .await
+        .unwrap();
+    assert_eq!(found.id, Some(client_id));
+    assert_eq!(found.role, UserRole::Client);
+    assert_eq!(found.status, UserStatus::Active);
+    assert!(matches!(
+        repo.get_user_by_email("nadie@cafe.com".to_string()).await,
+        Err(UserError::NotFound)
+    ));
+
+    repo.update_user(
+        client_id,
+        User {
+            name: "Ana María".to_string(),
+            phone_number: Some("600111222".to_string()),
+            ..client.clone()
+        },
+    )
+    .await
+    .unwrap();
+    repo.update_password(client_id, "new-hash".to_string())
+        .await
+        .unwrap();
+    let updated = repo.get_user_by_id(client_id as u8).await.unwrap();
+    assert_eq!(updated.name, "Ana María");
+    assert_eq!(updated.phone_number.as_deref(), Some("600111222"));
+    assert_eq!(updated.password, "new-hash");
+
+    repo.update_user_status(employee_id, UserStatus::Suspended)
+        .await
+        .unwrap();
+    let suspended = repo
+        .get_users_by_status(UserStatus::Suspended)
+        .await
+        .unwrap();
+    assert_eq!(suspended.len(), 1);
+    assert_eq!(suspended[0].id, Some(employee_id));
+    repo.update_user_status(employee_id, UserStatus::Active)
+        .await
+        .unwrap();
+
+    // Una baja oculta al usuario, libera su email y solo se purga pasada la retención.
+    let leaving_id = repo
+        .insert_user(user("baja@cafe.com", UserRole::Client))
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+    repo.delete_user(leaving_id).await.unwrap();
+    assert!(matches!(
+        repo.get_user_by_id(leaving_id as u8).await,
+        Err(UserError::NotFound)
+    ));
+    assert!(matches!(
+        repo.delete_user(leaving_id).await,
+        Err(UserError::NotFound)
+    ));
+    assert!(matches!(
+        repo.update_password(leaving_id, "hash".to_string()).await,
+        Err(UserError::NotFound)
+    ));
+    assert_eq!(repo.get_users().await.unwrap().len(), 2);
+    assert_eq!(repo.purge_deleted_users(30).await.unwrap(), 0);
+    assert_eq!(repo.purge_deleted_users(0).await.unwrap(), 1);
+    assert_eq!(
+        repo.get_users_by_status(UserStatus::Deleted)
+            .await
+            .unwrap()
+            .len(),
+        0
+    );
+
+    (client_id, employee_id)
+}
+
+async fn check_catalog(repo: &impl ICatalogRepository) -> (i32, i32) {
+    let category = repo
+        .insert_category(Category {
+            name: "Cafés".to_string(),
+            tax_rate: Some(1000),
+            ..Category::new()
+        })
+        .await
+        .unwrap();
+    let category_id = category.id.unwrap();
+    repo.update_category(
+        category_id,
+        Category {
+            description: Some("De especialidad".to_string()),
+            ..category
+        },
+    )
+    .await
+    .unwrap();
+    let stored = repo.get_category_by_id(category_id).await.unwrap();
+    assert_eq!(stored.description.as_deref(), Some("De especialidad"));
+    assert_eq!(stored.tax_rate, Some(1000));
+    assert!(matches!(
+        repo.get_category_by_id(category_id + 100).await,
+        Err(CatalogError::CategoryNotFound)
+    ));
+
+    let product = repo
+        .insert_product(Product {
+            category_id,
+            name: "Cortado".to_string(),
+            price: 250,
+            ..Product::new()
+        })
+        .await
+        .unwrap();
+    let product_id = product.id.unwrap();
+    let other_id = repo
+        .insert_product(Product {
+            category_id,
+            name: "Bizcocho".to_string(),
+            price: 180,
+            ..Product::new()
+        })
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+    repo.update_product(
+        product_id,
+        Product {
+            price: 270,
+            ..product
+        },
+    )
+    .await
+    .unwrap();
+    let stored = repo.get_product_by_id(product_id).await.unwrap();
+    assert_eq!(stored.price, 270);
+    assert!(stored.available);
+    assert!(stored.in_stock);
+    assert_eq!(repo.get_products(Some(category_id)).await.unwrap().len(), 2);
+    assert_eq!(
+        repo.get_products(Some(category_id + 100))
+            .await
+            .unwrap()
+            .len(),
+        0
+    );
+
+    let group = repo
+        .insert_modifier_group(ModifierGroup {
+            name: "Leche".to_string(),
+            min_selections: 0,
+            max_selections: 1,
+            ..ModifierGroup::new()
+        })
+        .await
+        .unwrap();
+    let group_id = group.id.unwrap();
+    let option = repo
+        .insert_modifier_option(ModifierOption {
+            group_id,
+            name: "Avena".to_string(),
+            price_delta: 40,
+            ..ModifierOption::new()
+        })
+        .await
+        .unwrap();
+    let option_id = option.id.unwrap();
+    repo.update_modifier_option(
+        option_id,
+        ModifierOption {
+            price_delta: 50,
+            ..option
+        },
+    )
+    .await
+    .unwrap();
+    assert!(matches!(
+        repo.update_modifier_option(option_id + 100, ModifierOption::new())
+            .await,
+        Err(CatalogError::ModifierOptionNotFound)
+    ));
+
+    repo.attach_modifier_group(product_id, group_id)
+        .await
+        .unwrap();
+    // Asociar dos veces el mismo grupo no lo duplica.
+    repo.attach_modifier_group(product_id, group_id)
+        .await
+        .unwrap();
+    let groups = repo
+        .get_modifier_groups_by_product(product_id)
+        .await
+        .unwrap();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].options.len(), 1);
+    assert_eq!(groups[0].options[0].price_delta, 50);
+    repo.detach_modifier_group(product_id, group_id)
+        .await
+        .unwrap();
+    assert!(matches!(
+        repo.detach_modifier_group(product_id, group_id).await,
+        Err(CatalogError::ModifierGroupNotFound)
+    ));
+
+    repo.delete_modifier_group(group_id).await.unwrap();
+    assert_eq!(repo.get_modifier_groups().await.unwrap().len(), 0);
+    repo.delete_product(other_id).await.unwrap();
+    assert!(matches!(
+        repo.delete_product(other_id).await,
+        Err(CatalogError::ProductNotFound)
+    ));
+
+    let empty_id = repo
+        .insert_category(Category {
+            name: "Tés".to_string(),
+            ..Category::new()
+        })
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+    repo.delete_category(empty_id).await.unwrap();
+    assert_eq!(repo.get_categories().await.unwrap().len(), 1);
+
+    (category_id, product_id)
+}
+
+async fn check_inventory(
+    repo: &impl IInventoryRepository,
+    catalog: &impl ICatalogRepository,
+    product_id: i32,
+) -> i32 {
+    let ingredient = repo
+        .insert_ingredient(Ingredient {
+            name: "Café en grano".to_string(),
+            stock: 100,
+            low_stock_threshold: 20,
+            ..Ingredient::new()
+        })
+        .await
+        .unwrap();
+    let ingredient_id = ingredient.id.unwrap();
+    repo.update_ingredient(
+        ingredient_id,
+        Ingredient {
+            low_stock_threshold: 30,
+            ..ingredient
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        repo.get_ingredient_by_id(ingredient_id)
+            .await
+            .unwrap()
+            .low_stock_threshold,
+        30
+    );
+    assert!(matches!(
+        repo.get_ingredient_by_id(ingredient_id + 100).await,
+        Err(InventoryError::IngredientNotFound)
+    ));
+
+    repo.set_product_recipe(
+        product_id,
+        vec![RecipeLine {
+            ingredient_id,
+            quantity: 18,
+        }],
+    )
+    .await
+    .unwrap();
+    let recipe = repo.get_product_recipe(product_id).await.unwrap();
+    assert_eq!(recipe.len(), 1);
+    assert_eq!(recipe[0].quantity, 18);
+
+    let adjusted = repo
+        .adjust_stock(StockAdjustment {
+            ingredient_id,
+            delta: -90,
+            reason: StockAdjustmentReason::Waste,
+            ..StockAdjustment::new()
+        })
+        .await
+        .unwrap();
+    assert_eq!(adjusted.stock, 10);
+    // Con menos stock que el de la receta el producto deja de estar disponible.
+    assert!(
+        !catalog
+            .get_product_by_id(product_id)
+            .await
+            .unwrap()
+            .in_stock
+    );
+
+    assert!(matches!(
+        repo.adjust_stock(StockAdjustment {
+            ingredient_id,
+            delta: -11,
+            ..StockAdjustment::new()
+        })
+        .await,
+        Err(InventoryError::InsufficientStock)
+    ));
+    assert!(matches!(
+        repo.adjust_stock(StockAdjustment {
+            ingredient_id: ingredient_id + 100,
+            delta: 5,
+            ..StockAdjustment::new()
+        })
+        .await,
+        Err(InventoryError::IngredientNotFound)
+    ));
+
+    repo.adjust_stock(StockAdjustment {
+        ingredient_id,
+        delta: 90,
+        reason: StockAdjustmentReason::Restock,
+        ..StockAdjustment::new()
+    })
+    .await
+    .unwrap();
+    assert!(
+        catalog
+            .get_product_by_id(product_id)
+            .await
+            .unwrap()
+            .in_stock
+    );
+    let adjustments = repo.get_adjustments(ingredient_id).await.unwrap();
+    assert_eq!(adjustments.len(), 2);
+    assert!(adjustments
+        .iter()
+        .any(|adjustment| adjustment.reason == StockAdjustmentReason::Waste));
+
+    let unused_id = repo
+        .insert_ingredient(Ingredient {
+            name: "Canela".to_string(),
+            ..Ingredient::new()
+        })
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+    repo.delete_ingredient(unused_id).await.unwrap();
+    assert!(matches!(
+        repo.delete_ingredient(unused_id).await,
+        Err(InventoryError::IngredientNotFound)
+    ));
+    assert_eq!(repo.get_ingredients().await.unwrap().len(), 1);
+
+    ingredient_id
+}
+
+async fn check_orders(
+    repo: &impl IOrderRepository,
+    inventory: &impl IInventoryRepository,
+    loyalty: &impl ILoyaltyRepository,
+    fixture: &Fixture,
+) {
+    let placed = repo.insert_order(order(fixture, 2)).await.unwrap();
+    let order_id = placed.id.unwrap();
+    assert!(placed.items[0].id.is_some());
+
+    let stored = repo.get_order_by_id(order_id).await.unwrap();
+    assert_eq!(stored.status, OrderStatus::Placed);
+    assert_eq!(stored.user_id, fixture.client_id);
+    assert_eq!(stored.items.len(), 1);
+    assert_eq!(stored.items[0].quantity, 2);
+    assert_eq!(stored.history.len(), 1);
+    assert!(matches!(
+        repo.get_order_by_id(order_id + 100).await,
+        Err(OrderError::NotFound)
+    ));
+
+    repo.update_order_status(
+        order_id,
+        OrderStatus::Placed,
+        OrderStatus::Accepted,
+        fixture.employee_id,
+        vec![],
+        vec![],
+    )
+    .await
+    .unwrap();
+    assert!(matches!(
+        repo.update_order_status(
+            order_id,
+            OrderStatus::Placed,
+            OrderStatus::Accepted,
+            fixture.employee_id,
+            vec![],
+            vec![],
+        )
+        .await,
+        Err(OrderError::InvalidTransition { .. })
+    ));
+
+    // Si falta stock se descarta toda la transición, incluidos los puntos.
+    let consumption = |delta| StockAdjustment {
+        ingredient_id: fixture.ingredient_id,
+        delta,
+        reason: StockAdjustmentReason::OrderConsumption,
+        order_id: Some(order_id),
+        adjusted_by: fixture.employee_id,
+        ..StockAdjustment::new()
+    };
+    let earned = LoyaltyEntry {
+        id: None,
+        user_id: fixture.client_id,
+        kind: LoyaltyEntryKind::Earned,
+        points: 5,
+        order_id: Some(order_id),
+        expires_at: Some(Utc::now() + Duration::days(365)),
+        created_at: Utc::now(),
+    };
+    assert!(matches!(
+        repo.update_order_status(
+            order_id,
+            OrderStatus::Accepted,
+            OrderStatus::Preparing,
+            fixture.employee_id,
+            vec![earned.clone()],
+            vec![consumption(-36), consumption(-1000)],
+        )
+        .await,
+        Err(OrderError::OutOfStock)
+    ));
+    assert_eq!(
+        repo.get_order_by_id(order_id).await.unwrap().status,
+        OrderStatus::Accepted
+    );
+    assert_eq!(
+        inventory
+            .get_ingredient_by_id(fixture.ingredient_id)
+            .await
+            .unwrap()
+            .stock,
+        100
+    );
+    assert_eq!(
+        loyalty.get_entries(fixture.client_id).await.unwrap().len(),
+        0
+    );
+
+    repo.update_order_status(
+        order_id,
+        OrderStatus::Accepted,
+        OrderStatus::Preparing,
+        fixture.employee_id,
+        vec![earned],
+        vec![consumption(-36)],
+    )
+    .await
+    .unwrap();
+    let stored = repo.get_order_by_id(order_id).await.unwrap();
+    assert_eq!(stored.status, OrderStatus::Preparing);
+    assert_eq!(stored.history.len(), 3);
+    assert_eq!(
+        inventory
+            .get_ingredient_by_id(fixture.ingredient_id)
+            .await
+            .unwrap()
+            .stock,
+        64
+    );
+    assert_eq!(
+        loyalty.get_entries(fixture.client_id).await.unwrap().len(),
+        1
+    );
+
+    repo.claim_order(order_id, fixture.employee_id)
+        .await
+        .unwrap();
+    repo.claim_order(order_id, fixture.employee_id)
+        .await
+        .unwrap();
+    assert!(matches!(
+        repo.claim_order(order_id, fixture.client_id).await,
+        Err(OrderError::AlreadyClaimed)
+    ));
+    assert_eq!(
+        repo.get_order_by_id(order_id).await.unwrap().claimed_by,
+        Some(fixture.employee_id)
+    );
+
+    repo.assign_courier(order_id, fixture.employee_id)
+        .await
+        .unwrap();
+    assert!(matches!(
+        repo.assign_courier(order_id, fixture.client_id).await,
+        Err(OrderError::InvalidCourier)
+    ));
+    assert_eq!(
+        repo.get_order_by_id(order_id).await.unwrap().courier_id,
+        Some(fixture.employee_id)
+    );
+
+    let now = Utc::now();
+    let scheduled_id = repo
+        .insert_order(Order {
+            scheduled_for: Some(now + Duration::minutes(30)),
+            release_at: Some(now - Duration::minutes(1)),
+            ..order(fixture, 1)
+        })
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+    let released = repo.release_due_orders(now).await.unwrap();
+    assert_eq!(released.len(), 1);
+    assert_eq!(released[0].id, Some(scheduled_id));
+    assert!(released[0].release_at.is_none());
+    // Un pedido se libera una sola vez.
+    assert_eq!(repo.release_due_orders(now).await.unwrap().len(), 0);
+
+    assert_eq!(
+        repo.get_orders_by_user(fixture.client_id)
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+    assert_eq!(
+        repo.get_orders(Some(OrderStatus::Placed))
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert_eq!(repo.get_orders(None).await.unwrap().len(), 2);
+    assert_eq!(repo.get_open_orders().await.unwrap().len(), 2);
+}
+
+async fn check_payments<W: IUnitOfWork>(
+    unit_of_work: &W,
+    repo: &impl IPaymentRepository<Transaction = W::Transaction>,
+    orders: &impl IOrderRepository,
+    fixture: &Fixture,
+) {
+    let placed = orders.insert_order(order(fixture, 1)).await.unwrap();
+    let order_id = placed.id.unwrap();
+
+    let payment = repo
+        .insert_payment(Payment {
+            order_id,
+            amount: 303,
+            reference: Some("ch_1".to_string()),
+            card_last_four: Some("4242".to_string()),
+            attempts: vec![PaymentAttempt::succeeded(PaymentOperation::Authorize, 303)],
+            ..Payment::new()
+        })
+        .await
+        .unwrap();
+    let payment_id = payment.id.unwrap();
+
+    repo.record_attempt(
+        payment_id,
+        Some(PaymentStatus::Captured),
+        PaymentAttempt::succeeded(PaymentOperation::Capture, 303),
+    )
+    .await
+    .unwrap();
+    repo.record_attempt(
+        payment_id,
+        None,
+        PaymentAttempt::failed(PaymentOperation::Refund, 50, "timeout".to_string()),
+    )
+    .await
+    .unwrap();
+    let payments = repo.get_payments_by_order(order_id).await.unwrap();
+    assert_eq!(payments.len(), 1);
+    assert_eq!(payments[0].status, PaymentStatus::Captured);
+    assert_eq!(payments[0].card_last_four.as_deref(), Some("4242"));
+    assert_eq!(payments[0].attempts.len(), 3);
+    assert!(payments[0]
+        .attempts
+        .iter()
+        .any(|attempt| !attempt.succeeded && attempt.failure_reason.as_deref() == Some("timeout")));
+
+    assert!(repo
+        .get_refund_by_key(order_id, "refund-1")
+        .await
+        .unwrap()
+        .is_none());
+    let refund = Refund {
+        order_id,
+        payment_id,
+        amount: 303,
+        item_ids: vec![placed.items[0].id.unwrap()],
+        idempotency_key: "refund-1".to_string(),
+        refunded_by: fixture.employee_id,
+        ..Refund::new()
+    };
+    let mut tx = unit_of_work.begin().await.unwrap();
+    let locked = repo.lock_payment_in(&mut tx, payment_id).await.unwrap();
+    assert_eq!(locked.status, PaymentStatus::Captured);
+    assert_eq!(locked.amount, 303);
+    assert!(repo
+        .get_refunds_in(&mut tx, order_id)
+        .await
+        .unwrap()
+        .is_empty());
+    let reserved = repo
+        .reserve_refund_in(&mut tx, refund.clone())
+        .await
+        .unwrap()
+        .unwrap();
+    tx.commit().await.unwrap();
+    assert!(reserved.id.is_some());
+    assert_eq!(reserved.status, RefundStatus::Pending);
+    assert!(matches!(
+        repo.lock_payment_in(&mut unit_of_work.begin().await.unwrap(), 0)
+            .await,
+        Err(PaymentError::PaymentNotFound)
+    ));
+
+    // Ni la misma clave ni la misma línea se pueden reservar dos veces.
+    let mut tx = unit_of_work.begin().await.unwrap();
+    let refunds = repo.get_refunds_in(&mut tx, order_id).await.unwrap();
+    assert_eq!(refunds.len(), 1);
+    assert_eq!(refunds[0].item_ids, reserved.item_ids);
+    assert!(repo
+        .reserve_refund_in(&mut tx, refund.clone())
+        .await
+        .unwrap()
+        .is_none());
+    drop(tx);
+    let mut tx = unit_of_work.begin().await.unwrap();
+    assert!(repo
+        .reserve_refund_in(
+            &mut tx,
+            Refund {
+                idempotency_key: "refund-1b".to_string(),
+                ..refund.clone()
+            },
+        )
+        .await
+        .unwrap()
+        .is_none());
+    drop(tx);
+
+    let stored = repo
+        .get_refund_by_key(order_id, "refund-1")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(stored.id, reserved.id);
+    assert_eq!(stored.item_ids, reserved.item_ids);
+    assert_eq!(stored.status, RefundStatus::Pending);
+
+    let failed = repo
+        .fail_refund(
+            reserved,
+            PaymentAttempt::failed(PaymentOperation::Refund, 303, "declined".to_string()),
+        )
+        .await
+        .unwrap();
+    assert_eq!(failed.status, RefundStatus::Failed);
+    assert_eq!(
+        repo.get_refund_by_key(order_id, "refund-1")
+            .await
+            .unwrap()
+            .unwrap()
+            .status,
+        RefundStatus::Failed
+    );
+    let payments = repo.get_payments_by_order(order_id).await.unwrap();
+    assert_eq!(payments[0].status, PaymentStatus::Captured);
+    assert_eq!(payments[0].attempts.len(), 4);
+
+    let stored_order = orders.get_order_by_id(order_id).await.unwrap();
+    assert_eq!(stored_order.refunds.len(), 1);
+    assert_eq!(stored_order.refunded_total(), 0);
+
+    // Las líneas de una devolución fallida se pueden volver a devolver.
+    let mut tx = unit_of_work.begin().await.unwrap();
+    let retried = repo
+        .reserve_refund_in(
+            &mut tx,
+            Refund {
+                idempotency_key: "refund-1b".to_string(),
+                ..refund
+            },
+        )
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+    assert!(retried.is_some());
+    assert_eq!(
+        orders
+            .get_order_by_id(order_id)
+            .await
+            .unwrap()
+            .refunded_total(),
+        303
+    );
+}
+
+/// El cobro con tarjeta de `PaymentService::pay_order`: se reserva con el pedido bloqueado y se
+/// cierra con la respuesta de la pasarela.
+async fn check_payment_authorization<W: IUnitOfWork>(
+    unit_of_work: &W,
+    repo: &impl IPaymentRepository<Transaction = W::Transaction>,
+    orders: &impl IOrderRepository,
+    fixture: &Fixture,
+) {
+    let placed = orders.insert_order(order(fixture, 1)).await.unwrap();
+    let order_id = placed.id.unwrap();
+
+    let mut tx = unit_of_work.begin().await.unwrap();
+    assert!(repo
+        .lock_order_payments_in(&mut tx, order_id)
+        .await
+        .unwrap()
+        .is_empty());
+    let reserved = repo
+        .insert_payment_in(
+            &mut tx,
+            Payment {
+                order_id,
+                status: PaymentStatus::Pending,
+                amount: 303,
+                card_last_four: Some("4242".to_string()),
+                ..Payment::new()
+            },
+        )
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+    let payment_id = reserved.id.unwrap();
+
+    let mut tx = unit_of_work.begin().await.unwrap();
+    let payments = repo
+        .lock_order_payments_in(&mut tx, order_id)
+        .await
+        .unwrap();
+    assert_eq!(payments.len(), 1);
+    assert_eq!(payments[0].status, PaymentStatus::Pending);
+    drop(tx);
+    assert!(matches!(
+        repo.lock_order_payments_in(&mut unit_of_work.begin().await.unwrap(), 0)
+            .await,
+        Err(PaymentError::OrderNotFound)
+    ));
+
+    repo.finish_authorization(
+        payment_id,
+        Some("ch_2".to_string()),
+        PaymentStatus::Authorized,
+        PaymentAttempt::succeeded(PaymentOperation::Authorize, 303),
+    )
+    .await
+    .unwrap();
+    let payments = repo.get_payments_by_order(order_id).await.unwrap();
+    assert_eq!(payments[0].status, PaymentStatus::Authorized);
+    assert_eq!(payments[0].reference.as_deref(), Some("ch_2"));
+    assert_eq!(payments[0].attempts.len(), 1);
+    assert!(matches!(
+        repo.finish_authorization(
+            0,
+            None,
+            PaymentStatus::Declined,
+            PaymentAttempt::failed(PaymentOperation::Authorize, 303, "declined".to_string()),
+        )
+        .await,
+        Err(PaymentError::PaymentNotFound)
+    ));
+}
+
+/// Lo que ocurre en `PaymentService::refund_order` si otro proceso cambia el estado del pedido
+/// mientras la pasarela devuelve el dinero: la devolución queda completada igual.
+async fn check_refund_after_failed_transition<W: IUnitOfWork>(
+    unit_of_work: &W,
+    payments: &impl IPaymentRepository<Transaction = W::Transaction>,
+    orders: &impl IOrderRepository,
+    fixture: &Fixture,
+) {
+    let placed = orders.insert_order(order(fixture, 1)).await.unwrap();
+    let order_id = placed.id.unwrap();
+    let payment_id = payments
+        .insert_payment(Payment {
+            order_id,
+            amount: 303,
+            status: PaymentStatus::Captured,
+            ..Payment::new()
+        })
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+    let mut tx = unit_of_work.begin().await.unwrap();
+    let reserved = payments
+        .reserve_refund_in(
+            &mut tx,
+            Refund {
+                order_id,
+                payment_id,
+                amount: 303,
+                idempotency_key: "refund-2".to_string(),
+                refunded_by: fixture.employee_id,
+                ..Refund::new()
+            },
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    orders
+        .update_order_status(
+            order_id,
+            OrderStatus::Placed,
+            OrderStatus::Accepted,
+            fixture.employee_id,
+            vec![],
+            vec![],
+        )
+        .await
+        .unwrap();
+    payments
+        .complete_refund(
+            reserved,
+            Some(PaymentStatus::Refunded),
+            PaymentAttempt::succeeded(PaymentOperation::Refund, 303),
+        )
+        .await
+        .unwrap();
+    let result = orders
+        .update_order_status(
+            order_id,
+            OrderStatus::Placed,
+            OrderStatus::Refunded,
+            fixture.employee_id,
+            vec![],
+            vec![],
+        )
+        .await;
+    assert!(matches!(result, Err(OrderError::InvalidTransition { .. })));
+
+    assert_eq!(
+        payments
+            .get_refund_by_key(order_id, "refund-2")
+            .await
+            .unwrap()
+            .unwrap()
+            .status,
+        RefundStatus::Completed
+    );
+    assert_eq!(
+        payments.get_payments_by_order(order_id).await.unwrap()[0].status,
+        PaymentStatus::Refunded
+    );
+    let stored = orders.get_order_by_id(order_id).await.unwrap();
+    assert_eq!(stored.status, OrderStatus::Accepted);
+    assert_eq!(stored.refunded_total(), 303);
+}
+
+async fn check_unit_of_work<W: IUnitOfWork>(
+    unit_of_work: &W,
+    orders: &impl IOrderRepository<Transaction = W::Transaction>,
+    fixture: &Fixture,
+) {
+    let placed = orders.insert_order(order(fixture, 1)).await.unwrap();
+    let order_id = placed.id.unwrap();
+    let placed_orders = orders
+        .get_orders_by_user(fixture.client_id)
+        .await
+        .unwrap()
+        .len();
+
+    // Una transacción revertida, descartada o con un error de negocio no deja nada escrito.
+    let mut tx = unit_of_work.begin().await.unwrap();
+    orders
+        .insert_order_in(&mut tx, order(fixture, 2))
+        .await
+        .unwrap();
+    orders
+        .update_order_status_in(
+            &mut tx,
+            order_id,
+            OrderStatus::Placed,
+            OrderStatus::Accepted,
+            fixture.employee_id,
+            vec![],
+            vec![],
+        )
+        .await
+        .unwrap();
+    tx.rollback().await.unwrap();
+
+    let mut tx = unit_of_work.begin().await.unwrap();
+    orders
+        .insert_order_in(&mut tx, order(fixture, 2))
+        .await
+        .unwrap();
+    let result = orders
+        .update_order_status_in(
+            &mut tx,
+            order_id,
+            OrderStatus::Ready,
+            OrderStatus::Accepted,
+            fixture.employee_id,
+            vec![],
+            vec![],
+        )
+        .await;
+    assert!(matches!(result, Err(OrderError::InvalidTransition { .. })));
+    drop(tx);
+
+    assert_eq!(
+        orders
+            .get_orders_by_user(fixture.client_id)
+            .await
+            .unwrap()
+            .len(),
+        placed_orders
+    );
+    assert_eq!(
+        orders.get_order_by_id(order_id).await.unwrap().status,
+        OrderStatus::Placed
+    );
+
+    let mut tx = unit_of_work.begin().await.unwrap();
+    let inserted = orders
+        .insert_order_in(&mut tx, order(fixture, 2))
+        .await
+        .unwrap();
+    orders
+        .update_order_status_in(
+            &mut tx,
+            order_id,
+            OrderStatus::Placed,
+            OrderStatus::Accepted,
+            fixture.employee_id,
+            vec![],
+            vec![],
+        )
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+
+    assert_eq!(
+        orders
+            .get_order_by_id(inserted.id.unwrap())
+            .await
+            .unwrap()
+            .items[0]
+            .quantity,
+        2
+    );
+    let stored = orders.get_order_by_id(order_id).await.unwrap();
+    assert_eq!(stored.status, OrderStatus::Accepted);
+    assert_eq!(stored.history.len(), 2);
+}
+
+async fn check_promotions<W: IUnitOfWork>(
+    unit_of_work: &W,
+    repo: &impl IPromotionRepository<Transaction = W::Transaction>,
+    orders: &impl IOrderRepository,
+    fixture: &Fixture,
+) {
+    let coupon = repo
+        .insert_promotion(Promotion {
+            name: "Bienvenida".to_string(),
+            code: Some("HOLA10".to_string()),
+            kind: PromotionKind::PercentageOff { rate: 1000 },
+            max_uses_per_user: Some(1),
+            ..Promotion::new()
+        })
+        .await
+        .unwrap();
+    let coupon_id = coupon.id.unwrap();
+    let automatic_id = repo
+        .insert_promotion(Promotion {
+            name: "2x1 en cafés".to_string(),
+            kind: PromotionKind::BuyOneGetOne {
+                category_id: fixture.category_id,
+            },
+            ..Promotion::new()
+        })
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+
+    let found = repo.get_promotion_by_code("hola10").await.unwrap();
+    assert_eq!(found.id, Some(coupon_id));
+    assert_eq!(found.kind, PromotionKind::PercentageOff { rate: 1000 });
+    assert_eq!(found.max_uses_per_user, Some(1));
+    assert!(matches!(
+        repo.get_promotion_by_code("NOEXISTE").await,
+        Err(PromotionError::NotFound)
+    ));
+
+    let automatic = repo.get_automatic_promotions().await.unwrap();
+    assert_eq!(automatic.len(), 1);
+    assert_eq!(automatic[0].id, Some(automatic_id));
+
+    repo.update_promotion(
+        coupon_id,
+        Promotion {
+            min_order_amount: 1000,
+            ..coupon
+        },
+    )
+    .await
+    .unwrap();
+    assert_eq!(
+        repo.get_promotion_by_id(coupon_id)
+            .await
+            .unwrap()
+            .min_order_amount,
+        1000
+    );
+
+    orders
+        .insert_order(Order {
+            discounts: vec![AppliedDiscount {
+                promotion_id: coupon_id,
+                name: "Bienvenida".to_string(),
+                code: Some("HOLA10".to_string()),
+                amount: 25,
+            }],
+            ..order(fixture, 1)
+        })
+        .await
+        .unwrap();
+    let usage = repo.get_usage(coupon_id, fixture.client_id).await.unwrap();
+    assert_eq!((usage.total, usage.by_user), (1, 1));
+    let usage = repo
+        .get_usage(coupon_id, fixture.employee_id)
+        .await
+        .unwrap();
+    assert_eq!((usage.total, usage.by_user), (1, 0));
+
+    let mut tx = unit_of_work.begin().await.unwrap();
+    let locked = repo.lock_promotion_in(&mut tx, coupon_id).await.unwrap();
+    assert_eq!(locked.code.as_deref(), Some("HOLA10"));
+    let usage = repo
+        .get_usage_in(&mut tx, coupon_id, fixture.client_id)
+        .await
+        .unwrap();
+    assert_eq!((usage.total, usage.by_user), (1, 1));
+    assert!(locked.check_usage(usage).is_err());
+    assert!(matches!(
+        repo.lock_promotion_in(&mut tx, 9999).await,
+        Err(PromotionError::NotFound)
+    ));
+    tx.rollback().await.unwrap();
+
+    repo.delete_promotion(automatic_id).await.unwrap();
+    assert!(matches!(
+        repo.get_promotion_by_id(automatic_id).await,
+        Err(PromotionError::NotFound)
+    ));
+    assert_eq!(repo.get_promotions().await.unwrap().len(), 1);
+}
+
+async fn check_loyalty<W: IUnitOfWork>(
+    unit_of_work: &W,
+    repo: &impl ILoyaltyRepository<Transaction = W::Transaction>,
+    fixture: &Fixture,
+) {
+    let entry = repo
+        .insert_entry(LoyaltyEntry {
+            id: None,
+            user_id: fixture.client_id,
+            kind: LoyaltyEntryKind::Redeemed,
+            points: -3,
+            order_id: None,
+            expires_at: None,
+            created_at: Utc::now(),
+        })
+        .await
+        .unwrap();
+    assert!(entry.id.is_some());
+
+    let entries = repo.get_entries(fixture.client_id).await.unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries.iter().map(|entry| entry.points).sum::<i64>(), 2);
+
+    let mut tx = unit_of_work.begin().await.unwrap();
+    repo.lock_account_in(&mut tx, fixture.client_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        repo.get_entries_in(&mut tx, fixture.client_id)
+            .await
+            .unwrap()
+            .len(),
+        2
+    );
+    let expired = repo
+        .insert_entry_in(
+            &mut tx,
+            LoyaltyEntry {
+                kind: LoyaltyEntryKind::Expired,
+                points: -1,
+                ..entry
+            },
+        )
+        .await
+        .unwrap();
+    assert!(expired.id.is_some());
+    tx.rollback().await.unwrap();
+    assert_eq!(repo.get_entries(fixture.client_id).await.unwrap().len(), 2);
+    assert!(repo
+        .get_entries(fixture.employee_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+async fn check_cart<W: IUnitOfWork>(
+    unit_of_work: &W,
+    repo: &impl ICartRepository<Transaction = W::Transaction>,
+    orders: &impl IOrderRepository,
+    promotions: &impl IPromotionRepository,
+    fixture: &Fixture,
+) {
+    let user_id = fixture.employee_id;
+    let item = CartItem {
+        product_id: fixture.product_id,
+        quantity: 1,
+        ..CartItem::new()
+    };
+
+    let first = repo.insert_cart_item(user_id, item.clone()).await.unwrap();
+    let first_id = first.id.unwrap();
+    let second_id = repo
+        .insert_cart_item(user_id, item.clone())
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+    repo.update_cart_item(user_id, first_id, 3).await.unwrap();
+    assert!(matches!(
+        repo.update_cart_item(fixture.client_id, first_id, 3).await,
+        Err(CartError::ItemNotFound)
+    ));
+
+    let items = repo.get_cart_items(user_id).await.unwrap();
+    assert_eq!(items.len(), 2);
+    let stored = items.iter().find(|item| item.id == Some(first_id)).unwrap();
+    assert_eq!(stored.quantity, 3);
+    assert_eq!(stored.product_id, fixture.product_id);
+
+    repo.delete_cart_item(user_id, second_id).await.unwrap();
+    assert!(matches!(
+        repo.delete_cart_item(user_id, second_id).await,
+        Err(CartError::ItemNotFound)
+    ));
+
+    let promotion_id = promotions.get_promotions().await.unwrap()[0].id.unwrap();
+    repo.insert_cart_coupon(user_id, promotion_id)
+        .await
+        .unwrap();
+    repo.insert_cart_coupon(user_id, promotion_id)
+        .await
+        .unwrap();
+    assert_eq!(
+        repo.get_cart_coupons(user_id).await.unwrap(),
+        vec![promotion_id]
+    );
+
+    // Una lista de líneas que ya no coincide con el carrito invalida la compra.
+    let mut tx = unit_of_work.begin().await.unwrap();
+    assert!(matches!(
+        repo.checkout_in(
+            &mut tx,
+            user_id,
+            vec![first_id, second_id],
+            order(fixture, 3)
+        )
+        .await,
+        Err(CartError::CartChanged)
+    ));
+    drop(tx);
+    assert_eq!(repo.get_cart_items(user_id).await.unwrap().len(), 1);
+
+    let mut tx = unit_of_work.begin().await.unwrap();
+    let placed = repo
+        .checkout_in(
+            &mut tx,
+            user_id,
+            vec![first_id],
+            Order {
+                user_id,
+                ..order(fixture, 3)
+            },
+        )
+        .await
+        .unwrap();
+    tx.commit().await.unwrap();
+    assert!(repo.get_cart_items(user_id).await.unwrap().is_empty());
+    assert!(repo.get_cart_coupons(user_id).await.unwrap().is_empty());
+    assert_eq!(
+        orders
+            .get_order_by_id(placed.id.unwrap())
+            .await
+            .unwrap()
+            .user_id,
+        user_id
+    );
+
+    repo.insert_cart_item(user_id, item).await.unwrap();
+    repo.insert_cart_coupon(user_id, promotion_id)
+        .await
+        .unwrap();
+    repo.delete_cart_coupon(user_id, promotion_id)
+        .await
+        .unwrap();
+    repo.clear_cart(user_id).await.unwrap();
+    assert!(repo.get_cart_items(user_id).await.unwrap().is_empty());
+}
+
+async fn check_stores<W: IUnitOfWork>(
+    unit_of_work: &W,
+    repo: &impl IStoreRepository<Transaction = W::Transaction>,
+    orders: &impl IOrderRepository,
+    fixture: &Fixture,
+) {
+    let store = repo
+        .insert_store(Store {
+            name: "Centro".to_string(),
+            address: "Gran Vía 1".to_string(),
+            timezone: chrono_tz::Europe::Madrid,
+            ..Store::new()
+        })
+        .await
+        .unwrap();
+    let store_id = store.id.unwrap();
+    repo.update_store(
+        store_id,
+        Store {
+            pickup_slot_capacity: 4,
+            ..store
+        },
+    )
+    .await
+    .unwrap();
+    assert!(matches!(
+        repo.get_store_by_id(store_id + 100).await,
+        Err(StoreError::NotFound)
+    ));
+
+    let tomorrow = Utc::now().date_naive() + Duration::days(1);
+    repo.upsert_exception(
+        store_id,
+        StoreException {
+            date: tomorrow,
+            opens: None,
+            closes: None,
+            reason: Some("Inventario".to_string()),
+        },
+    )
+    .await
+    .unwrap();
+    repo.upsert_exception(
+        store_id,
+        StoreException {
+            date: tomorrow,
+            opens: None,
+            closes: None,
+            reason: Some("Festivo".to_string()),
+        },
+    )
+    .await
+    .unwrap();
+    repo.set_product_availability(store_id, fixture.product_id, false)
+        .await
+        .unwrap();
+
+    let stored = repo.get_store_by_id(store_id).await.unwrap();
+    assert_eq!(stored.timezone, chrono_tz::Europe::Madrid);
+    assert_eq!(stored.pickup_slot_capacity, 4);
+    assert_eq!(stored.exceptions.len(), 1);
+    assert_eq!(stored.exceptions[0].reason.as_deref(), Some("Festivo"));
+    assert_eq!(stored.unavailable_product_ids, vec![fixture.product_id]);
+
+    repo.set_product_availability(store_id, fixture.product_id, true)
+        .await
+        .unwrap();
+    repo.delete_exception(store_id, tomorrow).await.unwrap();
+    let stored = repo.get_store_by_id(store_id).await.unwrap();
+    assert!(stored.exceptions.is_empty());
+    assert!(stored.unavailable_product_ids.is_empty());
+
+    let now = Utc::now();
+    let slot = now + Duration::hours(2);
+    for status in [OrderStatus::Placed, OrderStatus::Cancelled] {
+        orders
+            .insert_order(Order {
+                status,
+                store_id: Some(store_id),
+                scheduled_for: Some(slot),
+                ..order(fixture, 1)
+            })
+            .await
+            .unwrap();
+    }
+    let bookings = repo.get_pickup_bookings(store_id, now).await.unwrap();
+    assert_eq!(bookings.len(), 1);
+    assert_eq!(bookings[0].1, 1);
+
+    let mut tx = unit_of_work.begin().await.unwrap();
+    repo.lock_store_in(&mut tx, store_id).await.unwrap();
+    assert_eq!(
+        repo.count_bookings_in(&mut tx, store_id, bookings[0].0)
+            .await
+            .unwrap(),
+        1
+    );
+    assert!(matches!(
+        repo.lock_store_in(&mut tx, store_id + 100).await,
+        Err(StoreError::NotFound)
+    ));
+    tx.rollback().await.unwrap();
+
+    let closed_id = repo
+        .insert_store(Store {
+            name: "Cerrada".to_string(),
+            address: "Calle Mayor 2".to_string(),
+            ..Store::new()
+        })
+        .await
+        .unwrap()
+        .id
+        .unwrap();
+    repo.delete_store(closed_id).await.unwrap();
+    assert!(matches!(
+        repo.delete_store(closed_id).await,
+        Err(StoreError::NotFound)
+    ));
+    assert_eq!(repo.get_stores().await.unwrap().len(), 1);
+}
+
+async fn check_delivery(repo: &impl IDeliveryRepository, fixture: &Fixture) {
+    let address = repo
+        .insert_address(Address {
+            user_id: fixture.client_id,
+            province: "Madrid".to_string(),
+            city: "Madrid".to_string(),
+            address: "Gran Vía 1".to_string(),
+            ..Address::new()
+        })
+        .await
+        .unwrap();
+    let address_id = address.id.unwrap();
+    repo.update_address(Address {
+        address: "Gran Vía 2".to_string(),
+        ..address
+    })
+    .await
+    .unwrap();
+    assert_eq!(
+        repo.get_address(fixture.client_id, address_id)
+            .await
+            .unwrap()
+            .address,
+        "Gran Vía 2"
+    );
+    // Las direcciones de otro usuario no se ven ni se pueden borrar.
+    assert!(matches!(
+        repo.get_address(fixture.employee_id, address_id).await,
+        Err(DeliveryError::AddressNotFound)
+    ));
+    assert!(matches!(
+        repo.delete_address(fixture.employee_id, address_id).await,
+        Err(DeliveryError::AddressNotFound)
+    ));
+    assert_eq!(
+        repo.get_addresses(fixture.client_id).await.unwrap().len(),
+        1
+    );
+    repo.delete_address(fixture.client_id, address_id)
+        .await
+        .unwrap();
+    assert!(repo
+        .get_addresses(fixture.client_id)
+        .await
+        .unwrap()
+        .is_empty());
+
+    let zone = repo
+        .insert_delivery_zone(DeliveryZone {
+            province: "Madrid".to_string(),
+            city: Some("Madrid".to_string()),
+            fee: 300,
+            minimum_order: 1500,
+            ..DeliveryZone::new()
+        })
+        .await
+        .unwrap();
+    let zone_id = zone.id.unwrap();
+    repo.update_delivery_zone(
+        zone_id,
+        DeliveryZone {
+            active: false,
+            ..zone
+        },
+    )
+    .await
+    .unwrap();
+    let stored = repo.get_delivery_zone_by_id(zone_id).await.unwrap();
+    assert_eq!(stored.fee, 300);
+    assert!(!stored.active);
+    assert_eq!(repo.get_delivery_zones().await.unwrap().len(), 1);
+
+    repo.delete_delivery_zone(zone_id).await.unwrap();
+    assert!(matches!(
+        repo.get_delivery_zone_by_id(zone_id).await,
+        Err(DeliveryError::ZoneNotFound)
+    ));
+    assert!(matches!(
+        repo.delete_delivery_zone(zone_id).await,
+        Err(DeliveryError::ZoneNotFound)
+    ));
+}
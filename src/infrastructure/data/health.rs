@@ -0,0 +1,116 @@
+//! Comprobaciones de preparación de los backends SQL: que el pool entregue una conexión que
+//! responde y que la base tenga exactamente las migraciones de este binario. Se ejecutan en
+//! cada sonda, así que solo leen. El detalle de un fallo va al log; la respuesta de la sonda
+//! es pública y no lo incluye.
+use super::migrations::{self, Migrations};
+use crate::core::ports::health_port::IHealthCheck;
+use sqlx::{migrate::Migrate, Connection, Pool};
+use std::{fmt, future::Future, sync::Arc, time::Duration};
+
+/// Tiempo máximo de cada comprobación. El pool espera hasta 30 s por una conexión, mucho más de
+/// lo que suele esperar un orquestador por la respuesta de la sonda.
+const CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Comprobaciones de la base a la que apunta el pool.
+pub fn checks<DB>(pool: &Pool<DB>) -> Vec<Arc<dyn IHealthCheck>>
+where
+    DB: Migrations,
+    DB::Connection: Migrate,
+{
+    vec![
+        Arc::new(DatabaseHealthCheck { pool: pool.clone() }),
+        Arc::new(MigrationsHealthCheck { pool: pool.clone() }),
+    ]
+}
+
+pub struct DatabaseHealthCheck<DB: sqlx::Database> {
+    pub pool: Pool<DB>,
+}
+
+#[async_trait::async_trait]
+impl<DB: sqlx::Database> IHealthCheck for DatabaseHealthCheck<DB> {
+    fn name(&self) -> &'static str {
+        "database"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        with_timeout(self.name(), "database is not reachable", async {
+            let mut conn = self.pool.acquire().await?;
+            conn.ping().await
+        })
+        .await
+    }
+}
+
+pub struct MigrationsHealthCheck<DB: sqlx::Database> {
+    pub pool: Pool<DB>,
+}
+
+#[async_trait::async_trait]
+impl<DB: Migrations> IHealthCheck for MigrationsHealthCheck<DB> {
+    fn name(&self) -> &'static str {
+        "migrations"
+    }
+
+    async fn check(&self) -> Result<(), String> {
+        with_timeout(
+            self.name(),
+            "database schema is not up to date",
+            migrations::check(&self.pool),
+        )
+        .await
+    }
+}
+
+/// Ejecuta la comprobación `name` y, si falla, registra el error y retorna `failure`.
+async fn with_timeout<E: fmt::Display>(
+    name: &str,
+    failure: &str,
+    check: impl Future<Output = Result<(), E>>,
+) -> Result<(), String> {
+    match actix_web::rt::time::timeout(CHECK_TIMEOUT, check).await {
+        Ok(Ok(())) => Ok(()),
+        Ok(Err(err)) => {
+            log::error!("Health check {} failed: {}", name, err);
+            Err(failure.to_string())
+        }
+        Err(_) => Err(format!("no response after {} s", CHECK_TIMEOUT.as_secs())),
+    }
+}
+
+#[cfg(all(test, feature = "sqlite"))]
+mod health_tests {
+    use super::*;
+    use crate::infrastructure::{
+        config::{DatabaseBackend, DatabaseConfig, MigrationMode},
+        data::sqlite,
+    };
+
+    #[actix_web::test]
+    async fn test_pending_migrations_fail_the_check() {
+        let pool = sqlite::connect_to_database(&DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            backend: DatabaseBackend::Sqlite,
+            migrations: MigrationMode::Apply,
+        })
+        .await
+        .unwrap();
+        let [database, migrations] = &checks(&pool)[..] else {
+            panic!("expected two checks");
+        };
+
+        assert_eq!(database.check().await, Ok(()));
+        assert_eq!(
+            migrations.check().await,
+            Err("database schema is not up to date".to_string())
+        );
+        // La comprobación no crea la tabla de migraciones.
+        assert!(sqlx::query("SELECT 1 FROM _sqlx_migrations")
+            .fetch_optional(&pool)
+            .await
+            .is_err());
+
+        migrations::run(&pool).await.unwrap();
+        assert_eq!(migrations.check().await, Ok(()));
+    }
+}
@@ -0,0 +1,159 @@
+use super::{unit_of_work::MemoryTransaction, CartItemRow, MemoryDatabase};
+use crate::core::{
+    entities::{cart::CartItem as CartItemCore, order::Order as OrderCore},
+    errors::cart_errors::CartError,
+    ports::cart_port::ICartRepository,
+};
+use crate::infrastructure::data::models::cart::CartItem as CartItemModel;
+
+#[derive(Clone)]
+pub struct MemoryCartRepository {
+    db: MemoryDatabase,
+}
+
+#[async_trait::async_trait]
+impl ICartRepository for MemoryCartRepository {
+    type Transaction = MemoryTransaction;
+
+    async fn get_cart_items(&self, user_id: i32) -> Result<Vec<CartItemCore>, CartError> {
+        self.db.read(|tables| {
+            // Se arma igual que al leer de MySQL: el servicio completa el resto con el catálogo.
+            Ok(tables
+                .cart_items
+                .iter()
+                .filter(|row| row.user_id == user_id)
+                .map(|row| {
+                    CartItemCore::from_cart_item_model(
+                        CartItemModel::from_cart_item_core(user_id, &row.item),
+                        row.item.option_ids(),
+                    )
+                })
+                .collect())
+        })
+    }
+
+    async fn insert_cart_item(
+        &self,
+        user_id: i32,
+        mut item: CartItemCore,
+    ) -> Result<CartItemCore, CartError> {
+        self.db.write(|tables| {
+            item.id = Some(tables.next_id("cart_item"));
+            tables.cart_items.push(CartItemRow {
+                user_id,
+                item: item.clone(),
+            });
+
+            Ok(item)
+        })
+    }
+
+    async fn update_cart_item(
+        &self,
+        user_id: i32,
+        item_id: i32,
+        quantity: i32,
+    ) -> Result<(), CartError> {
+        self.db.write(|tables| {
+            let row = tables
+                .cart_items
+                .iter_mut()
+                .find(|row| row.user_id == user_id && row.item.id == Some(item_id))
+                .ok_or(CartError::ItemNotFound)?;
+            row.item.quantity = quantity;
+
+            Ok(())
+        })
+    }
+
+    async fn delete_cart_item(&self, user_id: i32, item_id: i32) -> Result<(), CartError> {
+        self.db.write(|tables| {
+            let before = tables.cart_items.len();
+            tables
+                .cart_items
+                .retain(|row| !(row.user_id == user_id && row.item.id == Some(item_id)));
+
+            if tables.cart_items.len() == before {
+                return Err(CartError::ItemNotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn clear_cart(&self, user_id: i32) -> Result<(), CartError> {
+        self.db.write(|tables| {
+            tables.cart_items.retain(|row| row.user_id != user_id);
+            tables.cart_coupons.retain(|(user, _)| *user != user_id);
+
+            Ok(())
+        })
+    }
+
+    async fn get_cart_coupons(&self, user_id: i32) -> Result<Vec<i32>, CartError> {
+        self.db.read(|tables| {
+            let mut coupons: Vec<i32> = tables
+                .cart_coupons
+                .iter()
+                .filter(|(user, _)| *user == user_id)
+                .map(|(_, promotion)| *promotion)
+                .collect();
+            coupons.sort();
+
+            Ok(coupons)
+        })
+    }
+
+    async fn insert_cart_coupon(&self, user_id: i32, promotion_id: i32) -> Result<(), CartError> {
+        self.db.write(|tables| {
+            if !tables.cart_coupons.contains(&(user_id, promotion_id)) {
+                tables.cart_coupons.push((user_id, promotion_id));
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn delete_cart_coupon(&self, user_id: i32, promotion_id: i32) -> Result<(), CartError> {
+        self.db.write(|tables| {
+            let before = tables.cart_coupons.len();
+            tables
+                .cart_coupons
+                .retain(|coupon| *coupon != (user_id, promotion_id));
+
+            if tables.cart_coupons.len() == before {
+                return Err(CartError::CouponNotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn checkout_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        user_id: i32,
+        item_ids: Vec<i32>,
+        order: OrderCore,
+    ) -> Result<OrderCore, CartError> {
+        let tables = &mut tx.tables;
+        let before = tables.cart_items.len();
+        tables.cart_items.retain(|row| {
+            !(row.user_id == user_id && row.item.id.is_some_and(|id| item_ids.contains(&id)))
+        });
+
+        // Si el carrito cambió mientras se leía, se descarta el pedido.
+        if before - tables.cart_items.len() != item_ids.len() {
+            return Err(CartError::CartChanged);
+        }
+
+        let order = tables.insert_order(order);
+        tables.cart_coupons.retain(|(user, _)| *user != user_id);
+
+        Ok(order)
+    }
+}
+
+impl MemoryCartRepository {
+    pub fn new(db: MemoryDatabase) -> Self {
+        MemoryCartRepository { db }
+    }
+}
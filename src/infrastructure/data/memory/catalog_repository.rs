@@ -0,0 +1,343 @@
+use super::{MemoryDatabase, Tables};
+use crate::core::{
+    entities::{
+        category::Category as CategoryCore,
+        modifier::{ModifierGroup as ModifierGroupCore, ModifierOption as ModifierOptionCore},
+        product::Product as ProductCore,
+    },
+    errors::catalog_errors::CatalogError,
+    ports::catalog_port::ICatalogRepository,
+};
+
+#[derive(Clone)]
+pub struct MemoryCatalogRepository {
+    db: MemoryDatabase,
+}
+
+#[async_trait::async_trait]
+impl ICatalogRepository for MemoryCatalogRepository {
+    async fn get_categories(&self) -> Result<Vec<CategoryCore>, CatalogError> {
+        self.db.read(|tables| Ok(tables.categories.clone()))
+    }
+
+    async fn get_category_by_id(&self, category_id: i32) -> Result<CategoryCore, CatalogError> {
+        self.db.read(|tables| {
+            tables
+                .categories
+                .iter()
+                .find(|category| category.id == Some(category_id))
+                .cloned()
+                .ok_or(CatalogError::CategoryNotFound)
+        })
+    }
+
+    async fn insert_category(
+        &self,
+        mut category: CategoryCore,
+    ) -> Result<CategoryCore, CatalogError> {
+        self.db.write(|tables| {
+            category.id = Some(tables.next_id("category"));
+            tables.categories.push(category.clone());
+
+            Ok(category)
+        })
+    }
+
+    async fn update_category(
+        &self,
+        category_id: i32,
+        category: CategoryCore,
+    ) -> Result<(), CatalogError> {
+        self.db.write(|tables| {
+            let stored = tables
+                .categories
+                .iter_mut()
+                .find(|category| category.id == Some(category_id))
+                .ok_or(CatalogError::CategoryNotFound)?;
+            *stored = CategoryCore {
+                id: Some(category_id),
+                ..category
+            };
+
+            Ok(())
+        })
+    }
+
+    async fn delete_category(&self, category_id: i32) -> Result<(), CatalogError> {
+        self.db.write(|tables| {
+            let before = tables.categories.len();
+            tables
+                .categories
+                .retain(|category| category.id != Some(category_id));
+
+            if tables.categories.len() == before {
+                return Err(CatalogError::CategoryNotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn get_products(
+        &self,
+        category_id: Option<i32>,
+    ) -> Result<Vec<ProductCore>, CatalogError> {
+        self.db.read(|tables| {
+            Ok(tables
+                .products
+                .iter()
+                .filter(|product| category_id.is_none_or(|id| product.category_id == id))
+                .map(|product| Self::with_stock(tables, product))
+                .collect())
+        })
+    }
+
+    async fn get_product_by_id(&self, product_id: i32) -> Result<ProductCore, CatalogError> {
+        self.db.read(|tables| {
+            tables
+                .products
+                .iter()
+                .find(|product| product.id == Some(product_id))
+                .map(|product| Self::with_stock(tables, product))
+                .ok_or(CatalogError::ProductNotFound)
+        })
+    }
+
+    async fn insert_product(&self, mut product: ProductCore) -> Result<ProductCore, CatalogError> {
+        self.db.write(|tables| {
+            product.id = Some(tables.next_id("product"));
+            product.modifier_groups = vec![];
+            tables.products.push(product.clone());
+
+            Ok(Self::with_stock(tables, &product))
+        })
+    }
+
+    async fn update_product(
+        &self,
+        product_id: i32,
+        product: ProductCore,
+    ) -> Result<(), CatalogError> {
+        self.db.write(|tables| {
+            let stored = tables
+                .products
+                .iter_mut()
+                .find(|product| product.id == Some(product_id))
+                .ok_or(CatalogError::ProductNotFound)?;
+            *stored = ProductCore {
+                id: Some(product_id),
+                modifier_groups: vec![],
+                ..product
+            };
+
+            Ok(())
+        })
+    }
+
+    async fn delete_product(&self, product_id: i32) -> Result<(), CatalogError> {
+        self.db.write(|tables| {
+            let before = tables.products.len();
+            tables
+                .products
+                .retain(|product| product.id != Some(product_id));
+            if tables.products.len() == before {
+                return Err(CatalogError::ProductNotFound);
+            }
+
+            tables
+                .product_modifier_groups
+                .retain(|(product, _)| *product != product_id);
+            tables
+                .product_ingredients
+                .retain(|(product, _)| *product != product_id);
+
+            Ok(())
+        })
+    }
+
+    async fn get_modifier_groups(&self) -> Result<Vec<ModifierGroupCore>, CatalogError> {
+        self.db.read(|tables| {
+            Ok(tables
+                .modifier_groups
+                .iter()
+                .map(|group| Self::with_options(tables, group))
+                .collect())
+        })
+    }
+
+    async fn get_modifier_groups_by_product(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ModifierGroupCore>, CatalogError> {
+        self.db.read(|tables| {
+            Ok(tables
+                .modifier_groups
+                .iter()
+                .filter(|group| {
+                    tables
+                        .product_modifier_groups
+                        .iter()
+                        .any(|(product, id)| *product == product_id && group.id == Some(*id))
+                })
+                .map(|group| Self::with_options(tables, group))
+                .collect())
+        })
+    }
+
+    async fn insert_modifier_group(
+        &self,
+        mut group: ModifierGroupCore,
+    ) -> Result<ModifierGroupCore, CatalogError> {
+        self.db.write(|tables| {
+            let group_id = tables.next_id("modifier_group");
+            group.id = Some(group_id);
+
+            for option in group.options.iter_mut() {
+                option.id = Some(tables.next_id("modifier_option"));
+                option.group_id = group_id;
+                tables.modifier_options.push(option.clone());
+            }
+            tables.modifier_groups.push(ModifierGroupCore {
+                options: vec![],
+                ..group.clone()
+            });
+
+            Ok(group)
+        })
+    }
+
+    async fn delete_modifier_group(&self, group_id: i32) -> Result<(), CatalogError> {
+        self.db.write(|tables| {
+            let before = tables.modifier_groups.len();
+            tables
+                .modifier_groups
+                .retain(|group| group.id != Some(group_id));
+            if tables.modifier_groups.len() == before {
+                return Err(CatalogError::ModifierGroupNotFound);
+            }
+
+            let option_ids: Vec<i32> = tables
+                .modifier_options
+                .iter()
+                .filter(|option| option.group_id == group_id)
+                .filter_map(|option| option.id)
+                .collect();
+            tables
+                .option_ingredients
+                .retain(|(option, _)| !option_ids.contains(option));
+            tables
+                .modifier_options
+                .retain(|option| option.group_id != group_id);
+            tables
+                .product_modifier_groups
+                .retain(|(_, group)| *group != group_id);
+
+            Ok(())
+        })
+    }
+
+    async fn insert_modifier_option(
+        &self,
+        mut option: ModifierOptionCore,
+    ) -> Result<ModifierOptionCore, CatalogError> {
+        self.db.write(|tables| {
+            option.id = Some(tables.next_id("modifier_option"));
+            tables.modifier_options.push(option.clone());
+
+            Ok(option)
+        })
+    }
+
+    async fn update_modifier_option(
+        &self,
+        option_id: i32,
+        option: ModifierOptionCore,
+    ) -> Result<(), CatalogError> {
+        self.db.write(|tables| {
+            let stored = tables
+                .modifier_options
+                .iter_mut()
+                .find(|stored| stored.id == Some(option_id) && stored.group_id == option.group_id)
+                .ok_or(CatalogError::ModifierOptionNotFound)?;
+            stored.name = option.name;
+            stored.price_delta = option.price_delta;
+            stored.available = option.available;
+
+            Ok(())
+        })
+    }
+
+    async fn attach_modifier_group(
+        &self,
+        product_id: i32,
+        group_id: i32,
+    ) -> Result<(), CatalogError> {
+        self.db.write(|tables| {
+            if !tables
+                .product_modifier_groups
+                .contains(&(product_id, group_id))
+            {
+                tables.product_modifier_groups.push((product_id, group_id));
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn detach_modifier_group(
+        &self,
+        product_id: i32,
+        group_id: i32,
+    ) -> Result<(), CatalogError> {
+        self.db.write(|tables| {
+            let before = tables.product_modifier_groups.len();
+            tables
+                .product_modifier_groups
+                .retain(|pair| *pair != (product_id, group_id));
+
+            if tables.product_modifier_groups.len() == before {
+                return Err(CatalogError::ModifierGroupNotFound);
+            }
+            Ok(())
+        })
+    }
+}
+
+impl MemoryCatalogRepository {
+    pub fn new(db: MemoryDatabase) -> Self {
+        MemoryCatalogRepository { db }
+    }
+
+    fn with_stock(tables: &Tables, product: &ProductCore) -> ProductCore {
+        ProductCore {
+            in_stock: tables.recipe_in_stock(
+                tables
+                    .product_ingredients
+                    .iter()
+                    .filter(|(id, _)| product.id == Some(*id))
+                    .map(|(_, line)| line),
+            ),
+            ..product.clone()
+        }
+    }
+
+    fn with_options(tables: &Tables, group: &ModifierGroupCore) -> ModifierGroupCore {
+        ModifierGroupCore {
+            options: tables
+                .modifier_options
+                .iter()
+                .filter(|option| Some(option.group_id) == group.id)
+                .map(|option| ModifierOptionCore {
+                    in_stock: tables.recipe_in_stock(
+                        tables
+                            .option_ingredients
+                            .iter()
+                            .filter(|(id, _)| option.id == Some(*id))
+                            .map(|(_, line)| line),
+                    ),
+                    ..option.clone()
+                })
+                .collect(),
+            ..group.clone()
+        }
+    }
+}
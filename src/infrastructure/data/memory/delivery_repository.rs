@@ -0,0 +1,151 @@
+use super::MemoryDatabase;
+use crate::core::{
+    entities::delivery::{Address as AddressCore, DeliveryZone as DeliveryZoneCore},
+    errors::delivery_errors::DeliveryError,
+    ports::delivery_port::IDeliveryRepository,
+};
+
+#[derive(Clone)]
+pub struct MemoryDeliveryRepository {
+    db: MemoryDatabase,
+}
+
+#[async_trait::async_trait]
+impl IDeliveryRepository for MemoryDeliveryRepository {
+    async fn get_addresses(&self, user_id: i32) -> Result<Vec<AddressCore>, DeliveryError> {
+        self.db.read(|tables| {
+            Ok(tables
+                .addresses
+                .iter()
+                .filter(|address| address.user_id == user_id)
+                .cloned()
+                .collect())
+        })
+    }
+
+    async fn get_address(
+        &self,
+        user_id: i32,
+        address_id: i32,
+    ) -> Result<AddressCore, DeliveryError> {
+        self.db.read(|tables| {
+            tables
+                .addresses
+                .iter()
+                .find(|address| address.id == Some(address_id) && address.user_id == user_id)
+                .cloned()
+                .ok_or(DeliveryError::AddressNotFound)
+        })
+    }
+
+    async fn insert_address(&self, mut address: AddressCore) -> Result<AddressCore, DeliveryError> {
+        self.db.write(|tables| {
+            address.id = Some(tables.next_id("address"));
+            tables.addresses.push(address.clone());
+
+            Ok(address)
+        })
+    }
+
+    async fn update_address(&self, address: AddressCore) -> Result<(), DeliveryError> {
+        self.db.write(|tables| {
+            if let Some(stored) = tables
+                .addresses
+                .iter_mut()
+                .find(|stored| stored.id == address.id && stored.user_id == address.user_id)
+            {
+                *stored = address;
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn delete_address(&self, user_id: i32, address_id: i32) -> Result<(), DeliveryError> {
+        self.db.write(|tables| {
+            let before = tables.addresses.len();
+            tables
+                .addresses
+                .retain(|address| !(address.id == Some(address_id) && address.user_id == user_id));
+
+            if tables.addresses.len() == before {
+                return Err(DeliveryError::AddressNotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn get_delivery_zones(&self) -> Result<Vec<DeliveryZoneCore>, DeliveryError> {
+        let mut zones = self.db.read(|tables| tables.delivery_zones.clone());
+        zones.sort_by(|a, b| (&a.province, &a.city).cmp(&(&b.province, &b.city)));
+
+        Ok(zones)
+    }
+
+    async fn get_delivery_zone_by_id(
+        &self,
+        zone_id: i32,
+    ) -> Result<DeliveryZoneCore, DeliveryError> {
+        self.db.read(|tables| {
+            tables
+                .delivery_zones
+                .iter()
+                .find(|zone| zone.id == Some(zone_id))
+                .cloned()
+                .ok_or(DeliveryError::ZoneNotFound)
+        })
+    }
+
+    async fn insert_delivery_zone(
+        &self,
+        mut zone: DeliveryZoneCore,
+    ) -> Result<DeliveryZoneCore, DeliveryError> {
+        self.db.write(|tables| {
+            zone.id = Some(tables.next_id("delivery_zone"));
+            tables.delivery_zones.push(zone.clone());
+
+            Ok(zone)
+        })
+    }
+
+    async fn update_delivery_zone(
+        &self,
+        zone_id: i32,
+        zone: DeliveryZoneCore,
+    ) -> Result<(), DeliveryError> {
+        self.db.write(|tables| {
+            if let Some(stored) = tables
+                .delivery_zones
+                .iter_mut()
+                .find(|stored| stored.id == Some(zone_id))
+            {
+                *stored = DeliveryZoneCore {
+                    id: Some(zone_id),
+                    ..zone
+                };
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn delete_delivery_zone(&self, zone_id: i32) -> Result<(), DeliveryError> {
+        self.db.write(|tables| {
+            let before = tables.delivery_zones.len();
+            tables
+                .delivery_zones
+                .retain(|zone| zone.id != Some(zone_id));
+
+            if tables.delivery_zones.len() == before {
+                return Err(DeliveryError::ZoneNotFound);
+            }
+            Ok(())
+        })
+    }
+}
+
+impl MemoryDeliveryRepository {
+    pub fn new(db: MemoryDatabase) -> Self {
+        MemoryDeliveryRepository { db }
+    }
+}
@@ -0,0 +1,203 @@
+use super::MemoryDatabase;
+use crate::core::{
+    entities::inventory::{
+        Ingredient as IngredientCore, RecipeLine as RecipeLineCore,
+        StockAdjustment as StockAdjustmentCore,
+    },
+    errors::inventory_errors::InventoryError,
+    ports::inventory_port::IInventoryRepository,
+};
+
+#[derive(Clone)]
+pub struct MemoryInventoryRepository {
+    db: MemoryDatabase,
+}
+
+#[async_trait::async_trait]
+impl IInventoryRepository for MemoryInventoryRepository {
+    async fn get_ingredients(&self) -> Result<Vec<IngredientCore>, InventoryError> {
+        let mut ingredients = self.db.read(|tables| tables.ingredients.clone());
+        ingredients.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(ingredients)
+    }
+
+    async fn get_ingredient_by_id(
+        &self,
+        ingredient_id: i32,
+    ) -> Result<IngredientCore, InventoryError> {
+        self.db.read(|tables| {
+            tables
+                .ingredients
+                .iter()
+                .find(|ingredient| ingredient.id == Some(ingredient_id))
+                .cloned()
+                .ok_or(InventoryError::IngredientNotFound)
+        })
+    }
+
+    async fn insert_ingredient(
+        &self,
+        mut ingredient: IngredientCore,
+    ) -> Result<IngredientCore, InventoryError> {
+        self.db.write(|tables| {
+            ingredient.id = Some(tables.next_id("ingredient"));
+            tables.ingredients.push(ingredient.clone());
+
+            Ok(ingredient)
+        })
+    }
+
+    async fn update_ingredient(
+        &self,
+        ingredient_id: i32,
+        ingredient: IngredientCore,
+    ) -> Result<(), InventoryError> {
+        self.db.write(|tables| {
+            if let Some(stored) = tables
+                .ingredients
+                .iter_mut()
+                .find(|stored| stored.id == Some(ingredient_id))
+            {
+                stored.name = ingredient.name;
+                stored.unit = ingredient.unit;
+                stored.low_stock_threshold = ingredient.low_stock_threshold;
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn delete_ingredient(&self, ingredient_id: i32) -> Result<(), InventoryError> {
+        self.db.write(|tables| {
+            let before = tables.ingredients.len();
+            tables
+                .ingredients
+                .retain(|ingredient| ingredient.id != Some(ingredient_id));
+            if tables.ingredients.len() == before {
+                return Err(InventoryError::IngredientNotFound);
+            }
+
+            tables
+                .product_ingredients
+                .retain(|(_, line)| line.ingredient_id != ingredient_id);
+            tables
+                .option_ingredients
+                .retain(|(_, line)| line.ingredient_id != ingredient_id);
+
+            Ok(())
+        })
+    }
+
+    async fn get_adjustments(
+        &self,
+        ingredient_id: i32,
+    ) -> Result<Vec<StockAdjustmentCore>, InventoryError> {
+        self.db.read(|tables| {
+            Ok(tables
+                .stock_adjustments
+                .iter()
+                .rev()
+                .filter(|adjustment| adjustment.ingredient_id == ingredient_id)
+                .cloned()
+                .collect())
+        })
+    }
+
+    async fn adjust_stock(
+        &self,
+        adjustment: StockAdjustmentCore,
+    ) -> Result<IngredientCore, InventoryError> {
+        let ingredient_id = adjustment.ingredient_id;
+
+        self.db.write(|tables| {
+            if !tables.apply_adjustment(&adjustment) {
+                // El movimiento no se aplicó: o el insumo no existe o el stock no alcanza.
+                let exists = tables
+                    .ingredients
+                    .iter()
+                    .any(|ingredient| ingredient.id == Some(ingredient_id));
+
+                return Err(if exists {
+                    InventoryError::InsufficientStock
+                } else {
+                    InventoryError::IngredientNotFound
+                });
+            }
+
+            tables
+                .ingredients
+                .iter()
+                .find(|ingredient| ingredient.id == Some(ingredient_id))
+                .cloned()
+                .ok_or(InventoryError::IngredientNotFound)
+        })
+    }
+
+    async fn get_product_recipe(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<RecipeLineCore>, InventoryError> {
+        self.db
+            .read(|tables| Ok(Self::recipe(&tables.product_ingredients, product_id)))
+    }
+
+    async fn set_product_recipe(
+        &self,
+        product_id: i32,
+        lines: Vec<RecipeLineCore>,
+    ) -> Result<(), InventoryError> {
+        self.db.write(|tables| {
+            tables
+                .product_ingredients
+                .retain(|(product, _)| *product != product_id);
+            tables
+                .product_ingredients
+                .extend(lines.into_iter().map(|line| (product_id, line)));
+
+            Ok(())
+        })
+    }
+
+    async fn get_option_recipe(
+        &self,
+        option_id: i32,
+    ) -> Result<Vec<RecipeLineCore>, InventoryError> {
+        self.db
+            .read(|tables| Ok(Self::recipe(&tables.option_ingredients, option_id)))
+    }
+
+    async fn set_option_recipe(
+        &self,
+        option_id: i32,
+        lines: Vec<RecipeLineCore>,
+    ) -> Result<(), InventoryError> {
+        self.db.write(|tables| {
+            tables
+                .option_ingredients
+                .retain(|(option, _)| *option != option_id);
+            tables
+                .option_ingredients
+                .extend(lines.into_iter().map(|line| (option_id, line)));
+
+            Ok(())
+        })
+    }
+}
+
+impl MemoryInventoryRepository {
+    pub fn new(db: MemoryDatabase) -> Self {
+        MemoryInventoryRepository { db }
+    }
+
+    fn recipe(lines: &[(i32, RecipeLineCore)], owner_id: i32) -> Vec<RecipeLineCore> {
+        let mut recipe: Vec<RecipeLineCore> = lines
+            .iter()
+            .filter(|(id, _)| *id == owner_id)
+            .map(|(_, line)| *line)
+            .collect();
+        recipe.sort_by_key(|line| line.ingredient_id);
+
+        recipe
+    }
+}
@@ -0,0 +1,66 @@
+use super::{unit_of_work::MemoryTransaction, MemoryDatabase, Tables};
+use crate::core::{
+    entities::loyalty::LoyaltyEntry as LoyaltyEntryCore, errors::loyalty_errors::LoyaltyError,
+    ports::loyalty_port::ILoyaltyRepository,
+};
+
+#[derive(Clone)]
+pub struct MemoryLoyaltyRepository {
+    db: MemoryDatabase,
+}
+
+#[async_trait::async_trait]
+impl ILoyaltyRepository for MemoryLoyaltyRepository {
+    type Transaction = MemoryTransaction;
+
+    async fn get_entries(&self, user_id: i32) -> Result<Vec<LoyaltyEntryCore>, LoyaltyError> {
+        self.db.read(|tables| Ok(Self::entries(tables, user_id)))
+    }
+
+    async fn insert_entry(
+        &self,
+        entry: LoyaltyEntryCore,
+    ) -> Result<LoyaltyEntryCore, LoyaltyError> {
+        self.db
+            .write(|tables| Ok(tables.insert_loyalty_entry(entry)))
+    }
+
+    async fn lock_account_in(
+        &self,
+        _tx: &mut MemoryTransaction,
+        _user_id: i32,
+    ) -> Result<(), LoyaltyError> {
+        Ok(())
+    }
+
+    async fn get_entries_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        user_id: i32,
+    ) -> Result<Vec<LoyaltyEntryCore>, LoyaltyError> {
+        Ok(Self::entries(&tx.tables, user_id))
+    }
+
+    async fn insert_entry_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        entry: LoyaltyEntryCore,
+    ) -> Result<LoyaltyEntryCore, LoyaltyError> {
+        Ok(tx.tables.insert_loyalty_entry(entry))
+    }
+}
+
+impl MemoryLoyaltyRepository {
+    pub fn new(db: MemoryDatabase) -> Self {
+        MemoryLoyaltyRepository { db }
+    }
+
+    fn entries(tables: &Tables, user_id: i32) -> Vec<LoyaltyEntryCore> {
+        tables
+            .loyalty_entries
+            .iter()
+            .filter(|entry| entry.user_id == user_id)
+            .cloned()
+            .collect()
+    }
+}
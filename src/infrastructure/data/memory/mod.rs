@@ -0,0 +1,308 @@
+//! Repositorios en memoria. Implementan los mismos puertos que los de MySQL y comparten una
+//! única [`MemoryDatabase`], de modo que sirven como backend para demos
+//! (`DATABASE_URL=memory://`) y para probar los servicios sin base de datos. Los datos se
+//! pierden al terminar el proceso.
+pub mod cart_repository;
+pub mod catalog_repository;
+pub mod delivery_repository;
+pub mod inventory_repository;
+pub mod loyalty_repository;
+pub mod order_repository;
+pub mod payment_repository;
+pub mod promotion_repository;
+pub mod store_repository;
+pub mod unit_of_work;
+pub mod user_repository;
+
+use self::{
+    cart_repository::MemoryCartRepository, catalog_repository::MemoryCatalogRepository,
+    delivery_repository::MemoryDeliveryRepository, inventory_repository::MemoryInventoryRepository,
+    loyalty_repository::MemoryLoyaltyRepository, order_repository::MemoryOrderRepository,
+    payment_repository::MemoryPaymentRepository, promotion_repository::MemoryPromotionRepository,
+    store_repository::MemoryStoreRepository, unit_of_work::MemoryUnitOfWork,
+    user_repository::MemoryUserRepository,
+};
+use super::Repositories;
+use crate::core::entities::{
+    cart::CartItem,
+    category::Category,
+    delivery::{Address, DeliveryZone},
+    inventory::{Ingredient, RecipeLine, StockAdjustment},
+    loyalty::LoyaltyEntry,
+    modifier::{ModifierGroup, ModifierOption},
+    order::Order,
+    payment::Payment,
+    product::Product,
+    promotion::Promotion,
+    refund::Refund,
+    store::Store,
+    user::User,
+};
+use chrono::{DateTime, Utc};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
+
+pub type MemoryRepositories = Repositories<
+    MemoryUserRepository,
+    MemoryCatalogRepository,
+    MemoryLoyaltyRepository,
+    MemoryInventoryRepository,
+    MemoryStoreRepository,
+    MemoryDeliveryRepository,
+    MemoryOrderRepository,
+    MemoryPaymentRepository,
+    MemoryPromotionRepository,
+    MemoryCartRepository,
+    MemoryUnitOfWork,
+>;
+
+/// Tablas compartidas por todos los repositorios en memoria. Se clona barato: todas las copias
+/// apuntan a los mismos datos.
+#[derive(Clone, Default)]
+pub struct MemoryDatabase {
+    tables: Arc<Mutex<Tables>>,
+}
+
+impl MemoryDatabase {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn read<T>(&self, f: impl FnOnce(&Tables) -> T) -> T {
+        f(&self.lock())
+    }
+
+    pub(crate) fn write<T>(&self, f: impl FnOnce(&mut Tables) -> T) -> T {
+        let mut tables = self.lock();
+        tables.version += 1;
+
+        f(&mut tables)
+    }
+
+    /// Ejecuta `f` sobre una copia de las tablas y solo la confirma si retorna `Ok`, igual que
+    /// una transacción que se descarta ante el primer error.
+    pub(crate) fn transaction<T, E>(
+        &self,
+        f: impl FnOnce(&mut Tables) -> Result<T, E>,
+    ) -> Result<T, E> {
+        let mut tables = self.lock();
+        let mut copy = tables.clone();
+
+        let result = f(&mut copy)?;
+        copy.version += 1;
+        *tables = copy;
+
+        Ok(result)
+    }
+
+    /// Copia de las tablas sobre la que trabaja una transacción de la unidad de trabajo.
+    pub(crate) fn snapshot(&self) -> Tables {
+        self.lock().clone()
+    }
+
+    /// Reemplaza las tablas por la copia de una transacción, salvo que otra escritura haya
+    /// ocurrido después de tomarla; en ese caso no cambia nada y retorna `false`.
+    pub(crate) fn replace(&self, mut copy: Tables) -> bool {
+        let mut tables = self.lock();
+        if tables.version != copy.version {
+            return false;
+        }
+        copy.version += 1;
+        *tables = copy;
+
+        true
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Tables> {
+        // Las tablas solo se reemplazan completas, así que siguen siendo válidas aunque otro
+        // hilo haya entrado en pánico con el candado tomado.
+        self.tables.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct Tables {
+    /// Se incrementa con cada escritura; permite detectar transacciones en conflicto.
+    version: u64,
+    last_ids: HashMap<&'static str, i32>,
+    pub users: Vec<UserRow>,
+    pub categories: Vec<Category>,
+    /// Productos sin grupos de modificadores; `in_stock` se calcula al leerlos.
+    pub products: Vec<Product>,
+    /// Grupos sin opciones, que se guardan en `modifier_options`.
+    pub modifier_groups: Vec<ModifierGroup>,
+    pub modifier_options: Vec<ModifierOption>,
+    /// Pares (producto, grupo de modificadores).
+    pub product_modifier_groups: Vec<(i32, i32)>,
+    pub ingredients: Vec<Ingredient>,
+    /// Líneas de receta por producto.
+    pub product_ingredients: Vec<(i32, RecipeLine)>,
+    /// Líneas de receta por opción de modificador.
+    pub option_ingredients: Vec<(i32, RecipeLine)>,
+    pub stock_adjustments: Vec<StockAdjustment>,
+    /// Pedidos sin devoluciones, que se guardan en `refunds`.
+    pub orders: Vec<Order>,
+    pub payments: Vec<Payment>,
+    pub refunds: Vec<Refund>,
+    pub promotions: Vec<Promotion>,
+    pub loyalty_entries: Vec<LoyaltyEntry>,
+    pub cart_items: Vec<CartItemRow>,
+    /// Pares (usuario, promoción).
+    pub cart_coupons: Vec<(i32, i32)>,
+    pub stores: Vec<Store>,
+    pub addresses: Vec<Address>,
+    pub delivery_zones: Vec<DeliveryZone>,
+}
+
+#[derive(Clone)]
+pub(crate) struct UserRow {
+    pub user: User,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone)]
+pub(crate) struct CartItemRow {
+    pub user_id: i32,
+    pub item: CartItem,
+}
+
+pub fn repositories(db: MemoryDatabase) -> MemoryRepositories {
+    Repositories {
+        user: MemoryUserRepository::new(db.clone()),
+        catalog: MemoryCatalogRepository::new(db.clone()),
+        loyalty: MemoryLoyaltyRepository::new(db.clone()),
+        inventory: MemoryInventoryRepository::new(db.clone()),
+        store: MemoryStoreRepository::new(db.clone()),
+        delivery: MemoryDeliveryRepository::new(db.clone()),
+        order: MemoryOrderRepository::new(db.clone()),
+        payment: MemoryPaymentRepository::new(db.clone()),
+        promotion: MemoryPromotionRepository::new(db.clone()),
+        cart: MemoryCartRepository::new(db.clone()),
+        unit_of_work: MemoryUnitOfWork::new(db),
+    }
+}
+
+impl Tables {
+    /// Próximo ID de la tabla, como una columna `auto_increment`.
+    pub fn next_id(&mut self, table: &'static str) -> i32 {
+        let id = self.last_ids.entry(table).or_insert(0);
+        *id += 1;
+
+        *id
+    }
+
+    /// Suma `delta` al stock del insumo y registra el movimiento. Retorna `false` sin cambiar
+    /// nada si el insumo no existe o si el stock quedaría negativo.
+    pub fn apply_adjustment(&mut self, adjustment: &StockAdjustment) -> bool {
+        let Some(ingredient) = self
+            .ingredients
+            .iter_mut()
+            .find(|ingredient| ingredient.id == Some(adjustment.ingredient_id))
+        else {
+            return false;
+        };
+        if ingredient.stock + adjustment.delta < 0 {
+            return false;
+        }
+        ingredient.stock += adjustment.delta;
+
+        let mut adjustment = adjustment.clone();
+        adjustment.id = Some(self.next_id("stock_adjustment"));
+        self.stock_adjustments.push(adjustment);
+
+        true
+    }
+
+    pub fn insert_loyalty_entry(&mut self, mut entry: LoyaltyEntry) -> LoyaltyEntry {
+        entry.id = Some(self.next_id("loyalty_entry"));
+        self.loyalty_entries.push(entry.clone());
+
+        entry
+    }
+
+    /// Inserta el pedido con sus líneas y, si canjeó puntos, el débito correspondiente.
+    pub fn insert_order(&mut self, mut order: Order) -> Order {
+        order.id = Some(self.next_id("orders"));
+        for item in order.items.iter_mut() {
+            item.id = Some(self.next_id("order_item"));
+        }
+        order.refunds = vec![];
+
+        if order.points_redeemed > 0 {
+            self.insert_loyalty_entry(LoyaltyEntry::redeemed(&order));
+        }
+        self.orders.push(order.clone());
+
+        order
+    }
+
+    /// Falso si a algún insumo de la receta no le alcanza el stock para preparar una unidad.
+    pub fn recipe_in_stock<'a>(&self, lines: impl Iterator<Item = &'a RecipeLine>) -> bool {
+        lines.into_iter().all(|line| {
+            self.ingredients
+                .iter()
+                .filter(|ingredient| ingredient.id == Some(line.ingredient_id))
+                .all(|ingredient| ingredient.stock >= line.quantity)
+        })
+    }
+}
+
+#[cfg(test)]
+mod memory_tests {
+    use super::*;
+    use crate::core::{
+        errors::transaction_errors::TransactionError,
+        ports::unit_of_work_port::{ITransaction, IUnitOfWork},
+    };
+
+    #[test]
+    fn test_ids_are_assigned_per_table() {
+        let mut tables = Tables::default();
+
+        assert_eq!(tables.next_id("user"), 1);
+        assert_eq!(tables.next_id("user"), 2);
+        assert_eq!(tables.next_id("orders"), 1);
+    }
+
+    #[test]
+    fn test_failed_transaction_is_discarded() {
+        let db = MemoryDatabase::new();
+
+        let result: Result<(), ()> = db.transaction(|tables| {
+            tables.cart_coupons.push((1, 1));
+            Err(())
+        });
+        assert!(result.is_err());
+        assert!(db.read(|tables| tables.cart_coupons.is_empty()));
+
+        let result: Result<(), ()> = db.transaction(|tables| {
+            tables.cart_coupons.push((1, 1));
+            Ok(())
+        });
+        assert!(result.is_ok());
+        assert_eq!(db.read(|tables| tables.cart_coupons.len()), 1);
+    }
+
+    #[actix_web::test]
+    async fn test_transaction_conflicts_with_concurrent_write() {
+        let db = MemoryDatabase::new();
+        let unit_of_work = MemoryUnitOfWork::new(db.clone());
+
+        let mut tx = unit_of_work.begin().await.unwrap();
+        tx.tables.cart_coupons.push((1, 1));
+        db.write(|tables| tables.cart_coupons.push((2, 2)));
+
+        assert!(matches!(tx.commit().await, Err(TransactionError::Conflict)));
+        assert_eq!(db.read(|tables| tables.cart_coupons.clone()), vec![(2, 2)]);
+    }
+
+    #[actix_web::test]
+    async fn test_repositories_pass_conformance_suite() {
+        crate::infrastructure::data::conformance::check_repositories(repositories(
+            MemoryDatabase::new(),
+        ))
+        .await;
+    }
+}
@@ -0,0 +1,235 @@
+use super::{unit_of_work::MemoryTransaction, MemoryDatabase, Tables};
+use crate::core::{
+    entities::{
+        inventory::StockAdjustment as StockAdjustmentCore,
+        loyalty::LoyaltyEntry as LoyaltyEntryCore,
+        order::{Order as OrderCore, OrderStatus, OrderStatusChange as OrderStatusChangeCore},
+    },
+    errors::order_errors::OrderError,
+    ports::order_port::IOrderRepository,
+};
+use chrono::{DateTime, Utc};
+
+#[derive(Clone)]
+pub struct MemoryOrderRepository {
+    db: MemoryDatabase,
+}
+
+#[async_trait::async_trait]
+impl IOrderRepository for MemoryOrderRepository {
+    type Transaction = MemoryTransaction;
+
+    async fn insert_order(&self, order: OrderCore) -> Result<OrderCore, OrderError> {
+        self.db.write(|tables| Ok(tables.insert_order(order)))
+    }
+
+    async fn insert_order_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        order: OrderCore,
+    ) -> Result<OrderCore, OrderError> {
+        Ok(tx.tables.insert_order(order))
+    }
+
+    async fn get_order_by_id(&self, order_id: i32) -> Result<OrderCore, OrderError> {
+        self.db.read(|tables| {
+            tables
+                .orders
+                .iter()
+                .find(|order| order.id == Some(order_id))
+                .map(|order| Self::with_refunds(tables, order))
+                .ok_or(OrderError::NotFound)
+        })
+    }
+
+    async fn get_orders_by_user(&self, user_id: i32) -> Result<Vec<OrderCore>, OrderError> {
+        let mut orders = self.find_orders(|order| order.user_id == user_id);
+        orders.sort_by_key(|order| std::cmp::Reverse(order.created_at));
+
+        Ok(orders)
+    }
+
+    async fn get_orders(&self, status: Option<OrderStatus>) -> Result<Vec<OrderCore>, OrderError> {
+        let mut orders = self.find_orders(|order| status.is_none_or(|s| order.status == s));
+        orders.sort_by_key(|order| order.created_at);
+
+        Ok(orders)
+    }
+
+    async fn get_open_orders(&self) -> Result<Vec<OrderCore>, OrderError> {
+        let open_statuses = OrderStatus::open_statuses();
+        let mut orders = self.find_orders(|order| open_statuses.contains(&order.status));
+        orders.sort_by_key(|order| order.scheduled_for.unwrap_or(order.created_at));
+
+        Ok(orders)
+    }
+
+    async fn update_order_status(
+        &self,
+        order_id: i32,
+        from: OrderStatus,
+        to: OrderStatus,
+        changed_by: i32,
+        loyalty_entries: Vec<LoyaltyEntryCore>,
+        stock_adjustments: Vec<StockAdjustmentCore>,
+    ) -> Result<(), OrderError> {
+        self.db.transaction(|tables| {
+            Self::update_order_status_with(
+                tables,
+                order_id,
+                from,
+                to,
+                changed_by,
+                loyalty_entries,
+                stock_adjustments,
+            )
+        })
+    }
+
+    async fn update_order_status_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        order_id: i32,
+        from: OrderStatus,
+        to: OrderStatus,
+        changed_by: i32,
+        loyalty_entries: Vec<LoyaltyEntryCore>,
+        stock_adjustments: Vec<StockAdjustmentCore>,
+    ) -> Result<(), OrderError> {
+        Self::update_order_status_with(
+            &mut tx.tables,
+            order_id,
+            from,
+            to,
+            changed_by,
+            loyalty_entries,
+            stock_adjustments,
+        )
+    }
+
+    async fn claim_order(&self, order_id: i32, employee_id: i32) -> Result<(), OrderError> {
+        self.db.write(|tables| {
+            let order = tables
+                .orders
+                .iter_mut()
+                .find(|order| {
+                    order.id == Some(order_id)
+                        && order.claimed_by.is_none_or(|id| id == employee_id)
+                })
+                .ok_or(OrderError::AlreadyClaimed)?;
+            order.claimed_by = Some(employee_id);
+
+            Ok(())
+        })
+    }
+
+    async fn assign_courier(&self, order_id: i32, courier_id: i32) -> Result<(), OrderError> {
+        self.db.write(|tables| {
+            let is_staff = tables
+                .users
+                .iter()
+                .any(|row| row.user.id == Some(courier_id) && row.user.role.is_staff());
+            if !is_staff {
+                return Err(OrderError::InvalidCourier);
+            }
+
+            if let Some(order) = tables
+                .orders
+                .iter_mut()
+                .find(|order| order.id == Some(order_id))
+            {
+                order.courier_id = Some(courier_id);
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn release_due_orders(&self, now: DateTime<Utc>) -> Result<Vec<OrderCore>, OrderError> {
+        let open_statuses = OrderStatus::open_statuses();
+
+        self.db.write(|tables| {
+            let mut released = vec![];
+            for order in tables.orders.iter_mut() {
+                if order.release_at.is_some_and(|release_at| release_at <= now)
+                    && open_statuses.contains(&order.status)
+                {
+                    order.release_at = None;
+                    released.push(order.clone());
+                }
+            }
+            released.sort_by_key(|order| order.scheduled_for);
+
+            Ok(released
+                .iter()
+                .map(|order| Self::with_refunds(tables, order))
+                .collect())
+        })
+    }
+}
+
+impl MemoryOrderRepository {
+    pub fn new(db: MemoryDatabase) -> Self {
+        MemoryOrderRepository { db }
+    }
+
+    fn update_order_status_with(
+        tables: &mut Tables,
+        order_id: i32,
+        from: OrderStatus,
+        to: OrderStatus,
+        changed_by: i32,
+        loyalty_entries: Vec<LoyaltyEntryCore>,
+        stock_adjustments: Vec<StockAdjustmentCore>,
+    ) -> Result<(), OrderError> {
+        let now = Utc::now();
+
+        let order = tables
+            .orders
+            .iter_mut()
+            .find(|order| order.id == Some(order_id) && order.status == from)
+            .ok_or(OrderError::InvalidTransition { from, to })?;
+        order.status = to;
+        order.updated_at = now;
+        order.history.push(OrderStatusChangeCore {
+            status: to,
+            changed_by,
+            changed_at: now,
+        });
+
+        for adjustment in &stock_adjustments {
+            if !tables.apply_adjustment(adjustment) {
+                return Err(OrderError::OutOfStock);
+            }
+        }
+
+        for entry in loyalty_entries {
+            tables.insert_loyalty_entry(entry);
+        }
+
+        Ok(())
+    }
+
+    fn find_orders(&self, filter: impl Fn(&OrderCore) -> bool) -> Vec<OrderCore> {
+        self.db.read(|tables| {
+            tables
+                .orders
+                .iter()
+                .filter(|order| filter(order))
+                .map(|order| Self::with_refunds(tables, order))
+                .collect()
+        })
+    }
+
+    fn with_refunds(tables: &Tables, order: &OrderCore) -> OrderCore {
+        OrderCore {
+            refunds: tables
+                .refunds
+                .iter()
+                .filter(|refund| order.id == Some(refund.order_id))
+                .cloned()
+                .collect(),
+            ..order.clone()
+        }
+    }
+}
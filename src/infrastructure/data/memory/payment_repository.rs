@@ -0,0 +1,246 @@
+use super::{unit_of_work::MemoryTransaction, MemoryDatabase, Tables};
+use crate::core::{
+    entities::{
+        payment::{Payment as PaymentCore, PaymentAttempt as PaymentAttemptCore, PaymentStatus},
+        refund::{Refund as RefundCore, RefundStatus},
+    },
+    errors::payment_errors::PaymentError,
+    ports::payment_port::IPaymentRepository,
+};
+
+#[derive(Clone)]
+pub struct MemoryPaymentRepository {
+    db: MemoryDatabase,
+}
+
+#[async_trait::async_trait]
+impl IPaymentRepository for MemoryPaymentRepository {
+    type Transaction = MemoryTransaction;
+
+    async fn insert_payment(&self, mut payment: PaymentCore) -> Result<PaymentCore, PaymentError> {
+        self.db.write(|tables| {
+            payment.id = Some(tables.next_id("payment"));
+            tables.payments.push(payment.clone());
+
+            Ok(payment)
+        })
+    }
+
+    async fn get_payments_by_order(&self, order_id: i32) -> Result<Vec<PaymentCore>, PaymentError> {
+        self.db.read(|tables| {
+            Ok(tables
+                .payments
+                .iter()
+                .filter(|payment| payment.order_id == order_id)
+                .cloned()
+                .collect())
+        })
+    }
+
+    async fn lock_order_payments_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        order_id: i32,
+    ) -> Result<Vec<PaymentCore>, PaymentError> {
+        if !tx
+            .tables
+            .orders
+            .iter()
+            .any(|order| order.id == Some(order_id))
+        {
+            return Err(PaymentError::OrderNotFound);
+        }
+
+        Ok(tx
+            .tables
+            .payments
+            .iter()
+            .filter(|payment| payment.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn insert_payment_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        mut payment: PaymentCore,
+    ) -> Result<PaymentCore, PaymentError> {
+        payment.id = Some(tx.tables.next_id("payment"));
+        tx.tables.payments.push(payment.clone());
+
+        Ok(payment)
+    }
+
+    async fn finish_authorization(
+        &self,
+        payment_id: i32,
+        reference: Option<String>,
+        status: PaymentStatus,
+        attempt: PaymentAttemptCore,
+    ) -> Result<(), PaymentError> {
+        self.db.write(|tables| {
+            let payment = tables
+                .payments
+                .iter_mut()
+                .find(|payment| payment.id == Some(payment_id))
+                .ok_or(PaymentError::PaymentNotFound)?;
+
+            payment.status = status;
+            payment.reference = reference;
+            payment.updated_at = attempt.created_at;
+            payment.attempts.push(attempt);
+
+            Ok(())
+        })
+    }
+
+    async fn record_attempt(
+        &self,
+        payment_id: i32,
+        status: Option<PaymentStatus>,
+        attempt: PaymentAttemptCore,
+    ) -> Result<(), PaymentError> {
+        self.db.write(|tables| {
+            let payment = tables
+                .payments
+                .iter_mut()
+                .find(|payment| payment.id == Some(payment_id))
+                .ok_or(PaymentError::PaymentNotFound)?;
+
+            if let Some(status) = status {
+                payment.status = status;
+                payment.updated_at = attempt.created_at;
+            }
+            payment.attempts.push(attempt);
+
+            Ok(())
+        })
+    }
+
+    async fn get_refund_by_key(
+        &self,
+        order_id: i32,
+        idempotency_key: &str,
+    ) -> Result<Option<RefundCore>, PaymentError> {
+        self.db.read(|tables| {
+            Ok(tables
+                .refunds
+                .iter()
+                .find(|refund| {
+                    refund.order_id == order_id && refund.idempotency_key == idempotency_key
+                })
+                .cloned())
+        })
+    }
+
+    async fn lock_payment_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        payment_id: i32,
+    ) -> Result<PaymentCore, PaymentError> {
+        tx.tables
+            .payments
+            .iter()
+            .find(|payment| payment.id == Some(payment_id))
+            .cloned()
+            .ok_or(PaymentError::PaymentNotFound)
+    }
+
+    async fn get_refunds_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        order_id: i32,
+    ) -> Result<Vec<RefundCore>, PaymentError> {
+        Ok(tx
+            .tables
+            .refunds
+            .iter()
+            .filter(|refund| refund.order_id == order_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn reserve_refund_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        mut refund: RefundCore,
+    ) -> Result<Option<RefundCore>, PaymentError> {
+        let tables = &mut tx.tables;
+        let conflict = tables.refunds.iter().any(|existing| {
+            existing.order_id == refund.order_id
+                && (existing.idempotency_key == refund.idempotency_key
+                    || existing.status != RefundStatus::Failed
+                        && existing
+                            .item_ids
+                            .iter()
+                            .any(|item_id| refund.item_ids.contains(item_id)))
+        });
+        if conflict {
+            return Ok(None);
+        }
+
+        refund.id = Some(tables.next_id("refund"));
+        refund.status = RefundStatus::Pending;
+        tables.refunds.push(refund.clone());
+
+        Ok(Some(refund))
+    }
+
+    async fn complete_refund(
+        &self,
+        refund: RefundCore,
+        status: Option<PaymentStatus>,
+        attempt: PaymentAttemptCore,
+    ) -> Result<RefundCore, PaymentError> {
+        self.db.transaction(|tables| {
+            Self::finish_refund_with(tables, refund, RefundStatus::Completed, status, attempt)
+        })
+    }
+
+    async fn fail_refund(
+        &self,
+        refund: RefundCore,
+        attempt: PaymentAttemptCore,
+    ) -> Result<RefundCore, PaymentError> {
+        self.db.transaction(|tables| {
+            Self::finish_refund_with(tables, refund, RefundStatus::Failed, None, attempt)
+        })
+    }
+}
+
+impl MemoryPaymentRepository {
+    pub fn new(db: MemoryDatabase) -> Self {
+        MemoryPaymentRepository { db }
+    }
+
+    fn finish_refund_with(
+        tables: &mut Tables,
+        mut refund: RefundCore,
+        refund_status: RefundStatus,
+        status: Option<PaymentStatus>,
+        attempt: PaymentAttemptCore,
+    ) -> Result<RefundCore, PaymentError> {
+        let payment = tables
+            .payments
+            .iter_mut()
+            .find(|payment| payment.id == Some(refund.payment_id))
+            .ok_or(PaymentError::PaymentNotFound)?;
+
+        if let Some(status) = status {
+            payment.status = status;
+            payment.updated_at = attempt.created_at;
+        }
+        payment.attempts.push(attempt);
+
+        refund.status = refund_status;
+        if let Some(stored) = tables
+            .refunds
+            .iter_mut()
+            .find(|stored| stored.id == refund.id)
+        {
+            stored.status = refund_status;
+        }
+
+        Ok(refund)
+    }
+}
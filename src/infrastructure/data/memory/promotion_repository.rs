@@ -0,0 +1,172 @@
+use super::{unit_of_work::MemoryTransaction, MemoryDatabase, Tables};
+use crate::core::{
+    entities::promotion::{Promotion as PromotionCore, PromotionUsage},
+    errors::promotion_errors::PromotionError,
+    ports::promotion_port::IPromotionRepository,
+};
+
+#[derive(Clone)]
+pub struct MemoryPromotionRepository {
+    db: MemoryDatabase,
+}
+
+#[async_trait::async_trait]
+impl IPromotionRepository for MemoryPromotionRepository {
+    type Transaction = MemoryTransaction;
+
+    async fn get_promotions(&self) -> Result<Vec<PromotionCore>, PromotionError> {
+        self.db.read(|tables| Ok(tables.promotions.clone()))
+    }
+
+    async fn get_promotion_by_id(
+        &self,
+        promotion_id: i32,
+    ) -> Result<PromotionCore, PromotionError> {
+        self.find_promotion(|promotion| promotion.id == Some(promotion_id))
+    }
+
+    async fn get_promotion_by_code(&self, code: &str) -> Result<PromotionCore, PromotionError> {
+        // Igual que en MySQL, los códigos se comparan sin distinguir mayúsculas.
+        self.find_promotion(|promotion| {
+            promotion
+                .code
+                .as_ref()
+                .is_some_and(|stored| stored.eq_ignore_ascii_case(code))
+        })
+    }
+
+    async fn get_automatic_promotions(&self) -> Result<Vec<PromotionCore>, PromotionError> {
+        self.db.read(|tables| {
+            Ok(tables
+                .promotions
+                .iter()
+                .filter(|promotion| promotion.code.is_none() && promotion.active)
+                .cloned()
+                .collect())
+        })
+    }
+
+    async fn insert_promotion(
+        &self,
+        mut promotion: PromotionCore,
+    ) -> Result<PromotionCore, PromotionError> {
+        self.db.write(|tables| {
+            promotion.id = Some(tables.next_id("promotion"));
+            tables.promotions.push(promotion.clone());
+
+            Ok(promotion)
+        })
+    }
+
+    async fn update_promotion(
+        &self,
+        promotion_id: i32,
+        promotion: PromotionCore,
+    ) -> Result<(), PromotionError> {
+        self.db.write(|tables| {
+            if let Some(stored) = tables
+                .promotions
+                .iter_mut()
+                .find(|stored| stored.id == Some(promotion_id))
+            {
+                *stored = PromotionCore {
+                    id: Some(promotion_id),
+                    ..promotion
+                };
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn delete_promotion(&self, promotion_id: i32) -> Result<(), PromotionError> {
+        self.db.write(|tables| {
+            let before = tables.promotions.len();
+            tables
+                .promotions
+                .retain(|promotion| promotion.id != Some(promotion_id));
+            if tables.promotions.len() == before {
+                return Err(PromotionError::NotFound);
+            }
+
+            tables
+                .cart_coupons
+                .retain(|(_, promotion)| *promotion != promotion_id);
+
+            Ok(())
+        })
+    }
+
+    async fn get_usage(
+        &self,
+        promotion_id: i32,
+        user_id: i32,
+    ) -> Result<PromotionUsage, PromotionError> {
+        self.db
+            .read(|tables| Ok(Self::usage(tables, promotion_id, user_id)))
+    }
+
+    async fn lock_promotion_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        promotion_id: i32,
+    ) -> Result<PromotionCore, PromotionError> {
+        tx.tables
+            .promotions
+            .iter()
+            .find(|promotion| promotion.id == Some(promotion_id))
+            .cloned()
+            .ok_or(PromotionError::NotFound)
+    }
+
+    async fn get_usage_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        promotion_id: i32,
+        user_id: i32,
+    ) -> Result<PromotionUsage, PromotionError> {
+        Ok(Self::usage(&tx.tables, promotion_id, user_id))
+    }
+}
+
+impl MemoryPromotionRepository {
+    pub fn new(db: MemoryDatabase) -> Self {
+        MemoryPromotionRepository { db }
+    }
+
+    fn usage(tables: &Tables, promotion_id: i32, user_id: i32) -> PromotionUsage {
+        let mut usage = PromotionUsage {
+            total: 0,
+            by_user: 0,
+        };
+
+        for order in &tables.orders {
+            let redemptions = order
+                .discounts
+                .iter()
+                .filter(|discount| discount.promotion_id == promotion_id)
+                .count() as i64;
+
+            usage.total += redemptions;
+            if order.user_id == user_id {
+                usage.by_user += redemptions;
+            }
+        }
+
+        usage
+    }
+
+    fn find_promotion(
+        &self,
+        filter: impl Fn(&PromotionCore) -> bool,
+    ) -> Result<PromotionCore, PromotionError> {
+        self.db.read(|tables| {
+            tables
+                .promotions
+                .iter()
+                .find(|promotion| filter(promotion))
+                .cloned()
+                .ok_or(PromotionError::NotFound)
+        })
+    }
+}
@@ -0,0 +1,223 @@
+use super::{unit_of_work::MemoryTransaction, MemoryDatabase};
+use crate::core::{
+    entities::{
+        order::OrderStatus,
+        store::{Store as StoreCore, StoreException as StoreExceptionCore},
+    },
+    errors::store_errors::StoreError,
+    ports::store_port::IStoreRepository,
+};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::collections::BTreeMap;
+
+#[derive(Clone)]
+pub struct MemoryStoreRepository {
+    db: MemoryDatabase,
+}
+
+#[async_trait::async_trait]
+impl IStoreRepository for MemoryStoreRepository {
+    type Transaction = MemoryTransaction;
+
+    async fn get_stores(&self) -> Result<Vec<StoreCore>, StoreError> {
+        let mut stores: Vec<StoreCore> = self
+            .db
+            .read(|tables| tables.stores.iter().map(Self::load_store).collect());
+        stores.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(stores)
+    }
+
+    async fn get_store_by_id(&self, store_id: i32) -> Result<StoreCore, StoreError> {
+        self.db.read(|tables| {
+            tables
+                .stores
+                .iter()
+                .find(|store| store.id == Some(store_id))
+                .map(Self::load_store)
+                .ok_or(StoreError::NotFound)
+        })
+    }
+
+    async fn insert_store(&self, mut store: StoreCore) -> Result<StoreCore, StoreError> {
+        self.db.write(|tables| {
+            store.id = Some(tables.next_id("store"));
+            tables.stores.push(StoreCore {
+                exceptions: vec![],
+                unavailable_product_ids: vec![],
+                ..store.clone()
+            });
+
+            Ok(store)
+        })
+    }
+
+    async fn update_store(&self, store_id: i32, store: StoreCore) -> Result<(), StoreError> {
+        self.db.write(|tables| {
+            if let Some(stored) = tables
+                .stores
+                .iter_mut()
+                .find(|stored| stored.id == Some(store_id))
+            {
+                *stored = StoreCore {
+                    id: Some(store_id),
+                    exceptions: stored.exceptions.clone(),
+                    unavailable_product_ids: stored.unavailable_product_ids.clone(),
+                    ..store
+                };
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn delete_store(&self, store_id: i32) -> Result<(), StoreError> {
+        self.db.write(|tables| {
+            let before = tables.stores.len();
+            tables.stores.retain(|store| store.id != Some(store_id));
+
+            if tables.stores.len() == before {
+                return Err(StoreError::NotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn upsert_exception(
+        &self,
+        store_id: i32,
+        exception: StoreExceptionCore,
+    ) -> Result<(), StoreError> {
+        self.db.write(|tables| {
+            if let Some(store) = tables
+                .stores
+                .iter_mut()
+                .find(|store| store.id == Some(store_id))
+            {
+                store
+                    .exceptions
+                    .retain(|stored| stored.date != exception.date);
+                store.exceptions.push(exception);
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn delete_exception(&self, store_id: i32, date: NaiveDate) -> Result<(), StoreError> {
+        self.db.write(|tables| {
+            let store = tables
+                .stores
+                .iter_mut()
+                .find(|store| store.id == Some(store_id))
+                .ok_or(StoreError::NotFound)?;
+
+            let before = store.exceptions.len();
+            store.exceptions.retain(|exception| exception.date != date);
+            if store.exceptions.len() == before {
+                return Err(StoreError::NotFound);
+            }
+            Ok(())
+        })
+    }
+
+    async fn set_product_availability(
+        &self,
+        store_id: i32,
+        product_id: i32,
+        available: bool,
+    ) -> Result<(), StoreError> {
+        self.db.write(|tables| {
+            if let Some(store) = tables
+                .stores
+                .iter_mut()
+                .find(|store| store.id == Some(store_id))
+            {
+                store.unavailable_product_ids.retain(|id| *id != product_id);
+                if !available {
+                    store.unavailable_product_ids.push(product_id);
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn get_pickup_bookings(
+        &self,
+        store_id: i32,
+        from: DateTime<Utc>,
+    ) -> Result<Vec<(DateTime<Utc>, i64)>, StoreError> {
+        self.db.read(|tables| {
+            let mut bookings = BTreeMap::new();
+
+            for order in &tables.orders {
+                if order.store_id != Some(store_id) || order.status == OrderStatus::Cancelled {
+                    continue;
+                }
+                if let Some(scheduled_for) = order.scheduled_for.filter(|at| *at > from) {
+                    *bookings.entry(scheduled_for).or_insert(0) += 1;
+                }
+            }
+
+            Ok(bookings.into_iter().collect())
+        })
+    }
+
+    async fn lock_store_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        store_id: i32,
+    ) -> Result<(), StoreError> {
+        if !tx
+            .tables
+            .stores
+            .iter()
+            .any(|store| store.id == Some(store_id))
+        {
+            return Err(StoreError::NotFound);
+        }
+
+        Ok(())
+    }
+
+    async fn count_bookings_in(
+        &self,
+        tx: &mut MemoryTransaction,
+        store_id: i32,
+        scheduled_for: DateTime<Utc>,
+    ) -> Result<i64, StoreError> {
+        Ok(tx
+            .tables
+            .orders
+            .iter()
+            .filter(|order| {
+                order.store_id == Some(store_id)
+                    && order.scheduled_for == Some(scheduled_for)
+                    && order.status != OrderStatus::Cancelled
+            })
+            .count() as i64)
+    }
+}
+
+impl MemoryStoreRepository {
+    pub fn new(db: MemoryDatabase) -> Self {
+        MemoryStoreRepository { db }
+    }
+
+    /// Ordena el horario y deja solo las excepciones vigentes, como al leer de MySQL.
+    fn load_store(store: &StoreCore) -> StoreCore {
+        // Se incluye el día anterior porque en algunas zonas horarias todavía no terminó.
+        let since = Utc::now().date_naive() - Duration::days(1);
+
+        let mut store = store.clone();
+        store
+            .opening_hours
+            .sort_by_key(|hours| (hours.weekday.number_from_monday(), hours.opens));
+        store.exceptions.retain(|exception| exception.date >= since);
+        store.exceptions.sort_by_key(|exception| exception.date);
+        store.unavailable_product_ids.sort();
+
+        store
+    }
+}
@@ -0,0 +1,53 @@
+use super::{MemoryDatabase, Tables};
+use crate::core::{
+    errors::transaction_errors::TransactionError,
+    ports::unit_of_work_port::{ITransaction, IUnitOfWork},
+};
+
+#[derive(Clone)]
+pub struct MemoryUnitOfWork {
+    db: MemoryDatabase,
+}
+
+#[async_trait::async_trait]
+impl IUnitOfWork for MemoryUnitOfWork {
+    type Transaction = MemoryTransaction;
+
+    async fn begin(&self) -> Result<MemoryTransaction, TransactionError> {
+        Ok(MemoryTransaction {
+            db: self.db.clone(),
+            tables: self.db.snapshot(),
+        })
+    }
+}
+
+impl MemoryUnitOfWork {
+    pub fn new(db: MemoryDatabase) -> Self {
+        MemoryUnitOfWork { db }
+    }
+}
+
+/// Los repositorios escriben en una copia de las tablas que reemplaza a las originales al
+/// confirmar. Si mientras tanto hubo otra escritura, `commit` falla con
+/// `TransactionError::Conflict` en lugar de pisarla. Por eso los métodos `lock_*_in` de los
+/// repositorios en memoria no bloquean nada: de dos transacciones simultáneas, la segunda en
+/// confirmar falla.
+pub struct MemoryTransaction {
+    db: MemoryDatabase,
+    pub(crate) tables: Tables,
+}
+
+#[async_trait::async_trait]
+impl ITransaction for MemoryTransaction {
+    async fn commit(self) -> Result<(), TransactionError> {
+        if self.db.replace(self.tables) {
+            Ok(())
+        } else {
+            Err(TransactionError::Conflict)
+        }
+    }
+
+    async fn rollback(self) -> Result<(), TransactionError> {
+        Ok(())
+    }
+}
@@ -0,0 +1,173 @@
+use super::{MemoryDatabase, UserRow};
+use crate::core::{
+    entities::{user::User as UserCore, user_status::UserStatus},
+    errors::user_errors::UserError,
+    ports::user_port::IUserRepository,
+};
+use chrono::{Duration, Utc};
+
+#[derive(Clone)]
+pub struct MemoryUserRepository {
+    db: MemoryDatabase,
+}
+
+#[async_trait::async_trait]
+impl IUserRepository for MemoryUserRepository {
+    async fn insert_user(&self, mut user: UserCore) -> Result<UserCore, UserError> {
+        self.db.write(|tables| {
+            user.id = Some(tables.next_id("user"));
+            user.phone_number = Some(user.phone_number.unwrap_or_default());
+            tables.users.push(UserRow {
+                user: user.clone(),
+                deleted_at: None,
+            });
+
+            Ok(user)
+        })
+    }
+
+    async fn get_user_by_email(&self, email: String) -> Result<UserCore, UserError> {
+        self.db.read(|tables| {
+            tables
+                .users
+                .iter()
+                .map(|row| &row.user)
+                .find(|user| {
+                    user.email.eq_ignore_ascii_case(&email) && user.status != UserStatus::Deleted
+                })
+                .cloned()
+                .ok_or(UserError::NotFound)
+        })
+    }
+
+    async fn get_users(&self) -> Result<Vec<UserCore>, UserError> {
+        self.db.read(|tables| {
+            Ok(tables
+                .users
+                .iter()
+                .map(|row| &row.user)
+                .filter(|user| user.status != UserStatus::Deleted)
+                .cloned()
+                .collect())
+        })
+    }
+
+    async fn get_user_by_id(&self, id: u8) -> Result<UserCore, UserError> {
+        self.db.read(|tables| {
+            tables
+                .users
+                .iter()
+                .map(|row| &row.user)
+                .find(|user| user.id == Some(id as i32) && user.status != UserStatus::Deleted)
+                .cloned()
+                .ok_or(UserError::NotFound)
+        })
+    }
+
+    async fn update_user(&self, user_id: i32, user: UserCore) -> Result<(), UserError> {
+        self.db.write(|tables| {
+            if let Some(row) = tables
+                .users
+                .iter_mut()
+                .find(|row| row.user.id == Some(user_id))
+            {
+                row.user.name = user.name;
+                row.user.surname = user.surname;
+                row.user.phone_number = Some(user.phone_number.unwrap_or_default());
+                row.user.email = user.email;
+            }
+
+            Ok(())
+        })
+    }
+
+    async fn delete_user(&self, user_id: i32) -> Result<(), UserError> {
+        self.db.write(|tables| {
+            let row = tables
+                .users
+                .iter_mut()
+                .find(|row| row.user.id == Some(user_id) && row.user.status != UserStatus::Deleted)
+                .ok_or(UserError::NotFound)?;
+            row.user.status = UserStatus::Deleted;
+            row.deleted_at = Some(Utc::now());
+
+            Ok(())
+        })
+    }
+
+    async fn get_users_by_status(&self, status: UserStatus) -> Result<Vec<UserCore>, UserError> {
+        self.db.read(|tables| {
+            Ok(tables
+                .users
+                .iter()
+                .map(|row| &row.user)
+                .filter(|user| user.status == status)
+                .cloned()
+                .collect())
+        })
+    }
+
+    async fn update_user_status(&self, user_id: i32, status: UserStatus) -> Result<(), UserError> {
+        self.db.write(|tables| {
+            let user = Self::find_active_mut(&mut tables.users, user_id)?;
+            user.status = status;
+
+            Ok(())
+        })
+    }
+
+    async fn update_password(&self, user_id: i32, password: String) -> Result<(), UserError> {
+        self.db.write(|tables| {
+            let user = Self::find_active_mut(&mut tables.users, user_id)?;
+            user.password = password;
+
+            Ok(())
+        })
+    }
+
+    async fn purge_deleted_users(&self, retention_days: u32) -> Result<u64, UserError> {
+        let cutoff = Utc::now() - Duration::days(retention_days as i64);
+
+        self.db.write(|tables| {
+            let purged: Vec<i32> = tables
+                .users
+                .iter()
+                .filter(|row| {
+                    row.deleted_at
+                        .is_some_and(|deleted_at| deleted_at <= cutoff)
+                })
+                .filter_map(|row| row.user.id)
+                .collect();
+
+            tables
+                .users
+                .retain(|row| !row.user.id.is_some_and(|id| purged.contains(&id)));
+            tables
+                .addresses
+                .retain(|address| !purged.contains(&address.user_id));
+            tables
+                .cart_items
+                .retain(|row| !purged.contains(&row.user_id));
+            tables
+                .cart_coupons
+                .retain(|(user_id, _)| !purged.contains(user_id));
+
+            Ok(purged.len() as u64)
+        })
+    }
+}
+
+impl MemoryUserRepository {
+    pub fn new(db: MemoryDatabase) -> Self {
+        MemoryUserRepository { db }
+    }
+
+    /// Usuario que no fue dado de baja.
+    fn find_active_mut(users: &mut [UserRow], user_id: i32) -> Result<&mut UserCore, UserError> {
+        users
+            .iter_mut()
+            .map(|row| &mut row.user)
+            .find(|user| user.id == Some(user_id) && user.status != UserStatus::Deleted)
+            .ok_or(UserError::NotFound)
+    }
+}
@@ -0,0 +1,259 @@
+use crate::core::errors::migration_errors::MigrationError;
+use futures::future::BoxFuture;
+use sqlx::{
+    database::HasArguments,
+    migrate::{AppliedMigration, Migrate, MigrateError, Migrator},
+    Database, Executor, FromRow, IntoArguments, MySql, Pool,
+};
+
+/// Migraciones de `database/migrations`, incrustadas en el binario. Se aplican en orden de
+/// versión y quedan registradas en la tabla `_sqlx_migrations`.
+pub static MIGRATOR: Migrator = sqlx::migrate!("database/migrations");
+
+/// Migraciones de PostgreSQL, en `database/migrations-postgres`.
+#[cfg(feature = "postgres")]
+pub static POSTGRES_MIGRATOR: Migrator = sqlx::migrate!("database/migrations-postgres");
+
+/// Migraciones de SQLite, en `database/migrations-sqlite`.
+#[cfg(feature = "sqlite")]
+pub static SQLITE_MIGRATOR: Migrator = sqlx::migrate!("database/migrations-sqlite");
+
+/// Motor con su propio juego de migraciones: el SQL de cada uno es distinto, así que sus
+/// versiones no se corresponden entre sí.
+pub trait Migrations: Database {
+    fn migrator() -> &'static Migrator;
+
+    /// Migraciones registradas en `_sqlx_migrations`. A diferencia de `Migrate`, no crea la
+    /// tabla si falta: la consulta falla, igual que con una base sin migrar.
+    fn recorded_migrations(
+        pool: &Pool<Self>,
+    ) -> BoxFuture<'_, Result<Vec<RecordedMigration>, sqlx::Error>>;
+}
+
+impl Migrations for MySql {
+    fn migrator() -> &'static Migrator {
+        &MIGRATOR
+    }
+
+    fn recorded_migrations(
+        pool: &Pool<Self>,
+    ) -> BoxFuture<'_, Result<Vec<RecordedMigration>, sqlx::Error>> {
+        Box::pin(read_recorded_migrations(pool))
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl Migrations for sqlx::Postgres {
+    fn migrator() -> &'static Migrator {
+        &POSTGRES_MIGRATOR
+    }
+
+    fn recorded_migrations(
+        pool: &Pool<Self>,
+    ) -> BoxFuture<'_, Result<Vec<RecordedMigration>, sqlx::Error>> {
+        Box::pin(read_recorded_migrations(pool))
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl Migrations for sqlx::Sqlite {
+    fn migrator() -> &'static Migrator {
+        &SQLITE_MIGRATOR
+    }
+
+    fn recorded_migrations(
+        pool: &Pool<Self>,
+    ) -> BoxFuture<'_, Result<Vec<RecordedMigration>, sqlx::Error>> {
+        Box::pin(read_recorded_migrations(pool))
+    }
+}
+
+/// Fila de `_sqlx_migrations`. `success` es falso si la migración se interrumpió a medias.
+#[derive(FromRow)]
+pub struct RecordedMigration {
+    pub version: i64,
+    pub checksum: Vec<u8>,
+    pub success: bool,
+}
+
+async fn read_recorded_migrations<DB: Database>(
+    pool: &Pool<DB>,
+) -> Result<Vec<RecordedMigration>, sqlx::Error>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    RecordedMigration: for<'r> FromRow<'r, DB::Row>,
+{
+    sqlx::query_as("SELECT version, checksum, success FROM _sqlx_migrations ORDER BY version")
+        .fetch_all(pool)
+        .await
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MigrationState {
+    Applied,
+    Pending,
+    /// Aplicada, pero su archivo cambió desde entonces.
+    Modified,
+    /// Registrada en la base pero ausente en este binario.
+    Unknown,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub description: String,
+    pub state: MigrationState,
+}
+
+impl From<MigrateError> for MigrationError {
+    fn from(err: MigrateError) -> Self {
+        match err {
+            MigrateError::VersionMissing(version) => MigrationError::Unknown(version),
+            MigrateError::VersionMismatch(version) => MigrationError::Modified(version),
+            MigrateError::Dirty(version) => MigrationError::Dirty(version),
+            _ => MigrationError::Unexpected(err.to_string()),
+        }
+    }
+}
+
+impl From<sqlx::Error> for MigrationError {
+    fn from(err: sqlx::Error) -> Self {
+        MigrationError::Unexpected(err.to_string())
+    }
+}
+
+/// Aplica las migraciones pendientes.
+pub async fn run<DB>(pool: &Pool<DB>) -> Result<(), MigrationError>
+where
+    DB: Migrations,
+    DB::Connection: Migrate,
+{
+    DB::migrator().run(pool).await?;
+
+    Ok(())
+}
+
+/// Estado de cada migración, conocida o solo registrada en la base, por orden de versión.
+pub async fn status<DB>(pool: &Pool<DB>) -> Result<Vec<MigrationStatus>, MigrationError>
+where
+    DB: Migrations,
+    DB::Connection: Migrate,
+{
+    let mut conn = pool.acquire().await?;
+    conn.ensure_migrations_table().await?;
+
+    if let Some(version) = conn.dirty_version().await? {
+        return Err(MigrationError::Dirty(version));
+    }
+    let applied = conn.list_applied_migrations().await?;
+
+    Ok(compare(DB::migrator(), &applied))
+}
+
+/// Verifica, sin escribir nada, que la base tenga exactamente las migraciones de este binario.
+pub async fn check<DB: Migrations>(pool: &Pool<DB>) -> Result<(), MigrationError> {
+    let recorded = DB::recorded_migrations(pool).await?;
+    if let Some(migration) = recorded.iter().find(|migration| !migration.success) {
+        return Err(MigrationError::Dirty(migration.version));
+    }
+    let applied: Vec<AppliedMigration> = recorded
+        .into_iter()
+        .map(|migration| AppliedMigration {
+            version: migration.version,
+            checksum: migration.checksum.into(),
+        })
+        .collect();
+
+    for migration in compare(DB::migrator(), &applied) {
+        match migration.state {
+            MigrationState::Applied => {}
+            MigrationState::Pending => return Err(MigrationError::Pending(migration.version)),
+            MigrationState::Modified => return Err(MigrationError::Modified(migration.version)),
+            MigrationState::Unknown => return Err(MigrationError::Unknown(migration.version)),
+        }
+    }
+
+    Ok(())
+}
+
+fn compare(migrator: &Migrator, applied: &[AppliedMigration]) -> Vec<MigrationStatus> {
+    let mut statuses: Vec<MigrationStatus> = migrator
+        .iter()
+        .filter(|migration| !migration.migration_type.is_down_migration())
+        .map(|migration| {
+            let state = match applied.iter().find(|a| a.version == migration.version) {
+                Some(a) if a.checksum == migration.checksum => MigrationState::Applied,
+                Some(_) => MigrationState::Modified,
+                None => MigrationState::Pending,
+            };
+
+            MigrationStatus {
+                version: migration.version,
+                description: migration.description.to_string(),
+                state,
+            }
+        })
+        .collect();
+
+    for migration in applied {
+        if !statuses.iter().any(|s| s.version == migration.version) {
+            statuses.push(MigrationStatus {
+                version: migration.version,
+                description: "".to_string(),
+                state: MigrationState::Unknown,
+            });
+        }
+    }
+    statuses.sort_by_key(|status| status.version);
+
+    statuses
+}
+
+#[cfg(test)]
+mod migrations_tests {
+    use super::*;
+    use std::borrow::Cow;
+
+    fn applied(version: i64, checksum: &[u8]) -> AppliedMigration {
+        AppliedMigration {
+            version,
+            checksum: Cow::Owned(checksum.to_vec()),
+        }
+    }
+
+    #[test]
+    fn test_embedded_migrations_are_ordered_and_unique() {
+        let migrators = [
+            &MIGRATOR,
+            #[cfg(feature = "postgres")]
+            &POSTGRES_MIGRATOR,
+            #[cfg(feature = "sqlite")]
+            &SQLITE_MIGRATOR,
+        ];
+
+        for migrator in migrators {
+            let versions: Vec<i64> = migrator.iter().map(|migration| migration.version).collect();
+
+            assert!(!versions.is_empty());
+            assert!(versions.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+    }
+
+    #[test]
+    fn test_compare_reports_each_state() {
+        let first = MIGRATOR.iter().next().unwrap();
+
+        let statuses = compare(&MIGRATOR, &[]);
+        assert_eq!(statuses[0].state, MigrationState::Pending);
+
+        let statuses = compare(&MIGRATOR, &[applied(first.version, &first.checksum)]);
+        assert_eq!(statuses[0].state, MigrationState::Applied);
+
+        let statuses = compare(&MIGRATOR, &[applied(first.version, b"changed")]);
+        assert_eq!(statuses[0].state, MigrationState::Modified);
+
+        let statuses = compare(&MIGRATOR, &[applied(i64::MAX, b"")]);
+        assert_eq!(statuses.last().unwrap().state, MigrationState::Unknown);
+    }
+}
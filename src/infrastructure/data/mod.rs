@@ -1,3 +1,32 @@
+pub mod backend;
+#[cfg(test)]
+mod conformance;
+pub mod health;
+pub mod memory;
+pub mod migrations;
 pub mod models;
 pub mod mysql;
+pub mod pool;
+#[cfg(feature = "postgres")]
+pub mod postgres;
 pub mod repositories;
+pub mod seed;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod unit_of_work;
+
+/// Un repositorio por puerto, todos sobre el mismo backend, más la unidad de trabajo con la que
+/// se abren transacciones entre ellos. Cada backend arma el suyo con su función `repositories`.
+pub struct Repositories<U, Ct, L, I, S, D, O, Py, Pr, Ca, W> {
+    pub user: U,
+    pub catalog: Ct,
+    pub loyalty: L,
+    pub inventory: I,
+    pub store: S,
+    pub delivery: D,
+    pub order: O,
+    pub payment: Py,
+    pub promotion: Pr,
+    pub cart: Ca,
+    pub unit_of_work: W,
+}
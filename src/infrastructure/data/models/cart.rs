@@ -0,0 +1,28 @@
+use crate::core::entities::cart::CartItem as CartItemCore;
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct CartItem {
+    pub id: Option<i32>,
+    pub user: i32,
+    pub product: i32,
+    pub quantity: i32,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct CartItemOption {
+    pub cart_item: i32,
+    pub modifier_option: i32,
+}
+
+impl CartItem {
+    pub fn from_cart_item_core(user: i32, item_core: &CartItemCore) -> Self {
+        CartItem {
+            id: item_core.id,
+            user,
+            product: item_core.product_id,
+            quantity: item_core.quantity,
+        }
+    }
+}
@@ -0,0 +1,26 @@
+use crate::core::entities::category::Category as CategoryCore;
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct Category {
+    pub id: Option<i32>,
+    pub name: String,
+    pub description: Option<String>,
+    pub tax_rate: Option<i32>,
+}
+
+impl Category {
+    pub fn from_category_core(category_core: CategoryCore) -> Self {
+        Category {
+            id: category_core.id,
+            name: category_core.name,
+            description: category_core.description,
+            tax_rate: category_core.tax_rate,
+        }
+    }
+
+    pub fn set_id(&mut self, id: i32) {
+        self.id = Some(id)
+    }
+}
@@ -0,0 +1,47 @@
+use crate::core::entities::delivery::{Address as AddressCore, DeliveryZone as DeliveryZoneCore};
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct Address {
+    pub id: Option<i32>,
+    pub user: i32,
+    pub province: String,
+    pub city: String,
+    pub address: String,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct DeliveryZone {
+    pub id: Option<i32>,
+    pub province: String,
+    pub city: Option<String>,
+    pub fee: i64,
+    pub minimum_order: i64,
+    pub active: bool,
+}
+
+impl Address {
+    pub fn from_address_core(address_core: &AddressCore) -> Self {
+        Address {
+            id: address_core.id,
+            user: address_core.user_id,
+            province: address_core.province.clone(),
+            city: address_core.city.clone(),
+            address: address_core.address.clone(),
+        }
+    }
+}
+
+impl DeliveryZone {
+    pub fn from_delivery_zone_core(zone_core: &DeliveryZoneCore) -> Self {
+        DeliveryZone {
+            id: zone_core.id,
+            province: zone_core.province.clone(),
+            city: zone_core.city.clone(),
+            fee: zone_core.fee,
+            minimum_order: zone_core.minimum_order,
+            active: zone_core.active,
+        }
+    }
+}
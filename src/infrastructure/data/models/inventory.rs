@@ -0,0 +1,60 @@
+use crate::core::entities::inventory::{
+    Ingredient as IngredientCore, StockAdjustment as StockAdjustmentCore,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct Ingredient {
+    pub id: Option<i32>,
+    pub name: String,
+    pub unit: String,
+    pub stock: i64,
+    pub low_stock_threshold: i64,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct RecipeLine {
+    pub ingredient: i32,
+    pub quantity: i64,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct StockAdjustment {
+    pub id: Option<i32>,
+    pub ingredient: i32,
+    pub delta: i64,
+    pub reason: String,
+    pub note: Option<String>,
+    pub order_id: Option<i32>,
+    pub adjusted_by: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Ingredient {
+    pub fn from_ingredient_core(ingredient_core: &IngredientCore) -> Self {
+        Ingredient {
+            id: ingredient_core.id,
+            name: ingredient_core.name.clone(),
+            unit: ingredient_core.unit.code().to_string(),
+            stock: ingredient_core.stock,
+            low_stock_threshold: ingredient_core.low_stock_threshold,
+        }
+    }
+}
+
+impl StockAdjustment {
+    pub fn from_stock_adjustment_core(adjustment_core: &StockAdjustmentCore) -> Self {
+        StockAdjustment {
+            id: adjustment_core.id,
+            ingredient: adjustment_core.ingredient_id,
+            delta: adjustment_core.delta,
+            reason: adjustment_core.reason.code().to_string(),
+            note: adjustment_core.note.clone(),
+            order_id: adjustment_core.order_id,
+            adjusted_by: adjustment_core.adjusted_by,
+            created_at: adjustment_core.created_at,
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::core::entities::loyalty::LoyaltyEntry as LoyaltyEntryCore;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct LoyaltyEntry {
+    pub id: Option<i32>,
+    pub user: i32,
+    pub kind: String,
+    pub points: i64,
+    pub order_id: Option<i32>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl LoyaltyEntry {
+    pub fn from_loyalty_entry_core(entry_core: &LoyaltyEntryCore) -> Self {
+        LoyaltyEntry {
+            id: entry_core.id,
+            user: entry_core.user_id,
+            kind: entry_core.kind.code().to_string(),
+            points: entry_core.points,
+            order_id: entry_core.order_id,
+            expires_at: entry_core.expires_at,
+            created_at: entry_core.created_at,
+        }
+    }
+}
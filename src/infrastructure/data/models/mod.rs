@@ -1 +1,12 @@
+pub mod cart;
+pub mod category;
+pub mod delivery;
+pub mod inventory;
+pub mod loyalty;
+pub mod modifier;
+pub mod order;
+pub mod payment;
+pub mod product;
+pub mod promotion;
+pub mod store;
 pub mod user;
@@ -0,0 +1,52 @@
+use crate::core::entities::modifier::{
+    ModifierGroup as ModifierGroupCore, ModifierOption as ModifierOptionCore,
+};
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct ModifierGroup {
+    pub id: Option<i32>,
+    pub name: String,
+    pub min_selections: i32,
+    pub max_selections: i32,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct ModifierOption {
+    pub id: Option<i32>,
+    pub modifier_group: i32,
+    pub name: String,
+    pub price_delta: i64,
+    pub available: bool,
+    /// Calculado a partir del stock de los insumos de la receta; no es una columna.
+    pub in_stock: bool,
+}
+
+impl ModifierGroup {
+    pub fn from_modifier_group_core(group_core: &ModifierGroupCore) -> Self {
+        ModifierGroup {
+            id: group_core.id,
+            name: group_core.name.clone(),
+            min_selections: group_core.min_selections,
+            max_selections: group_core.max_selections,
+        }
+    }
+}
+
+impl ModifierOption {
+    pub fn from_modifier_option_core(option_core: ModifierOptionCore) -> Self {
+        ModifierOption {
+            id: option_core.id,
+            modifier_group: option_core.group_id,
+            name: option_core.name,
+            price_delta: option_core.price_delta,
+            available: option_core.available,
+            in_stock: option_core.in_stock,
+        }
+    }
+
+    pub fn set_id(&mut self, id: i32) {
+        self.id = Some(id)
+    }
+}
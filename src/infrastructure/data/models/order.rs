@@ -0,0 +1,117 @@
+use crate::core::entities::order::{
+    Order as OrderCore, OrderItem as OrderItemCore, OrderItemOption as OrderItemOptionCore,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct Order {
+    pub id: Option<i32>,
+    pub user: i32,
+    pub status: String,
+    pub currency: String,
+    pub subtotal: i64,
+    pub discount: i64,
+    pub tax: i64,
+    pub service_charge: i64,
+    pub tip: i64,
+    pub delivery_fee: i64,
+    pub total: i64,
+    pub points_earned: i64,
+    pub points_redeemed: i64,
+    pub payment_method: String,
+    pub claimed_by: Option<i32>,
+    pub store_id: Option<i32>,
+    pub scheduled_for: Option<DateTime<Utc>>,
+    pub release_at: Option<DateTime<Utc>>,
+    pub fulfilment_type: String,
+    pub delivery_address_id: Option<i32>,
+    pub delivery_address: Option<String>,
+    pub courier_id: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct OrderStatusChange {
+    pub order_id: i32,
+    pub status: String,
+    pub changed_by: i32,
+    pub changed_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct OrderItem {
+    pub id: Option<i32>,
+    pub order_id: i32,
+    pub product: i32,
+    pub product_name: String,
+    pub quantity: i32,
+    pub unit_price: i64,
+    pub tax_rate: i32,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct OrderItemOption {
+    pub order_item: i32,
+    pub modifier_option: i32,
+    pub name: String,
+    pub price_delta: i64,
+}
+
+impl Order {
+    pub fn from_order_core(order_core: &OrderCore) -> Self {
+        Order {
+            id: order_core.id,
+            user: order_core.user_id,
+            status: order_core.status.code().to_string(),
+            currency: order_core.pricing.total.currency.code().to_string(),
+            subtotal: order_core.pricing.subtotal.amount,
+            discount: order_core.pricing.discount.amount,
+            tax: order_core.pricing.tax.amount,
+            service_charge: order_core.pricing.service_charge.amount,
+            tip: order_core.pricing.tip.amount,
+            delivery_fee: order_core.pricing.delivery_fee.amount,
+            total: order_core.pricing.total.amount,
+            points_earned: order_core.points_earned,
+            points_redeemed: order_core.points_redeemed,
+            payment_method: order_core.payment_method.code().to_string(),
+            claimed_by: order_core.claimed_by,
+            store_id: order_core.store_id,
+            scheduled_for: order_core.scheduled_for,
+            release_at: order_core.release_at,
+            fulfilment_type: order_core.fulfilment_type.code().to_string(),
+            delivery_address_id: order_core.delivery_address_id,
+            delivery_address: order_core.delivery_address.clone(),
+            courier_id: order_core.courier_id,
+            created_at: order_core.created_at,
+            updated_at: order_core.updated_at,
+        }
+    }
+}
+
+impl OrderItem {
+    pub fn from_order_item_core(order_id: i32, item_core: &OrderItemCore) -> Self {
+        OrderItem {
+            id: item_core.id,
+            order_id,
+            product: item_core.product_id,
+            product_name: item_core.product_name.clone(),
+            quantity: item_core.quantity,
+            unit_price: item_core.unit_price,
+            tax_rate: item_core.tax_rate,
+        }
+    }
+}
+
+impl OrderItemOption {
+    pub fn from_order_item_option_core(order_item: i32, option_core: &OrderItemOptionCore) -> Self {
+        OrderItemOption {
+            order_item,
+            modifier_option: option_core.option_id,
+            name: option_core.name.clone(),
+            price_delta: option_core.price_delta,
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::core::entities::{
+    payment::{Payment as PaymentCore, PaymentAttempt as PaymentAttemptCore},
+    refund::Refund as RefundCore,
+};
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct Payment {
+    pub id: Option<i32>,
+    pub order_id: i32,
+    pub method: String,
+    pub status: String,
+    pub amount: i64,
+    pub reference: Option<String>,
+    pub card_last_four: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct PaymentAttempt {
+    pub payment: i32,
+    pub operation: String,
+    pub amount: i64,
+    pub succeeded: bool,
+    pub failure_reason: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Payment {
+    pub fn from_payment_core(payment_core: &PaymentCore) -> Self {
+        Payment {
+            id: payment_core.id,
+            order_id: payment_core.order_id,
+            method: payment_core.method.code().to_string(),
+            status: payment_core.status.code().to_string(),
+            amount: payment_core.amount,
+            reference: payment_core.reference.clone(),
+            card_last_four: payment_core.card_last_four.clone(),
+            created_at: payment_core.created_at,
+            updated_at: payment_core.updated_at,
+        }
+    }
+}
+
+impl PaymentAttempt {
+    pub fn from_payment_attempt_core(payment: i32, attempt_core: &PaymentAttemptCore) -> Self {
+        PaymentAttempt {
+            payment,
+            operation: attempt_core.operation.code().to_string(),
+            amount: attempt_core.amount,
+            succeeded: attempt_core.succeeded,
+            failure_reason: attempt_core.failure_reason.clone(),
+            created_at: attempt_core.created_at,
+        }
+    }
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct Refund {
+    pub id: Option<i32>,
+    pub order_id: i32,
+    pub payment: i32,
+    pub amount: i64,
+    pub reason: String,
+    pub status: String,
+    pub note: Option<String>,
+    pub idempotency_key: String,
+    pub refunded_by: i32,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct RefundItem {
+    pub refund: i32,
+    pub order_item: i32,
+}
+
+impl Refund {
+    pub fn from_refund_core(refund_core: &RefundCore) -> Self {
+        Refund {
+            id: refund_core.id,
+            order_id: refund_core.order_id,
+            payment: refund_core.payment_id,
+            amount: refund_core.amount,
+            reason: refund_core.reason.code().to_string(),
+            status: refund_core.status.code().to_string(),
+            note: refund_core.note.clone(),
+            idempotency_key: refund_core.idempotency_key.clone(),
+            refunded_by: refund_core.refunded_by,
+            created_at: refund_core.created_at,
+        }
+    }
+}
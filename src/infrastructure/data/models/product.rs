@@ -0,0 +1,35 @@
+use crate::core::entities::product::Product as ProductCore;
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct Product {
+    pub id: Option<i32>,
+    pub category: i32,
+    pub name: String,
+    pub description: Option<String>,
+    pub price: i64,
+    pub image_url: Option<String>,
+    pub available: bool,
+    /// Calculado a partir del stock de los insumos de la receta; no es una columna.
+    pub in_stock: bool,
+}
+
+impl Product {
+    pub fn from_product_core(product_core: ProductCore) -> Self {
+        Product {
+            id: product_core.id,
+            category: product_core.category_id,
+            name: product_core.name,
+            description: product_core.description,
+            price: product_core.price,
+            image_url: product_core.image_url,
+            available: product_core.available,
+            in_stock: product_core.in_stock,
+        }
+    }
+
+    pub fn set_id(&mut self, id: i32) {
+        self.id = Some(id)
+    }
+}
@@ -0,0 +1,85 @@
+use crate::core::entities::promotion::{
+    AppliedDiscount as AppliedDiscountCore, Promotion as PromotionCore, PromotionKind,
+};
+use chrono::{DateTime, NaiveTime, Utc};
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct Promotion {
+    pub id: Option<i32>,
+    pub name: String,
+    pub code: Option<String>,
+    pub kind: String,
+    pub rate: Option<i32>,
+    pub amount: Option<i64>,
+    pub category: Option<i32>,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub daily_starts: Option<NaiveTime>,
+    pub daily_ends: Option<NaiveTime>,
+    pub min_order_amount: i64,
+    pub max_uses: Option<i32>,
+    pub max_uses_per_user: Option<i32>,
+    pub stackable: bool,
+    pub active: bool,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct PromotionRedemption {
+    pub promotion: i32,
+    pub user: i32,
+    pub order_id: i32,
+    pub name: String,
+    pub code: Option<String>,
+    pub amount: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Promotion {
+    pub fn from_promotion_core(promotion_core: &PromotionCore) -> Self {
+        let (rate, amount, category) = match promotion_core.kind {
+            PromotionKind::PercentageOff { rate } => (Some(rate), None, None),
+            PromotionKind::FixedAmountOff { amount } => (None, Some(amount), None),
+            PromotionKind::BuyOneGetOne { category_id } => (None, None, Some(category_id)),
+        };
+
+        Promotion {
+            id: promotion_core.id,
+            name: promotion_core.name.clone(),
+            code: promotion_core.code.clone(),
+            kind: promotion_core.kind.code().to_string(),
+            rate,
+            amount,
+            category,
+            starts_at: promotion_core.starts_at,
+            ends_at: promotion_core.ends_at,
+            daily_starts: promotion_core.daily_window.map(|window| window.starts),
+            daily_ends: promotion_core.daily_window.map(|window| window.ends),
+            min_order_amount: promotion_core.min_order_amount,
+            max_uses: promotion_core.max_uses,
+            max_uses_per_user: promotion_core.max_uses_per_user,
+            stackable: promotion_core.stackable,
+            active: promotion_core.active,
+        }
+    }
+}
+
+impl PromotionRedemption {
+    pub fn from_applied_discount_core(
+        user: i32,
+        order_id: i32,
+        discount_core: &AppliedDiscountCore,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        PromotionRedemption {
+            promotion: discount_core.promotion_id,
+            user,
+            order_id,
+            name: discount_core.name.clone(),
+            code: discount_core.code.clone(),
+            amount: discount_core.amount,
+            created_at,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use crate::core::entities::store::{
+    OpeningHours as OpeningHoursCore, Store as StoreCore, StoreException as StoreExceptionCore,
+};
+use chrono::{NaiveDate, NaiveTime};
+use serde::Serialize;
+use sqlx::FromRow;
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct Store {
+    pub id: Option<i32>,
+    pub name: String,
+    pub address: String,
+    pub timezone: String,
+    pub active: bool,
+    pub pickup_slot_minutes: i32,
+    pub pickup_slot_capacity: i32,
+    pub pickup_release_minutes: i32,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct OpeningHours {
+    pub store: i32,
+    /// Número ISO del día: 1 = lunes, 7 = domingo.
+    pub weekday: i32,
+    pub opens: NaiveTime,
+    pub closes: NaiveTime,
+}
+
+#[derive(Serialize, Debug, FromRow, Clone)]
+pub struct StoreException {
+    pub store: i32,
+    pub date: NaiveDate,
+    pub opens: Option<NaiveTime>,
+    pub closes: Option<NaiveTime>,
+    pub reason: Option<String>,
+}
+
+impl Store {
+    pub fn from_store_core(store_core: &StoreCore) -> Self {
+        Store {
+            id: store_core.id,
+            name: store_core.name.clone(),
+            address: store_core.address.clone(),
+            timezone: store_core.timezone.name().to_string(),
+            active: store_core.active,
+            pickup_slot_minutes: store_core.pickup_slot_minutes,
+            pickup_slot_capacity: store_core.pickup_slot_capacity,
+            pickup_release_minutes: store_core.pickup_release_minutes,
+        }
+    }
+}
+
+impl OpeningHours {
+    pub fn from_opening_hours_core(store_id: i32, hours_core: &OpeningHoursCore) -> Self {
+        OpeningHours {
+            store: store_id,
+            weekday: hours_core.weekday.number_from_monday() as i32,
+            opens: hours_core.opens,
+            closes: hours_core.closes,
+        }
+    }
+}
+
+impl StoreException {
+    pub fn from_store_exception_core(store_id: i32, exception_core: &StoreExceptionCore) -> Self {
+        StoreException {
+            store: store_id,
+            date: exception_core.date,
+            opens: exception_core.opens,
+            closes: exception_core.closes,
+            reason: exception_core.reason.clone(),
+        }
+    }
+}
@@ -1,8 +1,9 @@
 use crate::core::entities::user::User as UserCore;
 use serde::Serialize;
 use sqlx::FromRow;
+use std::fmt;
 
-#[derive(Serialize, Debug, FromRow, Clone)]
+#[derive(Serialize, FromRow, Clone)]
 pub struct User {
     pub id: Option<i32>,
     pub name: String,
@@ -10,6 +11,21 @@ pub struct User {
     pub phone_number: Option<String>,
     pub email: String,
     pub password: String,
+    pub role: String,
+    pub status: String,
+}
+
+/// Omite la contraseña, igual que la entidad.
+impl fmt::Debug for User {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("User")
+            .field("id", &self.id)
+            .field("email", &self.email)
+            .field("password", &"***")
+            .field("role", &self.role)
+            .field("status", &self.status)
+            .finish_non_exhaustive()
+    }
 }
 
 impl User {
@@ -21,6 +37,8 @@ impl User {
             phone_number: user_core.phone_number,
             email: user_core.email,
             password: user_core.password,
+            role: user_core.role.code().to_string(),
+            status: user_core.status.code().to_string(),
         }
     }
 
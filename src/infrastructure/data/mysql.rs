@@ -1,8 +1,87 @@
-use std::env;
+use super::{
+    pool::DbPool,
+    repositories::{
+        cart_repository::CartRepository, catalog_repository::CatalogRepository,
+        delivery_repository::DeliveryRepository, inventory_repository::InventoryRepository,
+        loyalty_repository::LoyaltyRepository, order_repository::OrderRepository,
+        payment_repository::PaymentRepository, promotion_repository::PromotionRepository,
+        store_repository::StoreRepository, user_repository::UserRepository,
+    },
+    unit_of_work::SqlUnitOfWork,
+    Repositories,
+};
+use crate::infrastructure::config::DatabaseConfig;
+use sqlx::{MySql, MySqlPool};
 
-use sqlx::MySqlPool;
+pub type MySqlRepositories = Repositories<
+    UserRepository,
+    CatalogRepository,
+    LoyaltyRepository,
+    InventoryRepository,
+    StoreRepository,
+    DeliveryRepository,
+    OrderRepository,
+    PaymentRepository,
+    PromotionRepository,
+    CartRepository,
+    SqlUnitOfWork<MySql>,
+>;
 
-pub async fn connect_to_database() -> Result<MySqlPool, sqlx::Error> {
-    let conn = MySqlPool::connect(&env::var("DATABASE_URL").unwrap()).await?;
+pub async fn connect_to_database(config: &DatabaseConfig) -> Result<MySqlPool, sqlx::Error> {
+    let conn = MySqlPool::connect(&config.url).await?;
     Ok(conn)
 }
+
+pub fn repositories(conn: DbPool<MySql>) -> MySqlRepositories {
+    Repositories {
+        user: UserRepository::new(conn.clone()),
+        catalog: CatalogRepository::new(conn.clone()),
+        loyalty: LoyaltyRepository::new(conn.clone()),
+        inventory: InventoryRepository::new(conn.clone()),
+        store: StoreRepository::new(conn.clone()),
+        delivery: DeliveryRepository::new(conn.clone()),
+        order: OrderRepository::new(conn.clone()),
+        payment: PaymentRepository::new(conn.clone()),
+        promotion: PromotionRepository::new(conn.clone()),
+        cart: CartRepository::new(conn.clone()),
+        unit_of_work: SqlUnitOfWork::new(conn),
+    }
+}
+
+#[cfg(test)]
+mod mysql_tests {
+    use super::*;
+    use crate::infrastructure::data::{conformance, migrations};
+    use sqlx::{mysql::MySqlConnectOptions, Executor};
+    use std::str::FromStr;
+
+    /// Necesita un servidor: se ejecuta solo si `TEST_MYSQL_URL` apunta a un usuario que pueda
+    /// crear bases. Cada ejecución usa una base propia que se borra al final.
+    #[actix_web::test]
+    async fn test_repositories_pass_conformance_suite() {
+        let Ok(url) = std::env::var("TEST_MYSQL_URL") else {
+            return;
+        };
+        let database = format!("conformance_{}", std::process::id());
+
+        let admin = MySqlPool::connect(&url).await.unwrap();
+        admin
+            .execute(format!("CREATE DATABASE {}", database).as_str())
+            .await
+            .unwrap();
+
+        let options = MySqlConnectOptions::from_str(&url)
+            .unwrap()
+            .database(&database);
+        let conn = MySqlPool::connect_with(options).await.unwrap();
+        migrations::run(&conn).await.unwrap();
+
+        conformance::check_repositories(repositories(DbPool::new(conn.clone()))).await;
+
+        conn.close().await;
+        admin
+            .execute(format!("DROP DATABASE {}", database).as_str())
+            .await
+            .unwrap();
+    }
+}
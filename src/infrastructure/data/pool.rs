@@ -0,0 +1,130 @@
+//! Pool de conexiones de los repositorios SQL. Envuelve el de sqlx para informar cuánto espera
+//! cada consulta por una conexión, algo que sqlx no mide.
+use futures::{future::BoxFuture, stream, stream::BoxStream, TryStreamExt};
+use sqlx::{
+    database::HasStatement, pool::PoolConnection, Database, Describe, Either, Execute, Executor,
+    Pool, Transaction,
+};
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Recibe la espera de cada conexión que se obtiene del pool.
+pub trait AcquireObserver: Send + Sync {
+    fn observe_acquire(&self, wait: Duration);
+}
+
+pub struct DbPool<DB: Database> {
+    pool: Pool<DB>,
+    observer: Option<Arc<dyn AcquireObserver>>,
+}
+
+impl<DB: Database> DbPool<DB> {
+    pub fn new(pool: Pool<DB>) -> Self {
+        DbPool {
+            pool,
+            observer: None,
+        }
+    }
+
+    /// Informa a `observer` la espera de cada conexión.
+    pub fn observe_acquire(mut self, observer: impl AcquireObserver + 'static) -> Self {
+        self.observer = Some(Arc::new(observer));
+
+        self
+    }
+
+    pub async fn acquire(&self) -> Result<PoolConnection<DB>, sqlx::Error> {
+        let started = Instant::now();
+        let conn = self.pool.acquire().await;
+
+        if let Some(observer) = &self.observer {
+            observer.observe_acquire(started.elapsed());
+        }
+
+        conn
+    }
+
+    pub async fn begin(&self) -> Result<Transaction<'static, DB>, sqlx::Error> {
+        let conn = self.acquire().await?;
+
+        Transaction::begin(conn).await
+    }
+}
+
+impl<DB: Database> Clone for DbPool<DB> {
+    fn clone(&self) -> Self {
+        DbPool {
+            pool: self.pool.clone(),
+            observer: self.observer.clone(),
+        }
+    }
+}
+
+impl<DB: Database> fmt::Debug for DbPool<DB> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DbPool").field("pool", &self.pool).finish()
+    }
+}
+
+/// Igual que el `Executor` del pool de sqlx, pero obteniendo la conexión con `acquire`.
+impl<'p, DB: Database> Executor<'p> for &'_ DbPool<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+{
+    type Database = DB;
+
+    fn fetch_many<'e, 'q: 'e, E>(
+        self,
+        query: E,
+    ) -> BoxStream<'e, Result<Either<DB::QueryResult, DB::Row>, sqlx::Error>>
+    where
+        E: 'q + Execute<'q, DB>,
+    {
+        let pool = self.clone();
+
+        // Las filas se juntan antes de entregarlas porque la conexión no puede salir del bloque;
+        // los repositorios las leen todas de una vez de todos modos.
+        Box::pin(
+            stream::once(async move {
+                let mut conn = pool.acquire().await?;
+                conn.fetch_many(query).try_collect::<Vec<_>>().await
+            })
+            .map_ok(|results| stream::iter(results.into_iter().map(Ok)))
+            .try_flatten(),
+        )
+    }
+
+    fn fetch_optional<'e, 'q: 'e, E>(
+        self,
+        query: E,
+    ) -> BoxFuture<'e, Result<Option<DB::Row>, sqlx::Error>>
+    where
+        E: 'q + Execute<'q, DB>,
+    {
+        let pool = self.clone();
+
+        Box::pin(async move { pool.acquire().await?.fetch_optional(query).await })
+    }
+
+    fn prepare_with<'e, 'q: 'e>(
+        self,
+        sql: &'q str,
+        parameters: &'e [DB::TypeInfo],
+    ) -> BoxFuture<'e, Result<<DB as HasStatement<'q>>::Statement, sqlx::Error>> {
+        let pool = self.clone();
+
+        Box::pin(async move { pool.acquire().await?.prepare_with(sql, parameters).await })
+    }
+
+    fn describe<'e, 'q: 'e>(
+        self,
+        sql: &'q str,
+    ) -> BoxFuture<'e, Result<Describe<DB>, sqlx::Error>> {
+        let pool = self.clone();
+
+        Box::pin(async move { pool.acquire().await?.describe(sql).await })
+    }
+}
@@ -0,0 +1,90 @@
+//! Backend de PostgreSQL: usa los repositorios SQL compartidos, que adaptan cada consulta con
+//! `Backend::sql`.
+
+use super::{
+    pool::DbPool,
+    repositories::{
+        cart_repository::CartRepository, catalog_repository::CatalogRepository,
+        delivery_repository::DeliveryRepository, inventory_repository::InventoryRepository,
+        loyalty_repository::LoyaltyRepository, order_repository::OrderRepository,
+        payment_repository::PaymentRepository, promotion_repository::PromotionRepository,
+        store_repository::StoreRepository, user_repository::UserRepository,
+    },
+    unit_of_work::SqlUnitOfWork,
+    Repositories,
+};
+use crate::infrastructure::config::DatabaseConfig;
+use sqlx::{PgPool, Postgres};
+
+pub type PostgresRepositories = Repositories<
+    UserRepository<Postgres>,
+    CatalogRepository<Postgres>,
+    LoyaltyRepository<Postgres>,
+    InventoryRepository<Postgres>,
+    StoreRepository<Postgres>,
+    DeliveryRepository<Postgres>,
+    OrderRepository<Postgres>,
+    PaymentRepository<Postgres>,
+    PromotionRepository<Postgres>,
+    CartRepository<Postgres>,
+    SqlUnitOfWork<Postgres>,
+>;
+
+pub async fn connect_to_database(config: &DatabaseConfig) -> Result<PgPool, sqlx::Error> {
+    let conn = PgPool::connect(&config.url).await?;
+    Ok(conn)
+}
+
+pub fn repositories(conn: DbPool<Postgres>) -> PostgresRepositories {
+    Repositories {
+        user: UserRepository::new(conn.clone()),
+        catalog: CatalogRepository::new(conn.clone()),
+        loyalty: LoyaltyRepository::new(conn.clone()),
+        inventory: InventoryRepository::new(conn.clone()),
+        store: StoreRepository::new(conn.clone()),
+        delivery: DeliveryRepository::new(conn.clone()),
+        order: OrderRepository::new(conn.clone()),
+        payment: PaymentRepository::new(conn.clone()),
+        promotion: PromotionRepository::new(conn.clone()),
+        cart: CartRepository::new(conn.clone()),
+        unit_of_work: SqlUnitOfWork::new(conn),
+    }
+}
+
+#[cfg(test)]
+mod postgres_tests {
+    use super::*;
+    use crate::infrastructure::data::{conformance, migrations};
+    use sqlx::{postgres::PgConnectOptions, Executor};
+    use std::str::FromStr;
+
+    /// Necesita un servidor: se ejecuta solo si `TEST_POSTGRES_URL` apunta a una base en la que
+    /// se puedan crear esquemas. Cada ejecución usa un esquema propio que se borra al final.
+    #[actix_web::test]
+    async fn test_repositories_pass_conformance_suite() {
+        let Ok(url) = std::env::var("TEST_POSTGRES_URL") else {
+            return;
+        };
+        let schema = format!("conformance_{}", std::process::id());
+
+        let admin = PgPool::connect(&url).await.unwrap();
+        admin
+            .execute(format!("CREATE SCHEMA {}", schema).as_str())
+            .await
+            .unwrap();
+
+        let options = PgConnectOptions::from_str(&url)
+            .unwrap()
+            .options([("search_path", schema.as_str())]);
+        let conn = PgPool::connect_with(options).await.unwrap();
+        migrations::run(&conn).await.unwrap();
+
+        conformance::check_repositories(repositories(DbPool::new(conn.clone()))).await;
+
+        conn.close().await;
+        admin
+            .execute(format!("DROP SCHEMA {} CASCADE", schema).as_str())
+            .await
+            .unwrap();
+    }
+}
@@ -0,0 +1,338 @@
+use super::order_repository::OrderRepository;
+use crate::core::{
+    entities::{cart::CartItem as CartItemCore, order::Order as OrderCore},
+    errors::cart_errors::CartError,
+    ports::cart_port::ICartRepository,
+};
+use crate::infrastructure::data::backend::{Backend, RowsAffected};
+use crate::infrastructure::data::models::cart::{
+    CartItem as CartItemModel, CartItemOption as CartItemOptionModel,
+};
+use crate::infrastructure::data::pool::DbPool;
+use crate::infrastructure::data::unit_of_work::SqlTransaction;
+use chrono::{DateTime, Utc};
+use sqlx::{database::HasArguments, Encode, Executor, FromRow, IntoArguments, MySql, Type};
+
+pub struct CartRepository<DB: Backend = MySql> {
+    pub conn: DbPool<DB>,
+}
+
+#[async_trait::async_trait]
+impl<DB: Backend> ICartRepository for CartRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    CartItemModel: for<'r> FromRow<'r, DB::Row>,
+    CartItemOptionModel: for<'r> FromRow<'r, DB::Row>,
+    (i32,): for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+    for<'q> DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<i32>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<String>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<DateTime<Utc>>: Encode<'q, DB> + Type<DB>,
+{
+    type Transaction = SqlTransaction<DB>;
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_cart_items(&self, user_id: i32) -> Result<Vec<CartItemCore>, CartError> {
+        let items = sqlx::query_as::<_, CartItemModel>(&DB::sql(
+            "SELECT * FROM cart_item WHERE `user`=? ORDER BY id",
+        ))
+        .bind(user_id)
+        .fetch_all(&self.conn)
+        .await;
+        let options = sqlx::query_as::<_, CartItemOptionModel>(&DB::sql(
+            "SELECT o.* FROM cart_item_option o \
+            JOIN cart_item i ON i.id = o.cart_item WHERE i.`user`=?",
+        ))
+        .bind(user_id)
+        .fetch_all(&self.conn)
+        .await;
+
+        match (items, options) {
+            (Ok(items), Ok(options)) => Ok(items
+                .into_iter()
+                .map(|item| {
+                    let option_ids = options
+                        .iter()
+                        .filter(|option| Some(option.cart_item) == item.id)
+                        .map(|option| option.modifier_option)
+                        .collect();
+
+                    CartItemCore::from_cart_item_model(item, option_ids)
+                })
+                .collect()),
+            (Err(err), _) | (_, Err(err)) => {
+                log::error!("{:?}", err);
+                Err(CartError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_cart_item(
+        &self,
+        user_id: i32,
+        mut item: CartItemCore,
+    ) -> Result<CartItemCore, CartError> {
+        let item_model = CartItemModel::from_cart_item_core(user_id, &item);
+
+        let result: Result<CartItemCore, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            let query =
+                DB::insert_sql("INSERT INTO cart_item (`user`, product, quantity) VALUES (?,?,?)");
+            let insert = sqlx::query(&query)
+                .bind(item_model.user)
+                .bind(item_model.product)
+                .bind(item_model.quantity);
+            let item_id = DB::insert_id(insert, &mut *tx).await?;
+            item.id = Some(item_id);
+
+            for option_id in item.option_ids() {
+                sqlx::query(&DB::sql(
+                    "INSERT INTO cart_item_option (cart_item, modifier_option) VALUES (?,?)",
+                ))
+                .bind(item_id)
+                .bind(option_id)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            tx.commit().await?;
+            Ok(item)
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            CartError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_cart_item(
+        &self,
+        user_id: i32,
+        item_id: i32,
+        quantity: i32,
+    ) -> Result<(), CartError> {
+        let result = sqlx::query(&DB::sql(
+            "UPDATE cart_item SET quantity=? WHERE id=? AND `user`=?",
+        ))
+        .bind(quantity)
+        .bind(item_id)
+        .bind(user_id)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(CartError::ItemNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CartError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn delete_cart_item(&self, user_id: i32, item_id: i32) -> Result<(), CartError> {
+        let result: Result<u64, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+            let deleted = Self::delete_items_with(&mut tx, user_id, &[item_id]).await?;
+            tx.commit().await?;
+
+            Ok(deleted)
+        }
+        .await;
+
+        match result {
+            Ok(0) => Err(CartError::ItemNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CartError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn clear_cart(&self, user_id: i32) -> Result<(), CartError> {
+        let result: Result<(), sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            sqlx::query(&DB::sql(
+                "DELETE FROM cart_item_option WHERE cart_item IN \
+                (SELECT id FROM cart_item WHERE `user`=?)",
+            ))
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(&DB::sql("DELETE FROM cart_item WHERE `user`=?"))
+                .bind(user_id)
+                .execute(&mut *tx)
+                .await?;
+            Self::delete_coupons_with(&mut tx, user_id).await?;
+
+            tx.commit().await
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            CartError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_cart_coupons(&self, user_id: i32) -> Result<Vec<i32>, CartError> {
+        let result = sqlx::query_as::<_, (i32,)>(&DB::sql(
+            "SELECT promotion FROM cart_coupon WHERE `user`=? ORDER BY promotion",
+        ))
+        .bind(user_id)
+        .fetch_all(&self.conn)
+        .await;
+
+        match result {
+            Ok(rows) => Ok(rows.into_iter().map(|(promotion,)| promotion).collect()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CartError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_cart_coupon(&self, user_id: i32, promotion_id: i32) -> Result<(), CartError> {
+        let query = DB::insert_ignore("INSERT INTO cart_coupon (`user`, promotion) VALUES (?,?)");
+        let result = sqlx::query(&DB::sql(&query))
+            .bind(user_id)
+            .bind(promotion_id)
+            .execute(&self.conn)
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CartError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn delete_cart_coupon(&self, user_id: i32, promotion_id: i32) -> Result<(), CartError> {
+        let result = sqlx::query(&DB::sql(
+            "DELETE FROM cart_coupon WHERE `user`=? AND promotion=?",
+        ))
+        .bind(user_id)
+        .bind(promotion_id)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(CartError::CouponNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CartError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn checkout_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        user_id: i32,
+        item_ids: Vec<i32>,
+        order: OrderCore,
+    ) -> Result<OrderCore, CartError> {
+        let result: Result<Option<OrderCore>, sqlx::Error> = async {
+            let conn = tx.connection();
+
+            // Si otro proceso modificó el carrito mientras se leía, se descarta el pedido.
+            let deleted = Self::delete_items_with(&mut *conn, user_id, &item_ids).await?;
+            if deleted != item_ids.len() as u64 {
+                return Ok(None);
+            }
+
+            let order = OrderRepository::<DB>::insert_order_with(&mut *conn, order).await?;
+            Self::delete_coupons_with(&mut *conn, user_id).await?;
+
+            Ok(Some(order))
+        }
+        .await;
+
+        match result {
+            Ok(Some(order)) => Ok(order),
+            Ok(None) => Err(CartError::CartChanged),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CartError::Unexpected)
+            }
+        }
+    }
+}
+
+impl<DB: Backend> CartRepository<DB> {
+    pub fn new(conn: DbPool<DB>) -> Self {
+        CartRepository { conn }
+    }
+}
+
+impl<DB: Backend> Clone for CartRepository<DB> {
+    fn clone(&self) -> Self {
+        CartRepository::new(self.conn.clone())
+    }
+}
+
+impl<DB: Backend> CartRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+{
+    async fn delete_items_with(
+        conn: &mut DB::Connection,
+        user_id: i32,
+        item_ids: &[i32],
+    ) -> Result<u64, sqlx::Error> {
+        let mut deleted = 0;
+
+        for item_id in item_ids {
+            let rows = sqlx::query(&DB::sql("DELETE FROM cart_item WHERE id=? AND `user`=?"))
+                .bind(item_id)
+                .bind(user_id)
+                .execute(&mut *conn)
+                .await?
+                .rows_affected();
+
+            if rows > 0 {
+                sqlx::query(&DB::sql("DELETE FROM cart_item_option WHERE cart_item=?"))
+                    .bind(item_id)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+            deleted += rows;
+        }
+
+        Ok(deleted)
+    }
+
+    async fn delete_coupons_with(
+        conn: &mut DB::Connection,
+        user_id: i32,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(&DB::sql("DELETE FROM cart_coupon WHERE `user`=?"))
+            .bind(user_id)
+            .execute(&mut *conn)
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,581 @@
+use crate::core::{
+    entities::{
+        category::Category as CategoryCore,
+        modifier::{ModifierGroup as ModifierGroupCore, ModifierOption as ModifierOptionCore},
+        product::Product as ProductCore,
+    },
+    errors::catalog_errors::CatalogError,
+    ports::catalog_port::ICatalogRepository,
+};
+use crate::infrastructure::data::backend::{Backend, RowsAffected};
+use crate::infrastructure::data::models::{
+    category::Category as CategoryModel,
+    modifier::{ModifierGroup as ModifierGroupModel, ModifierOption as ModifierOptionModel},
+    product::Product as ProductModel,
+};
+use crate::infrastructure::data::pool::DbPool;
+use sqlx::{database::HasArguments, Encode, Executor, FromRow, IntoArguments, MySql, Type};
+
+/// Columnas del producto más `in_stock`, que es falso si a algún insumo de su receta no le
+/// alcanza el stock para preparar una unidad.
+const SELECT_PRODUCT: &str = "SELECT p.*, NOT EXISTS (SELECT 1 FROM product_ingredient pi \
+    JOIN ingredient i ON i.id = pi.ingredient WHERE pi.product = p.id AND i.stock < pi.quantity) \
+    AS in_stock FROM product p";
+
+/// Columnas de la opción más `in_stock`, calculado igual que el de los productos.
+const SELECT_MODIFIER_OPTION: &str = "SELECT o.*, NOT EXISTS (SELECT 1 \
+    FROM modifier_option_ingredient oi JOIN ingredient i ON i.id = oi.ingredient \
+    WHERE oi.modifier_option = o.id AND i.stock < oi.quantity) AS in_stock FROM modifier_option o";
+
+pub struct CatalogRepository<DB: Backend = MySql> {
+    pub conn: DbPool<DB>,
+}
+
+#[async_trait::async_trait]
+impl<DB: Backend> ICatalogRepository for CatalogRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    CategoryModel: for<'r> FromRow<'r, DB::Row>,
+    ProductModel: for<'r> FromRow<'r, DB::Row>,
+    ModifierGroupModel: for<'r> FromRow<'r, DB::Row>,
+    ModifierOptionModel: for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB> + Type<DB>,
+    for<'q> bool: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<i32>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<String>: Encode<'q, DB> + Type<DB>,
+{
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_categories(&self) -> Result<Vec<CategoryCore>, CatalogError> {
+        let result = sqlx::query_as::<_, CategoryModel>(&DB::sql("SELECT * FROM category"))
+            .fetch_all(&self.conn)
+            .await;
+
+        match result {
+            Ok(rows) => Ok(CategoryCore::from_category_model_vec(rows)),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_category_by_id(&self, category_id: i32) -> Result<CategoryCore, CatalogError> {
+        let result =
+            sqlx::query_as::<_, CategoryModel>(&DB::sql("SELECT * FROM category WHERE id=?"))
+                .bind(category_id)
+                .fetch_one(&self.conn)
+                .await;
+
+        match result {
+            Ok(row) => Ok(CategoryCore::from_category_model(row)),
+            Err(err) => match &err {
+                sqlx::Error::RowNotFound => Err(CatalogError::CategoryNotFound),
+                _ => {
+                    log::error!("{:?}", err);
+                    Err(CatalogError::Unexpected)
+                }
+            },
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_category(&self, category: CategoryCore) -> Result<CategoryCore, CatalogError> {
+        let mut category_model = CategoryModel::from_category_core(category);
+
+        let query =
+            DB::insert_sql("INSERT INTO category (name, description, tax_rate) VALUES (?,?,?)");
+        let insert = sqlx::query(&query)
+            .bind(&category_model.name)
+            .bind(&category_model.description)
+            .bind(category_model.tax_rate);
+        let result = DB::insert_id(insert, &self.conn).await;
+
+        match result {
+            Ok(id) => {
+                category_model.set_id(id);
+
+                Ok(CategoryCore::from_category_model(category_model))
+            }
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_category(
+        &self,
+        category_id: i32,
+        category: CategoryCore,
+    ) -> Result<(), CatalogError> {
+        let category_model = CategoryModel::from_category_core(category);
+
+        let result = sqlx::query(&DB::sql(
+            "UPDATE category SET name=?, description=?, tax_rate=? WHERE id=?",
+        ))
+        .bind(&category_model.name)
+        .bind(&category_model.description)
+        .bind(category_model.tax_rate)
+        .bind(category_id)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(CatalogError::CategoryNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn delete_category(&self, category_id: i32) -> Result<(), CatalogError> {
+        let result = sqlx::query(&DB::sql("DELETE FROM category WHERE id=?"))
+            .bind(category_id)
+            .execute(&self.conn)
+            .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(CatalogError::CategoryNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_products(
+        &self,
+        category_id: Option<i32>,
+    ) -> Result<Vec<ProductCore>, CatalogError> {
+        let result = match category_id {
+            Some(category_id) => {
+                sqlx::query_as::<_, ProductModel>(&DB::sql(&format!(
+                    "{} WHERE p.category=?",
+                    SELECT_PRODUCT
+                )))
+                .bind(category_id)
+                .fetch_all(&self.conn)
+                .await
+            }
+            None => {
+                sqlx::query_as::<_, ProductModel>(&DB::sql(SELECT_PRODUCT))
+                    .fetch_all(&self.conn)
+                    .await
+            }
+        };
+
+        match result {
+            Ok(rows) => Ok(ProductCore::from_product_model_vec(rows)),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_product_by_id(&self, product_id: i32) -> Result<ProductCore, CatalogError> {
+        let result = sqlx::query_as::<_, ProductModel>(&DB::sql(&format!(
+            "{} WHERE p.id=?",
+            SELECT_PRODUCT
+        )))
+        .bind(product_id)
+        .fetch_one(&self.conn)
+        .await;
+
+        match result {
+            Ok(row) => Ok(ProductCore::from_product_model(row)),
+            Err(err) => match &err {
+                sqlx::Error::RowNotFound => Err(CatalogError::ProductNotFound),
+                _ => {
+                    log::error!("{:?}", err);
+                    Err(CatalogError::Unexpected)
+                }
+            },
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_product(&self, product: ProductCore) -> Result<ProductCore, CatalogError> {
+        let mut product_model = ProductModel::from_product_core(product);
+
+        let query = DB::insert_sql(
+            "INSERT INTO product (category, name, description, price, image_url, available) \
+            VALUES (?,?,?,?,?,?)",
+        );
+        let insert = sqlx::query(&query)
+            .bind(product_model.category)
+            .bind(&product_model.name)
+            .bind(&product_model.description)
+            .bind(product_model.price)
+            .bind(&product_model.image_url)
+            .bind(product_model.available);
+        let result = DB::insert_id(insert, &self.conn).await;
+
+        match result {
+            Ok(id) => {
+                product_model.set_id(id);
+
+                Ok(ProductCore::from_product_model(product_model))
+            }
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_product(
+        &self,
+        product_id: i32,
+        product: ProductCore,
+    ) -> Result<(), CatalogError> {
+        let product_model = ProductModel::from_product_core(product);
+
+        let result = sqlx::query(&DB::sql(
+            "UPDATE product SET category=?, name=?, description=?, price=?, image_url=?, \
+            available=? WHERE id=?",
+        ))
+        .bind(product_model.category)
+        .bind(&product_model.name)
+        .bind(&product_model.description)
+        .bind(product_model.price)
+        .bind(&product_model.image_url)
+        .bind(product_model.available)
+        .bind(product_id)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(CatalogError::ProductNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn delete_product(&self, product_id: i32) -> Result<(), CatalogError> {
+        let result: Result<u64, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            sqlx::query(&DB::sql(
+                "DELETE FROM product_modifier_group WHERE product=?",
+            ))
+            .bind(product_id)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(&DB::sql("DELETE FROM product_ingredient WHERE product=?"))
+                .bind(product_id)
+                .execute(&mut *tx)
+                .await?;
+            let deleted = sqlx::query(&DB::sql("DELETE FROM product WHERE id=?"))
+                .bind(product_id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+
+            tx.commit().await?;
+            Ok(deleted)
+        }
+        .await;
+
+        match result {
+            Ok(0) => Err(CatalogError::ProductNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_modifier_groups(&self) -> Result<Vec<ModifierGroupCore>, CatalogError> {
+        let groups =
+            sqlx::query_as::<_, ModifierGroupModel>(&DB::sql("SELECT * FROM modifier_group"))
+                .fetch_all(&self.conn)
+                .await;
+        let options = sqlx::query_as::<_, ModifierOptionModel>(&DB::sql(SELECT_MODIFIER_OPTION))
+            .fetch_all(&self.conn)
+            .await;
+
+        match (groups, options) {
+            (Ok(groups), Ok(options)) => Ok(ModifierGroupCore::from_modifier_group_model_vec(
+                groups,
+                ModifierOptionCore::from_modifier_option_model_vec(options),
+            )),
+            (Err(err), _) | (_, Err(err)) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_modifier_groups_by_product(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<ModifierGroupCore>, CatalogError> {
+        let groups = sqlx::query_as::<_, ModifierGroupModel>(&DB::sql(
+            "SELECT g.* FROM modifier_group g \
+            JOIN product_modifier_group pg ON pg.modifier_group = g.id WHERE pg.product=?",
+        ))
+        .bind(product_id)
+        .fetch_all(&self.conn)
+        .await;
+        let options = sqlx::query_as::<_, ModifierOptionModel>(&DB::sql(&format!(
+            "{} JOIN product_modifier_group pg ON pg.modifier_group = o.modifier_group \
+            WHERE pg.product=?",
+            SELECT_MODIFIER_OPTION
+        )))
+        .bind(product_id)
+        .fetch_all(&self.conn)
+        .await;
+
+        match (groups, options) {
+            (Ok(groups), Ok(options)) => Ok(ModifierGroupCore::from_modifier_group_model_vec(
+                groups,
+                ModifierOptionCore::from_modifier_option_model_vec(options),
+            )),
+            (Err(err), _) | (_, Err(err)) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_modifier_group(
+        &self,
+        mut group: ModifierGroupCore,
+    ) -> Result<ModifierGroupCore, CatalogError> {
+        let group_model = ModifierGroupModel::from_modifier_group_core(&group);
+
+        let result: Result<ModifierGroupCore, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            let query = DB::insert_sql(
+                "INSERT INTO modifier_group (name, min_selections, max_selections) VALUES (?,?,?)",
+            );
+            let insert = sqlx::query(&query)
+                .bind(&group_model.name)
+                .bind(group_model.min_selections)
+                .bind(group_model.max_selections);
+            let group_id = DB::insert_id(insert, &mut *tx).await?;
+            group.id = Some(group_id);
+
+            for option in group.options.iter_mut() {
+                option.group_id = group_id;
+                let option_model = ModifierOptionModel::from_modifier_option_core(option.clone());
+
+                let query = DB::insert_sql(
+                    "INSERT INTO modifier_option (modifier_group, name, price_delta, available) \
+                    VALUES (?,?,?,?)",
+                );
+                let insert = sqlx::query(&query)
+                    .bind(option_model.modifier_group)
+                    .bind(&option_model.name)
+                    .bind(option_model.price_delta)
+                    .bind(option_model.available);
+                let option_id = DB::insert_id(insert, &mut *tx).await?;
+                option.id = Some(option_id);
+            }
+
+            tx.commit().await?;
+            Ok(group)
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            CatalogError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn delete_modifier_group(&self, group_id: i32) -> Result<(), CatalogError> {
+        let result: Result<u64, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            sqlx::query(&DB::sql(
+                "DELETE FROM product_modifier_group WHERE modifier_group=?",
+            ))
+            .bind(group_id)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(&DB::sql(
+                "DELETE FROM modifier_option_ingredient WHERE modifier_option IN \
+                (SELECT id FROM modifier_option WHERE modifier_group=?)",
+            ))
+            .bind(group_id)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(&DB::sql(
+                "DELETE FROM modifier_option WHERE modifier_group=?",
+            ))
+            .bind(group_id)
+            .execute(&mut *tx)
+            .await?;
+            let deleted = sqlx::query(&DB::sql("DELETE FROM modifier_group WHERE id=?"))
+                .bind(group_id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+
+            tx.commit().await?;
+            Ok(deleted)
+        }
+        .await;
+
+        match result {
+            Ok(0) => Err(CatalogError::ModifierGroupNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_modifier_option(
+        &self,
+        option: ModifierOptionCore,
+    ) -> Result<ModifierOptionCore, CatalogError> {
+        let mut option_model = ModifierOptionModel::from_modifier_option_core(option);
+
+        let query = DB::insert_sql(
+            "INSERT INTO modifier_option (modifier_group, name, price_delta, available) \
+            VALUES (?,?,?,?)",
+        );
+        let insert = sqlx::query(&query)
+            .bind(option_model.modifier_group)
+            .bind(&option_model.name)
+            .bind(option_model.price_delta)
+            .bind(option_model.available);
+        let result = DB::insert_id(insert, &self.conn).await;
+
+        match result {
+            Ok(id) => {
+                option_model.set_id(id);
+
+                Ok(ModifierOptionCore::from_modifier_option_model(option_model))
+            }
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_modifier_option(
+        &self,
+        option_id: i32,
+        option: ModifierOptionCore,
+    ) -> Result<(), CatalogError> {
+        let option_model = ModifierOptionModel::from_modifier_option_core(option);
+
+        let result = sqlx::query(&DB::sql(
+            "UPDATE modifier_option SET name=?, price_delta=?, available=? \
+            WHERE id=? AND modifier_group=?",
+        ))
+        .bind(&option_model.name)
+        .bind(option_model.price_delta)
+        .bind(option_model.available)
+        .bind(option_id)
+        .bind(option_model.modifier_group)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(CatalogError::ModifierOptionNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn attach_modifier_group(
+        &self,
+        product_id: i32,
+        group_id: i32,
+    ) -> Result<(), CatalogError> {
+        let query = DB::insert_ignore(
+            "INSERT INTO product_modifier_group (product, modifier_group) VALUES (?,?)",
+        );
+        let result = sqlx::query(&DB::sql(&query))
+            .bind(product_id)
+            .bind(group_id)
+            .execute(&self.conn)
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn detach_modifier_group(
+        &self,
+        product_id: i32,
+        group_id: i32,
+    ) -> Result<(), CatalogError> {
+        let result = sqlx::query(&DB::sql(
+            "DELETE FROM product_modifier_group WHERE product=? AND modifier_group=?",
+        ))
+        .bind(product_id)
+        .bind(group_id)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(CatalogError::ModifierGroupNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(CatalogError::Unexpected)
+            }
+        }
+    }
+}
+
+impl<DB: Backend> CatalogRepository<DB> {
+    pub fn new(conn: DbPool<DB>) -> Self {
+        CatalogRepository { conn }
+    }
+}
+
+impl<DB: Backend> Clone for CatalogRepository<DB> {
+    fn clone(&self) -> Self {
+        CatalogRepository::new(self.conn.clone())
+    }
+}
+
+impl<DB: Backend> CatalogRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+{
+}
@@ -0,0 +1,275 @@
+use crate::core::{
+    entities::delivery::{Address as AddressCore, DeliveryZone as DeliveryZoneCore},
+    errors::delivery_errors::DeliveryError,
+    ports::delivery_port::IDeliveryRepository,
+};
+use crate::infrastructure::data::backend::{Backend, RowsAffected};
+use crate::infrastructure::data::models::delivery::{
+    Address as AddressModel, DeliveryZone as DeliveryZoneModel,
+};
+use crate::infrastructure::data::pool::DbPool;
+use sqlx::{database::HasArguments, Encode, Executor, FromRow, IntoArguments, MySql, Type};
+
+pub struct DeliveryRepository<DB: Backend = MySql> {
+    pub conn: DbPool<DB>,
+}
+
+#[async_trait::async_trait]
+impl<DB: Backend> IDeliveryRepository for DeliveryRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    AddressModel: for<'r> FromRow<'r, DB::Row>,
+    DeliveryZoneModel: for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB> + Type<DB>,
+    for<'q> bool: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<i32>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<String>: Encode<'q, DB> + Type<DB>,
+{
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_addresses(&self, user_id: i32) -> Result<Vec<AddressCore>, DeliveryError> {
+        let result =
+            sqlx::query_as::<_, AddressModel>(&DB::sql("SELECT * FROM address WHERE `user`=?"))
+                .bind(user_id)
+                .fetch_all(&self.conn)
+                .await;
+
+        match result {
+            Ok(rows) => Ok(AddressCore::from_address_model_vec(rows)),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(DeliveryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_address(
+        &self,
+        user_id: i32,
+        address_id: i32,
+    ) -> Result<AddressCore, DeliveryError> {
+        let result = sqlx::query_as::<_, AddressModel>(&DB::sql(
+            "SELECT * FROM address WHERE id=? AND `user`=?",
+        ))
+        .bind(address_id)
+        .bind(user_id)
+        .fetch_one(&self.conn)
+        .await;
+
+        match result {
+            Ok(row) => Ok(AddressCore::from_address_model(row)),
+            Err(sqlx::Error::RowNotFound) => Err(DeliveryError::AddressNotFound),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(DeliveryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_address(&self, mut address: AddressCore) -> Result<AddressCore, DeliveryError> {
+        let address_model = AddressModel::from_address_core(&address);
+
+        let query = DB::insert_sql(
+            "INSERT INTO address (`user`, province, city, address) VALUES (?,?,?,?)",
+        );
+        let insert = sqlx::query(&query)
+            .bind(address_model.user)
+            .bind(&address_model.province)
+            .bind(&address_model.city)
+            .bind(&address_model.address);
+        let result = DB::insert_id(insert, &self.conn).await;
+
+        match result {
+            Ok(id) => {
+                address.id = Some(id);
+                Ok(address)
+            }
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(DeliveryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_address(&self, address: AddressCore) -> Result<(), DeliveryError> {
+        let address_model = AddressModel::from_address_core(&address);
+
+        let result = sqlx::query(&DB::sql(
+            "UPDATE address SET province=?, city=?, address=? WHERE id=? AND `user`=?",
+        ))
+        .bind(&address_model.province)
+        .bind(&address_model.city)
+        .bind(&address_model.address)
+        .bind(address_model.id)
+        .bind(address_model.user)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(DeliveryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn delete_address(&self, user_id: i32, address_id: i32) -> Result<(), DeliveryError> {
+        let result = sqlx::query(&DB::sql("DELETE FROM address WHERE id=? AND `user`=?"))
+            .bind(address_id)
+            .bind(user_id)
+            .execute(&self.conn)
+            .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(DeliveryError::AddressNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(DeliveryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_delivery_zones(&self) -> Result<Vec<DeliveryZoneCore>, DeliveryError> {
+        let result = sqlx::query_as::<_, DeliveryZoneModel>(&DB::sql(
+            "SELECT * FROM delivery_zone ORDER BY province, city",
+        ))
+        .fetch_all(&self.conn)
+        .await;
+
+        match result {
+            Ok(rows) => Ok(DeliveryZoneCore::from_delivery_zone_model_vec(rows)),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(DeliveryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_delivery_zone_by_id(
+        &self,
+        zone_id: i32,
+    ) -> Result<DeliveryZoneCore, DeliveryError> {
+        let result = sqlx::query_as::<_, DeliveryZoneModel>(&DB::sql(
+            "SELECT * FROM delivery_zone WHERE id=?",
+        ))
+        .bind(zone_id)
+        .fetch_one(&self.conn)
+        .await;
+
+        match result {
+            Ok(row) => Ok(DeliveryZoneCore::from_delivery_zone_model(row)),
+            Err(sqlx::Error::RowNotFound) => Err(DeliveryError::ZoneNotFound),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(DeliveryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_delivery_zone(
+        &self,
+        mut zone: DeliveryZoneCore,
+    ) -> Result<DeliveryZoneCore, DeliveryError> {
+        let zone_model = DeliveryZoneModel::from_delivery_zone_core(&zone);
+
+        let query = DB::insert_sql(
+            "INSERT INTO delivery_zone (province, city, fee, minimum_order, active) \
+            VALUES (?,?,?,?,?)",
+        );
+        let insert = sqlx::query(&query)
+            .bind(&zone_model.province)
+            .bind(&zone_model.city)
+            .bind(zone_model.fee)
+            .bind(zone_model.minimum_order)
+            .bind(zone_model.active);
+        let result = DB::insert_id(insert, &self.conn).await;
+
+        match result {
+            Ok(id) => {
+                zone.id = Some(id);
+                Ok(zone)
+            }
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(DeliveryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_delivery_zone(
+        &self,
+        zone_id: i32,
+        zone: DeliveryZoneCore,
+    ) -> Result<(), DeliveryError> {
+        let zone_model = DeliveryZoneModel::from_delivery_zone_core(&zone);
+
+        let result = sqlx::query(&DB::sql(
+            "UPDATE delivery_zone SET province=?, city=?, fee=?, minimum_order=?, active=? \
+            WHERE id=?",
+        ))
+        .bind(&zone_model.province)
+        .bind(&zone_model.city)
+        .bind(zone_model.fee)
+        .bind(zone_model.minimum_order)
+        .bind(zone_model.active)
+        .bind(zone_id)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(DeliveryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn delete_delivery_zone(&self, zone_id: i32) -> Result<(), DeliveryError> {
+        let result = sqlx::query(&DB::sql("DELETE FROM delivery_zone WHERE id=?"))
+            .bind(zone_id)
+            .execute(&self.conn)
+            .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(DeliveryError::ZoneNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(DeliveryError::Unexpected)
+            }
+        }
+    }
+}
+
+impl<DB: Backend> DeliveryRepository<DB> {
+    pub fn new(conn: DbPool<DB>) -> Self {
+        DeliveryRepository { conn }
+    }
+}
+
+impl<DB: Backend> Clone for DeliveryRepository<DB> {
+    fn clone(&self) -> Self {
+        DeliveryRepository::new(self.conn.clone())
+    }
+}
+
+impl<DB: Backend> DeliveryRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+{
+}
@@ -0,0 +1,399 @@
+use crate::core::{
+    entities::inventory::{
+        Ingredient as IngredientCore, RecipeLine as RecipeLineCore,
+        StockAdjustment as StockAdjustmentCore,
+    },
+    errors::inventory_errors::InventoryError,
+    ports::inventory_port::IInventoryRepository,
+};
+use crate::infrastructure::data::backend::{Backend, RowsAffected};
+use crate::infrastructure::data::models::inventory::{
+    Ingredient as IngredientModel, RecipeLine as RecipeLineModel,
+    StockAdjustment as StockAdjustmentModel,
+};
+use crate::infrastructure::data::pool::DbPool;
+use chrono::{DateTime, Utc};
+use sqlx::{database::HasArguments, Encode, Executor, FromRow, IntoArguments, MySql, Type};
+
+pub struct InventoryRepository<DB: Backend = MySql> {
+    pub conn: DbPool<DB>,
+}
+
+#[async_trait::async_trait]
+impl<DB: Backend> IInventoryRepository for InventoryRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    IngredientModel: for<'r> FromRow<'r, DB::Row>,
+    StockAdjustmentModel: for<'r> FromRow<'r, DB::Row>,
+    RecipeLineModel: for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<i32>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<String>: Encode<'q, DB> + Type<DB>,
+{
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_ingredients(&self) -> Result<Vec<IngredientCore>, InventoryError> {
+        let result = sqlx::query_as::<_, IngredientModel>(&DB::sql(
+            "SELECT * FROM ingredient ORDER BY name",
+        ))
+        .fetch_all(&self.conn)
+        .await;
+
+        match result {
+            Ok(rows) => Ok(IngredientCore::from_ingredient_model_vec(rows)),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(InventoryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_ingredient_by_id(
+        &self,
+        ingredient_id: i32,
+    ) -> Result<IngredientCore, InventoryError> {
+        let result =
+            sqlx::query_as::<_, IngredientModel>(&DB::sql("SELECT * FROM ingredient WHERE id=?"))
+                .bind(ingredient_id)
+                .fetch_one(&self.conn)
+                .await;
+
+        match result {
+            Ok(row) => Ok(IngredientCore::from_ingredient_model(row)),
+            Err(sqlx::Error::RowNotFound) => Err(InventoryError::IngredientNotFound),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(InventoryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_ingredient(
+        &self,
+        mut ingredient: IngredientCore,
+    ) -> Result<IngredientCore, InventoryError> {
+        let ingredient_model = IngredientModel::from_ingredient_core(&ingredient);
+
+        let query = DB::insert_sql(
+            "INSERT INTO ingredient (name, unit, stock, low_stock_threshold) VALUES (?,?,?,?)",
+        );
+        let insert = sqlx::query(&query)
+            .bind(&ingredient_model.name)
+            .bind(&ingredient_model.unit)
+            .bind(ingredient_model.stock)
+            .bind(ingredient_model.low_stock_threshold);
+        let result = DB::insert_id(insert, &self.conn).await;
+
+        match result {
+            Ok(id) => {
+                ingredient.id = Some(id);
+
+                Ok(ingredient)
+            }
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(InventoryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_ingredient(
+        &self,
+        ingredient_id: i32,
+        ingredient: IngredientCore,
+    ) -> Result<(), InventoryError> {
+        let ingredient_model = IngredientModel::from_ingredient_core(&ingredient);
+
+        let result = sqlx::query(&DB::sql(
+            "UPDATE ingredient SET name=?, unit=?, low_stock_threshold=? WHERE id=?",
+        ))
+        .bind(&ingredient_model.name)
+        .bind(&ingredient_model.unit)
+        .bind(ingredient_model.low_stock_threshold)
+        .bind(ingredient_id)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(InventoryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn delete_ingredient(&self, ingredient_id: i32) -> Result<(), InventoryError> {
+        let result: Result<u64, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            sqlx::query(&DB::sql(
+                "DELETE FROM product_ingredient WHERE ingredient=?",
+            ))
+            .bind(ingredient_id)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(&DB::sql(
+                "DELETE FROM modifier_option_ingredient WHERE ingredient=?",
+            ))
+            .bind(ingredient_id)
+            .execute(&mut *tx)
+            .await?;
+            let deleted = sqlx::query(&DB::sql("DELETE FROM ingredient WHERE id=?"))
+                .bind(ingredient_id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+
+            tx.commit().await?;
+            Ok(deleted)
+        }
+        .await;
+
+        match result {
+            Ok(0) => Err(InventoryError::IngredientNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(InventoryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_adjustments(
+        &self,
+        ingredient_id: i32,
+    ) -> Result<Vec<StockAdjustmentCore>, InventoryError> {
+        let result = sqlx::query_as::<_, StockAdjustmentModel>(&DB::sql(
+            "SELECT * FROM stock_adjustment WHERE ingredient=? ORDER BY id DESC",
+        ))
+        .bind(ingredient_id)
+        .fetch_all(&self.conn)
+        .await;
+
+        match result {
+            Ok(rows) => Ok(StockAdjustmentCore::from_stock_adjustment_model_vec(rows)),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(InventoryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn adjust_stock(
+        &self,
+        adjustment: StockAdjustmentCore,
+    ) -> Result<IngredientCore, InventoryError> {
+        let ingredient_id = adjustment.ingredient_id;
+
+        let result: Result<Option<IngredientModel>, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            if !Self::apply_adjustment_with(&mut tx, &adjustment).await? {
+                return Ok(None);
+            }
+            let ingredient = sqlx::query_as::<_, IngredientModel>(&DB::sql(
+                "SELECT * FROM ingredient WHERE id=?",
+            ))
+            .bind(ingredient_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+            tx.commit().await?;
+            Ok(Some(ingredient))
+        }
+        .await;
+
+        match result {
+            Ok(Some(row)) => Ok(IngredientCore::from_ingredient_model(row)),
+            Ok(None) => {
+                // El movimiento no se aplicó: o el insumo no existe o el stock no alcanza.
+                self.get_ingredient_by_id(ingredient_id).await?;
+                Err(InventoryError::InsufficientStock)
+            }
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(InventoryError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_product_recipe(
+        &self,
+        product_id: i32,
+    ) -> Result<Vec<RecipeLineCore>, InventoryError> {
+        let result = sqlx::query_as::<_, RecipeLineModel>(&DB::sql("SELECT ingredient, quantity FROM product_ingredient WHERE product=? ORDER BY ingredient"))
+        .bind(product_id)
+        .fetch_all(&self.conn)
+        .await;
+
+        Self::map_recipe(result)
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn set_product_recipe(
+        &self,
+        product_id: i32,
+        lines: Vec<RecipeLineCore>,
+    ) -> Result<(), InventoryError> {
+        let result: Result<(), sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            sqlx::query(&DB::sql("DELETE FROM product_ingredient WHERE product=?"))
+                .bind(product_id)
+                .execute(&mut *tx)
+                .await?;
+            for line in lines {
+                sqlx::query(&DB::sql(
+                    "INSERT INTO product_ingredient (product, ingredient, quantity) VALUES (?,?,?)",
+                ))
+                .bind(product_id)
+                .bind(line.ingredient_id)
+                .bind(line.quantity)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            tx.commit().await
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            InventoryError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_option_recipe(
+        &self,
+        option_id: i32,
+    ) -> Result<Vec<RecipeLineCore>, InventoryError> {
+        let result = sqlx::query_as::<_, RecipeLineModel>(&DB::sql(
+            "SELECT ingredient, quantity FROM modifier_option_ingredient WHERE modifier_option=? \
+            ORDER BY ingredient",
+        ))
+        .bind(option_id)
+        .fetch_all(&self.conn)
+        .await;
+
+        Self::map_recipe(result)
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn set_option_recipe(
+        &self,
+        option_id: i32,
+        lines: Vec<RecipeLineCore>,
+    ) -> Result<(), InventoryError> {
+        let result: Result<(), sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            sqlx::query(&DB::sql("DELETE FROM modifier_option_ingredient WHERE modifier_option=?"))
+                .bind(option_id)
+                .execute(&mut *tx)
+                .await?;
+            for line in lines {
+                sqlx::query(&DB::sql("INSERT INTO modifier_option_ingredient (modifier_option, ingredient, quantity) \
+                    VALUES (?,?,?)"))
+                .bind(option_id)
+                .bind(line.ingredient_id)
+                .bind(line.quantity)
+                .execute(&mut *tx)
+                .await?;
+            }
+
+            tx.commit().await
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            InventoryError::Unexpected
+        })
+    }
+}
+
+impl<DB: Backend> InventoryRepository<DB> {
+    pub fn new(conn: DbPool<DB>) -> Self {
+        InventoryRepository { conn }
+    }
+}
+
+impl<DB: Backend> Clone for InventoryRepository<DB> {
+    fn clone(&self) -> Self {
+        InventoryRepository::new(self.conn.clone())
+    }
+}
+
+impl<DB: Backend> InventoryRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<i32>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<String>: Encode<'q, DB> + Type<DB>,
+{
+    fn map_recipe(
+        result: Result<Vec<RecipeLineModel>, sqlx::Error>,
+    ) -> Result<Vec<RecipeLineCore>, InventoryError> {
+        match result {
+            Ok(rows) => Ok(RecipeLineCore::from_recipe_line_model_vec(rows)),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(InventoryError::Unexpected)
+            }
+        }
+    }
+
+    /// Suma `delta` al stock del insumo y registra el movimiento, usando la conexión recibida
+    /// para que forme parte de una transacción más grande. Retorna `false` sin registrar nada
+    /// si el insumo no existe o si el stock quedaría negativo.
+    pub(crate) async fn apply_adjustment_with(
+        conn: &mut DB::Connection,
+        adjustment: &StockAdjustmentCore,
+    ) -> Result<bool, sqlx::Error> {
+        let adjustment_model = StockAdjustmentModel::from_stock_adjustment_core(adjustment);
+
+        let updated = sqlx::query(&DB::sql(
+            "UPDATE ingredient SET stock=stock+? WHERE id=? AND stock+?>=0",
+        ))
+        .bind(adjustment_model.delta)
+        .bind(adjustment_model.ingredient)
+        .bind(adjustment_model.delta)
+        .execute(&mut *conn)
+        .await?
+        .rows_affected();
+
+        if updated == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query(&DB::sql("INSERT INTO stock_adjustment (ingredient, delta, reason, note, order_id, adjusted_by, \
+            created_at) VALUES (?,?,?,?,?,?,?)"))
+        .bind(adjustment_model.ingredient)
+        .bind(adjustment_model.delta)
+        .bind(&adjustment_model.reason)
+        .bind(&adjustment_model.note)
+        .bind(adjustment_model.order_id)
+        .bind(adjustment_model.adjusted_by)
+        .bind(adjustment_model.created_at)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(true)
+    }
+}
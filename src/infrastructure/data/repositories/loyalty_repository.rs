@@ -0,0 +1,171 @@
+use crate::core::{
+    entities::loyalty::LoyaltyEntry as LoyaltyEntryCore, errors::loyalty_errors::LoyaltyError,
+    ports::loyalty_port::ILoyaltyRepository,
+};
+use crate::infrastructure::data::backend::Backend;
+use crate::infrastructure::data::models::loyalty::LoyaltyEntry as LoyaltyEntryModel;
+use crate::infrastructure::data::pool::DbPool;
+use crate::infrastructure::data::unit_of_work::SqlTransaction;
+use chrono::{DateTime, Utc};
+use sqlx::{database::HasArguments, Encode, Executor, FromRow, IntoArguments, MySql, Type};
+
+pub struct LoyaltyRepository<DB: Backend = MySql> {
+    pub conn: DbPool<DB>,
+}
+
+#[async_trait::async_trait]
+impl<DB: Backend> ILoyaltyRepository for LoyaltyRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    LoyaltyEntryModel: for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<i32>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<DateTime<Utc>>: Encode<'q, DB> + Type<DB>,
+{
+    type Transaction = SqlTransaction<DB>;
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_entries(&self, user_id: i32) -> Result<Vec<LoyaltyEntryCore>, LoyaltyError> {
+        Self::entries_with(&self.conn, user_id).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_entry(
+        &self,
+        entry: LoyaltyEntryCore,
+    ) -> Result<LoyaltyEntryCore, LoyaltyError> {
+        let result: Result<LoyaltyEntryCore, sqlx::Error> = async {
+            let mut conn = self.conn.acquire().await?;
+
+            Self::insert_entry_with(&mut conn, entry).await
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            LoyaltyError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn lock_account_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        user_id: i32,
+    ) -> Result<(), LoyaltyError> {
+        let query = DB::for_update("SELECT id FROM `user` WHERE id=?");
+        let result = sqlx::query(&DB::sql(&query))
+            .bind(user_id)
+            .fetch_one(tx.connection())
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(LoyaltyError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_entries_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        user_id: i32,
+    ) -> Result<Vec<LoyaltyEntryCore>, LoyaltyError> {
+        Self::entries_with(tx.connection(), user_id).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_entry_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        entry: LoyaltyEntryCore,
+    ) -> Result<LoyaltyEntryCore, LoyaltyError> {
+        Self::insert_entry_with(tx.connection(), entry)
+            .await
+            .map_err(|err| {
+                log::error!("{:?}", err);
+                LoyaltyError::Unexpected
+            })
+    }
+}
+
+impl<DB: Backend> LoyaltyRepository<DB> {
+    pub fn new(conn: DbPool<DB>) -> Self {
+        LoyaltyRepository { conn }
+    }
+}
+
+impl<DB: Backend> Clone for LoyaltyRepository<DB> {
+    fn clone(&self) -> Self {
+        LoyaltyRepository::new(self.conn.clone())
+    }
+}
+
+impl<DB: Backend> LoyaltyRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<i32>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<DateTime<Utc>>: Encode<'q, DB> + Type<DB>,
+{
+    async fn entries_with<'e, E>(
+        executor: E,
+        user_id: i32,
+    ) -> Result<Vec<LoyaltyEntryCore>, LoyaltyError>
+    where
+        E: 'e + Executor<'e, Database = DB>,
+        LoyaltyEntryModel: for<'r> FromRow<'r, DB::Row>,
+    {
+        let result = sqlx::query_as::<_, LoyaltyEntryModel>(&DB::sql(
+            "SELECT * FROM loyalty_entry WHERE `user`=? ORDER BY id",
+        ))
+        .bind(user_id)
+        .fetch_all(executor)
+        .await;
+
+        match result {
+            Ok(rows) => Ok(rows
+                .into_iter()
+                .map(LoyaltyEntryCore::from_loyalty_entry_model)
+                .collect()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(LoyaltyError::Unexpected)
+            }
+        }
+    }
+
+    pub(crate) async fn insert_entry_with(
+        conn: &mut DB::Connection,
+        mut entry: LoyaltyEntryCore,
+    ) -> Result<LoyaltyEntryCore, sqlx::Error> {
+        let entry_model = LoyaltyEntryModel::from_loyalty_entry_core(&entry);
+
+        let query = DB::insert_sql(
+            "INSERT INTO loyalty_entry (`user`, kind, points, order_id, expires_at, created_at) \
+            VALUES (?,?,?,?,?,?)",
+        );
+        let insert = sqlx::query(&query)
+            .bind(entry_model.user)
+            .bind(&entry_model.kind)
+            .bind(entry_model.points)
+            .bind(entry_model.order_id)
+            .bind(entry_model.expires_at)
+            .bind(entry_model.created_at);
+        let entry_id = DB::insert_id(insert, &mut *conn).await?;
+        entry.id = Some(entry_id);
+
+        Ok(entry)
+    }
+}
@@ -1 +1,10 @@
+pub mod cart_repository;
+pub mod catalog_repository;
+pub mod delivery_repository;
+pub mod inventory_repository;
+pub mod loyalty_repository;
+pub mod order_repository;
+pub mod payment_repository;
+pub mod promotion_repository;
+pub mod store_repository;
 pub mod user_repository;
@@ -0,0 +1,687 @@
+use super::{inventory_repository::InventoryRepository, loyalty_repository::LoyaltyRepository};
+use crate::core::{
+    entities::{
+        inventory::StockAdjustment as StockAdjustmentCore,
+        loyalty::LoyaltyEntry as LoyaltyEntryCore,
+        order::{
+            Order as OrderCore, OrderItem as OrderItemCore, OrderItemOption as OrderItemOptionCore,
+            OrderStatus, OrderStatusChange as OrderStatusChangeCore,
+        },
+        promotion::AppliedDiscount as AppliedDiscountCore,
+        refund::Refund as RefundCore,
+        user_role::UserRole,
+    },
+    errors::order_errors::OrderError,
+    ports::order_port::IOrderRepository,
+};
+use crate::infrastructure::data::backend::{Backend, RowsAffected};
+use crate::infrastructure::data::models::{
+    order::{
+        Order as OrderModel, OrderItem as OrderItemModel, OrderItemOption as OrderItemOptionModel,
+        OrderStatusChange as OrderStatusChangeModel,
+    },
+    payment::{Refund as RefundModel, RefundItem as RefundItemModel},
+    promotion::PromotionRedemption as PromotionRedemptionModel,
+};
+use crate::infrastructure::data::pool::DbPool;
+use crate::infrastructure::data::unit_of_work::SqlTransaction;
+use chrono::{DateTime, Utc};
+use sqlx::{database::HasArguments, Encode, Executor, FromRow, IntoArguments, MySql, Type};
+
+pub struct OrderRepository<DB: Backend = MySql> {
+    pub conn: DbPool<DB>,
+}
+
+#[async_trait::async_trait]
+impl<DB: Backend> IOrderRepository for OrderRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    OrderModel: for<'r> FromRow<'r, DB::Row>,
+    OrderItemModel: for<'r> FromRow<'r, DB::Row>,
+    OrderItemOptionModel: for<'r> FromRow<'r, DB::Row>,
+    OrderStatusChangeModel: for<'r> FromRow<'r, DB::Row>,
+    PromotionRedemptionModel: for<'r> FromRow<'r, DB::Row>,
+    RefundItemModel: for<'r> FromRow<'r, DB::Row>,
+    RefundModel: for<'r> FromRow<'r, DB::Row>,
+    (String,): for<'r> FromRow<'r, DB::Row>,
+    (i32,): for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+    for<'q> DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<i32>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<String>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<DateTime<Utc>>: Encode<'q, DB> + Type<DB>,
+{
+    type Transaction = SqlTransaction<DB>;
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_order(&self, order: OrderCore) -> Result<OrderCore, OrderError> {
+        let result: Result<OrderCore, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+            let order = Self::insert_order_with(&mut tx, order).await?;
+            tx.commit().await?;
+
+            Ok(order)
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            OrderError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_order_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        order: OrderCore,
+    ) -> Result<OrderCore, OrderError> {
+        Self::insert_order_with(tx.connection(), order)
+            .await
+            .map_err(|err| {
+                log::error!("{:?}", err);
+                OrderError::Unexpected
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_order_by_id(&self, order_id: i32) -> Result<OrderCore, OrderError> {
+        let result = sqlx::query_as::<_, OrderModel>(&DB::sql("SELECT * FROM orders WHERE id=?"))
+            .bind(order_id)
+            .fetch_one(&self.conn)
+            .await;
+
+        match result {
+            Ok(row) => self
+                .load_orders(vec![row])
+                .await
+                .map(|mut orders| orders.remove(0)),
+            Err(err) => match &err {
+                sqlx::Error::RowNotFound => Err(OrderError::NotFound),
+                _ => {
+                    log::error!("{:?}", err);
+                    Err(OrderError::Unexpected)
+                }
+            },
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_orders_by_user(&self, user_id: i32) -> Result<Vec<OrderCore>, OrderError> {
+        let result = sqlx::query_as::<_, OrderModel>(&DB::sql(
+            "SELECT * FROM orders WHERE `user`=? ORDER BY created_at DESC",
+        ))
+        .bind(user_id)
+        .fetch_all(&self.conn)
+        .await;
+
+        match result {
+            Ok(rows) => self.load_orders(rows).await,
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(OrderError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_orders(&self, status: Option<OrderStatus>) -> Result<Vec<OrderCore>, OrderError> {
+        let result = match status {
+            Some(status) => {
+                sqlx::query_as::<_, OrderModel>(&DB::sql(
+                    "SELECT * FROM orders WHERE status=? ORDER BY created_at",
+                ))
+                .bind(status.code())
+                .fetch_all(&self.conn)
+                .await
+            }
+            None => {
+                sqlx::query_as::<_, OrderModel>(&DB::sql(
+                    "SELECT * FROM orders ORDER BY created_at",
+                ))
+                .fetch_all(&self.conn)
+                .await
+            }
+        };
+
+        match result {
+            Ok(rows) => self.load_orders(rows).await,
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(OrderError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_open_orders(&self) -> Result<Vec<OrderCore>, OrderError> {
+        let result = sqlx::query_as::<_, OrderModel>(&DB::sql(
+            "SELECT * FROM orders WHERE status IN (?,?,?,?,?) \
+            ORDER BY COALESCE(scheduled_for, created_at)",
+        ))
+        .bind(OrderStatus::Placed.code())
+        .bind(OrderStatus::Accepted.code())
+        .bind(OrderStatus::Preparing.code())
+        .bind(OrderStatus::Ready.code())
+        .bind(OrderStatus::OutForDelivery.code())
+        .fetch_all(&self.conn)
+        .await;
+
+        match result {
+            Ok(rows) => self.load_orders(rows).await,
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(OrderError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_order_status(
+        &self,
+        order_id: i32,
+        from: OrderStatus,
+        to: OrderStatus,
+        changed_by: i32,
+        loyalty_entries: Vec<LoyaltyEntryCore>,
+        stock_adjustments: Vec<StockAdjustmentCore>,
+    ) -> Result<(), OrderError> {
+        let result: Result<Result<(), OrderError>, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+            let result = Self::update_order_status_with(
+                &mut tx,
+                order_id,
+                from,
+                to,
+                changed_by,
+                loyalty_entries,
+                stock_adjustments,
+            )
+            .await?;
+            if result.is_ok() {
+                tx.commit().await?;
+            }
+            Ok(result)
+        }
+        .await;
+
+        match result {
+            Ok(result) => result,
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(OrderError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_order_status_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        order_id: i32,
+        from: OrderStatus,
+        to: OrderStatus,
+        changed_by: i32,
+        loyalty_entries: Vec<LoyaltyEntryCore>,
+        stock_adjustments: Vec<StockAdjustmentCore>,
+    ) -> Result<(), OrderError> {
+        let result = Self::update_order_status_with(
+            tx.connection(),
+            order_id,
+            from,
+            to,
+            changed_by,
+            loyalty_entries,
+            stock_adjustments,
+        )
+        .await;
+
+        match result {
+            Ok(result) => result,
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(OrderError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn claim_order(&self, order_id: i32, employee_id: i32) -> Result<(), OrderError> {
+        let result = sqlx::query(&DB::sql(
+            "UPDATE orders SET claimed_by=? WHERE id=? AND (claimed_by IS NULL OR claimed_by=?)",
+        ))
+        .bind(employee_id)
+        .bind(order_id)
+        .bind(employee_id)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(OrderError::AlreadyClaimed),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(OrderError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn assign_courier(&self, order_id: i32, courier_id: i32) -> Result<(), OrderError> {
+        let result: Result<bool, sqlx::Error> = async {
+            let role =
+                sqlx::query_as::<_, (String,)>(&DB::sql("SELECT role FROM `user` WHERE id=?"))
+                    .bind(courier_id)
+                    .fetch_optional(&self.conn)
+                    .await?;
+            if !role.is_some_and(|(role,)| UserRole::from_code(&role).is_staff()) {
+                return Ok(false);
+            }
+
+            sqlx::query(&DB::sql("UPDATE orders SET courier_id=? WHERE id=?"))
+                .bind(courier_id)
+                .bind(order_id)
+                .execute(&self.conn)
+                .await?;
+
+            Ok(true)
+        }
+        .await;
+
+        match result {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(OrderError::InvalidCourier),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(OrderError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn release_due_orders(&self, now: DateTime<Utc>) -> Result<Vec<OrderCore>, OrderError> {
+        let result: Result<Vec<OrderModel>, sqlx::Error> = async {
+            let due_ids = sqlx::query_as::<_, (i32,)>(&DB::sql(
+                "SELECT id FROM orders WHERE release_at <= ? AND status IN (?,?,?,?,?) \
+                ORDER BY scheduled_for",
+            ))
+            .bind(now)
+            .bind(OrderStatus::Placed.code())
+            .bind(OrderStatus::Accepted.code())
+            .bind(OrderStatus::Preparing.code())
+            .bind(OrderStatus::Ready.code())
+            .bind(OrderStatus::OutForDelivery.code())
+            .fetch_all(&self.conn)
+            .await?;
+
+            let mut released = vec![];
+            for (order_id,) in due_ids {
+                // Solo se retorna el pedido si este proceso fue el que lo liberó.
+                let updated = sqlx::query(&DB::sql(
+                    "UPDATE orders SET release_at=NULL WHERE id=? AND release_at IS NOT NULL",
+                ))
+                .bind(order_id)
+                .execute(&self.conn)
+                .await?
+                .rows_affected();
+
+                if updated == 1 {
+                    released.push(
+                        sqlx::query_as::<_, OrderModel>(&DB::sql(
+                            "SELECT * FROM orders WHERE id=?",
+                        ))
+                        .bind(order_id)
+                        .fetch_one(&self.conn)
+                        .await?,
+                    );
+                }
+            }
+
+            Ok(released)
+        }
+        .await;
+
+        match result {
+            Ok(rows) => self.load_orders(rows).await,
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(OrderError::Unexpected)
+            }
+        }
+    }
+}
+
+impl<DB: Backend> OrderRepository<DB> {
+    pub fn new(conn: DbPool<DB>) -> Self {
+        OrderRepository { conn }
+    }
+}
+
+impl<DB: Backend> Clone for OrderRepository<DB> {
+    fn clone(&self) -> Self {
+        OrderRepository::new(self.conn.clone())
+    }
+}
+
+impl<DB: Backend> OrderRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+    for<'q> DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<i32>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<String>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<DateTime<Utc>>: Encode<'q, DB> + Type<DB>,
+{
+    /// Cambia el estado del pedido con sus efectos usando la conexión recibida. Los errores de
+    /// negocio se retornan dentro de `Ok` para distinguirlos de los de la base de datos; en ambos
+    /// casos quien abrió la transacción debe descartarla sin confirmar.
+    async fn update_order_status_with(
+        conn: &mut DB::Connection,
+        order_id: i32,
+        from: OrderStatus,
+        to: OrderStatus,
+        changed_by: i32,
+        loyalty_entries: Vec<LoyaltyEntryCore>,
+        stock_adjustments: Vec<StockAdjustmentCore>,
+    ) -> Result<Result<(), OrderError>, sqlx::Error> {
+        let now = chrono::Utc::now();
+
+        let updated = sqlx::query(&DB::sql(
+            "UPDATE orders SET status=?, updated_at=? WHERE id=? AND status=?",
+        ))
+        .bind(to.code())
+        .bind(now)
+        .bind(order_id)
+        .bind(from.code())
+        .execute(&mut *conn)
+        .await?
+        .rows_affected();
+
+        if updated == 0 {
+            return Ok(Err(OrderError::InvalidTransition { from, to }));
+        }
+
+        for adjustment in &stock_adjustments {
+            if !InventoryRepository::<DB>::apply_adjustment_with(&mut *conn, adjustment).await? {
+                return Ok(Err(OrderError::OutOfStock));
+            }
+        }
+
+        Self::insert_status_change_with(
+            &mut *conn,
+            order_id,
+            &OrderStatusChangeCore {
+                status: to,
+                changed_by,
+                changed_at: now,
+            },
+        )
+        .await?;
+
+        for entry in loyalty_entries {
+            LoyaltyRepository::<DB>::insert_entry_with(&mut *conn, entry).await?;
+        }
+
+        Ok(Ok(()))
+    }
+
+    /// Inserta el pedido con sus líneas usando la conexión recibida, de modo que pueda formar
+    /// parte de una transacción más grande.
+    pub(crate) async fn insert_order_with(
+        conn: &mut DB::Connection,
+        mut order: OrderCore,
+    ) -> Result<OrderCore, sqlx::Error> {
+        let order_model = OrderModel::from_order_core(&order);
+
+        let query = DB::insert_sql(
+            "INSERT INTO orders (`user`, status, currency, subtotal, discount, tax, \
+            service_charge, tip, delivery_fee, total, points_earned, points_redeemed, \
+            payment_method, claimed_by, store_id, scheduled_for, release_at, fulfilment_type, \
+            delivery_address_id, delivery_address, courier_id, created_at, updated_at) \
+            VALUES (?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?,?)",
+        );
+        let insert = sqlx::query(&query)
+            .bind(order_model.user)
+            .bind(&order_model.status)
+            .bind(&order_model.currency)
+            .bind(order_model.subtotal)
+            .bind(order_model.discount)
+            .bind(order_model.tax)
+            .bind(order_model.service_charge)
+            .bind(order_model.tip)
+            .bind(order_model.delivery_fee)
+            .bind(order_model.total)
+            .bind(order_model.points_earned)
+            .bind(order_model.points_redeemed)
+            .bind(&order_model.payment_method)
+            .bind(order_model.claimed_by)
+            .bind(order_model.store_id)
+            .bind(order_model.scheduled_for)
+            .bind(order_model.release_at)
+            .bind(&order_model.fulfilment_type)
+            .bind(order_model.delivery_address_id)
+            .bind(&order_model.delivery_address)
+            .bind(order_model.courier_id)
+            .bind(order_model.created_at)
+            .bind(order_model.updated_at);
+        let order_id = DB::insert_id(insert, &mut *conn).await?;
+        order.id = Some(order_id);
+
+        for change in &order.history {
+            Self::insert_status_change_with(&mut *conn, order_id, change).await?;
+        }
+
+        for item in order.items.iter_mut() {
+            let item_model = OrderItemModel::from_order_item_core(order_id, item);
+
+            let query = DB::insert_sql(
+                "INSERT INTO order_item (order_id, product, product_name, quantity, unit_price, \
+                tax_rate) VALUES (?,?,?,?,?,?)",
+            );
+            let insert = sqlx::query(&query)
+                .bind(item_model.order_id)
+                .bind(item_model.product)
+                .bind(&item_model.product_name)
+                .bind(item_model.quantity)
+                .bind(item_model.unit_price)
+                .bind(item_model.tax_rate);
+            let item_id = DB::insert_id(insert, &mut *conn).await?;
+            item.id = Some(item_id);
+
+            for option in &item.options {
+                let option_model =
+                    OrderItemOptionModel::from_order_item_option_core(item_id, option);
+
+                sqlx::query(&DB::sql("INSERT INTO order_item_option (order_item, modifier_option, name, price_delta) \
+                    VALUES (?,?,?,?)"))
+                .bind(option_model.order_item)
+                .bind(option_model.modifier_option)
+                .bind(&option_model.name)
+                .bind(option_model.price_delta)
+                .execute(&mut *conn)
+                .await?;
+            }
+        }
+
+        for discount in &order.discounts {
+            let redemption_model = PromotionRedemptionModel::from_applied_discount_core(
+                order.user_id,
+                order_id,
+                discount,
+                order.created_at,
+            );
+
+            sqlx::query(&DB::sql("INSERT INTO promotion_redemption (promotion, `user`, order_id, name, code, amount, \
+                created_at) VALUES (?,?,?,?,?,?,?)"))
+            .bind(redemption_model.promotion)
+            .bind(redemption_model.user)
+            .bind(redemption_model.order_id)
+            .bind(&redemption_model.name)
+            .bind(&redemption_model.code)
+            .bind(redemption_model.amount)
+            .bind(redemption_model.created_at)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        if order.points_redeemed > 0 {
+            LoyaltyRepository::<DB>::insert_entry_with(
+                &mut *conn,
+                LoyaltyEntryCore::redeemed(&order),
+            )
+            .await?;
+        }
+
+        Ok(order)
+    }
+
+    async fn insert_status_change_with(
+        conn: &mut DB::Connection,
+        order_id: i32,
+        change: &OrderStatusChangeCore,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query(&DB::sql(
+            "INSERT INTO order_status_history (order_id, status, changed_by, changed_at) \
+            VALUES (?,?,?,?)",
+        ))
+        .bind(order_id)
+        .bind(change.status.code())
+        .bind(change.changed_by)
+        .bind(change.changed_at)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+}
+
+impl<DB: Backend> OrderRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    OrderItemModel: for<'r> FromRow<'r, DB::Row>,
+    OrderItemOptionModel: for<'r> FromRow<'r, DB::Row>,
+    OrderStatusChangeModel: for<'r> FromRow<'r, DB::Row>,
+    PromotionRedemptionModel: for<'r> FromRow<'r, DB::Row>,
+    RefundModel: for<'r> FromRow<'r, DB::Row>,
+    RefundItemModel: for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+{
+    async fn load_orders(&self, rows: Vec<OrderModel>) -> Result<Vec<OrderCore>, OrderError> {
+        let mut orders = vec![];
+
+        for row in rows {
+            let order_id = row.id.unwrap_or_default();
+
+            let items = sqlx::query_as::<_, OrderItemModel>(&DB::sql(
+                "SELECT * FROM order_item WHERE order_id=? ORDER BY id",
+            ))
+            .bind(order_id)
+            .fetch_all(&self.conn)
+            .await;
+            let options = sqlx::query_as::<_, OrderItemOptionModel>(&DB::sql(
+                "SELECT o.* FROM order_item_option o \
+                JOIN order_item i ON i.id = o.order_item WHERE i.order_id=?",
+            ))
+            .bind(order_id)
+            .fetch_all(&self.conn)
+            .await;
+            let history = sqlx::query_as::<_, OrderStatusChangeModel>(&DB::sql(
+                "SELECT order_id, status, changed_by, changed_at FROM order_status_history \
+                WHERE order_id=? ORDER BY id",
+            ))
+            .bind(order_id)
+            .fetch_all(&self.conn)
+            .await;
+            let refunds = self.load_refunds(order_id).await;
+            let discounts = sqlx::query_as::<_, PromotionRedemptionModel>(&DB::sql(
+                "SELECT promotion, `user`, order_id, name, code, amount, created_at \
+                FROM promotion_redemption WHERE order_id=? ORDER BY id",
+            ))
+            .bind(order_id)
+            .fetch_all(&self.conn)
+            .await;
+
+            match (items, options, history, refunds, discounts) {
+                (Ok(items), Ok(options), Ok(history), Ok(refunds), Ok(discounts)) => {
+                    let items = items
+                        .into_iter()
+                        .map(|item| {
+                            let item_options = options
+                                .iter()
+                                .filter(|option| Some(option.order_item) == item.id)
+                                .map(|option| {
+                                    OrderItemOptionCore::from_order_item_option_model(
+                                        option.clone(),
+                                    )
+                                })
+                                .collect();
+
+                            OrderItemCore::from_order_item_model(item, item_options)
+                        })
+                        .collect();
+
+                    let history = history
+                        .into_iter()
+                        .map(OrderStatusChangeCore::from_order_status_change_model)
+                        .collect();
+
+                    let discounts = discounts
+                        .into_iter()
+                        .map(AppliedDiscountCore::from_promotion_redemption_model)
+                        .collect();
+
+                    orders.push(OrderCore::from_order_model(
+                        row, items, history, refunds, discounts,
+                    ));
+                }
+                (Err(err), _, _, _, _)
+                | (_, Err(err), _, _, _)
+                | (_, _, Err(err), _, _)
+                | (_, _, _, Err(err), _)
+                | (_, _, _, _, Err(err)) => {
+                    log::error!("{:?}", err);
+                    return Err(OrderError::Unexpected);
+                }
+            }
+        }
+
+        Ok(orders)
+    }
+
+    async fn load_refunds(&self, order_id: i32) -> Result<Vec<RefundCore>, sqlx::Error> {
+        let refunds = sqlx::query_as::<_, RefundModel>(&DB::sql(
+            "SELECT * FROM refund WHERE order_id=? ORDER BY id",
+        ))
+        .bind(order_id)
+        .fetch_all(&self.conn)
+        .await?;
+        let refund_items = sqlx::query_as::<_, RefundItemModel>(&DB::sql(
+            "SELECT ri.* FROM refund_item ri JOIN refund r ON r.id = ri.refund WHERE r.order_id=?",
+        ))
+        .bind(order_id)
+        .fetch_all(&self.conn)
+        .await?;
+
+        Ok(refunds
+            .into_iter()
+            .map(|refund| {
+                let item_ids = refund_items
+                    .iter()
+                    .filter(|item| Some(item.refund) == refund.id)
+                    .map(|item| item.order_item)
+                    .collect();
+
+                RefundCore::from_refund_model(refund, item_ids)
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,575 @@
+use crate::core::{
+    entities::{
+        payment::{Payment as PaymentCore, PaymentAttempt as PaymentAttemptCore, PaymentStatus},
+        refund::{Refund as RefundCore, RefundStatus},
+    },
+    errors::payment_errors::PaymentError,
+    ports::payment_port::IPaymentRepository,
+};
+use crate::infrastructure::data::backend::{Backend, RowsAffected};
+use crate::infrastructure::data::models::payment::{
+    Payment as PaymentModel, PaymentAttempt as PaymentAttemptModel, Refund as RefundModel,
+    RefundItem as RefundItemModel,
+};
+use crate::infrastructure::data::pool::DbPool;
+use crate::infrastructure::data::unit_of_work::SqlTransaction;
+use chrono::{DateTime, Utc};
+use sqlx::{database::HasArguments, Encode, Executor, FromRow, IntoArguments, MySql, Type};
+
+pub struct PaymentRepository<DB: Backend = MySql> {
+    pub conn: DbPool<DB>,
+}
+
+#[async_trait::async_trait]
+impl<DB: Backend> IPaymentRepository for PaymentRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    PaymentModel: for<'r> FromRow<'r, DB::Row>,
+    PaymentAttemptModel: for<'r> FromRow<'r, DB::Row>,
+    RefundModel: for<'r> FromRow<'r, DB::Row>,
+    RefundItemModel: for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB> + Type<DB>,
+    for<'q> bool: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+    for<'q> DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<i32>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<String>: Encode<'q, DB> + Type<DB>,
+{
+    type Transaction = SqlTransaction<DB>;
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_payment(&self, payment: PaymentCore) -> Result<PaymentCore, PaymentError> {
+        let result: Result<PaymentCore, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+            let payment = Self::insert_payment_with(&mut tx, payment).await?;
+
+            tx.commit().await?;
+            Ok(payment)
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            PaymentError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_payments_by_order(&self, order_id: i32) -> Result<Vec<PaymentCore>, PaymentError> {
+        let result: Result<Vec<PaymentCore>, sqlx::Error> = async {
+            let mut conn = self.conn.acquire().await?;
+
+            Self::payments_with(&mut conn, order_id).await
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            PaymentError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn lock_order_payments_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        order_id: i32,
+    ) -> Result<Vec<PaymentCore>, PaymentError> {
+        let result: Result<Vec<PaymentCore>, sqlx::Error> = async {
+            let conn = tx.connection();
+            let query = DB::for_update("SELECT id FROM orders WHERE id=?");
+            sqlx::query(&DB::sql(&query))
+                .bind(order_id)
+                .fetch_one(&mut *conn)
+                .await?;
+
+            Self::payments_with(conn, order_id).await
+        }
+        .await;
+
+        match result {
+            Ok(payments) => Ok(payments),
+            Err(sqlx::Error::RowNotFound) => Err(PaymentError::OrderNotFound),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(PaymentError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_payment_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        payment: PaymentCore,
+    ) -> Result<PaymentCore, PaymentError> {
+        Self::insert_payment_with(tx.connection(), payment)
+            .await
+            .map_err(|err| {
+                log::error!("{:?}", err);
+                PaymentError::Unexpected
+            })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn finish_authorization(
+        &self,
+        payment_id: i32,
+        reference: Option<String>,
+        status: PaymentStatus,
+        attempt: PaymentAttemptCore,
+    ) -> Result<(), PaymentError> {
+        let result: Result<u64, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            let updated = sqlx::query(&DB::sql(
+                "UPDATE payment SET status=?, reference=?, updated_at=? WHERE id=?",
+            ))
+            .bind(status.code())
+            .bind(reference)
+            .bind(attempt.created_at)
+            .bind(payment_id)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+            if updated > 0 {
+                Self::insert_attempt_with(&mut tx, payment_id, &attempt).await?;
+            }
+
+            tx.commit().await?;
+            Ok(updated)
+        }
+        .await;
+
+        match result {
+            Ok(0) => Err(PaymentError::PaymentNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(PaymentError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn record_attempt(
+        &self,
+        payment_id: i32,
+        status: Option<PaymentStatus>,
+        attempt: PaymentAttemptCore,
+    ) -> Result<(), PaymentError> {
+        let result: Result<u64, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            let updated = match status {
+                Some(status) => sqlx::query(&DB::sql(
+                    "UPDATE payment SET status=?, updated_at=? WHERE id=?",
+                ))
+                .bind(status.code())
+                .bind(attempt.created_at)
+                .bind(payment_id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected(),
+                None => 1,
+            };
+
+            if updated > 0 {
+                Self::insert_attempt_with(&mut tx, payment_id, &attempt).await?;
+            }
+
+            tx.commit().await?;
+            Ok(updated)
+        }
+        .await;
+
+        match result {
+            Ok(0) => Err(PaymentError::PaymentNotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(PaymentError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_refund_by_key(
+        &self,
+        order_id: i32,
+        idempotency_key: &str,
+    ) -> Result<Option<RefundCore>, PaymentError> {
+        let result: Result<Option<RefundCore>, sqlx::Error> = async {
+            let refund = sqlx::query_as::<_, RefundModel>(&DB::sql(
+                "SELECT * FROM refund WHERE order_id=? AND idempotency_key=?",
+            ))
+            .bind(order_id)
+            .bind(idempotency_key)
+            .fetch_optional(&self.conn)
+            .await?;
+
+            match refund {
+                Some(refund) => {
+                    let items = sqlx::query_as::<_, RefundItemModel>(&DB::sql(
+                        "SELECT * FROM refund_item WHERE refund=?",
+                    ))
+                    .bind(refund.id)
+                    .fetch_all(&self.conn)
+                    .await?;
+                    let item_ids = items.into_iter().map(|item| item.order_item).collect();
+
+                    Ok(Some(RefundCore::from_refund_model(refund, item_ids)))
+                }
+                None => Ok(None),
+            }
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            PaymentError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn lock_payment_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        payment_id: i32,
+    ) -> Result<PaymentCore, PaymentError> {
+        let result: Result<PaymentCore, sqlx::Error> = async {
+            let conn = tx.connection();
+            let query = DB::for_update("SELECT * FROM payment WHERE id=?");
+            let payment = sqlx::query_as::<_, PaymentModel>(&DB::sql(&query))
+                .bind(payment_id)
+                .fetch_one(&mut *conn)
+                .await?;
+            let attempts = sqlx::query_as::<_, PaymentAttemptModel>(&DB::sql(
+                "SELECT payment, operation, amount, succeeded, failure_reason, created_at \
+                FROM payment_attempt WHERE payment=? ORDER BY id",
+            ))
+            .bind(payment_id)
+            .fetch_all(&mut *conn)
+            .await?;
+
+            Ok(PaymentCore::from_payment_model(
+                payment,
+                attempts
+                    .into_iter()
+                    .map(PaymentAttemptCore::from_payment_attempt_model)
+                    .collect(),
+            ))
+        }
+        .await;
+
+        match result {
+            Ok(payment) => Ok(payment),
+            Err(sqlx::Error::RowNotFound) => Err(PaymentError::PaymentNotFound),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(PaymentError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_refunds_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        order_id: i32,
+    ) -> Result<Vec<RefundCore>, PaymentError> {
+        let result: Result<Vec<RefundCore>, sqlx::Error> = async {
+            let conn = tx.connection();
+            let refunds = sqlx::query_as::<_, RefundModel>(&DB::sql(
+                "SELECT * FROM refund WHERE order_id=? ORDER BY id",
+            ))
+            .bind(order_id)
+            .fetch_all(&mut *conn)
+            .await?;
+            let refund_items = sqlx::query_as::<_, RefundItemModel>(&DB::sql(
+                "SELECT ri.* FROM refund_item ri JOIN refund r ON r.id = ri.refund \
+                WHERE r.order_id=?",
+            ))
+            .bind(order_id)
+            .fetch_all(&mut *conn)
+            .await?;
+
+            Ok(refunds
+                .into_iter()
+                .map(|refund| {
+                    let item_ids = refund_items
+                        .iter()
+                        .filter(|item| Some(item.refund) == refund.id)
+                        .map(|item| item.order_item)
+                        .collect();
+
+                    RefundCore::from_refund_model(refund, item_ids)
+                })
+                .collect())
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            PaymentError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn reserve_refund_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        mut refund: RefundCore,
+    ) -> Result<Option<RefundCore>, PaymentError> {
+        let result: Result<RefundCore, sqlx::Error> = async {
+            let conn = tx.connection();
+            refund.status = RefundStatus::Pending;
+            let refund_model = RefundModel::from_refund_core(&refund);
+
+            let query = DB::insert_sql(
+                "INSERT INTO refund (order_id, payment, amount, reason, status, note, \
+                idempotency_key, refunded_by, created_at) VALUES (?,?,?,?,?,?,?,?,?)",
+            );
+            let insert = sqlx::query(&query)
+                .bind(refund_model.order_id)
+                .bind(refund_model.payment)
+                .bind(refund_model.amount)
+                .bind(&refund_model.reason)
+                .bind(&refund_model.status)
+                .bind(&refund_model.note)
+                .bind(&refund_model.idempotency_key)
+                .bind(refund_model.refunded_by)
+                .bind(refund_model.created_at);
+            let refund_id = DB::insert_id(insert, &mut *conn).await?;
+            refund.id = Some(refund_id);
+
+            for item_id in &refund.item_ids {
+                sqlx::query(&DB::sql(
+                    "INSERT INTO refund_item (refund, order_item, active_order_item) \
+                    VALUES (?,?,?)",
+                ))
+                .bind(refund_id)
+                .bind(item_id)
+                .bind(item_id)
+                .execute(&mut *conn)
+                .await?;
+            }
+
+            Ok(refund)
+        }
+        .await;
+
+        // Las claves únicas (order_id, idempotency_key) y `active_order_item` son las que impiden
+        // reservar dos veces la misma solicitud o la misma línea.
+        match result {
+            Ok(refund) => Ok(Some(refund)),
+            Err(sqlx::Error::Database(err)) if err.is_unique_violation() => Ok(None),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(PaymentError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn complete_refund(
+        &self,
+        refund: RefundCore,
+        status: Option<PaymentStatus>,
+        attempt: PaymentAttemptCore,
+    ) -> Result<RefundCore, PaymentError> {
+        let result: Result<RefundCore, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+            let refund =
+                Self::finish_refund_with(&mut tx, refund, RefundStatus::Completed, status, attempt)
+                    .await?;
+            tx.commit().await?;
+            Ok(refund)
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            PaymentError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn fail_refund(
+        &self,
+        refund: RefundCore,
+        attempt: PaymentAttemptCore,
+    ) -> Result<RefundCore, PaymentError> {
+        let result: Result<RefundCore, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+            let refund =
+                Self::finish_refund_with(&mut tx, refund, RefundStatus::Failed, None, attempt)
+                    .await?;
+            tx.commit().await?;
+            Ok(refund)
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            PaymentError::Unexpected
+        })
+    }
+}
+
+impl<DB: Backend> PaymentRepository<DB> {
+    pub fn new(conn: DbPool<DB>) -> Self {
+        PaymentRepository { conn }
+    }
+}
+
+impl<DB: Backend> Clone for PaymentRepository<DB> {
+    fn clone(&self) -> Self {
+        PaymentRepository::new(self.conn.clone())
+    }
+}
+
+impl<DB: Backend> PaymentRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    PaymentModel: for<'r> FromRow<'r, DB::Row>,
+    PaymentAttemptModel: for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB> + Type<DB>,
+    for<'q> bool: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+    for<'q> DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<String>: Encode<'q, DB> + Type<DB>,
+{
+    /// Cierra la devolución reservada con `refund_status` y registra el intento y, si se indica,
+    /// el nuevo estado del pago.
+    async fn finish_refund_with(
+        conn: &mut DB::Connection,
+        mut refund: RefundCore,
+        refund_status: RefundStatus,
+        status: Option<PaymentStatus>,
+        attempt: PaymentAttemptCore,
+    ) -> Result<RefundCore, sqlx::Error> {
+        sqlx::query(&DB::sql("UPDATE refund SET status=? WHERE id=?"))
+            .bind(refund_status.code())
+            .bind(refund.id.unwrap_or_default())
+            .execute(&mut *conn)
+            .await?;
+        refund.status = refund_status;
+
+        // Las líneas de una devolución fallida se pueden volver a devolver.
+        if refund_status == RefundStatus::Failed {
+            sqlx::query(&DB::sql(
+                "UPDATE refund_item SET active_order_item=NULL WHERE refund=?",
+            ))
+            .bind(refund.id.unwrap_or_default())
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        if let Some(status) = status {
+            sqlx::query(&DB::sql(
+                "UPDATE payment SET status=?, updated_at=? WHERE id=?",
+            ))
+            .bind(status.code())
+            .bind(attempt.created_at)
+            .bind(refund.payment_id)
+            .execute(&mut *conn)
+            .await?;
+        }
+        Self::insert_attempt_with(&mut *conn, refund.payment_id, &attempt).await?;
+
+        Ok(refund)
+    }
+
+    async fn insert_payment_with(
+        conn: &mut DB::Connection,
+        mut payment: PaymentCore,
+    ) -> Result<PaymentCore, sqlx::Error> {
+        let payment_model = PaymentModel::from_payment_core(&payment);
+
+        let query = DB::insert_sql(
+            "INSERT INTO payment (order_id, method, status, amount, reference, \
+            card_last_four, created_at, updated_at) VALUES (?,?,?,?,?,?,?,?)",
+        );
+        let insert = sqlx::query(&query)
+            .bind(payment_model.order_id)
+            .bind(&payment_model.method)
+            .bind(&payment_model.status)
+            .bind(payment_model.amount)
+            .bind(&payment_model.reference)
+            .bind(&payment_model.card_last_four)
+            .bind(payment_model.created_at)
+            .bind(payment_model.updated_at);
+        let payment_id = DB::insert_id(insert, &mut *conn).await?;
+        payment.id = Some(payment_id);
+
+        for attempt in &payment.attempts {
+            Self::insert_attempt_with(&mut *conn, payment_id, attempt).await?;
+        }
+
+        Ok(payment)
+    }
+
+    async fn payments_with(
+        conn: &mut DB::Connection,
+        order_id: i32,
+    ) -> Result<Vec<PaymentCore>, sqlx::Error> {
+        let payments = sqlx::query_as::<_, PaymentModel>(&DB::sql(
+            "SELECT * FROM payment WHERE order_id=? ORDER BY id",
+        ))
+        .bind(order_id)
+        .fetch_all(&mut *conn)
+        .await?;
+        let attempts = sqlx::query_as::<_, PaymentAttemptModel>(&DB::sql(
+            "SELECT a.payment, a.operation, a.amount, a.succeeded, a.failure_reason, \
+            a.created_at FROM payment_attempt a JOIN payment p ON p.id = a.payment \
+            WHERE p.order_id=? ORDER BY a.id",
+        ))
+        .bind(order_id)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        Ok(payments
+            .into_iter()
+            .map(|payment| {
+                let payment_attempts = attempts
+                    .iter()
+                    .filter(|attempt| Some(attempt.payment) == payment.id)
+                    .map(|attempt| PaymentAttemptCore::from_payment_attempt_model(attempt.clone()))
+                    .collect();
+
+                PaymentCore::from_payment_model(payment, payment_attempts)
+            })
+            .collect())
+    }
+
+    async fn insert_attempt_with(
+        conn: &mut DB::Connection,
+        payment_id: i32,
+        attempt: &PaymentAttemptCore,
+    ) -> Result<(), sqlx::Error> {
+        let attempt_model = PaymentAttemptModel::from_payment_attempt_core(payment_id, attempt);
+
+        sqlx::query(&DB::sql(
+            "INSERT INTO payment_attempt (payment, operation, amount, succeeded, \
+            failure_reason, created_at) VALUES (?,?,?,?,?,?)",
+        ))
+        .bind(attempt_model.payment)
+        .bind(&attempt_model.operation)
+        .bind(attempt_model.amount)
+        .bind(attempt_model.succeeded)
+        .bind(&attempt_model.failure_reason)
+        .bind(attempt_model.created_at)
+        .execute(&mut *conn)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,304 @@
+use crate::core::{
+    entities::promotion::{Promotion as PromotionCore, PromotionUsage},
+    errors::promotion_errors::PromotionError,
+    ports::promotion_port::IPromotionRepository,
+};
+use crate::infrastructure::data::backend::{Backend, RowsAffected};
+use crate::infrastructure::data::models::promotion::Promotion as PromotionModel;
+use crate::infrastructure::data::pool::DbPool;
+use crate::infrastructure::data::unit_of_work::SqlTransaction;
+use chrono::{DateTime, NaiveTime, Utc};
+use sqlx::{database::HasArguments, Encode, Executor, FromRow, IntoArguments, MySql, Type};
+
+pub struct PromotionRepository<DB: Backend = MySql> {
+    pub conn: DbPool<DB>,
+}
+
+#[async_trait::async_trait]
+impl<DB: Backend> IPromotionRepository for PromotionRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    PromotionModel: for<'r> FromRow<'r, DB::Row>,
+    (i64, i64): for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> i64: Encode<'q, DB> + Type<DB>,
+    for<'q> bool: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+    for<'q> NaiveTime: Encode<'q, DB> + Type<DB>,
+    for<'q> DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<i32>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<i64>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<String>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<NaiveTime>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<DateTime<Utc>>: Encode<'q, DB> + Type<DB>,
+{
+    type Transaction = SqlTransaction<DB>;
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_promotions(&self) -> Result<Vec<PromotionCore>, PromotionError> {
+        let result =
+            sqlx::query_as::<_, PromotionModel>(&DB::sql("SELECT * FROM promotion ORDER BY id"))
+                .fetch_all(&self.conn)
+                .await;
+
+        match result {
+            Ok(rows) => Ok(PromotionCore::from_promotion_model_vec(rows)),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(PromotionError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_promotion_by_id(
+        &self,
+        promotion_id: i32,
+    ) -> Result<PromotionCore, PromotionError> {
+        let result =
+            sqlx::query_as::<_, PromotionModel>(&DB::sql("SELECT * FROM promotion WHERE id=?"))
+                .bind(promotion_id)
+                .fetch_one(&self.conn)
+                .await;
+
+        Self::map_promotion(result)
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_promotion_by_code(&self, code: &str) -> Result<PromotionCore, PromotionError> {
+        let result = sqlx::query_as::<_, PromotionModel>(&DB::sql(
+            "SELECT * FROM promotion WHERE lower(code)=lower(?)",
+        ))
+        .bind(code)
+        .fetch_one(&self.conn)
+        .await;
+
+        Self::map_promotion(result)
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_automatic_promotions(&self) -> Result<Vec<PromotionCore>, PromotionError> {
+        let result = sqlx::query_as::<_, PromotionModel>(&DB::sql(
+            "SELECT * FROM promotion WHERE code IS NULL AND active=true ORDER BY id",
+        ))
+        .fetch_all(&self.conn)
+        .await;
+
+        match result {
+            Ok(rows) => Ok(PromotionCore::from_promotion_model_vec(rows)),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(PromotionError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_promotion(
+        &self,
+        mut promotion: PromotionCore,
+    ) -> Result<PromotionCore, PromotionError> {
+        let promotion_model = PromotionModel::from_promotion_core(&promotion);
+
+        let query = DB::insert_sql(
+            "INSERT INTO promotion (name, code, kind, rate, amount, category, starts_at, ends_at, \
+            daily_starts, daily_ends, min_order_amount, max_uses, max_uses_per_user, stackable, \
+            active) VALUES (?,?,?,?,?,?,?,?,?,?,?,?,?,?,?)",
+        );
+        let insert = sqlx::query(&query)
+            .bind(&promotion_model.name)
+            .bind(&promotion_model.code)
+            .bind(&promotion_model.kind)
+            .bind(promotion_model.rate)
+            .bind(promotion_model.amount)
+            .bind(promotion_model.category)
+            .bind(promotion_model.starts_at)
+            .bind(promotion_model.ends_at)
+            .bind(promotion_model.daily_starts)
+            .bind(promotion_model.daily_ends)
+            .bind(promotion_model.min_order_amount)
+            .bind(promotion_model.max_uses)
+            .bind(promotion_model.max_uses_per_user)
+            .bind(promotion_model.stackable)
+            .bind(promotion_model.active);
+        let result = DB::insert_id(insert, &self.conn).await;
+
+        match result {
+            Ok(id) => {
+                promotion.id = Some(id);
+
+                Ok(promotion)
+            }
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(PromotionError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_promotion(
+        &self,
+        promotion_id: i32,
+        promotion: PromotionCore,
+    ) -> Result<(), PromotionError> {
+        let promotion_model = PromotionModel::from_promotion_core(&promotion);
+
+        let result = sqlx::query(&DB::sql(
+            "UPDATE promotion SET name=?, code=?, kind=?, rate=?, amount=?, category=?, \
+            starts_at=?, ends_at=?, daily_starts=?, daily_ends=?, min_order_amount=?, \
+            max_uses=?, max_uses_per_user=?, stackable=?, active=? WHERE id=?",
+        ))
+        .bind(&promotion_model.name)
+        .bind(&promotion_model.code)
+        .bind(&promotion_model.kind)
+        .bind(promotion_model.rate)
+        .bind(promotion_model.amount)
+        .bind(promotion_model.category)
+        .bind(promotion_model.starts_at)
+        .bind(promotion_model.ends_at)
+        .bind(promotion_model.daily_starts)
+        .bind(promotion_model.daily_ends)
+        .bind(promotion_model.min_order_amount)
+        .bind(promotion_model.max_uses)
+        .bind(promotion_model.max_uses_per_user)
+        .bind(promotion_model.stackable)
+        .bind(promotion_model.active)
+        .bind(promotion_id)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(PromotionError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn delete_promotion(&self, promotion_id: i32) -> Result<(), PromotionError> {
+        let result: Result<u64, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            sqlx::query(&DB::sql("DELETE FROM cart_coupon WHERE promotion=?"))
+                .bind(promotion_id)
+                .execute(&mut *tx)
+                .await?;
+            let deleted = sqlx::query(&DB::sql("DELETE FROM promotion WHERE id=?"))
+                .bind(promotion_id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+
+            tx.commit().await?;
+            Ok(deleted)
+        }
+        .await;
+
+        match result {
+            Ok(0) => Err(PromotionError::NotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(PromotionError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_usage(
+        &self,
+        promotion_id: i32,
+        user_id: i32,
+    ) -> Result<PromotionUsage, PromotionError> {
+        Self::usage_with(&self.conn, promotion_id, user_id).await
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn lock_promotion_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        promotion_id: i32,
+    ) -> Result<PromotionCore, PromotionError> {
+        let query = DB::for_update("SELECT * FROM promotion WHERE id=?");
+        let result = sqlx::query_as::<_, PromotionModel>(&DB::sql(&query))
+            .bind(promotion_id)
+            .fetch_one(tx.connection())
+            .await;
+
+        Self::map_promotion(result)
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_usage_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        promotion_id: i32,
+        user_id: i32,
+    ) -> Result<PromotionUsage, PromotionError> {
+        Self::usage_with(tx.connection(), promotion_id, user_id).await
+    }
+}
+
+impl<DB: Backend> PromotionRepository<DB> {
+    pub fn new(conn: DbPool<DB>) -> Self {
+        PromotionRepository { conn }
+    }
+}
+
+impl<DB: Backend> Clone for PromotionRepository<DB> {
+    fn clone(&self) -> Self {
+        PromotionRepository::new(self.conn.clone())
+    }
+}
+
+impl<DB: Backend> PromotionRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+{
+    async fn usage_with<'e, E>(
+        executor: E,
+        promotion_id: i32,
+        user_id: i32,
+    ) -> Result<PromotionUsage, PromotionError>
+    where
+        E: 'e + Executor<'e, Database = DB>,
+        (i64, i64): for<'r> FromRow<'r, DB::Row>,
+        for<'q> i32: Encode<'q, DB> + Type<DB>,
+    {
+        let result = sqlx::query_as::<_, (i64, i64)>(&DB::sql(
+            "SELECT COUNT(*), COUNT(CASE WHEN `user`=? THEN 1 END) FROM promotion_redemption \
+            WHERE promotion=?",
+        ))
+        .bind(user_id)
+        .bind(promotion_id)
+        .fetch_one(executor)
+        .await;
+
+        match result {
+            Ok((total, by_user)) => Ok(PromotionUsage { total, by_user }),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(PromotionError::Unexpected)
+            }
+        }
+    }
+
+    fn map_promotion(
+        result: Result<PromotionModel, sqlx::Error>,
+    ) -> Result<PromotionCore, PromotionError> {
+        match result {
+            Ok(row) => Ok(PromotionCore::from_promotion_model(row)),
+            Err(sqlx::Error::RowNotFound) => Err(PromotionError::NotFound),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(PromotionError::Unexpected)
+            }
+        }
+    }
+}
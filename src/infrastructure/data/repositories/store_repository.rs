@@ -0,0 +1,441 @@
+use crate::core::{
+    entities::{
+        order::OrderStatus,
+        store::{
+            OpeningHours as OpeningHoursCore, Store as StoreCore,
+            StoreException as StoreExceptionCore,
+        },
+    },
+    errors::store_errors::StoreError,
+    ports::store_port::IStoreRepository,
+};
+use crate::infrastructure::data::backend::{Backend, RowsAffected};
+use crate::infrastructure::data::models::store::{
+    OpeningHours as OpeningHoursModel, Store as StoreModel, StoreException as StoreExceptionModel,
+};
+use crate::infrastructure::data::pool::DbPool;
+use crate::infrastructure::data::unit_of_work::SqlTransaction;
+use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
+use sqlx::{database::HasArguments, Encode, Executor, FromRow, IntoArguments, MySql, Type};
+
+pub struct StoreRepository<DB: Backend = MySql> {
+    pub conn: DbPool<DB>,
+}
+
+#[async_trait::async_trait]
+impl<DB: Backend> IStoreRepository for StoreRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    StoreModel: for<'r> FromRow<'r, DB::Row>,
+    OpeningHoursModel: for<'r> FromRow<'r, DB::Row>,
+    StoreExceptionModel: for<'r> FromRow<'r, DB::Row>,
+    (DateTime<Utc>, i64): for<'r> FromRow<'r, DB::Row>,
+    (i32, i32): for<'r> FromRow<'r, DB::Row>,
+    (i64,): for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> bool: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+    for<'q> NaiveDate: Encode<'q, DB> + Type<DB>,
+    for<'q> NaiveTime: Encode<'q, DB> + Type<DB>,
+    for<'q> DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<String>: Encode<'q, DB> + Type<DB>,
+    for<'q> Option<NaiveTime>: Encode<'q, DB> + Type<DB>,
+{
+    type Transaction = SqlTransaction<DB>;
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_stores(&self) -> Result<Vec<StoreCore>, StoreError> {
+        let result = sqlx::query_as::<_, StoreModel>(&DB::sql("SELECT * FROM store ORDER BY name"))
+            .fetch_all(&self.conn)
+            .await;
+
+        match result {
+            Ok(rows) => self.load_stores(rows).await,
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(StoreError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_store_by_id(&self, store_id: i32) -> Result<StoreCore, StoreError> {
+        let result = sqlx::query_as::<_, StoreModel>(&DB::sql("SELECT * FROM store WHERE id=?"))
+            .bind(store_id)
+            .fetch_one(&self.conn)
+            .await;
+
+        match result {
+            Ok(row) => self
+                .load_stores(vec![row])
+                .await
+                .map(|mut stores| stores.remove(0)),
+            Err(sqlx::Error::RowNotFound) => Err(StoreError::NotFound),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(StoreError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn insert_store(&self, mut store: StoreCore) -> Result<StoreCore, StoreError> {
+        let store_model = StoreModel::from_store_core(&store);
+
+        let result: Result<StoreCore, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            let query = DB::insert_sql(
+                "INSERT INTO store (name, address, timezone, active, pickup_slot_minutes, \
+                pickup_slot_capacity, pickup_release_minutes) VALUES (?,?,?,?,?,?,?)",
+            );
+            let insert = sqlx::query(&query)
+                .bind(&store_model.name)
+                .bind(&store_model.address)
+                .bind(&store_model.timezone)
+                .bind(store_model.active)
+                .bind(store_model.pickup_slot_minutes)
+                .bind(store_model.pickup_slot_capacity)
+                .bind(store_model.pickup_release_minutes);
+            let store_id = DB::insert_id(insert, &mut *tx).await?;
+            store.id = Some(store_id);
+
+            Self::insert_opening_hours_with(&mut tx, store_id, &store.opening_hours).await?;
+
+            tx.commit().await?;
+            Ok(store)
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            StoreError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_store(&self, store_id: i32, store: StoreCore) -> Result<(), StoreError> {
+        let store_model = StoreModel::from_store_core(&store);
+
+        let result: Result<(), sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            sqlx::query(&DB::sql(
+                "UPDATE store SET name=?, address=?, timezone=?, active=?, pickup_slot_minutes=?, \
+                pickup_slot_capacity=?, pickup_release_minutes=? WHERE id=?",
+            ))
+            .bind(&store_model.name)
+            .bind(&store_model.address)
+            .bind(&store_model.timezone)
+            .bind(store_model.active)
+            .bind(store_model.pickup_slot_minutes)
+            .bind(store_model.pickup_slot_capacity)
+            .bind(store_model.pickup_release_minutes)
+            .bind(store_id)
+            .execute(&mut *tx)
+            .await?;
+            sqlx::query(&DB::sql("DELETE FROM store_opening_hours WHERE store=?"))
+                .bind(store_id)
+                .execute(&mut *tx)
+                .await?;
+            Self::insert_opening_hours_with(&mut tx, store_id, &store.opening_hours).await?;
+
+            tx.commit().await
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            StoreError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn delete_store(&self, store_id: i32) -> Result<(), StoreError> {
+        let result: Result<u64, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            for table in [
+                "store_opening_hours",
+                "store_exception",
+                "store_unavailable_product",
+            ] {
+                sqlx::query(&DB::sql(&format!("DELETE FROM {} WHERE store=?", table)))
+                    .bind(store_id)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+            let deleted = sqlx::query(&DB::sql("DELETE FROM store WHERE id=?"))
+                .bind(store_id)
+                .execute(&mut *tx)
+                .await?
+                .rows_affected();
+
+            tx.commit().await?;
+            Ok(deleted)
+        }
+        .await;
+
+        match result {
+            Ok(0) => Err(StoreError::NotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(StoreError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn upsert_exception(
+        &self,
+        store_id: i32,
+        exception: StoreExceptionCore,
+    ) -> Result<(), StoreError> {
+        let exception_model = StoreExceptionModel::from_store_exception_core(store_id, &exception);
+
+        let query = DB::upsert(
+            "INSERT INTO store_exception (store, date, opens, closes, reason) VALUES (?,?,?,?,?)",
+            &["store", "date"],
+            &["opens", "closes", "reason"],
+        );
+        let result = sqlx::query(&DB::sql(&query))
+            .bind(exception_model.store)
+            .bind(exception_model.date)
+            .bind(exception_model.opens)
+            .bind(exception_model.closes)
+            .bind(&exception_model.reason)
+            .execute(&self.conn)
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(StoreError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn delete_exception(&self, store_id: i32, date: NaiveDate) -> Result<(), StoreError> {
+        let result = sqlx::query(&DB::sql(
+            "DELETE FROM store_exception WHERE store=? AND date=?",
+        ))
+        .bind(store_id)
+        .bind(date)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(StoreError::NotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(StoreError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn set_product_availability(
+        &self,
+        store_id: i32,
+        product_id: i32,
+        available: bool,
+    ) -> Result<(), StoreError> {
+        let query = if available {
+            "DELETE FROM store_unavailable_product WHERE store=? AND product=?".to_string()
+        } else {
+            DB::insert_ignore("INSERT INTO store_unavailable_product (store, product) VALUES (?,?)")
+        };
+
+        let result = sqlx::query(&DB::sql(&query))
+            .bind(store_id)
+            .bind(product_id)
+            .execute(&self.conn)
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(StoreError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_pickup_bookings(
+        &self,
+        store_id: i32,
+        from: DateTime<Utc>,
+    ) -> Result<Vec<(DateTime<Utc>, i64)>, StoreError> {
+        let result = sqlx::query_as::<_, (DateTime<Utc>, i64)>(&DB::sql(
+            "SELECT scheduled_for, COUNT(*) FROM orders \
+            WHERE store_id=? AND scheduled_for > ? AND status <> ? \
+            GROUP BY scheduled_for ORDER BY scheduled_for",
+        ))
+        .bind(store_id)
+        .bind(from)
+        .bind(OrderStatus::Cancelled.code())
+        .fetch_all(&self.conn)
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            StoreError::Unexpected
+        })
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn lock_store_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        store_id: i32,
+    ) -> Result<(), StoreError> {
+        let query = DB::for_update("SELECT * FROM store WHERE id=?");
+        let result = sqlx::query_as::<_, StoreModel>(&DB::sql(&query))
+            .bind(store_id)
+            .fetch_one(tx.connection())
+            .await;
+
+        match result {
+            Ok(_) => Ok(()),
+            Err(sqlx::Error::RowNotFound) => Err(StoreError::NotFound),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(StoreError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn count_bookings_in(
+        &self,
+        tx: &mut SqlTransaction<DB>,
+        store_id: i32,
+        scheduled_for: DateTime<Utc>,
+    ) -> Result<i64, StoreError> {
+        let result = sqlx::query_as::<_, (i64,)>(&DB::sql(
+            "SELECT COUNT(*) FROM orders WHERE store_id=? AND scheduled_for=? AND status <> ?",
+        ))
+        .bind(store_id)
+        .bind(scheduled_for)
+        .bind(OrderStatus::Cancelled.code())
+        .fetch_one(tx.connection())
+        .await;
+
+        match result {
+            Ok((count,)) => Ok(count),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(StoreError::Unexpected)
+            }
+        }
+    }
+}
+
+impl<DB: Backend> StoreRepository<DB> {
+    pub fn new(conn: DbPool<DB>) -> Self {
+        StoreRepository { conn }
+    }
+}
+
+impl<DB: Backend> Clone for StoreRepository<DB> {
+    fn clone(&self) -> Self {
+        StoreRepository::new(self.conn.clone())
+    }
+}
+
+impl<DB: Backend> StoreRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    OpeningHoursModel: for<'r> FromRow<'r, DB::Row>,
+    StoreExceptionModel: for<'r> FromRow<'r, DB::Row>,
+    (i32, i32): for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> NaiveDate: Encode<'q, DB> + Type<DB>,
+    for<'q> NaiveTime: Encode<'q, DB> + Type<DB>,
+{
+    async fn insert_opening_hours_with(
+        conn: &mut DB::Connection,
+        store_id: i32,
+        opening_hours: &[OpeningHoursCore],
+    ) -> Result<(), sqlx::Error> {
+        for hours in opening_hours {
+            let hours_model = OpeningHoursModel::from_opening_hours_core(store_id, hours);
+
+            sqlx::query(&DB::sql(
+                "INSERT INTO store_opening_hours (store, weekday, opens, closes) VALUES (?,?,?,?)",
+            ))
+            .bind(hours_model.store)
+            .bind(hours_model.weekday)
+            .bind(hours_model.opens)
+            .bind(hours_model.closes)
+            .execute(&mut *conn)
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Completa los locales con su horario, sus excepciones vigentes y los productos que no
+    /// ofrecen.
+    async fn load_stores(&self, rows: Vec<StoreModel>) -> Result<Vec<StoreCore>, StoreError> {
+        let hours = sqlx::query_as::<_, OpeningHoursModel>(&DB::sql(
+            "SELECT * FROM store_opening_hours ORDER BY weekday, opens",
+        ))
+        .fetch_all(&self.conn)
+        .await;
+        // Se incluye el día anterior porque en algunas zonas horarias todavía no terminó.
+        let exceptions = sqlx::query_as::<_, StoreExceptionModel>(&DB::sql(
+            "SELECT * FROM store_exception WHERE date >= ? ORDER BY date",
+        ))
+        .bind(Utc::now().date_naive() - Duration::days(1))
+        .fetch_all(&self.conn)
+        .await;
+        let unavailable = sqlx::query_as::<_, (i32, i32)>(&DB::sql(
+            "SELECT store, product FROM store_unavailable_product ORDER BY product",
+        ))
+        .fetch_all(&self.conn)
+        .await;
+
+        match (hours, exceptions, unavailable) {
+            (Ok(hours), Ok(exceptions), Ok(unavailable)) => Ok(rows
+                .into_iter()
+                .map(|row| {
+                    let store_id = row.id.unwrap_or_default();
+
+                    StoreCore::from_store_model(
+                        row,
+                        hours
+                            .iter()
+                            .filter(|hours| hours.store == store_id)
+                            .map(|hours| OpeningHoursCore::from_opening_hours_model(hours.clone()))
+                            .collect(),
+                        exceptions
+                            .iter()
+                            .filter(|exception| exception.store == store_id)
+                            .map(|exception| {
+                                StoreExceptionCore::from_store_exception_model(exception.clone())
+                            })
+                            .collect(),
+                        unavailable
+                            .iter()
+                            .filter(|(store, _)| *store == store_id)
+                            .map(|(_, product)| *product)
+                            .collect(),
+                    )
+                })
+                .collect()),
+            (Err(err), _, _) | (_, Err(err), _) | (_, _, Err(err)) => {
+                log::error!("{:?}", err);
+                Err(StoreError::Unexpected)
+            }
+        }
+    }
+}
@@ -1,33 +1,52 @@
 use crate::core::{
-    entities::user::User as UserCore, errors::user_errors::UserError,
+    entities::{user::User as UserCore, user_status::UserStatus},
+    errors::user_errors::UserError,
     ports::user_port::IUserRepository,
 };
+use crate::infrastructure::data::backend::{Backend, RowsAffected};
 use crate::infrastructure::data::models::user::User as UserModel;
+use crate::infrastructure::data::pool::DbPool;
+use chrono::{DateTime, Duration, Utc};
+use sqlx::{database::HasArguments, Encode, Executor, FromRow, IntoArguments, MySql, Type};
 
-#[derive(Clone)]
-pub struct UserRepository {
-    pub conn: sqlx::MySqlPool,
+/// Cuentas dadas de baja antes de la fecha del único parámetro.
+const PURGEABLE_USERS: &str = "SELECT id FROM `user` WHERE status='B' AND deleted_at <= ?";
+
+pub struct UserRepository<DB: Backend = MySql> {
+    pub conn: DbPool<DB>,
 }
 
 #[async_trait::async_trait]
-impl IUserRepository for UserRepository {
+impl<DB: Backend> IUserRepository for UserRepository<DB>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    UserModel: for<'r> FromRow<'r, DB::Row>,
+    for<'q> i32: Encode<'q, DB> + Type<DB>,
+    for<'q> String: Encode<'q, DB> + Type<DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+    for<'q> DateTime<Utc>: Encode<'q, DB> + Type<DB>,
+{
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
     async fn insert_user(&self, user: UserCore) -> Result<UserCore, UserError> {
         let mut user_model = UserModel::from_user_core(user);
 
-        let result = sqlx::query(
-            "INSERT INTO user (name, surname, phone_number, email, password) VALUES (?,?,?,?,?)",
-        )
-        .bind(&user_model.name)
-        .bind(&user_model.surname)
-        .bind(&user_model.clone().phone_number.unwrap_or_default())
-        .bind(&user_model.email)
-        .bind(&user_model.password)
-        .execute(&self.conn)
-        .await;
+        let query = DB::insert_sql(
+            "INSERT INTO `user` (name, surname, phone_number, email, password, role) \
+            VALUES (?,?,?,?,?,?)",
+        );
+        let insert = sqlx::query(&query)
+            .bind(&user_model.name)
+            .bind(&user_model.surname)
+            .bind(user_model.clone().phone_number.unwrap_or_default())
+            .bind(&user_model.email)
+            .bind(&user_model.password)
+            .bind(&user_model.role);
+        let result = DB::insert_id(insert, &self.conn).await;
 
         match result {
-            Ok(r) => {
-                user_model.set_id(r.last_insert_id() as i32);
+            Ok(id) => {
+                user_model.set_id(id);
 
                 Ok(UserCore::from_user_model(user_model))
             }
@@ -38,11 +57,14 @@ impl IUserRepository for UserRepository {
         }
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
     async fn get_user_by_email(&self, email: String) -> Result<UserCore, UserError> {
-        let result = sqlx::query_as::<_, UserModel>("SELECT * FROM user WHERE email=?")
-            .bind(email)
-            .fetch_one(&self.conn)
-            .await;
+        let result = sqlx::query_as::<_, UserModel>(&DB::sql(
+            "SELECT * FROM `user` WHERE lower(email)=lower(?) AND status<>'B'",
+        ))
+        .bind(email)
+        .fetch_one(&self.conn)
+        .await;
 
         match result {
             Ok(row) => Ok(UserCore::from_user_model(row)),
@@ -56,10 +78,12 @@ impl IUserRepository for UserRepository {
         }
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
     async fn get_users(&self) -> Result<Vec<UserCore>, UserError> {
-        let result = sqlx::query_as::<_, UserModel>("SELECT * FROM user")
-            .fetch_all(&self.conn)
-            .await;
+        let result =
+            sqlx::query_as::<_, UserModel>(&DB::sql("SELECT * FROM `user` WHERE status<>'B'"))
+                .fetch_all(&self.conn)
+                .await;
 
         match result {
             Ok(rows) => Ok(UserCore::from_user_model_vec(rows)),
@@ -73,11 +97,14 @@ impl IUserRepository for UserRepository {
         }
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
     async fn get_user_by_id(&self, id: u8) -> Result<UserCore, UserError> {
-        let result = sqlx::query_as::<_, UserModel>("SELECT * FROM user WHERE id=?")
-            .bind(id)
-            .fetch_one(&self.conn)
-            .await;
+        let result = sqlx::query_as::<_, UserModel>(&DB::sql(
+            "SELECT * FROM `user` WHERE id=? AND status<>'B'",
+        ))
+        .bind(i32::from(id))
+        .fetch_one(&self.conn)
+        .await;
 
         match result {
             Ok(row) => Ok(UserCore::from_user_model(row)),
@@ -91,18 +118,20 @@ impl IUserRepository for UserRepository {
         }
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
     async fn update_user(&self, user_id: i32, user: UserCore) -> Result<(), UserError> {
         let user_model = UserModel::from_user_core(user);
 
-        let result =
-            sqlx::query("UPDATE user SET name=?, surname=?, phone_number=?, email=? WHERE id=?")
-                .bind(&user_model.name)
-                .bind(&user_model.surname)
-                .bind(&user_model.phone_number.unwrap_or_default())
-                .bind(&user_model.email)
-                .bind(user_id)
-                .execute(&self.conn)
-                .await;
+        let result = sqlx::query(&DB::sql(
+            "UPDATE `user` SET name=?, surname=?, phone_number=?, email=? WHERE id=?",
+        ))
+        .bind(&user_model.name)
+        .bind(&user_model.surname)
+        .bind(user_model.phone_number.unwrap_or_default())
+        .bind(&user_model.email)
+        .bind(user_id)
+        .execute(&self.conn)
+        .await;
 
         match result {
             Ok(_) => Ok(()),
@@ -116,27 +145,143 @@ impl IUserRepository for UserRepository {
         }
     }
 
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
     async fn delete_user(&self, user_id: i32) -> Result<(), UserError> {
-        let result = sqlx::query("DELETE FROM user WHERE id=?")
-            .bind(user_id)
-            .execute(&self.conn)
-            .await;
+        let result = sqlx::query(&DB::sql(
+            "UPDATE `user` SET status='B', deleted_at=? WHERE id=? AND status<>'B'",
+        ))
+        .bind(Utc::now())
+        .bind(user_id)
+        .execute(&self.conn)
+        .await;
 
         match result {
+            Ok(r) if r.rows_affected() == 0 => Err(UserError::NotFound),
             Ok(_) => Ok(()),
-            Err(err) => match &err {
-                sqlx::Error::RowNotFound => Err(UserError::NotFound),
-                _ => {
-                    log::error!("{:?}", err);
-                    Err(UserError::Unexpected)
-                }
-            },
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(UserError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn get_users_by_status(&self, status: UserStatus) -> Result<Vec<UserCore>, UserError> {
+        let result =
+            sqlx::query_as::<_, UserModel>(&DB::sql("SELECT * FROM `user` WHERE status=?"))
+                .bind(status.code())
+                .fetch_all(&self.conn)
+                .await;
+
+        match result {
+            Ok(rows) => Ok(UserCore::from_user_model_vec(rows)),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(UserError::Unexpected)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_user_status(&self, user_id: i32, status: UserStatus) -> Result<(), UserError> {
+        let result = sqlx::query(&DB::sql(
+            "UPDATE `user` SET status=? WHERE id=? AND status<>'B'",
+        ))
+        .bind(status.code())
+        .bind(user_id)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(UserError::NotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(UserError::Unexpected)
+            }
         }
     }
+
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn update_password(&self, user_id: i32, password: String) -> Result<(), UserError> {
+        let result = sqlx::query(&DB::sql(
+            "UPDATE `user` SET password=? WHERE id=? AND status<>'B'",
+        ))
+        .bind(password)
+        .bind(user_id)
+        .execute(&self.conn)
+        .await;
+
+        match result {
+            Ok(r) if r.rows_affected() == 0 => Err(UserError::NotFound),
+            Ok(_) => Ok(()),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(UserError::Unexpected)
+            }
+        }
+    }
+
+    /// Borra las cuentas junto con sus direcciones y carrito. Los pedidos, pagos y movimientos
+    /// de puntos se conservan como historial.
+    #[tracing::instrument(skip_all, fields(db.system = DB::SYSTEM))]
+    async fn purge_deleted_users(&self, retention_days: u32) -> Result<u64, UserError> {
+        let cutoff = Utc::now() - Duration::days(i64::from(retention_days));
+
+        let result: Result<u64, sqlx::Error> = async {
+            let mut tx = self.conn.begin().await?;
+
+            let queries = [
+                format!("DELETE FROM address WHERE `user` IN ({})", PURGEABLE_USERS),
+                format!(
+                    "DELETE FROM cart_item_option WHERE cart_item IN \
+                    (SELECT id FROM cart_item WHERE `user` IN ({}))",
+                    PURGEABLE_USERS
+                ),
+                format!(
+                    "DELETE FROM cart_item WHERE `user` IN ({})",
+                    PURGEABLE_USERS
+                ),
+                format!(
+                    "DELETE FROM cart_coupon WHERE `user` IN ({})",
+                    PURGEABLE_USERS
+                ),
+            ];
+            for query in &queries {
+                sqlx::query(&DB::sql(query))
+                    .bind(cutoff)
+                    .execute(&mut *tx)
+                    .await?;
+            }
+            let deleted = sqlx::query(&DB::sql(&format!(
+                "DELETE FROM `user` WHERE id IN (SELECT id FROM ({}) AS purgeable)",
+                PURGEABLE_USERS
+            )))
+            .bind(cutoff)
+            .execute(&mut *tx)
+            .await?
+            .rows_affected();
+
+            tx.commit().await?;
+            Ok(deleted)
+        }
+        .await;
+
+        result.map_err(|err| {
+            log::error!("{:?}", err);
+            UserError::Unexpected
+        })
+    }
 }
 
-impl UserRepository {
-    pub fn new(conn: sqlx::MySqlPool) -> Self {
+impl<DB: Backend> UserRepository<DB> {
+    pub fn new(conn: DbPool<DB>) -> Self {
         UserRepository { conn }
     }
 }
+
+impl<DB: Backend> Clone for UserRepository<DB> {
+    fn clone(&self) -> Self {
+        UserRepository::new(self.conn.clone())
+    }
+}
@@ -0,0 +1,65 @@
+use super::backend::{Backend, RowsAffected};
+use crate::core::entities::{payment::PaymentMethod, user_role::UserRole};
+use sqlx::{database::HasArguments, Encode, Executor, IntoArguments, Pool, Type};
+
+/// Roles de `user_role` con su descripción.
+const ROLES: [(UserRole, &str); 3] = [
+    (UserRole::Administrator, "Administrador"),
+    (UserRole::Employee, "Empleado"),
+    (UserRole::Client, "Cliente"),
+];
+
+/// Medios de pago de `payment_method` con su descripción.
+const PAYMENT_METHODS: [(PaymentMethod, &str); 2] = [
+    (PaymentMethod::Card, "Tarjeta"),
+    (PaymentMethod::CashOnPickup, "Efectivo al retirar"),
+];
+
+/// Filas insertadas por [`seed_reference_data`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct SeedReport {
+    pub roles: u64,
+    pub payment_methods: u64,
+}
+
+/// Inserta los roles y medios de pago que falten. Se puede ejecutar las veces que haga falta:
+/// no modifica las filas existentes.
+pub async fn seed_reference_data<DB: Backend>(pool: &Pool<DB>) -> Result<SeedReport, sqlx::Error>
+where
+    for<'c> &'c mut DB::Connection: Executor<'c, Database = DB>,
+    for<'q> <DB as HasArguments<'q>>::Arguments: IntoArguments<'q, DB>,
+    for<'q> &'q str: Encode<'q, DB> + Type<DB>,
+{
+    let mut tx = pool.begin().await?;
+    let mut report = SeedReport::default();
+
+    for (role, description) in ROLES {
+        report.roles += sqlx::query(&DB::sql(
+            "INSERT INTO user_role (code, description) SELECT ?, ? FROM (SELECT 1) AS one \
+            WHERE NOT EXISTS (SELECT 1 FROM user_role WHERE code=?)",
+        ))
+        .bind(role.code())
+        .bind(description)
+        .bind(role.code())
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    }
+
+    for (method, description) in PAYMENT_METHODS {
+        report.payment_methods += sqlx::query(&DB::sql(
+            "INSERT INTO payment_method (code, description, enabled) \
+            SELECT ?, ?, true FROM (SELECT 1) AS one \
+            WHERE NOT EXISTS (SELECT 1 FROM payment_method WHERE code=?)",
+        ))
+        .bind(method.code())
+        .bind(description)
+        .bind(method.code())
+        .execute(&mut *tx)
+        .await?
+        .rows_affected();
+    }
+
+    tx.commit().await?;
+    Ok(report)
+}
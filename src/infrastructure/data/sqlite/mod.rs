@@ -0,0 +1,111 @@
+//! Backend de SQLite, para desarrollo local y CI sin servidor de base de datos. Usa los
+//! repositorios SQL compartidos.
+
+use super::{
+    pool::DbPool,
+    repositories::{
+        cart_repository::CartRepository, catalog_repository::CatalogRepository,
+        delivery_repository::DeliveryRepository, inventory_repository::InventoryRepository,
+        loyalty_repository::LoyaltyRepository, order_repository::OrderRepository,
+        payment_repository::PaymentRepository, promotion_repository::PromotionRepository,
+        store_repository::StoreRepository, user_repository::UserRepository,
+    },
+    unit_of_work::SqlUnitOfWork,
+    Repositories,
+};
+use crate::infrastructure::config::DatabaseConfig;
+use sqlx::{
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+    Sqlite, SqlitePool,
+};
+use std::str::FromStr;
+
+pub type SqliteRepositories = Repositories<
+    UserRepository<Sqlite>,
+    CatalogRepository<Sqlite>,
+    LoyaltyRepository<Sqlite>,
+    InventoryRepository<Sqlite>,
+    StoreRepository<Sqlite>,
+    DeliveryRepository<Sqlite>,
+    OrderRepository<Sqlite>,
+    PaymentRepository<Sqlite>,
+    PromotionRepository<Sqlite>,
+    CartRepository<Sqlite>,
+    SqlUnitOfWork<Sqlite>,
+>;
+
+/// Abre la base, creando el archivo si no existe. Una base en memoria (`sqlite::memory:`) vive
+/// mientras tenga una conexión abierta, así que se usa una sola que no se cierra nunca.
+pub async fn connect_to_database(config: &DatabaseConfig) -> Result<SqlitePool, sqlx::Error> {
+    let options = SqliteConnectOptions::from_str(&config.url)?.create_if_missing(true);
+
+    let conn = if config.url.contains(":memory:") || config.url.contains("mode=memory") {
+        SqlitePoolOptions::new()
+            .max_connections(1)
+            .idle_timeout(None)
+            .max_lifetime(None)
+            .connect_with(options)
+            .await?
+    } else {
+        SqlitePool::connect_with(options).await?
+    };
+    Ok(conn)
+}
+
+pub fn repositories(conn: DbPool<Sqlite>) -> SqliteRepositories {
+    Repositories {
+        user: UserRepository::new(conn.clone()),
+        catalog: CatalogRepository::new(conn.clone()),
+        loyalty: LoyaltyRepository::new(conn.clone()),
+        inventory: InventoryRepository::new(conn.clone()),
+        store: StoreRepository::new(conn.clone()),
+        delivery: DeliveryRepository::new(conn.clone()),
+        order: OrderRepository::new(conn.clone()),
+        payment: PaymentRepository::new(conn.clone()),
+        promotion: PromotionRepository::new(conn.clone()),
+        cart: CartRepository::new(conn.clone()),
+        unit_of_work: SqlUnitOfWork::new(conn),
+    }
+}
+
+#[cfg(test)]
+mod sqlite_tests {
+    use super::*;
+    use crate::infrastructure::{
+        config::{DatabaseBackend, MigrationMode},
+        data::{conformance, migrations, seed},
+    };
+
+    async fn migrated_database() -> SqlitePool {
+        let conn = connect_to_database(&DatabaseConfig {
+            url: "sqlite::memory:".to_string(),
+            backend: DatabaseBackend::Sqlite,
+            migrations: MigrationMode::Apply,
+        })
+        .await
+        .unwrap();
+        migrations::run(&conn).await.unwrap();
+
+        conn
+    }
+
+    #[actix_web::test]
+    async fn test_repositories_pass_conformance_suite() {
+        let conn = migrated_database().await;
+
+        conformance::check_repositories(repositories(DbPool::new(conn))).await;
+    }
+
+    #[actix_web::test]
+    async fn test_seed_inserts_only_missing_rows() {
+        let conn = migrated_database().await;
+
+        let first = seed::seed_reference_data(&conn).await.unwrap();
+        let second = seed::seed_reference_data(&conn).await.unwrap();
+
+        // Los roles ya los crea la migración inicial.
+        assert_eq!(first.roles, 0);
+        assert_eq!(first.payment_methods, 2);
+        assert_eq!(second, seed::SeedReport::default());
+    }
+}
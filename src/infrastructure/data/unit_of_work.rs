@@ -0,0 +1,67 @@
+//! Unidad de trabajo de los backends SQL: cada transacción es una transacción de sqlx sobre el
+//! pool, que se revierte sola si se descarta sin confirmar.
+use crate::core::{
+    errors::transaction_errors::TransactionError,
+    ports::unit_of_work_port::{ITransaction, IUnitOfWork},
+};
+use crate::infrastructure::data::pool::DbPool;
+use sqlx::Database;
+
+pub struct SqlUnitOfWork<DB: Database> {
+    pub conn: DbPool<DB>,
+}
+
+#[async_trait::async_trait]
+impl<DB: Database> IUnitOfWork for SqlUnitOfWork<DB> {
+    type Transaction = SqlTransaction<DB>;
+
+    async fn begin(&self) -> Result<SqlTransaction<DB>, TransactionError> {
+        match self.conn.begin().await {
+            Ok(tx) => Ok(SqlTransaction { tx }),
+            Err(err) => {
+                log::error!("{:?}", err);
+                Err(TransactionError::Unexpected)
+            }
+        }
+    }
+}
+
+impl<DB: Database> SqlUnitOfWork<DB> {
+    pub fn new(conn: DbPool<DB>) -> Self {
+        SqlUnitOfWork { conn }
+    }
+}
+
+impl<DB: Database> Clone for SqlUnitOfWork<DB> {
+    fn clone(&self) -> Self {
+        SqlUnitOfWork::new(self.conn.clone())
+    }
+}
+
+pub struct SqlTransaction<DB: Database> {
+    tx: sqlx::Transaction<'static, DB>,
+}
+
+impl<DB: Database> SqlTransaction<DB> {
+    /// Conexión de la transacción, para los helpers `xxx_with` de los repositorios.
+    pub(crate) fn connection(&mut self) -> &mut DB::Connection {
+        &mut self.tx
+    }
+}
+
+#[async_trait::async_trait]
+impl<DB: Database> ITransaction for SqlTransaction<DB> {
+    async fn commit(self) -> Result<(), TransactionError> {
+        self.tx.commit().await.map_err(|err| {
+            log::error!("{:?}", err);
+            TransactionError::Unexpected
+        })
+    }
+
+    async fn rollback(self) -> Result<(), TransactionError> {
+        self.tx.rollback().await.map_err(|err| {
+            log::error!("{:?}", err);
+            TransactionError::Unexpected
+        })
+    }
+}
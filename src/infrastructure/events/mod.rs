@@ -0,0 +1,3 @@
+pub mod order_event_broadcaster;
+pub mod scheduled_order_releaser;
+pub mod stock_alert_logger;
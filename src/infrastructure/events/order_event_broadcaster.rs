@@ -0,0 +1,37 @@
+use crate::core::{
+    entities::order_event::OrderEvent, ports::order_event_port::IOrderEventPublisher,
+};
+use tokio::sync::broadcast;
+
+/// Difunde los eventos de pedidos dentro del proceso a todas las conexiones abiertas.
+#[derive(Clone)]
+pub struct OrderEventBroadcaster {
+    sender: broadcast::Sender<OrderEvent>,
+}
+
+impl OrderEventBroadcaster {
+    /// `capacity` es la cantidad de eventos que un suscriptor lento puede acumular antes de
+    /// empezar a perder los más antiguos.
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+
+        OrderEventBroadcaster { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<OrderEvent> {
+        self.sender.subscribe()
+    }
+}
+
+impl IOrderEventPublisher for OrderEventBroadcaster {
+    fn publish(&self, event: OrderEvent) {
+        // `send` solo falla cuando no hay suscriptores, en cuyo caso el evento se descarta.
+        let _ = self.sender.send(event);
+    }
+}
+
+impl Default for OrderEventBroadcaster {
+    fn default() -> Self {
+        Self::new(256)
+    }
+}
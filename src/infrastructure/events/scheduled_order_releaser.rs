@@ -0,0 +1,38 @@
+use crate::core::ports::order_port::IOrderService;
+use std::{sync::Arc, time::Duration};
+
+/// Tarea de fondo que pasa a la cola del barista los pedidos programados cuando se acerca su
+/// franja de retiro.
+#[derive(Clone)]
+pub struct ScheduledOrderReleaser {
+    order_service: Arc<dyn IOrderService>,
+    interval: Duration,
+}
+
+impl ScheduledOrderReleaser {
+    pub fn new(order_service: Arc<dyn IOrderService>, interval: Duration) -> Self {
+        ScheduledOrderReleaser {
+            order_service,
+            interval,
+        }
+    }
+
+    /// Inicia la tarea en el runtime actual; revisa los pedidos cada `interval`.
+    pub fn spawn(self) {
+        actix_web::rt::spawn(async move {
+            let mut interval = actix_web::rt::time::interval(self.interval);
+
+            loop {
+                interval.tick().await;
+
+                match self.order_service.release_scheduled_orders().await {
+                    Ok(orders) if !orders.is_empty() => {
+                        log::info!("Released {} scheduled orders", orders.len());
+                    }
+                    Ok(_) => {}
+                    Err(err) => log::error!("{:?}", err.to_string()),
+                }
+            }
+        });
+    }
+}
@@ -0,0 +1,17 @@
+use crate::core::{entities::inventory::StockAlert, ports::stock_alert_port::IStockAlertPublisher};
+
+/// Registra las alertas de stock bajo en el log de la aplicación.
+#[derive(Clone, Default)]
+pub struct StockAlertLogger;
+
+impl IStockAlertPublisher for StockAlertLogger {
+    fn publish(&self, alert: StockAlert) {
+        log::warn!(
+            "Low stock: {} has {} {} left (threshold {})",
+            alert.ingredient.name,
+            alert.ingredient.stock,
+            alert.ingredient.unit.code(),
+            alert.ingredient.low_stock_threshold
+        );
+    }
+}
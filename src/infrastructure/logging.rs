@@ -0,0 +1,148 @@
+//! Log de la aplicación, en texto para desarrollo o en JSON (un objeto por línea) para
+//! producción. Cada registro emitido mientras se atiende una petición, ya sea desde un handler,
+//! un servicio o un repositorio, incluye el id, el método y la ruta de esa petición.
+use crate::infrastructure::config::{LogFormat, LoggingConfig};
+use chrono::{SecondsFormat, Utc};
+use env_logger::fmt::Formatter;
+use log::Record;
+use std::{future::Future, io::Write};
+
+tokio::task_local! {
+    static REQUEST: RequestContext;
+}
+
+/// Petición que se está atendiendo. La ruta no incluye la query string, que podría llevar
+/// datos sensibles.
+#[derive(Clone, Debug)]
+pub struct RequestContext {
+    pub id: String,
+    pub method: String,
+    pub path: String,
+}
+
+/// Ejecuta `future` con `context` como petición actual de los registros que emita.
+pub async fn with_request<F: Future>(context: RequestContext, future: F) -> F::Output {
+    REQUEST.scope(context, future).await
+}
+
+/// Petición actual, si el código se ejecuta dentro de [`with_request`].
+pub fn current_request() -> Option<RequestContext> {
+    REQUEST.try_with(|request| request.clone()).ok()
+}
+
+/// Inicializa el log. `RUST_LOG`, si está definida, tiene prioridad sobre el nivel configurado.
+pub fn init(config: &LoggingConfig) {
+    let mut builder = env_logger::Builder::from_env(
+        env_logger::Env::default().default_filter_or(config.level.as_str()),
+    );
+
+    match config.format {
+        LogFormat::Text => builder.format(format_text),
+        LogFormat::Json => builder.format(format_json),
+    };
+    builder.init();
+}
+
+fn format_text(buf: &mut Formatter, record: &Record) -> std::io::Result<()> {
+    let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+
+    match current_request() {
+        Some(request) => writeln!(
+            buf,
+            "[{} {:<5} {}] [{}] {}",
+            timestamp,
+            record.level(),
+            record.target(),
+            request.id,
+            record.args()
+        ),
+        None => writeln!(
+            buf,
+            "[{} {:<5} {}] {}",
+            timestamp,
+            record.level(),
+            record.target(),
+            record.args()
+        ),
+    }
+}
+
+fn format_json(buf: &mut Formatter, record: &Record) -> std::io::Result<()> {
+    writeln!(buf, "{}", json_entry(record, current_request()))
+}
+
+fn json_entry(record: &Record, request: Option<RequestContext>) -> serde_json::Value {
+    let mut entry = serde_json::json!({
+        "timestamp": Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true),
+        "level": record.level().as_str(),
+        "target": record.target(),
+        "message": record.args().to_string(),
+    });
+
+    if let Some(request) = request {
+        entry["request_id"] = request.id.into();
+        entry["method"] = request.method.into();
+        entry["path"] = request.path.into();
+    }
+
+    entry
+}
+
+#[cfg(test)]
+mod logging_tests {
+    use super::*;
+
+    fn request() -> RequestContext {
+        RequestContext {
+            id: "abc-123".to_string(),
+            method: "GET".to_string(),
+            path: "/orders/7".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_json_entry_includes_request() {
+        let entry = json_entry(
+            &Record::builder()
+                .args(format_args!("Order not found"))
+                .level(log::Level::Error)
+                .target("cafelatte::orders")
+                .build(),
+            Some(request()),
+        );
+
+        assert_eq!(entry["level"], "ERROR");
+        assert_eq!(entry["target"], "cafelatte::orders");
+        assert_eq!(entry["message"], "Order not found");
+        assert_eq!(entry["request_id"], "abc-123");
+        assert_eq!(entry["path"], "/orders/7");
+    }
+
+    #[test]
+    fn test_json_entry_without_request() {
+        let entry = json_entry(
+            &Record::builder()
+                .args(format_args!("Listening"))
+                .level(log::Level::Info)
+                .build(),
+            None,
+        );
+
+        assert_eq!(entry["message"], "Listening");
+        assert!(entry.get("request_id").is_none());
+    }
+
+    #[actix_web::test]
+    async fn test_request_is_visible_inside_the_scope_only() {
+        assert!(current_request().is_none());
+
+        let id = with_request(request(), async {
+            actix_web::rt::task::yield_now().await;
+            current_request().map(|request| request.id)
+        })
+        .await;
+
+        assert_eq!(id.as_deref(), Some("abc-123"));
+        assert!(current_request().is_none());
+    }
+}
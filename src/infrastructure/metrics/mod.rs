@@ -0,0 +1,2 @@
+pub mod noop_metrics_recorder;
+pub mod prometheus_metrics;
@@ -0,0 +1,10 @@
+use crate::core::{entities::metric_event::MetricEvent, ports::metrics_port::IMetricsRecorder};
+
+/// Descarta los eventos. Para los procesos que no exponen métricas, como la CLI de
+/// administración, y para las pruebas.
+#[derive(Clone, Default)]
+pub struct NoopMetricsRecorder;
+
+impl IMetricsRecorder for NoopMetricsRecorder {
+    fn record(&self, _event: MetricEvent) {}
+}
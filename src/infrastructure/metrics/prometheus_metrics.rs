@@ -0,0 +1,233 @@
+use crate::core::{entities::metric_event::MetricEvent, ports::metrics_port::IMetricsRecorder};
+use crate::infrastructure::data::pool::AcquireObserver;
+use prometheus::{
+    Histogram, HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, Opts, Registry,
+    TextEncoder,
+};
+use sqlx::{Database, Pool};
+use std::{sync::Arc, time::Duration};
+
+/// Pool de conexiones del que se informa el tamaño. La espera por una conexión la informa el
+/// propio pool de los repositorios, a través de `AcquireObserver`.
+pub trait DatabasePool: Send + Sync {
+    fn size(&self) -> u32;
+    fn idle(&self) -> usize;
+}
+
+impl<DB: Database> DatabasePool for Pool<DB> {
+    fn size(&self) -> u32 {
+        Pool::size(self)
+    }
+
+    fn idle(&self) -> usize {
+        self.num_idle()
+    }
+}
+
+/// Métricas del servidor en formato de texto de Prometheus. Se clona barato: todas las copias
+/// comparten los mismos contadores.
+#[derive(Clone)]
+pub struct PrometheusMetrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    logins: IntCounterVec,
+    token_verification_failures: IntCounterVec,
+    user_registrations: IntCounter,
+    orders_placed: IntCounter,
+    db_pool_connections: IntGauge,
+    db_pool_idle_connections: IntGauge,
+    db_pool_acquire_duration: Histogram,
+    pool: Option<Arc<dyn DatabasePool>>,
+}
+
+impl PrometheusMetrics {
+    pub fn new() -> Self {
+        let registry = Registry::new_custom(Some("cafelatte".to_string()), None)
+            .expect("the prefix is a valid metric name");
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "HTTP requests handled"),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new(
+                "http_request_duration_seconds",
+                "Time taken to handle HTTP requests",
+            ),
+            &["method", "route", "status"],
+        )
+        .unwrap();
+        let logins = IntCounterVec::new(
+            Opts::new("logins_total", "Login attempts by result"),
+            &["result"],
+        )
+        .unwrap();
+        let token_verification_failures = IntCounterVec::new(
+            Opts::new(
+                "token_verification_failures_total",
+                "Requests rejected by the authentication middleware, by reason",
+            ),
+            &["reason"],
+        )
+        .unwrap();
+        let user_registrations =
+            IntCounter::new("user_registrations_total", "Users registered").unwrap();
+        let orders_placed = IntCounter::new("orders_placed_total", "Orders placed").unwrap();
+        let db_pool_connections =
+            IntGauge::new("db_pool_connections", "Open database connections").unwrap();
+        let db_pool_idle_connections =
+            IntGauge::new("db_pool_idle_connections", "Idle database connections").unwrap();
+        let db_pool_acquire_duration = Histogram::with_opts(HistogramOpts::new(
+            "db_pool_acquire_duration_seconds",
+            "Time waited for a database connection",
+        ))
+        .unwrap();
+
+        registry.register(Box::new(http_requests.clone())).unwrap();
+        registry
+            .register(Box::new(http_request_duration.clone()))
+            .unwrap();
+        registry.register(Box::new(logins.clone())).unwrap();
+        registry
+            .register(Box::new(token_verification_failures.clone()))
+            .unwrap();
+        registry
+            .register(Box::new(user_registrations.clone()))
+            .unwrap();
+        registry.register(Box::new(orders_placed.clone())).unwrap();
+
+        PrometheusMetrics {
+            registry,
+            http_requests,
+            http_request_duration,
+            logins,
+            token_verification_failures,
+            user_registrations,
+            orders_placed,
+            db_pool_connections,
+            db_pool_idle_connections,
+            db_pool_acquire_duration,
+            pool: None,
+        }
+    }
+
+    /// Informa también el estado del pool. El backend en memoria no tiene pool.
+    pub fn with_pool(mut self, pool: impl DatabasePool + 'static) -> Self {
+        self.registry
+            .register(Box::new(self.db_pool_connections.clone()))
+            .unwrap();
+        self.registry
+            .register(Box::new(self.db_pool_idle_connections.clone()))
+            .unwrap();
+        self.registry
+            .register(Box::new(self.db_pool_acquire_duration.clone()))
+            .unwrap();
+        self.pool = Some(Arc::new(pool));
+
+        self
+    }
+
+    /// `route` es el patrón de la ruta, como `/orders/{id}`, para no crear una serie por cada id.
+    pub fn observe_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let status = status.to_string();
+        let labels = [method, route, status.as_str()];
+
+        self.http_requests.with_label_values(&labels).inc();
+        self.http_request_duration
+            .with_label_values(&labels)
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Actualiza el estado del pool y retorna todas las métricas.
+    pub fn render(&self) -> String {
+        if let Some(pool) = &self.pool {
+            self.db_pool_connections.set(pool.size().into());
+            self.db_pool_idle_connections.set(pool.idle() as i64);
+        }
+
+        TextEncoder::new()
+            .encode_to_string(&self.registry.gather())
+            .unwrap_or_else(|err| {
+                log::error!("{}", err);
+                String::new()
+            })
+    }
+}
+
+impl IMetricsRecorder for PrometheusMetrics {
+    fn record(&self, event: MetricEvent) {
+        match event {
+            MetricEvent::UserRegistered => self.user_registrations.inc(),
+            MetricEvent::LoginSucceeded => self.logins.with_label_values(&["success"]).inc(),
+            MetricEvent::LoginFailed => self.logins.with_label_values(&["failure"]).inc(),
+            MetricEvent::OrderPlaced => self.orders_placed.inc(),
+            MetricEvent::TokenRejected(reason) => self
+                .token_verification_failures
+                .with_label_values(&[reason])
+                .inc(),
+        }
+    }
+}
+
+impl AcquireObserver for PrometheusMetrics {
+    fn observe_acquire(&self, wait: Duration) {
+        self.db_pool_acquire_duration.observe(wait.as_secs_f64());
+    }
+}
+
+impl Default for PrometheusMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod prometheus_metrics_tests {
+    use super::*;
+
+    struct FakePool;
+
+    impl DatabasePool for FakePool {
+        fn size(&self) -> u32 {
+            3
+        }
+
+        fn idle(&self) -> usize {
+            1
+        }
+    }
+
+    #[test]
+    fn test_render_exposes_counters_with_prefix() {
+        let metrics = PrometheusMetrics::new();
+
+        metrics.record(MetricEvent::LoginFailed);
+        metrics.record(MetricEvent::LoginFailed);
+        metrics.record(MetricEvent::OrderPlaced);
+        metrics.record(MetricEvent::TokenRejected("expired"));
+        metrics.observe_request("GET", "/orders/{id}", 200, Duration::from_millis(5));
+
+        let text = metrics.render();
+        assert!(text.contains("cafelatte_logins_total{result=\"failure\"} 2"));
+        assert!(text.contains("cafelatte_orders_placed_total 1"));
+        assert!(text.contains("cafelatte_token_verification_failures_total{reason=\"expired\"} 1"));
+        assert!(text.contains(
+            "cafelatte_http_requests_total{method=\"GET\",route=\"/orders/{id}\",status=\"200\"} 1"
+        ));
+        assert!(!text.contains("cafelatte_db_pool_connections"));
+    }
+
+    #[test]
+    fn test_render_exposes_pool_state_and_observed_acquires() {
+        let metrics = PrometheusMetrics::new().with_pool(FakePool);
+
+        metrics.observe_acquire(Duration::from_millis(20));
+
+        let text = metrics.render();
+        assert!(text.contains("cafelatte_db_pool_connections 3"));
+        assert!(text.contains("cafelatte_db_pool_idle_connections 1"));
+        assert!(text.contains("cafelatte_db_pool_acquire_duration_seconds_count 1"));
+    }
+}
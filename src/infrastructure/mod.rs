@@ -1,2 +1,8 @@
 pub mod api;
+pub mod config;
 pub mod data;
+pub mod events;
+pub mod logging;
+pub mod metrics;
+pub mod payments;
+pub mod telemetry;
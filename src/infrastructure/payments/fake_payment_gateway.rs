@@ -0,0 +1,193 @@
+use crate::core::{
+    entities::payment::PaymentCard, errors::payment_errors::PaymentError,
+    ports::payment_port::IPaymentGateway,
+};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+};
+
+#[derive(Debug, Default)]
+struct Authorization {
+    amount: i64,
+    captured: i64,
+    refunded: i64,
+    /// Claves de las devoluciones ya aplicadas.
+    refund_keys: Vec<String>,
+    voided: bool,
+}
+
+/// Pasarela en memoria para desarrollo local y pruebas. Las referencias son secuenciales, por lo
+/// que el resultado de cada operación es siempre el mismo para la misma secuencia de llamadas.
+pub struct FakePaymentGateway {
+    declined_cards: Vec<String>,
+    sequence: AtomicU64,
+    authorizations: Mutex<HashMap<String, Authorization>>,
+}
+
+impl FakePaymentGateway {
+    pub fn new(declined_cards: Vec<String>) -> Self {
+        FakePaymentGateway {
+            declined_cards,
+            sequence: AtomicU64::new(0),
+            authorizations: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn with_authorization<T>(
+        &self,
+        reference: &str,
+        f: impl FnOnce(&mut Authorization) -> Result<T, PaymentError>,
+    ) -> Result<T, PaymentError> {
+        let mut authorizations = self.authorizations.lock().map_err(|err| {
+            log::error!("{:?}", err);
+            PaymentError::Unexpected
+        })?;
+
+        match authorizations.get_mut(reference) {
+            Some(authorization) => f(authorization),
+            None => Err(PaymentError::Gateway(format!(
+                "unknown reference {}",
+                reference
+            ))),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl IPaymentGateway for FakePaymentGateway {
+    async fn authorize(&self, card: &PaymentCard, amount: i64) -> Result<String, PaymentError> {
+        if self.declined_cards.contains(&card.number) {
+            return Err(PaymentError::Declined("card declined".to_string()));
+        }
+
+        let sequence = self.sequence.fetch_add(1, Ordering::SeqCst) + 1;
+        let reference = format!("fake_auth_{:06}", sequence);
+
+        self.authorizations
+            .lock()
+            .map_err(|err| {
+                log::error!("{:?}", err);
+                PaymentError::Unexpected
+            })?
+            .insert(
+                reference.clone(),
+                Authorization {
+                    amount,
+                    ..Authorization::default()
+                },
+            );
+
+        Ok(reference)
+    }
+
+    async fn capture(&self, reference: &str, amount: i64) -> Result<(), PaymentError> {
+        self.with_authorization(reference, |authorization| {
+            if authorization.voided || authorization.captured > 0 {
+                return Err(PaymentError::Gateway("authorization closed".to_string()));
+            }
+            if amount > authorization.amount {
+                return Err(PaymentError::Gateway(
+                    "amount exceeds authorization".to_string(),
+                ));
+            }
+
+            authorization.captured = amount;
+            Ok(())
+        })
+    }
+
+    async fn void(&self, reference: &str) -> Result<(), PaymentError> {
+        self.with_authorization(reference, |authorization| {
+            if authorization.captured > 0 {
+                return Err(PaymentError::Gateway("authorization captured".to_string()));
+            }
+
+            authorization.voided = true;
+            Ok(())
+        })
+    }
+
+    async fn refund(
+        &self,
+        reference: &str,
+        amount: i64,
+        idempotency_key: &str,
+    ) -> Result<(), PaymentError> {
+        self.with_authorization(reference, |authorization| {
+            if authorization
+                .refund_keys
+                .iter()
+                .any(|key| key == idempotency_key)
+            {
+                return Ok(());
+            }
+            if authorization.refunded + amount > authorization.captured {
+                return Err(PaymentError::Gateway(
+                    "amount exceeds captured amount".to_string(),
+                ));
+            }
+
+            authorization.refunded += amount;
+            authorization.refund_keys.push(idempotency_key.to_string());
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod fake_payment_gateway_tests {
+    use super::*;
+
+    fn card(number: &str) -> PaymentCard {
+        PaymentCard {
+            number: number.to_string(),
+            expiry_month: 12,
+            expiry_year: 2099,
+            cvc: "123".to_string(),
+        }
+    }
+
+    #[actix_web::test]
+    async fn test_authorize_declines_configured_cards() {
+        let gateway = FakePaymentGateway::new(vec!["4000000000000002".to_string()]);
+
+        let result = gateway.authorize(&card("4000000000000002"), 1000).await;
+        assert!(matches!(result, Err(PaymentError::Declined(_))));
+
+        let reference = gateway.authorize(&card("4242424242424242"), 1000).await;
+        assert_eq!(reference.unwrap(), "fake_auth_000001");
+    }
+
+    #[actix_web::test]
+    async fn test_capture_and_refund_limits() {
+        let gateway = FakePaymentGateway::new(vec![]);
+        let reference = gateway
+            .authorize(&card("4242424242424242"), 1000)
+            .await
+            .unwrap();
+
+        assert!(gateway.capture(&reference, 1500).await.is_err());
+        assert!(gateway.capture(&reference, 1000).await.is_ok());
+        assert!(gateway.void(&reference).await.is_err());
+        assert!(gateway.refund(&reference, 600, "r-1").await.is_ok());
+        assert!(gateway.refund(&reference, 600, "r-2").await.is_err());
+    }
+
+    #[actix_web::test]
+    async fn test_refund_with_the_same_key_is_applied_once() {
+        let gateway = FakePaymentGateway::new(vec![]);
+        let reference = gateway
+            .authorize(&card("4242424242424242"), 1000)
+            .await
+            .unwrap();
+        gateway.capture(&reference, 1000).await.unwrap();
+
+        assert!(gateway.refund(&reference, 600, "r-1").await.is_ok());
+        assert!(gateway.refund(&reference, 600, "r-1").await.is_ok());
+        assert!(gateway.refund(&reference, 600, "r-2").await.is_err());
+    }
+}
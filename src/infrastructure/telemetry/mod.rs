@@ -0,0 +1,2 @@
+pub mod otlp;
+pub mod traced_user_service;
@@ -0,0 +1,88 @@
+use crate::infrastructure::config::TracingConfig;
+use opentelemetry::trace::TracerProvider as _;
+use opentelemetry_otlp::{ExporterBuildError, SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{trace::SdkTracerProvider, Resource};
+use tracing::Subscriber;
+use tracing_subscriber::layer::SubscriberExt;
+
+const SERVICE_NAME: &str = "cafelatte";
+
+/// Exportación de trazas por OTLP. Hay que cerrarla al apagar el servidor para enviar los spans
+/// que todavía están en el buffer.
+pub struct Tracing {
+    provider: Option<SdkTracerProvider>,
+}
+
+/// Instala el exportador si la configuración indica un colector. Si no, no se instala ningún
+/// suscriptor y los spans no se registran.
+pub fn init(config: &TracingConfig) -> Result<Tracing, ExporterBuildError> {
+    let Some(endpoint) = &config.otlp_endpoint else {
+        return Ok(Tracing { provider: None });
+    };
+
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+        .build()?;
+    let provider = SdkTracerProvider::builder()
+        .with_resource(Resource::builder().with_service_name(SERVICE_NAME).build())
+        .with_batch_exporter(exporter)
+        .build();
+
+    if let Err(err) = tracing::subscriber::set_global_default(subscriber(&provider)) {
+        log::warn!("Tracing subscriber already installed: {}", err);
+    }
+    log::info!("Exporting traces to {}", endpoint);
+
+    Ok(Tracing {
+        provider: Some(provider),
+    })
+}
+
+/// Suscriptor que convierte los spans de `tracing` en spans de OpenTelemetry.
+pub(crate) fn subscriber(provider: &SdkTracerProvider) -> impl Subscriber + Send + Sync {
+    tracing_subscriber::registry()
+        .with(tracing_opentelemetry::layer().with_tracer(provider.tracer(SERVICE_NAME)))
+}
+
+impl Tracing {
+    pub fn shutdown(self) {
+        if let Some(provider) = self.provider {
+            if let Err(err) = provider.shutdown() {
+                log::error!("Cannot flush pending traces: {}", err);
+            }
+        }
+    }
+}
+
+/// Exportador que guarda los spans en memoria para revisarlos en los tests.
+#[cfg(test)]
+#[derive(Clone, Debug, Default)]
+pub(crate) struct RecordingExporter {
+    spans: std::sync::Arc<std::sync::Mutex<Vec<opentelemetry_sdk::trace::SpanData>>>,
+}
+
+#[cfg(test)]
+impl RecordingExporter {
+    /// Proveedor que exporta cada span apenas termina.
+    pub(crate) fn provider(&self) -> SdkTracerProvider {
+        SdkTracerProvider::builder()
+            .with_simple_exporter(self.clone())
+            .build()
+    }
+
+    pub(crate) fn spans(&self) -> Vec<opentelemetry_sdk::trace::SpanData> {
+        self.spans.lock().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+impl opentelemetry_sdk::trace::SpanExporter for RecordingExporter {
+    fn export(
+        &self,
+        batch: Vec<opentelemetry_sdk::trace::SpanData>,
+    ) -> impl std::future::Future<Output = opentelemetry_sdk::error::OTelSdkResult> + Send {
+        self.spans.lock().unwrap().extend(batch);
+        futures::future::ready(Ok(()))
+    }
+}
@@ -0,0 +1,159 @@
+use crate::core::{
+    entities::{login::Login, user::User, user_status::UserStatus},
+    errors::user_errors::UserError,
+    ports::user_port::IUserService,
+};
+use async_trait::async_trait;
+use std::{future::Future, sync::Arc};
+use tracing::{field::Empty, Instrument, Span};
+
+/// Envuelve el servicio de usuarios con un span por método. Las consultas a la base y el hash
+/// de bcrypt quedan como hijos, así se ve en qué se va el tiempo de un login.
+pub struct TracedUserService {
+    inner: Arc<dyn IUserService>,
+}
+
+impl TracedUserService {
+    pub fn new(inner: Arc<dyn IUserService>) -> Self {
+        TracedUserService { inner }
+    }
+}
+
+fn user_span(method: &'static str) -> Span {
+    tracing::info_span!(
+        "IUserService",
+        otel.name = method,
+        otel.status_description = Empty,
+        user.id = Empty,
+    )
+}
+
+/// Ejecuta la llamada dentro del span y lo marca con error si falla.
+async fn traced<T>(
+    span: Span,
+    call: impl Future<Output = Result<T, UserError>>,
+) -> Result<T, UserError> {
+    let result = call.instrument(span.clone()).await;
+    if let Err(err) = &result {
+        span.record("otel.status_description", err.to_string());
+    }
+
+    result
+}
+
+#[async_trait]
+impl IUserService for TracedUserService {
+    async fn register(&self, user: User) -> Result<Login, UserError> {
+        traced(
+            user_span("IUserService::register"),
+            self.inner.register(user),
+        )
+        .await
+    }
+
+    async fn login(&self, email: String, password: String) -> Result<Login, UserError> {
+        traced(
+            user_span("IUserService::login"),
+            self.inner.login(email, password),
+        )
+        .await
+    }
+
+    async fn get_users(&self) -> Result<Vec<User>, UserError> {
+        traced(user_span("IUserService::get_users"), self.inner.get_users()).await
+    }
+
+    async fn get_user_by_id(&self, id: u8) -> Result<User, UserError> {
+        let span = user_span("IUserService::get_user_by_id");
+        span.record("user.id", id);
+        traced(span, self.inner.get_user_by_id(id)).await
+    }
+
+    async fn update_user(&self, user_id: i32, user: User) -> Result<(), UserError> {
+        let span = user_span("IUserService::update_user");
+        span.record("user.id", user_id);
+        traced(span, self.inner.update_user(user_id, user)).await
+    }
+
+    async fn delete_user(&self, user_id: i32) -> Result<(), UserError> {
+        let span = user_span("IUserService::delete_user");
+        span.record("user.id", user_id);
+        traced(span, self.inner.delete_user(user_id)).await
+    }
+
+    async fn create_administrator(&self, user: User) -> Result<User, UserError> {
+        traced(
+            user_span("IUserService::create_administrator"),
+            self.inner.create_administrator(user),
+        )
+        .await
+    }
+
+    async fn get_users_by_status(&self, status: UserStatus) -> Result<Vec<User>, UserError> {
+        traced(
+            user_span("IUserService::get_users_by_status"),
+            self.inner.get_users_by_status(status),
+        )
+        .await
+    }
+
+    async fn suspend_user(&self, user_id: i32) -> Result<(), UserError> {
+        let span = user_span("IUserService::suspend_user");
+        span.record("user.id", user_id);
+        traced(span, self.inner.suspend_user(user_id)).await
+    }
+
+    async fn reactivate_user(&self, user_id: i32) -> Result<(), UserError> {
+        let span = user_span("IUserService::reactivate_user");
+        span.record("user.id", user_id);
+        traced(span, self.inner.reactivate_user(user_id)).await
+    }
+
+    async fn reset_password(&self, user_id: i32, password: String) -> Result<(), UserError> {
+        let span = user_span("IUserService::reset_password");
+        span.record("user.id", user_id);
+        traced(span, self.inner.reset_password(user_id, password)).await
+    }
+
+    async fn purge_deleted_users(&self, retention_days: u32) -> Result<u64, UserError> {
+        traced(
+            user_span("IUserService::purge_deleted_users"),
+            self.inner.purge_deleted_users(retention_days),
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod traced_user_service_tests {
+    use super::*;
+    use crate::{
+        core::services::user_service_stub::UserServiceStub,
+        infrastructure::telemetry::otlp::{subscriber, RecordingExporter},
+    };
+    use opentelemetry::trace::Status;
+
+    #[actix_web::test]
+    async fn test_each_call_is_recorded_in_its_own_span() {
+        let exporter = RecordingExporter::default();
+        let provider = exporter.provider();
+        let _guard = tracing::subscriber::set_default(subscriber(&provider));
+
+        let ok = TracedUserService::new(Arc::new(UserServiceStub { status_code: 200 }));
+        let failing = TracedUserService::new(Arc::new(UserServiceStub { status_code: 500 }));
+        ok.login("a@b.com".to_string(), "password".to_string())
+            .await
+            .unwrap();
+        failing.suspend_user(7).await.unwrap_err();
+
+        let spans = exporter.spans();
+        assert_eq!(spans.len(), 2);
+        assert_eq!(spans[0].name, "IUserService::login");
+        assert_eq!(spans[0].status, Status::Unset);
+        assert_eq!(spans[1].name, "IUserService::suspend_user");
+        assert!(matches!(spans[1].status, Status::Error { .. }));
+        assert!(spans[1].attributes.iter().any(
+            |attribute| attribute.key.as_str() == "user.id" && attribute.value.as_str() == "7"
+        ));
+    }
+}
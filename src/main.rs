@@ -1,35 +1,346 @@
 use actix_web::{middleware::Logger, web, App, HttpServer};
+#[cfg(feature = "postgres")]
+use cafelatte::infrastructure::data::postgres;
+#[cfg(feature = "sqlite")]
+use cafelatte::infrastructure::data::sqlite;
 use cafelatte::{
-    core::{ports::user_port::IUserService, services::user_service::UserService},
+    core::{
+        ports::{
+            cart_port::{ICartRepository, ICartService},
+            catalog_port::{ICatalogRepository, ICatalogService},
+            delivery_port::{IDeliveryRepository, IDeliveryService},
+            health_port::{IHealthCheck, IHealthService},
+            inventory_port::{IInventoryRepository, IInventoryService},
+            loyalty_port::{ILoyaltyRepository, ILoyaltyService},
+            metrics_port::IMetricsRecorder,
+            order_port::{IOrderRepository, IOrderService},
+            payment_port::{IPaymentRepository, IPaymentService},
+            promotion_port::{IPromotionRepository, IPromotionService},
+            store_port::{IStoreRepository, IStoreService},
+            unit_of_work_port::IUnitOfWork,
+            user_port::{IUserRepository, IUserService},
+        },
+        services::{
+            cart_service::CartService, catalog_service::CatalogService,
+            delivery_service::DeliveryService, health_service::HealthService,
+            inventory_service::InventoryService, loyalty_service::LoyaltyService,
+            order_service::OrderService, payment_service::PaymentService,
+            promotion_service::PromotionService, store_service::StoreService,
+            user_service::UserService,
+        },
+    },
     infrastructure::{
-        api::handlers::user_handler::routes,
-        data::{mysql, repositories::user_repository::UserRepository},
+        api::{
+            handlers::{
+                cart_handler, catalog_handler, delivery_handler, health_handler, inventory_handler,
+                loyalty_handler, metrics_handler, order_handler, payment_handler,
+                promotion_handler, queue_handler, store_handler, user_handler,
+            },
+            middlewares::{
+                metrics_middleware::MetricsMiddlewareFactory,
+                request_id_middleware::RequestIdMiddlewareFactory,
+                tracing_middleware::TracingMiddlewareFactory,
+            },
+        },
+        config::{Config, DatabaseBackend, LoggingConfig, MigrationMode, PaymentGateway},
+        data::{
+            health,
+            memory::{self, MemoryDatabase},
+            migrations::{self, Migrations},
+            mysql,
+            pool::DbPool,
+            Repositories,
+        },
+        events::{
+            order_event_broadcaster::OrderEventBroadcaster,
+            scheduled_order_releaser::ScheduledOrderReleaser, stock_alert_logger::StockAlertLogger,
+        },
+        logging,
+        metrics::prometheus_metrics::PrometheusMetrics,
+        payments::fake_payment_gateway::FakePaymentGateway,
+        telemetry::{otlp, traced_user_service::TracedUserService},
     },
 };
-use std::{env, io, sync};
+use sqlx::{migrate::Migrate, Pool};
+use std::{future::Future, io, process, sync};
 
 #[actix_web::main]
 async fn main() -> io::Result<()> {
-    env_logger::init_from_env(env_logger::Env::default().default_filter_or("debug"));
-
-    log::info!("Loading environment variables...");
-    dotenv::dotenv().ok();
+    // El logger se configura desde la configuración, así que se inicializa después de cargarla.
+    let config = Config::load();
+    match &config {
+        Ok(config) => logging::init(&config.logging),
+        Err(_) => logging::init(&LoggingConfig::default()),
+    }
+    let config = config.unwrap_or_else(|err| {
+        log::error!("Invalid configuration: {}", err);
+        process::exit(1);
+    });
+    let telemetry = otlp::init(&config.tracing).unwrap_or_else(|err| {
+        log::error!("Cannot start the trace exporter: {}", err);
+        process::exit(1);
+    });
 
-    log::info!("Connecting to database...");
-    let conn = mysql::connect_to_database().await.unwrap();
-    let user_repo = UserRepository::new(conn);
-    let user_service: sync::Arc<dyn IUserService> = sync::Arc::new(UserService::new(user_repo));
+    let order_events = OrderEventBroadcaster::default();
+    let mut metrics = PrometheusMetrics::new();
+    let services = match config.database.backend {
+        DatabaseBackend::MySql => {
+            let conn =
+                prepare_database(mysql::connect_to_database(&config.database), &config).await;
+            metrics = metrics.with_pool(conn.clone());
+            build_services(
+                mysql::repositories(DbPool::new(conn.clone()).observe_acquire(metrics.clone())),
+                health::checks(&conn),
+                &metrics,
+                &config,
+                &order_events,
+            )
+        }
+        #[cfg(feature = "postgres")]
+        DatabaseBackend::Postgres => {
+            let conn =
+                prepare_database(postgres::connect_to_database(&config.database), &config).await;
+            metrics = metrics.with_pool(conn.clone());
+            build_services(
+                postgres::repositories(DbPool::new(conn.clone()).observe_acquire(metrics.clone())),
+                health::checks(&conn),
+                &metrics,
+                &config,
+                &order_events,
+            )
+        }
+        #[cfg(feature = "sqlite")]
+        DatabaseBackend::Sqlite => {
+            let conn =
+                prepare_database(sqlite::connect_to_database(&config.database), &config).await;
+            metrics = metrics.with_pool(conn.clone());
+            build_services(
+                sqlite::repositories(DbPool::new(conn.clone()).observe_acquire(metrics.clone())),
+                health::checks(&conn),
+                &metrics,
+                &config,
+                &order_events,
+            )
+        }
+        #[cfg(not(feature = "postgres"))]
+        DatabaseBackend::Postgres => unreachable!("rejected when loading the configuration"),
+        #[cfg(not(feature = "sqlite"))]
+        DatabaseBackend::Sqlite => unreachable!("rejected when loading the configuration"),
+        DatabaseBackend::Memory => {
+            log::warn!("Using the in-memory database; data is lost on shutdown.");
+            build_services(
+                memory::repositories(MemoryDatabase::new()),
+                vec![],
+                &metrics,
+                &config,
+                &order_events,
+            )
+        }
+    };
+    ScheduledOrderReleaser::new(services.order.clone(), config.orders.release_interval).spawn();
 
-    let server_port = env::var("SERVER_PORT").unwrap().parse::<u16>().unwrap();
-    log::info!("Listening server on port: {:?}", server_port);
+    let server_address = (config.server.host.clone(), config.server.port);
+    log::info!(
+        "Listening server on: {}:{}",
+        server_address.0,
+        server_address.1
+    );
     HttpServer::new(move || {
         App::new()
-            .wrap(Logger::default())
-            .wrap(Logger::new("%a %{User-Agent}i"))
-            .service(web::scope("").configure(routes))
-            .app_data(web::Data::new(user_service.clone()))
+            .wrap(MetricsMiddlewareFactory::new(metrics.clone()))
+            .wrap(TracingMiddlewareFactory)
+            .wrap(RequestIdMiddlewareFactory)
+            // El Logger escribe cuando termina el cuerpo, fuera del contexto de la petición, así
+            // que toma el id de la cabecera de la respuesta. `%U` omite la query string.
+            .wrap(
+                Logger::new(
+                    "%a \"%{method}xi %U\" %s %b %T %{User-Agent}i request_id=%{x-request-id}o",
+                )
+                .custom_request_replace("method", |req| req.method().to_string()),
+            )
+            .service(
+                web::scope("")
+                    .configure(cart_handler::routes)
+                    .configure(loyalty_handler::routes)
+                    .configure(delivery_handler::routes)
+                    .configure(user_handler::routes)
+                    .configure(catalog_handler::routes)
+                    .configure(order_handler::routes)
+                    .configure(queue_handler::routes)
+                    .configure(payment_handler::routes)
+                    .configure(promotion_handler::routes)
+                    .configure(inventory_handler::routes)
+                    .configure(store_handler::routes)
+                    .configure(health_handler::routes)
+                    .configure(metrics_handler::routes),
+            )
+            .app_data(web::Data::new(services.user.clone()))
+            .app_data(web::Data::new(services.catalog.clone()))
+            .app_data(web::Data::new(services.order.clone()))
+            .app_data(web::Data::new(services.cart.clone()))
+            .app_data(web::Data::new(services.payment.clone()))
+            .app_data(web::Data::new(services.promotion.clone()))
+            .app_data(web::Data::new(services.loyalty.clone()))
+            .app_data(web::Data::new(services.inventory.clone()))
+            .app_data(web::Data::new(services.store.clone()))
+            .app_data(web::Data::new(services.delivery.clone()))
+            .app_data(web::Data::new(services.health.clone()))
+            .app_data(web::Data::new(order_events.clone()))
+            .app_data(web::Data::new(metrics.clone()))
+            .app_data(web::Data::new(
+                sync::Arc::new(metrics.clone()) as sync::Arc<dyn IMetricsRecorder>
+            ))
+            .app_data(web::Data::new(config.clone()))
     })
-    .bind(("127.0.0.1", server_port))?
+    .bind(server_address)?
     .run()
-    .await
+    .await?;
+
+    telemetry.shutdown();
+    Ok(())
+}
+
+/// Servicios que reciben los handlers.
+struct Services {
+    user: sync::Arc<dyn IUserService>,
+    catalog: sync::Arc<dyn ICatalogService>,
+    loyalty: sync::Arc<dyn ILoyaltyService>,
+    inventory: sync::Arc<dyn IInventoryService>,
+    store: sync::Arc<dyn IStoreService>,
+    delivery: sync::Arc<dyn IDeliveryService>,
+    order: sync::Arc<dyn IOrderService>,
+    payment: sync::Arc<dyn IPaymentService>,
+    promotion: sync::Arc<dyn IPromotionService>,
+    cart: sync::Arc<dyn ICartService>,
+    health: sync::Arc<dyn IHealthService>,
+}
+
+/// Pasarela de pago configurada. Termina el proceso si no hay ninguna.
+fn payment_gateway(config: &Config) -> FakePaymentGateway {
+    match config.payments.gateway {
+        Some(PaymentGateway::Fake) => {
+            log::warn!("Using the fake payment gateway; cards are never charged.");
+            FakePaymentGateway::new(config.payments.fake_gateway_declined_cards.clone())
+        }
+        None => {
+            log::error!("No payment gateway configured; set PAYMENT_GATEWAY");
+            process::exit(1);
+        }
+    }
+}
+
+/// Conecta con la base y aplica o verifica las migraciones según la configuración. Termina el
+/// proceso si algo falla.
+async fn prepare_database<DB>(
+    connecting: impl Future<Output = Result<Pool<DB>, sqlx::Error>>,
+    config: &Config,
+) -> Pool<DB>
+where
+    DB: Migrations,
+    DB::Connection: Migrate,
+{
+    log::info!("Connecting to database...");
+    let conn = connecting.await.unwrap_or_else(|err| {
+        log::error!("Cannot connect to database: {}", err);
+        process::exit(1);
+    });
+
+    let migrated = match config.database.migrations {
+        MigrationMode::Apply => {
+            log::info!("Applying database migrations...");
+            migrations::run(&conn).await
+        }
+        MigrationMode::Check => migrations::check(&conn).await,
+    };
+    if let Err(err) = migrated {
+        log::error!("Database schema is not up to date: {}", err);
+        process::exit(1);
+    }
+
+    conn
+}
+
+fn build_services<U, Ct, L, I, S, D, O, Py, Pr, Ca, W>(
+    repos: Repositories<U, Ct, L, I, S, D, O, Py, Pr, Ca, W>,
+    health_checks: Vec<sync::Arc<dyn IHealthCheck>>,
+    metrics: &PrometheusMetrics,
+    config: &Config,
+    order_events: &OrderEventBroadcaster,
+) -> Services
+where
+    U: IUserRepository + 'static,
+    Ct: ICatalogRepository + Clone + 'static,
+    L: ILoyaltyRepository<Transaction = W::Transaction> + Clone + 'static,
+    I: IInventoryRepository + Clone + 'static,
+    S: IStoreRepository<Transaction = W::Transaction> + Clone + 'static,
+    D: IDeliveryRepository + Clone + 'static,
+    O: IOrderRepository<Transaction = W::Transaction> + Clone + 'static,
+    Py: IPaymentRepository<Transaction = W::Transaction> + 'static,
+    Pr: IPromotionRepository<Transaction = W::Transaction> + Clone + 'static,
+    Ca: ICartRepository<Transaction = W::Transaction> + 'static,
+    W: IUnitOfWork + Clone + 'static,
+{
+    let pricing_rules = config.pricing;
+    let loyalty_rules = config.loyalty;
+
+    Services {
+        user: sync::Arc::new(TracedUserService::new(sync::Arc::new(UserService::new(
+            repos.user,
+            config.security.jwt_secret.clone(),
+            sync::Arc::new(metrics.clone()),
+        )))),
+        catalog: sync::Arc::new(CatalogService::new(repos.catalog.clone())),
+        loyalty: sync::Arc::new(LoyaltyService::new(
+            repos.loyalty.clone(),
+            repos.unit_of_work.clone(),
+        )),
+        inventory: sync::Arc::new(InventoryService::new(
+            repos.inventory.clone(),
+            repos.catalog.clone(),
+            sync::Arc::new(StockAlertLogger),
+        )),
+        store: sync::Arc::new(StoreService::new(
+            repos.store.clone(),
+            repos.catalog.clone(),
+        )),
+        delivery: sync::Arc::new(DeliveryService::new(repos.delivery.clone())),
+        order: sync::Arc::new(OrderService::new(
+            repos.order.clone(),
+            repos.catalog.clone(),
+            repos.inventory,
+            repos.store.clone(),
+            repos.delivery.clone(),
+            repos.unit_of_work.clone(),
+            sync::Arc::new(order_events.clone()),
+            sync::Arc::new(StockAlertLogger),
+            sync::Arc::new(metrics.clone()),
+            pricing_rules,
+            loyalty_rules,
+        )),
+        payment: sync::Arc::new(PaymentService::new(
+            repos.payment,
+            repos.order,
+            payment_gateway(config),
+            repos.unit_of_work.clone(),
+            loyalty_rules,
+        )),
+        promotion: sync::Arc::new(PromotionService::new(
+            repos.promotion.clone(),
+            repos.catalog.clone(),
+        )),
+        cart: sync::Arc::new(CartService::new(
+            repos.cart,
+            repos.catalog,
+            repos.promotion,
+            repos.loyalty,
+            repos.store,
+            repos.delivery,
+            repos.unit_of_work,
+            sync::Arc::new(order_events.clone()),
+            sync::Arc::new(metrics.clone()),
+            pricing_rules,
+            loyalty_rules,
+        )),
+        health: sync::Arc::new(HealthService::new(health_checks)),
+    }
 }
@@ -4,13 +4,10 @@ use crate::core::{
 };
 use bcrypt::{hash, verify};
 use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
-use std::env;
 
-pub fn create_jwt_token(payload: UserPayload) -> Result<String, JwtError> {
+pub fn create_jwt_token(payload: UserPayload, secret: &str) -> Result<String, JwtError> {
     match serde_json::to_value(payload) {
         Ok(payload) => {
-            let secret = env::var("JWT_SECRET").unwrap();
-
             match encode(
                 &Header::new(jsonwebtoken::Algorithm::HS256),
                 &payload,
@@ -32,9 +29,7 @@ pub fn create_jwt_token(payload: UserPayload) -> Result<String, JwtError> {
     }
 }
 
-pub fn verify_jwt_token(mut token: String) -> Result<UserPayload, JwtError> {
-    let secret = env::var("JWT_SECRET").unwrap();
-
+pub fn verify_jwt_token(mut token: String, secret: &str) -> Result<UserPayload, JwtError> {
     match token.strip_prefix("Bearer ") {
         Some(t) => token = t.to_string(),
         None => {
@@ -58,7 +53,10 @@ pub fn verify_jwt_token(mut token: String) -> Result<UserPayload, JwtError> {
         Err(err) => {
             log::error!("{}", err);
 
-            Err(JwtError::Unexpected)
+            match err.kind() {
+                jsonwebtoken::errors::ErrorKind::ExpiredSignature => Err(JwtError::Expired),
+                _ => Err(JwtError::Invalid),
+            }
         }
     }
 }
@@ -73,6 +71,7 @@ pub fn verify_jwt_token(mut token: String) -> Result<UserPayload, JwtError> {
 /// assert_ne!(result, "password");
 /// ```
 pub fn crypt_password(password: &String) -> Result<String, UserError> {
+    let _span = tracing::info_span!("bcrypt.hash", bcrypt.cost = bcrypt::DEFAULT_COST).entered();
     match hash(password, bcrypt::DEFAULT_COST) {
         Ok(crypted_password) => Ok(crypted_password),
         Err(err) => {
@@ -96,8 +95,9 @@ pub fn crypt_password(password: &String) -> Result<String, UserError> {
 ///     cafelatte::utils::security_util::verify_password(password, &crypted_password));
 /// ```
 pub fn verify_password(password_to_verify: String, password: &str) -> bool {
+    let _span = tracing::info_span!("bcrypt.verify").entered();
     match verify(password_to_verify, password) {
-        Ok(_) => true,
+        Ok(valid) => valid,
         Err(err) => {
             log::error!("{}", err);
             false